@@ -72,42 +72,6 @@ e2e_pdu! {
         }
     "#,
     r#"
-    #[doc = " Anonymous SEQUENCE OF member "]
-    #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
-    #[rasn(automatic_tags, identifier = "SEQUENCE")]
-    pub struct AnonymousA2XPC5FlowBitRatesIEExtensions {
-        pub id: Integer,
-        pub criticality: Integer,
-        #[rasn(identifier = "extensionValue")]
-        pub extension_value: Any,
-    }
-    impl AnonymousA2XPC5FlowBitRatesIEExtensions {
-        pub fn new(id: Integer, criticality: Integer, extension_value: Any) -> Self {
-            Self {
-                id,
-                criticality,
-                extension_value,
-            }
-        }
-    }
-    impl AnonymousA2XPC5FlowBitRatesIEExtensions {
-        pub fn decode_extension_value<D: Decoder>(
-            &self,
-            decoder: &mut D,
-        ) -> Result<A2XPC5FlowBitRatesExtIEs_Extension, D::Error> {
-            A2XPC5FlowBitRatesExtIEs_Extension::decode(
-                decoder,
-                Some(&self.extension_value),
-                &self.id,
-            )
-        }
-    }
-    #[doc = " Inner type "]
-    #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
-    #[rasn(delegate, size("1.."))]
-    pub struct A2XPC5FlowBitRatesIEExtensions(
-        pub SequenceOf<AnonymousA2XPC5FlowBitRatesIEExtensions>,
-    );
     #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
     #[rasn(automatic_tags, identifier = "A2X-PC5-FlowBitRates")]
     #[non_exhaustive]
@@ -115,12 +79,12 @@ e2e_pdu! {
         #[rasn(identifier = "a2X-GuaranteedFlowBitRate")]
         pub a2_x_guaranteed_flow_bit_rate: bool,
         #[rasn(identifier = "iE-Extensions")]
-        pub i_e_extensions: Option<A2XPC5FlowBitRatesIEExtensions>,
+        pub i_e_extensions: Option<ProtocolExtensionContainerA2XPC5FlowBitRatesExtIEs>,
     }
     impl A2XPC5FlowBitRates {
         pub fn new(
             a2_x_guaranteed_flow_bit_rate: bool,
-            i_e_extensions: Option<A2XPC5FlowBitRatesIEExtensions>,
+            i_e_extensions: Option<ProtocolExtensionContainerA2XPC5FlowBitRatesExtIEs>,
         ) -> Self {
             Self {
                 a2_x_guaranteed_flow_bit_rate,
@@ -280,6 +244,47 @@ e2e_pdu! {
             }
         }
     }
+    #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+    #[rasn(
+        delegate,
+        size("1.."),
+        identifier = "ProtocolExtensionContainer-A2X-PC5-FlowBitRates-ExtIEs"
+    )]
+    pub struct ProtocolExtensionContainerA2XPC5FlowBitRatesExtIEs(
+        pub SequenceOf<ProtocolExtensionFieldA2XPC5FlowBitRatesExtIEs>,
+    );
+    #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+    #[rasn(
+        automatic_tags,
+        identifier = "ProtocolExtensionField-A2X-PC5-FlowBitRates-ExtIEs"
+    )]
+    pub struct ProtocolExtensionFieldA2XPC5FlowBitRatesExtIEs {
+        pub id: Integer,
+        pub criticality: Integer,
+        #[rasn(identifier = "extensionValue")]
+        pub extension_value: Any,
+    }
+    impl ProtocolExtensionFieldA2XPC5FlowBitRatesExtIEs {
+        pub fn new(id: Integer, criticality: Integer, extension_value: Any) -> Self {
+            Self {
+                id,
+                criticality,
+                extension_value,
+            }
+        }
+    }
+    impl ProtocolExtensionFieldA2XPC5FlowBitRatesExtIEs {
+        pub fn decode_extension_value<D: Decoder>(
+            &self,
+            decoder: &mut D,
+        ) -> Result<A2XPC5FlowBitRatesExtIEs_Extension, D::Error> {
+            A2XPC5FlowBitRatesExtIEs_Extension::decode(
+                decoder,
+                Some(&self.extension_value),
+                &self.id,
+            )
+        }
+    }
     "#
 }
 
@@ -302,14 +307,6 @@ r#"
             }
         "#,
     r#"
-            #[doc = "Inner type"]
-            #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
-            #[rasn(choice, automatic_tags)]
-            pub enum LocationMeasurementIndicationIEsMeasurementIndication {
-                release(()),
-                setup(LocationMeasurementInfo),
-            }
-            
             #[doc = "Inner type"]
             #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
             #[rasn(automatic_tags)]
@@ -331,7 +328,7 @@ r#"
             #[rasn(automatic_tags, identifier = "LocationMeasurementIndication-IEs")]
             pub struct LocationMeasurementIndicationIEs {
                 #[rasn(identifier = "measurementIndication")]
-                pub measurement_indication: LocationMeasurementIndicationIEsMeasurementIndication,
+                pub measurement_indication: SetupReleaseLocationMeasurementInfo,
                 #[rasn(identifier = "lateNonCriticalExtension")]
                 pub late_non_critical_extension: Option<OctetString>,
                 #[rasn(identifier = "nonCriticalExtension")]
@@ -340,7 +337,7 @@ r#"
             
             impl LocationMeasurementIndicationIEs {
                 pub fn new(
-                    measurement_indication: LocationMeasurementIndicationIEsMeasurementIndication,
+                    measurement_indication: SetupReleaseLocationMeasurementInfo,
                     late_non_critical_extension: Option<OctetString>,
                     non_critical_extension: Option<LocationMeasurementIndicationIEsNonCriticalExtension>,
                 ) -> Self {
@@ -363,5 +360,98 @@ r#"
                     Self { test }
                 }
             }
+
+            #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+            #[rasn(choice, automatic_tags, identifier = "SetupRelease-LocationMeasurementInfo")]
+            pub enum SetupReleaseLocationMeasurementInfo {
+                release(()),
+                setup(LocationMeasurementInfo),
+            }
         "#
 }
+
+e2e_pdu! {
+distinct_instantiations,
+r#"
+        Container { ElementType, INTEGER: maxSize } ::= SEQUENCE (SIZE (1..maxSize)) OF ElementType
+
+        Pair { ElementType } ::= SEQUENCE {
+            first ElementType,
+            rest Container { ElementType, 4 }
+        }
+
+        Message ::= SEQUENCE {
+            ids Container { INTEGER, 8 },
+            names Container { IA5String, 8 },
+            more-ids Container { INTEGER, 8 },
+            pair Pair { BOOLEAN }
+        }
+    "#,
+r#"
+        #[doc = "Anonymous SEQUENCE OF member"]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash, Copy)]
+        #[rasn(delegate, identifier = "BOOLEAN")]
+        pub struct AnonymousContainerBOOLEAN4(pub bool);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(delegate, size("1..=4"), identifier = "Container-BOOLEAN-4")]
+        pub struct ContainerBOOLEAN4(pub SequenceOf<AnonymousContainerBOOLEAN4>);
+
+        #[doc = "Anonymous SEQUENCE OF member"]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(delegate, identifier = "IA5String")]
+        pub struct AnonymousContainerIA5String8(pub Ia5String);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(delegate, size("1..=8"), identifier = "Container-IA5String-8")]
+        pub struct ContainerIA5String8(pub SequenceOf<AnonymousContainerIA5String8>);
+
+        #[doc = "Anonymous SEQUENCE OF member"]
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(delegate, identifier = "INTEGER")]
+        pub struct AnonymousContainerINTEGER8(pub Integer);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(delegate, size("1..=8"), identifier = "Container-INTEGER-8")]
+        pub struct ContainerINTEGER8(pub SequenceOf<AnonymousContainerINTEGER8>);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(automatic_tags)]
+        pub struct Message {
+            pub ids: ContainerINTEGER8,
+            pub names: ContainerIA5String8,
+            #[rasn(identifier = "more-ids")]
+            pub more_ids: ContainerINTEGER8,
+            pub pair: PairBOOLEAN,
+        }
+
+        impl Message {
+            pub fn new(
+                ids: ContainerINTEGER8,
+                names: ContainerIA5String8,
+                more_ids: ContainerINTEGER8,
+                pair: PairBOOLEAN,
+            ) -> Self {
+                Self {
+                    ids,
+                    names,
+                    more_ids,
+                    pair,
+                }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(automatic_tags, identifier = "Pair-BOOLEAN")]
+        pub struct PairBOOLEAN {
+            pub first: bool,
+            pub rest: ContainerBOOLEAN4,
+        }
+
+        impl PairBOOLEAN {
+            pub fn new(first: bool, rest: ContainerBOOLEAN4) -> Self {
+                Self { first, rest }
+            }
+        }
+    "#
+}
@@ -362,13 +362,13 @@ e2e_pdu!(
 e2e_pdu!(
     bmp_strict,
     r#" Test-String ::= BMPString SIZE (4)
-        test-string-val Test-String ::= "012345""#,
+        test-string-val Test-String ::= "0123""#,
     r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
         #[rasn(delegate, identifier = "Test-String", size("4"))]
         pub struct TestString(pub BmpString);
         pub static TEST_STRING_VAL: LazyLock<TestString> = LazyLock::new(||
             TestString(
-                BmpString::try_from("012345").unwrap()
+                BmpString::try_from("0123").unwrap()
             )
         );                                                           "#
 );
@@ -431,13 +431,13 @@ e2e_pdu!(
 e2e_pdu!(
     numeric_strict,
     r#" Test-String ::= NumericString SIZE (4)
-        test-string-val Test-String ::= "012345""#,
+        test-string-val Test-String ::= "0123""#,
     r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
         #[rasn(delegate, identifier = "Test-String", size("4"))]
         pub struct TestString(pub NumericString);
         pub static TEST_STRING_VAL: LazyLock<TestString> = LazyLock::new(||
             TestString(
-                NumericString::try_from("012345").unwrap()
+                NumericString::try_from("0123").unwrap()
             )
         );                                                           "#
 );
@@ -500,13 +500,13 @@ e2e_pdu!(
 e2e_pdu!(
     ia5_strict,
     r#" Test-String ::= IA5String SIZE (4)
-        test-string-val Test-String ::= "012345""#,
+        test-string-val Test-String ::= "0123""#,
     r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
         #[rasn(delegate, identifier = "Test-String", size("4"))]
         pub struct TestString(pub Ia5String);
         pub static TEST_STRING_VAL: LazyLock<TestString> = LazyLock::new(||
             TestString(
-                Ia5String::try_from("012345").unwrap()
+                Ia5String::try_from("0123").unwrap()
             )
         );                                                           "#
 );
@@ -569,13 +569,13 @@ e2e_pdu!(
 e2e_pdu!(
     printable_strict,
     r#" Test-String ::= PrintableString SIZE (4)
-        test-string-val Test-String ::= "012345""#,
+        test-string-val Test-String ::= "0123""#,
     r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
         #[rasn(delegate, identifier = "Test-String", size("4"))]
         pub struct TestString(pub PrintableString);
         pub static TEST_STRING_VAL: LazyLock<TestString> = LazyLock::new(||
             TestString(
-                PrintableString::try_from("012345").unwrap()
+                PrintableString::try_from("0123").unwrap()
             )
         );                                                           "#
 );
@@ -632,12 +632,12 @@ e2e_pdu!(
 e2e_pdu!(
     general_strict,
     r#" Test-String ::= GeneralString SIZE (4)
-        test-string-val Test-String ::= "012345""#,
+        test-string-val Test-String ::= "0123""#,
     r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
         #[rasn(delegate, identifier = "Test-String", size("4"))]
         pub struct TestString(pub GeneralString);
         pub static  TEST_STRING_VAL: LazyLock<TestString> = LazyLock::new(||
-            TestString(GeneralString::try_from(String::from("012345")).unwrap())
+            TestString(GeneralString::try_from(String::from("0123")).unwrap())
         );                                                           "#
 );
 
@@ -692,12 +692,12 @@ e2e_pdu!(
 e2e_pdu!(
     graphic_strict,
     r#" Test-String ::= GraphicString SIZE (4)
-        test-string-val Test-String ::= "012345""#,
+        test-string-val Test-String ::= "0123""#,
     r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
         #[rasn(delegate, identifier = "Test-String", size("4"))]
         pub struct TestString(pub GraphicString);
         pub static TEST_STRING_VAL: LazyLock<TestString> = LazyLock::new(||
-            TestString(GraphicString::try_from(String::from("012345")).unwrap())
+            TestString(GraphicString::try_from(String::from("0123")).unwrap())
         );                                                           "#
 );
 
@@ -751,12 +751,12 @@ e2e_pdu!(
 e2e_pdu!(
     utf8_strict,
     r#" Test-String ::= UTF8String SIZE (4)
-        test-string-val Test-String ::= "012345""#,
+        test-string-val Test-String ::= "0123""#,
     r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
         #[rasn(delegate, identifier = "Test-String", size("4"))]
         pub struct TestString(pub Utf8String);
         pub static TEST_STRING_VAL: LazyLock<TestString> = LazyLock::new(||
-            TestString(String::from("012345"))
+            TestString(String::from("0123"))
         );                                                          "#
 );
 
@@ -810,12 +810,12 @@ e2e_pdu!(
 e2e_pdu!(
     visible_strict,
     r#" Test-String ::= VisibleString SIZE (4)
-        test-string-val Test-String ::= "012345""#,
+        test-string-val Test-String ::= "0123""#,
     r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
         #[rasn(delegate, identifier = "Test-String", size("4"))]
         pub struct TestString(pub VisibleString);
         pub static TEST_STRING_VAL: LazyLock<TestString> = LazyLock::new(||
-            TestString(VisibleString::try_from("012345").unwrap())
+            TestString(VisibleString::try_from("0123").unwrap())
         );                                                          "#
 );
 
@@ -898,3 +898,79 @@ e2e_pdu!(
         );
     "#
 );
+
+e2e_pdu!(
+    relative_oid,
+    "Test-Relative-Oid ::= RELATIVE-OID",
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(delegate, identifier = "Test-Relative-Oid", tag(universal, 13))]
+        pub struct TestRelativeOid(pub OctetString);                     "#
+);
+
+e2e_pdu!(
+    relative_oid_value,
+    "relative-oid RELATIVE-OID ::= { 8571 3 2 }",
+    r#" pub static RELATIVE_OID: LazyLock<OctetString> = LazyLock::new(||
+            <OctetString as From<&'static [u8]>>::from(&[194, 123, 3, 2])
+        );                                                               "#
+);
+
+e2e_pdu!(
+    oid_iri,
+    r#"
+        Test-Oid-Iri ::= OID-IRI
+        Test-Relative-Oid-Iri ::= RELATIVE-OID-IRI
+    "#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(delegate, identifier = "Test-Oid-Iri", tag(universal, 35))]
+        pub struct TestOidIri(pub Utf8String);
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(delegate, identifier = "Test-Relative-Oid-Iri", tag(universal, 36))]
+        pub struct TestRelativeOidIri(pub Utf8String);                   "#
+);
+
+e2e_pdu!(
+    object_descriptor,
+    "Test-Descriptor ::= ObjectDescriptor (SIZE(1..8))",
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(delegate, identifier = "Test-Descriptor", tag(universal, 7), size("1..=8"))]
+        pub struct TestDescriptor(pub GraphicString);                    "#
+);
+
+e2e_pdu!(
+    unrestricted_character_string,
+    "Test-Character-String ::= CHARACTER STRING",
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(delegate, identifier = "Test-Character-String")]
+        pub struct TestCharacterString(pub Any);                         "#
+);
+
+e2e_pdu!(
+    integer_value_beyond_128_bits,
+    "test-int INTEGER ::= -340282366920938463463374607431768211457",
+    r#" pub static TEST_INT: LazyLock<Integer> = LazyLock::new(|| {
+            <Integer as IntegerType>::try_from_signed_bytes(
+                &[254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,],
+                rasn::Codec::Ber,
+            )
+            .unwrap()
+        });                                                              "#
+);
+
+e2e_pdu!(
+    integer_named_number_beyond_128_bits,
+    r#"
+        Test-Int ::= INTEGER { huge(1234567890123456789012345678901234567890) } (0..1234567890123456789012345678901234567890)
+        test-int Test-Int ::= huge
+    "#,
+    r#" #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(delegate, identifier = "Test-Int", value("0.."))]
+        pub struct TestInt(pub Integer);
+        pub static TEST_INT: LazyLock<TestInt> = LazyLock::new(|| {
+            TestInt(<Integer as IntegerType>::try_from_signed_bytes(
+                &[3, 160, 201, 32, 117, 192, 219, 243, 184, 172, 188, 95, 150, 206, 63, 10, 210,],
+                rasn::Codec::Ber,
+            )
+            .unwrap(),)
+        });                                                              "#
+);
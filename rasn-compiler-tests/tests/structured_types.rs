@@ -629,3 +629,123 @@ e2e_pdu!(
         }
     "#
 );
+
+e2e_pdu!(
+    any_defined_by,
+    r#"
+        AlgorithmIdentifier ::= SEQUENCE {
+            algorithm OBJECT IDENTIFIER,
+            parameters ANY DEFINED BY algorithm OPTIONAL
+        }
+        AttributeValue ::= ANY
+    "#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(automatic_tags)]
+        pub struct AlgorithmIdentifier {
+            pub algorithm: ObjectIdentifier,
+            pub parameters: Option<Any>,
+        }
+
+        impl AlgorithmIdentifier {
+            pub fn new(algorithm: ObjectIdentifier, parameters: Option<Any>) -> Self {
+                Self {
+                    algorithm,
+                    parameters,
+                }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(delegate)]
+        pub struct AttributeValue(pub Any);
+    "#
+);
+
+e2e_pdu!(
+    any_defined_by_decoder,
+    rasn_compiler::prelude::RasnConfig {
+        any_defined_by_types: vec![
+            rasn_compiler::prelude::DefinedByType {
+                component: "contentType".into(),
+                identifier: "1.2.840.113549.1.7.1".into(),
+                ty: "OCTET STRING".into(),
+            },
+            rasn_compiler::prelude::DefinedByType {
+                component: "ContentInfo.contentType".into(),
+                identifier: "1.2.840.113549.1.7.2".into(),
+                ty: "SignedData".into(),
+            },
+        ],
+        ..Default::default()
+    },
+    r#"
+        ContentType ::= OBJECT IDENTIFIER
+        ContentInfo ::= SEQUENCE {
+            contentType ContentType,
+            content [0] EXPLICIT ANY DEFINED BY contentType
+        }
+        SignedData ::= SEQUENCE { version INTEGER }
+    "#,
+    r#"
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        pub struct ContentInfo {
+            #[rasn(identifier = "contentType")]
+            pub content_type: ContentType,
+            #[rasn(tag(explicit(context, 0)))]
+            pub content: Any,
+        }
+
+        impl ContentInfo {
+            pub fn new(content_type: ContentType, content: Any) -> Self {
+                Self {
+                    content_type,
+                    content,
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum ContentInfo_Content {
+            OctetString(OctetString),
+            SignedData(SignedData),
+        }
+
+        impl ContentInfo {
+            pub fn decode_content<D: Decoder>(
+                &self,
+                decoder: &mut D,
+            ) -> Result<ContentInfo_Content, D::Error> {
+                let identifier: &[u32] = &self.content_type.0;
+                let any = &self.content;
+                match identifier {
+                    [1, 2, 840, 113549, 1, 7, 1] => Ok(decoder.codec().decode_from_binary(any.as_bytes()).map(ContentInfo_Content::OctetString)?),
+                    [1, 2, 840, 113549, 1, 7, 2] => Ok(decoder.codec().decode_from_binary(any.as_bytes()).map(ContentInfo_Content::SignedData)?),
+                    _ => Err(rasn::error::DecodeError::from_kind(
+                        rasn::error::DecodeErrorKind::Custom {
+                            msg: alloc::format!("Unknown identifier of ANY DEFINED BY component."),
+                        },
+                        decoder.codec(),
+                    )
+                    .into()),
+                }
+            }
+        }
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(delegate)]
+        pub struct ContentType(pub ObjectIdentifier);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]
+        #[rasn(automatic_tags)]
+        pub struct SignedData {
+            pub version: Integer,
+        }
+
+        impl SignedData {
+            pub fn new(version: Integer) -> Self {
+                Self { version }
+            }
+        }
+    "#
+);
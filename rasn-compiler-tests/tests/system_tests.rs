@@ -25,6 +25,19 @@ fn trailing_comments() {
     assert_eq!(test_module_b::World(2).0, 2);
 }
 
+#[test]
+fn instances_with_default_members() {
+    asn1!(
+        r#"
+            TestModuleA DEFINITIONS AUTOMATIC TAGS::= BEGIN
+                Seq { INTEGER: initial } ::= SEQUENCE { a INTEGER DEFAULT initial }
+                Message ::= SEQUENCE { seq Seq { 5 } }
+            END
+    "#
+    );
+    assert_eq!(test_module_a::Seq5::default().a, 5.into());
+}
+
 #[test]
 fn multi_module_error() {
     assert!(matches!(
@@ -166,3 +179,229 @@ fn no_std_compliant_bindings() {
     }"#
     ));
 }
+
+#[test]
+fn parse_and_validate_stages() {
+    use rasn_compiler::prelude::{ir::*, *};
+
+    let parsed = Compiler::<RasnBackend, _>::new()
+        .add_asn_literal(
+            r#"
+            TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN
+                Small ::= INTEGER (0..7)
+                Wrapper ::= SEQUENCE { small Small }
+                invalid-small Small ::= 12
+            END
+        "#,
+        )
+        .parse()
+        .unwrap();
+    let (header, definitions) = &parsed.modules()[0];
+    assert_eq!(header.name, "TestModule");
+    assert_eq!(definitions.len(), 3);
+
+    let validated = parsed.validate().unwrap();
+    assert_eq!(validated.definitions().len(), 2);
+    assert_eq!(validated.warnings().len(), 1);
+    assert!(matches!(
+        &validated.definitions()[1],
+        ToplevelDefinition::Type(ToplevelTypeDefinition {
+            ty: ASN1Type::Sequence(SequenceOrSet { members, .. }),
+            ..
+        }) if matches!(members[0].ty, ASN1Type::ElsewhereDeclaredType(_))
+    ));
+
+    let generated = validated
+        .with_backend(TypescriptBackend::default())
+        .compile_to_string()
+        .unwrap();
+    assert!(generated.generated.contains("Wrapper"));
+    assert_eq!(generated.warnings.len(), 1);
+}
+
+#[test]
+fn deterministic_module_order() {
+    use rasn_compiler::prelude::*;
+
+    let sources = (0..12)
+        .rev()
+        .map(|i| {
+            format!(
+                "Module-{i:02} DEFINITIONS AUTOMATIC TAGS::= BEGIN
+                    Item-{i:02} ::= INTEGER (0..{i})
+                END"
+            )
+        })
+        .collect::<Vec<_>>();
+    let compile = || {
+        sources[1..]
+            .iter()
+            .fold(
+                Compiler::<RasnBackend, _>::new().add_asn_literal(&sources[0]),
+                |compiler, source| compiler.add_asn_literal(source),
+            )
+            .compile_to_string()
+            .unwrap()
+            .generated
+    };
+    let generated = compile();
+    assert_eq!(generated, compile());
+    let positions = (0..12)
+        .map(|i| generated.find(&format!("module_{i:02}")).unwrap())
+        .collect::<Vec<_>>();
+    assert!(positions.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn incremental_compilation_cache() {
+    use rasn_compiler::prelude::*;
+
+    let dir = std::env::temp_dir().join(format!("rasn-compiler-cache-{}", std::process::id()));
+    let spec = |bound: u32| {
+        format!(
+            "Base DEFINITIONS AUTOMATIC TAGS::= BEGIN
+                Small ::= INTEGER (0..{bound})
+            END
+
+            User DEFINITIONS AUTOMATIC TAGS::= BEGIN
+                IMPORTS Small FROM Base;
+                Wrapper ::= SEQUENCE {{ small Small }}
+            END"
+        )
+    };
+    let compile = |spec: &str, cache: bool| {
+        let compiler = Compiler::<RasnBackend, _>::new();
+        let compiler = if cache {
+            compiler.set_cache_dir(&dir)
+        } else {
+            compiler
+        };
+        compiler.add_asn_literal(spec).compile_to_string().unwrap()
+    };
+    let cache_entries = || std::fs::read_dir(&dir).unwrap().count();
+
+    let clean = compile(&spec(7), false).generated;
+    assert_eq!(compile(&spec(7), true).generated, clean);
+    let entries = cache_entries();
+    assert!(entries > 0);
+    assert_eq!(compile(&spec(7), true).generated, clean);
+    assert_eq!(cache_entries(), entries);
+
+    let changed = compile(&spec(15), true).generated;
+    assert_eq!(changed, compile(&spec(15), false).generated);
+    assert_ne!(changed, clean);
+    // The entries of the previous version of the specification are pruned
+    assert_eq!(cache_entries(), entries);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn unchanged_output_is_not_rewritten() {
+    use rasn_compiler::prelude::*;
+
+    let dir = std::env::temp_dir().join(format!("rasn-compiler-output-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let compile = |bound: u32| {
+        Compiler::<RasnBackend, _>::new()
+            .add_asn_literal(format!(
+                "Base DEFINITIONS AUTOMATIC TAGS::= BEGIN
+                    Small ::= INTEGER (0..{bound})
+                END
+
+                Other DEFINITIONS AUTOMATIC TAGS::= BEGIN
+                    Flag ::= BOOLEAN
+                END"
+            ))
+            .set_output_mode(rasn_compiler::OutputMode::Directory(dir.clone()))
+            .compile()
+            .unwrap();
+    };
+    let modified = |file: &str| {
+        std::fs::metadata(dir.join(file))
+            .unwrap()
+            .modified()
+            .unwrap()
+    };
+
+    compile(7);
+    let (base, other) = (modified("Base.rs"), modified("Other.rs"));
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    compile(15);
+    assert_ne!(modified("Base.rs"), base);
+    assert_eq!(modified("Other.rs"), other);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn ir_json_export() {
+    use rasn_compiler::prelude::*;
+
+    let validated = Compiler::<RasnBackend, _>::new()
+        .add_asn_literal(
+            r#"
+            First DEFINITIONS AUTOMATIC TAGS::= BEGIN
+                Small ::= INTEGER (0..7)
+                Wrapper ::= SEQUENCE { small Small }
+            END
+
+            Second DEFINITIONS AUTOMATIC TAGS::= BEGIN
+                IMPORTS Small FROM First;
+                small-value Small ::= 5
+            END
+        "#,
+        )
+        .validate()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&validated.to_ir_json()).unwrap();
+    assert_eq!(json["version"], IR_JSON_VERSION);
+    let modules = json["modules"].as_array().unwrap();
+    assert_eq!(modules.len(), 2);
+    let module = |name: &str| {
+        modules
+            .iter()
+            .find(|m| m["header"]["name"] == name)
+            .unwrap()
+    };
+    assert_eq!(module("First")["definitions"].as_array().unwrap().len(), 2);
+    assert_eq!(
+        module("Second")["header"]["imports"][0]["global_module_reference"]["module_reference"],
+        "First"
+    );
+    let value = &module("Second")["definitions"][0]["Value"];
+    assert_eq!(value["name"], "small-value");
+    assert_eq!(
+        value["associated_type"]["ElsewhereDeclaredType"]["identifier"],
+        "Small"
+    );
+}
+
+#[test]
+fn ir_passes() {
+    use rasn_compiler::prelude::*;
+
+    let result = Compiler::<RasnBackend, _>::new()
+        .add_pass(FlattenAliases)
+        .add_pass(PruneTypes::new().keep("Root"))
+        .add_pass(RenameIdentifiers::new().rename("Base", "Renamed-base"))
+        .add_pass(|definitions: &mut Vec<ToplevelDefinition>| {
+            assert_eq!(definitions.len(), 2);
+            Ok(())
+        })
+        .add_asn_literal(
+            r#"
+            TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN
+                Base ::= INTEGER (0..7)
+                Alias ::= Base
+                Unused ::= BOOLEAN
+                Root ::= SEQUENCE { a Alias, b Alias DEFAULT 4 }
+            END
+        "#,
+        )
+        .compile_to_string()
+        .unwrap();
+    assert!(result.warnings.is_empty());
+    assert!(result.generated.contains("pub struct RenamedBase(pub u8);"));
+    assert!(result.generated.contains("pub a: RenamedBase,"));
+    assert!(!result.generated.contains("Alias"));
+    assert!(!result.generated.contains("Unused"));
+}
@@ -1,23 +1,234 @@
-use std::path::PathBuf;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
+use std::time::{Duration, SystemTime};
+use std::{fs, thread};
 
-use clap::{arg, command, Parser};
+use clap::Parser;
 use colored::Colorize;
-use rasn_compiler::{OutputMode, RasnCompiler, TsCompiler};
+use rasn_compiler::{
+    prelude::{
+        extract_marked_asn1, format_asn1, sarif_log, Backend, Compatibility, Compiler,
+        CompilerError, CompilerMissingParams, DefinitionKind, DefinitionListing, Diagnostic,
+        LexerError, LexerErrorType, LintConfig, LintLevel, LintReport, LintRule, MemoryCache,
+        Severity, SpecDiff, TypeExplanation,
+    },
+    Asn1Compiler, DotCompiler, HtmlCompiler, JsonGraphCompiler, MarkdownCompiler, OutputMode,
+    PyCompiler, RasnCompiler, TsCompiler,
+};
 use walkdir::WalkDir;
 
 #[derive(clap::Parser, Debug)]
-#[command(author, version, about, long_about = None)]
+#[command(
+    author,
+    version,
+    about,
+    long_about = None,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 pub struct CompilerArgs {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[clap(flatten)]
+    generate: GenerateArgs,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct GenerateArgs {
     #[clap(flatten, next_help_heading = "Input")]
     source: SourceArgsGroup,
 
+    #[clap(flatten, next_help_heading = "Validation")]
+    validation: ValidationArgs,
+
     #[clap(flatten, next_help_heading = "Output")]
     output: OutputArgGroup,
 
     /// Specify which compiler backend to use
     #[arg(short, long, default_value = "rasn")]
     backend: BackendArg,
+
+    /// Compile only the ASN1 between `-- ASN1START` and `-- ASN1STOP` marker lines of the input
+    /// files, e.g. of 3GPP specifications saved as text. Diagnostics refer to the lines of the input files
+    #[arg(long)]
+    extract_asn1: bool,
+
+    /// Emit an intermediate format instead of generating bindings with the backend
+    #[arg(long, value_name = "FORMAT")]
+    emit: Option<EmitArg>,
+
+    /// Specify how errors and warnings are reported
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        default_value = "human",
+        conflicts_with = "stdout"
+    )]
+    message_format: MessageFormatArg,
+
+    /// Treat warnings as errors and fail if any warning is reported
+    #[arg(long)]
+    deny_warnings: bool,
+
+    /// Cache parsed sources, validated and generated modules in DIR, so that later runs only
+    /// lex, validate and generate the modules that changed and the modules that depend on them
+    #[arg(long, value_name = "DIR")]
+    cache_dir: Option<PathBuf>,
+
+    /// Keep running and recompile whenever an ASN1 module, or a module that is imported from
+    /// the search paths, is changed, added or removed. Only the changed modules and the modules
+    /// that depend on them are compiled again, using the cache in --cache-dir or a cache in memory,
+    /// and output files are only written if their content changes
+    #[arg(long, conflicts_with_all = ["stdout", "message_format"])]
+    watch: bool,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Generate bindings for ASN1 modules with a backend.
+    /// This is the default if no subcommand is given
+    Generate(GenerateArgs),
+    /// Validate ASN1 modules without generating bindings.
+    /// Fails if any error is reported
+    Check(CheckArgs),
+    /// List the modules of an ASN1 specification with their types, values,
+    /// classes, objects and object sets, their kinds and their locations
+    List(ListArgs),
+    /// Show the fully resolved type tree of a type with its effective constraints,
+    /// tags and PER bit widths
+    Explain(ExplainArgs),
+    /// Compare two versions of an ASN1 specification and classify every change
+    /// as wire-compatible or breaking, for PER and BER separately.
+    /// Fails if any change is breaking
+    Diff(DiffArgs),
+    /// Export the module import graph and the definition dependency graph
+    /// of an ASN1 specification as Graphviz DOT or JSON
+    Graph(GraphArgs),
+    /// Check ASN1 modules against configurable style rules.
+    /// Fails if any denied rule is violated
+    Lint(LintArgs),
+    /// Format ASN1 modules canonically in place.
+    /// Definitions and comments keep their order
+    Fmt(FmtArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct CheckArgs {
+    /// ASN1 modules, or directories to search for them
+    #[arg(value_name = "PATH", num_args(1..), required = true)]
+    paths: Vec<PathBuf>,
+
+    #[clap(flatten)]
+    validation: ValidationArgs,
+
+    /// Validate only the ASN1 between `-- ASN1START` and `-- ASN1STOP` marker lines of the input files
+    #[arg(long)]
+    extract_asn1: bool,
+
+    /// Specify how errors and warnings are reported
+    #[arg(long, value_name = "FORMAT", default_value = "human")]
+    message_format: MessageFormatArg,
+
+    /// Treat warnings as errors and fail if any warning is reported
+    #[arg(long)]
+    deny_warnings: bool,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ListArgs {
+    /// ASN1 modules, or directories to search for them
+    #[arg(value_name = "PATH", num_args(1..), required = true)]
+    paths: Vec<PathBuf>,
+
+    /// List only the ASN1 between `-- ASN1START` and `-- ASN1STOP` marker lines of the input files
+    #[arg(long)]
+    extract_asn1: bool,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ExplainArgs {
+    /// Name of the type. Types that are defined in several modules
+    /// must be qualified with their module, as in `Module.Type`
+    #[arg(value_name = "TYPE")]
+    name: String,
+
+    /// ASN1 modules, or directories to search for them
+    #[arg(value_name = "PATH", num_args(1..), required = true)]
+    paths: Vec<PathBuf>,
+
+    #[clap(flatten)]
+    validation: ValidationArgs,
+
+    /// Read only the ASN1 between `-- ASN1START` and `-- ASN1STOP` marker lines of the input files
+    #[arg(long)]
+    extract_asn1: bool,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct DiffArgs {
+    /// ASN1 modules of the old version, or directories to search for them
+    #[arg(long, value_name = "PATH", num_args(1..), required = true)]
+    old: Vec<PathBuf>,
+
+    /// ASN1 modules of the new version, or directories to search for them
+    #[arg(long, value_name = "PATH", num_args(1..), required = true)]
+    new: Vec<PathBuf>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct GraphArgs {
+    /// ASN1 modules, or directories to search for them
+    #[arg(value_name = "PATH", num_args(1..), required = true)]
+    paths: Vec<PathBuf>,
+
+    #[clap(flatten)]
+    validation: ValidationArgs,
+
+    /// Read only the ASN1 between `-- ASN1START` and `-- ASN1STOP` marker lines of the input files
+    #[arg(long)]
+    extract_asn1: bool,
+
+    /// Specify the format of the exported graphs
+    #[arg(long, default_value = "dot")]
+    format: GraphFormatArg,
+
+    /// Write the graphs to PATH instead of stdout.
+    /// Warnings are only reported if the graphs are written to a file
+    #[arg(short, long, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct LintArgs {
+    /// ASN1 modules, or directories to search for them
+    #[arg(value_name = "PATH", num_args(1..), required = true)]
+    paths: Vec<PathBuf>,
+
+    /// Do not check RULE. Can be repeated
+    #[arg(long, value_name = "RULE", value_parser = str::parse::<LintRule>)]
+    allow: Vec<LintRule>,
+
+    /// Report violations of RULE as warnings. Can be repeated
+    #[arg(long, value_name = "RULE", value_parser = str::parse::<LintRule>)]
+    warn: Vec<LintRule>,
+
+    /// Fail on violations of RULE. Can be repeated
+    #[arg(long, value_name = "RULE", value_parser = str::parse::<LintRule>)]
+    deny: Vec<LintRule>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct FmtArgs {
+    /// ASN1 modules, or directories to search for them
+    #[arg(value_name = "PATH", num_args(1..), required = true)]
+    paths: Vec<PathBuf>,
+
+    /// Check that the ASN1 modules are formatted canonically instead of formatting them.
+    /// Fails if formatting a module would change it
+    #[arg(long)]
+    check: bool,
 }
 
 #[derive(clap::Args, Debug)]
@@ -33,6 +244,31 @@ pub struct SourceArgsGroup {
     module_files: Vec<PathBuf>,
 }
 
+/// Options of the validation of the ASN1 modules, shared by all subcommands that validate them
+#[derive(clap::Args, Debug)]
+pub struct ValidationArgs {
+    /// Search DIR recursively for modules that the compiled modules import, but do not include.
+    /// Can be repeated
+    #[arg(short = 'I', long = "search-path", value_name = "DIR")]
+    search_paths: Vec<PathBuf>,
+
+    /// Report imports of symbols that the imported module does not list in its `EXPORTS`
+    /// clause as warnings instead of errors
+    #[arg(long)]
+    warn_unexported_imports: bool,
+}
+
+impl ValidationArgs {
+    /// Severity of imports of symbols that the imported module does not export
+    fn unexported_imports(&self) -> Severity {
+        if self.warn_unexported_imports {
+            Severity::Warning
+        } else {
+            Severity::Error
+        }
+    }
+}
+
 #[derive(clap::Args, Debug)]
 #[group(required = false, multiple = false)]
 pub struct OutputArgGroup {
@@ -44,6 +280,12 @@ pub struct OutputArgGroup {
     #[arg(short, long, value_name = "PATH")]
     output_path: Option<PathBuf>,
 
+    /// Write each compiled module to its own file in DIR.
+    ///
+    /// File names are derived from the module names.
+    #[arg(long, value_name = "DIR")]
+    output_dir: Option<PathBuf>,
+
     /// Write all compiled modules to stdout.
     #[arg(long)]
     stdout: bool,
@@ -59,72 +301,618 @@ enum BackendArg {
     Rasn,
     /// Generate typescript type definitions
     Typescript,
+    /// Generate python dataclasses
+    Python,
+    /// Generate HTML documentation
+    Html,
+    /// Generate Markdown documentation
+    Markdown,
+    /// Generate canonically formatted ASN.1
+    Asn1,
+    /// Export the module import graph and the definition dependency graph as Graphviz DOT
+    Dot,
+    /// Export the module import graph and the definition dependency graph as JSON
+    Json,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum GraphFormatArg {
+    /// Graphviz DOT
+    Dot,
+    /// JSON
+    Json,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum EmitArg {
+    /// The validated intermediate representation as JSON
+    IrJson,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum MessageFormatArg {
+    /// Human-readable messages
+    Human,
+    /// One JSON object per line for every diagnostic
+    Json,
+    /// A SARIF 2.1.0 log of all diagnostics
+    Sarif,
 }
 
 fn main() -> ExitCode {
     let args = CompilerArgs::parse();
 
+    match args.command {
+        Some(Command::Generate(generate_args)) => generate(generate_args),
+        Some(Command::Check(check_args)) => check(check_args),
+        Some(Command::List(list_args)) => list(list_args),
+        Some(Command::Explain(explain_args)) => explain(explain_args),
+        Some(Command::Diff(diff_args)) => diff(diff_args),
+        Some(Command::Graph(graph_args)) => graph(graph_args),
+        Some(Command::Lint(lint_args)) => lint(lint_args),
+        Some(Command::Fmt(fmt_args)) => format_modules(fmt_args),
+        None => generate(args.generate),
+    }
+}
+
+/// Generates bindings for the modules with the selected backend.
+fn generate(args: GenerateArgs) -> ExitCode {
     // Read module paths
-    let mut modules = args.source.module_files;
+    let mut modules = args.source.module_files.clone();
 
     // Scan directory, if given
     if let Some(dir) = &args.source.directory {
-        let mut module_found = false;
-        for entry in WalkDir::new(dir).follow_links(true) {
-            let entry = match entry {
-                Ok(entry) => entry,
-                Err(err) => {
+        modules.append(&mut find_modules(
+            dir,
+            args.message_format == MessageFormatArg::Human,
+        ));
+    }
+
+    if modules.is_empty() {
+        println!("{}: No modules", "error".red());
+        return ExitCode::FAILURE;
+    }
+
+    let output = make_output_mode(args.output);
+    let input = CompilerInput {
+        modules,
+        extract_marked_asn1: args.extract_asn1,
+        unexported_imports: args.validation.unexported_imports(),
+        search_paths: args.validation.search_paths,
+        emit: args.emit,
+        cache_dir: args.cache_dir,
+        memory_cache: None,
+    };
+    if args.watch {
+        watch(
+            &args.source,
+            args.backend,
+            input,
+            output,
+            args.deny_warnings,
+        );
+    }
+    let results = compile_with(args.backend, input, output, &mut vec![]);
+
+    let diagnostics = diagnostics(&results, args.deny_warnings);
+    report(&diagnostics, args.message_format);
+
+    if diagnostics.iter().any(|d| d.severity == Severity::Error) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Sources of a compilation
+#[derive(Clone)]
+struct CompilerInput {
+    modules: Vec<PathBuf>,
+    extract_marked_asn1: bool,
+    search_paths: Vec<PathBuf>,
+    unexported_imports: Severity,
+    emit: Option<EmitArg>,
+    cache_dir: Option<PathBuf>,
+    /// Cache that is used if no cache directory is given
+    memory_cache: Option<MemoryCache>,
+}
+
+/// Compiles the modules with the selected backend.
+/// The source files of the parsed modules, including the modules loaded from the
+/// search paths, are added to `sources`.
+fn compile_with(
+    backend: BackendArg,
+    input: CompilerInput,
+    output: OutputMode,
+    sources: &mut Vec<PathBuf>,
+) -> Result<Vec<CompilerError>, CompilerError> {
+    match backend {
+        BackendArg::Rasn => compile(RasnCompiler::new(), input, output, sources),
+        BackendArg::Typescript => compile(TsCompiler::new(), input, output, sources),
+        BackendArg::Python => compile(PyCompiler::new(), input, output, sources),
+        BackendArg::Html => compile(HtmlCompiler::new(), input, output, sources),
+        BackendArg::Markdown => compile(MarkdownCompiler::new(), input, output, sources),
+        BackendArg::Asn1 => compile(Asn1Compiler::new(), input, output, sources),
+        BackendArg::Dot => compile(DotCompiler::new(), input, output, sources),
+        BackendArg::Json => compile(JsonGraphCompiler::new(), input, output, sources),
+    }
+}
+
+fn compile<B: Backend + Clone + Send>(
+    compiler: Compiler<B, CompilerMissingParams>,
+    input: CompilerInput,
+    output: OutputMode,
+    sources: &mut Vec<PathBuf>,
+) -> Result<Vec<CompilerError>, CompilerError> {
+    let compiler = if input.extract_marked_asn1 {
+        compiler.add_marked_asn_sources_by_path(input.modules.into_iter())
+    } else {
+        compiler.add_asn_sources_by_path(input.modules.into_iter())
+    };
+    let mut compiler = compiler
+        .add_search_paths(input.search_paths.into_iter())
+        .set_unexported_import_severity(input.unexported_imports)
+        .set_output_mode(output);
+    if let Some(dir) = input.cache_dir {
+        compiler = compiler.set_cache_dir(dir);
+    } else if let Some(cache) = input.memory_cache {
+        compiler = compiler.set_memory_cache(cache);
+    }
+    let compiler = compiler.parse()?;
+    sources.extend(
+        compiler
+            .modules()
+            .iter()
+            .filter_map(|(header, _)| header.source_file.as_ref().map(PathBuf::from)),
+    );
+    match input.emit {
+        Some(EmitArg::IrJson) => compiler.validate()?.emit_ir_json(),
+        None => compiler.validate()?.compile(),
+    }
+}
+
+/// Diagnostics of the result of a compilation. Warnings are reported as errors if `deny_warnings` is set.
+fn diagnostics(
+    results: &Result<Vec<CompilerError>, CompilerError>,
+    deny_warnings: bool,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = match results {
+        Ok(warnings) => warnings.iter().map(Diagnostic::from).collect::<Vec<_>>(),
+        Err(error) => vec![Diagnostic::error(error)],
+    };
+    if deny_warnings {
+        for diagnostic in &mut diagnostics {
+            diagnostic.severity = Severity::Error;
+        }
+    }
+    diagnostics
+}
+
+/// Interval at which the watched modules are checked for changes
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Compiles the modules and recompiles them whenever a module is changed, added or removed.
+/// Runs until the process is interrupted.
+fn watch(
+    source: &SourceArgsGroup,
+    backend: BackendArg,
+    mut input: CompilerInput,
+    output: OutputMode,
+    deny_warnings: bool,
+) -> ! {
+    // Without a cache directory, the cache lives as long as the process
+    if input.cache_dir.is_none() {
+        input.memory_cache = Some(MemoryCache::default());
+    }
+    let mut compiled_snapshot = None;
+    // Files of the modules imported from the search paths by the last compilation
+    let mut imported = BTreeSet::new();
+    loop {
+        let mut modules = source.module_files.clone();
+        if let Some(dir) = &source.directory {
+            modules.append(&mut find_modules(dir, false));
+        }
+        let snapshot = snapshot(modules.iter().chain(&imported));
+        if compiled_snapshot.as_ref() != Some(&snapshot) {
+            if modules.is_empty() {
+                println!("{}: No modules", "error".red());
+                compiled_snapshot = Some(snapshot);
+            } else {
+                println!("{}: Compiling {} modules", "info".blue(), modules.len());
+                input.modules = modules.clone();
+                let mut sources = vec![];
+                let results = compile_with(backend, input.clone(), output.clone(), &mut sources);
+                imported = sources
+                    .into_iter()
+                    .filter(|source| !modules.contains(source))
+                    .collect();
+                compiled_snapshot = Some(self::snapshot(modules.iter().chain(&imported)));
+                report(
+                    &diagnostics(&results, deny_warnings),
+                    MessageFormatArg::Human,
+                );
+                if let Err(error) = &results {
+                    if let Some(context) = source_context(error, input.extract_marked_asn1) {
+                        println!("{}", context.trim_matches('\n').trim_end());
+                    }
+                }
+            }
+            println!("{}: Watching for changes", "info".blue());
+        }
+        thread::sleep(WATCH_INTERVAL);
+    }
+}
+
+/// Modification times and sizes of the given files.
+/// Modification times may be too coarse to tell apart quick successive edits, so sizes are compared as well
+fn snapshot<'a>(
+    files: impl Iterator<Item = &'a PathBuf>,
+) -> Vec<(PathBuf, Option<SystemTime>, Option<u64>)> {
+    files
+        .map(|file| {
+            let metadata = fs::metadata(file).ok();
+            let modified = metadata.as_ref().and_then(|m| m.modified().ok());
+            (file.clone(), modified, metadata.map(|m| m.len()))
+        })
+        .collect()
+}
+
+/// The ASN1 source around the position at which the lexer failed, if `error` is a lexer error.
+fn source_context(error: &CompilerError, extract_asn1: bool) -> Option<String> {
+    let CompilerError::Lexer(LexerError {
+        kind: LexerErrorType::MatchingError(report),
+    }) = error
+    else {
+        return None;
+    };
+    let text = fs::read_to_string(report.src_file.as_ref()?).ok()?;
+    // Positions of errors in extracted ASN1 refer to the extracted text
+    let source = if extract_asn1 {
+        extract_marked_asn1(&text)?
+    } else {
+        text
+    };
+    // The file may have changed since it was compiled
+    if report.context_start_offset > report.offset || report.offset >= source.len() {
+        return None;
+    }
+    source
+        .is_char_boundary(report.context_start_offset)
+        .then(|| error.contextualize(&source))
+}
+
+/// Prints diagnostics in the requested format.
+fn report(diagnostics: &[Diagnostic], format: MessageFormatArg) {
+    match format {
+        MessageFormatArg::Human => {
+            for diagnostic in diagnostics {
+                let severity = match diagnostic.severity {
+                    Severity::Error => "error".red(),
+                    Severity::Warning => "warning".yellow(),
+                };
+                println!("{severity}[{}]: {}", diagnostic.code, diagnostic.message);
+                if let Some(span) = &diagnostic.span {
+                    println!("  --> {span}");
+                }
+                for note in &diagnostic.notes {
+                    println!("  = note: {note}");
+                }
+            }
+        }
+        MessageFormatArg::Json => {
+            for diagnostic in diagnostics {
+                println!("{}", diagnostic.to_json());
+            }
+        }
+        MessageFormatArg::Sarif => println!("{}", sarif_log(diagnostics)),
+    }
+}
+
+/// Searches `dir` recursively for `.asn` and `.asn1` files.
+/// Progress is only printed if `verbose` is set.
+fn find_modules(dir: &Path, verbose: bool) -> Vec<PathBuf> {
+    let mut modules = vec![];
+    for entry in WalkDir::new(dir).follow_links(true) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) => {
+                if verbose {
                     println!("{}: {err}", "warning".yellow());
-                    continue;
                 }
-            };
-            let file_name = entry.file_name().to_string_lossy();
+                continue;
+            }
+        };
+        let file_name = entry.file_name().to_string_lossy();
 
-            if file_name.ends_with(".asn") || file_name.ends_with(".asn1") {
+        if file_name.ends_with(".asn") || file_name.ends_with(".asn1") {
+            if verbose {
                 println!("{}: Found ASN1 module {}", "info".blue(), file_name);
-                modules.push(entry.into_path());
-                module_found = true;
             }
+            modules.push(entry.into_path());
+        }
+    }
+
+    if verbose && modules.is_empty() {
+        println!(
+            "{}: No modules where found in '{}'",
+            "warning".yellow(),
+            dir.display(),
+        );
+    }
+    modules
+}
+
+/// Expands directories to the ASN1 modules found in them.
+/// Progress is only printed if `verbose` is set.
+fn modules(paths: Vec<PathBuf>, verbose: bool) -> Vec<PathBuf> {
+    paths
+        .into_iter()
+        .flat_map(|path| {
+            if path.is_dir() {
+                find_modules(&path, verbose)
+            } else {
+                vec![path]
+            }
+        })
+        .collect()
+}
+
+/// Validates the modules without generating bindings and reports every diagnostic.
+fn check(args: CheckArgs) -> ExitCode {
+    let modules = modules(args.paths, args.message_format == MessageFormatArg::Human);
+    let compiler = if args.extract_asn1 {
+        RasnCompiler::new().add_marked_asn_sources_by_path(modules.into_iter())
+    } else {
+        RasnCompiler::new().add_asn_sources_by_path(modules.into_iter())
+    };
+    let results = compiler
+        .set_unexported_import_severity(args.validation.unexported_imports())
+        .add_search_paths(args.validation.search_paths.into_iter())
+        .validate()
+        .map(|validated| validated.into_parts().1);
+
+    let diagnostics = diagnostics(&results, args.deny_warnings);
+    report(&diagnostics, args.message_format);
+
+    if diagnostics.iter().any(|d| d.severity == Severity::Error) {
+        ExitCode::FAILURE
+    } else {
+        if args.message_format == MessageFormatArg::Human {
+            println!("{}: No errors", "info".blue());
         }
+        ExitCode::SUCCESS
+    }
+}
 
-        if !module_found {
+/// Prints the modules of a specification with their definitions.
+fn list(args: ListArgs) -> ExitCode {
+    let modules = modules(args.paths, false);
+    let listing = if args.extract_asn1 {
+        DefinitionListing::from_marked_paths(modules)
+    } else {
+        DefinitionListing::from_paths(modules)
+    };
+    match listing {
+        Ok(listing) => {
+            print!("{listing}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            println!("{}: {error}", "error".red());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints the resolved type tree of a type.
+fn explain(args: ExplainArgs) -> ExitCode {
+    let modules = modules(args.paths, false);
+    if !args.name.contains('.') {
+        // Validation keeps only one of the definitions that share a name across modules
+        let listing = if args.extract_asn1 {
+            DefinitionListing::from_marked_paths(modules.clone())
+        } else {
+            DefinitionListing::from_paths(modules.clone())
+        };
+        let defining_modules = listing
+            .map(|listing| {
+                listing
+                    .modules
+                    .iter()
+                    .filter(|module| {
+                        module
+                            .definitions
+                            .iter()
+                            .any(|d| d.kind == DefinitionKind::Type && d.name == args.name)
+                    })
+                    .count()
+            })
+            .unwrap_or_default();
+        if defining_modules > 1 {
+            println!(
+                "{}: Type `{}` is defined in {defining_modules} modules",
+                "error".red(),
+                args.name
+            );
             println!(
-                "{}: No modules where found in '{}'",
-                "warning".yellow(),
-                dir.display(),
+                "  = note: types that are defined in several modules must be qualified, as in `Module.{}`",
+                args.name
             );
+            return ExitCode::FAILURE;
+        }
+    }
+    let compiler = if args.extract_asn1 {
+        RasnCompiler::new().add_marked_asn_sources_by_path(modules.into_iter())
+    } else {
+        RasnCompiler::new().add_asn_sources_by_path(modules.into_iter())
+    };
+    let validated = match compiler
+        .set_unexported_import_severity(args.validation.unexported_imports())
+        .add_search_paths(args.validation.search_paths.into_iter())
+        .validate()
+    {
+        Ok(validated) => validated,
+        Err(error) => {
+            println!("{}: {error}", "error".red());
+            return ExitCode::FAILURE;
+        }
+    };
+    for warning in validated.warnings() {
+        println!("{}: {warning}", "warning".yellow())
+    }
+    match TypeExplanation::new(validated.definitions(), &args.name) {
+        Some(explanation) => {
+            print!("{explanation}");
+            ExitCode::SUCCESS
+        }
+        None => {
+            println!("{}: Found no type `{}`", "error".red(), args.name);
+            ExitCode::FAILURE
         }
     }
+}
 
-    if modules.is_empty() {
-        println!("{}: No modules", "error".red());
-        return ExitCode::FAILURE;
+/// Compares two versions of a specification and prints every change with its compatibility.
+fn diff(args: DiffArgs) -> ExitCode {
+    let diff = match SpecDiff::from_paths(modules(args.old, false), modules(args.new, false)) {
+        Ok(diff) => diff,
+        Err(error) => {
+            println!("{}: {error}", "error".red());
+            return ExitCode::FAILURE;
+        }
+    };
+    for warning in &diff.warnings {
+        println!("{}: {warning}", "warning".yellow())
+    }
+    if diff.changes.is_empty() {
+        println!("{}: No changes", "info".blue());
+    }
+    for change in &diff.changes {
+        let label =
+            if change.per == Compatibility::Compatible && change.ber == Compatibility::Compatible {
+                "compatible".green()
+            } else {
+                "breaking".red()
+            };
+        println!("{label}: {change}");
+    }
+    if diff.is_compatible() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
     }
+}
 
-    let output = make_output_mode(args.output);
-    let results = match args.backend {
-        BackendArg::Rasn => RasnCompiler::new()
-            .add_asn_sources_by_path(modules.into_iter())
-            .set_output_mode(output)
-            .compile(),
-        BackendArg::Typescript => TsCompiler::new()
-            .add_asn_sources_by_path(modules.into_iter())
-            .set_output_mode(output)
-            .compile(),
+/// Exports the dependency graphs of a specification with the same backends as `-b dot|json`.
+fn graph(args: GraphArgs) -> ExitCode {
+    let backend = match args.format {
+        GraphFormatArg::Dot => BackendArg::Dot,
+        GraphFormatArg::Json => BackendArg::Json,
     };
+    let input = CompilerInput {
+        modules: modules(args.paths, false),
+        extract_marked_asn1: args.extract_asn1,
+        unexported_imports: args.validation.unexported_imports(),
+        search_paths: args.validation.search_paths,
+        emit: None,
+        cache_dir: None,
+        memory_cache: None,
+    };
+    let output = args
+        .output
+        .clone()
+        .map_or(OutputMode::Stdout, OutputMode::SingleFile);
+    let results = compile_with(backend, input, output, &mut vec![]);
 
-    match results {
-        Ok(warnings) => {
-            for warning in warnings {
-                println!("{}: {warning}", "warning".yellow())
-            }
-            ExitCode::SUCCESS
+    let mut diagnostics = diagnostics(&results, false);
+    if args.output.is_none() {
+        // Keep the graphs on stdout readable, they highlight unresolved imports and references anyway
+        diagnostics.retain(|d| d.severity == Severity::Error);
+    }
+    report(&diagnostics, MessageFormatArg::Human);
+
+    if diagnostics.iter().any(|d| d.severity == Severity::Error) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Lints a specification and prints every violated rule.
+fn lint(args: LintArgs) -> ExitCode {
+    let mut config = LintConfig::default();
+    for (rules, level) in [
+        (args.allow, LintLevel::Allow),
+        (args.warn, LintLevel::Warn),
+        (args.deny, LintLevel::Deny),
+    ] {
+        for rule in rules {
+            config = config.set_level(rule, level);
         }
+    }
+    let report = match LintReport::from_paths(modules(args.paths, false), &config) {
+        Ok(report) => report,
         Err(error) => {
             println!("{}: {error}", "error".red());
-            ExitCode::FAILURE
+            return ExitCode::FAILURE;
+        }
+    };
+    for warning in &report.warnings {
+        println!("{}: {warning}", "warning".yellow())
+    }
+    for lint in &report.lints {
+        let level = match lint.level {
+            LintLevel::Deny => "error".red(),
+            _ => "warning".yellow(),
+        };
+        println!("{level}[{}]: {}: {}", lint.rule, lint.module, lint.message);
+    }
+    if report.is_denied() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Compares every module with its canonically formatted form and either rewrites
+/// or reports the modules that are not formatted canonically.
+fn format_modules(args: FmtArgs) -> ExitCode {
+    let mut unformatted = 0;
+    for module in modules(args.paths, true) {
+        let source = match std::fs::read_to_string(&module) {
+            Ok(source) => source,
+            Err(err) => {
+                println!("{}: {}: {err}", "error".red(), module.display());
+                return ExitCode::FAILURE;
+            }
+        };
+        match format_asn1(&source) {
+            Ok(formatted) if formatted == source => (),
+            Ok(formatted) if !args.check => {
+                if let Err(err) = std::fs::write(&module, formatted) {
+                    println!("{}: {}: {err}", "error".red(), module.display());
+                    return ExitCode::FAILURE;
+                }
+            }
+            Ok(_) => {
+                println!(
+                    "{}: {} is not formatted canonically",
+                    "warning".yellow(),
+                    module.display()
+                );
+                unformatted += 1;
+            }
+            Err(error) => {
+                println!("{}: {}: {error}", "error".red(), module.display());
+                return ExitCode::FAILURE;
+            }
         }
     }
+    if unformatted == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
 }
 
 /// Create an [OutputConf] from command arguments, that can be used with
@@ -133,6 +921,8 @@ fn make_output_mode(args: OutputArgGroup) -> OutputMode {
     // Only zero or one output argument is allowed, and enforced by Clap.
     if let Some(v) = args.output_path {
         OutputMode::SingleFile(v)
+    } else if let Some(v) = args.output_dir {
+        OutputMode::Directory(v)
     } else if args.stdout {
         OutputMode::Stdout
     } else if args.no_output {
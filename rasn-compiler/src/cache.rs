@@ -0,0 +1,668 @@
+//! Cache for incremental compilations, which is kept on disk, see
+//! [Compiler::set_cache_dir](crate::Compiler::set_cache_dir), or in memory, see
+//! [Compiler::set_memory_cache](crate::Compiler::set_memory_cache).
+//!
+//! The cache stores three kinds of entries:
+//! * the parsed definitions of every ASN.1 source, keyed by the content of the source,
+//!   so that unchanged sources are not lexed again,
+//! * the validated definitions of every ASN.1 module, keyed by the parsed definitions of the
+//!   module and of the modules it imports from, directly or indirectly, so that only the
+//!   modules that changed and the modules that import from them are validated again,
+//! * the generated bindings of every ASN.1 module, keyed by the backend, its config, and the
+//!   validated definitions of the module, of the modules it imports from, and of the modules
+//!   that import from it, so that only the modules whose definitions or dependencies changed
+//!   are generated again.
+//!
+//! Keys are computed from the JSON serialisation of the definitions, which is why the cache
+//! requires the `serde` feature.
+//!
+//! Entries that cannot be read are treated as missing and entries that cannot be written are
+//! skipped, so that a broken cache never fails a compilation.
+//!
+//! After a successful compilation, the entries that it neither read nor wrote are removed,
+//! so that the cache does not grow with every change of the sources.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    hash::{Hash, Hasher},
+    path::PathBuf,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use crate::{
+    error::CompilerError,
+    generator::{Backend, GeneratedModule},
+    intermediate::{ModuleHeader, SharedModuleHeader, ToplevelDefinition},
+    prelude::GeneratorError,
+};
+
+/// Validated definitions along with the warnings raised during validation
+type Validated = (Vec<ToplevelDefinition>, Vec<CompilerError>);
+
+/// Version of the compiler, which is part of every key, since both the IR
+/// and the generated bindings may change between versions
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 64-bit FNV-1a hasher. Unlike the hasher of the standard library,
+/// its hashes are stable across Rust versions, so they can be used as keys on disk.
+struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+fn stable_hash(values: &[&str]) -> u64 {
+    let mut hasher = StableHasher::default();
+    for value in values {
+        value.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Cache of incremental compilations that is kept in memory for as long as it is used.
+/// Clones share their entries, so that successive compilers can reuse the cache,
+/// e.g. to recompile a specification whenever it changes.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryCache {
+    entries: Arc<Mutex<BTreeMap<String, String>>>,
+}
+
+#[derive(Debug, Clone)]
+enum Storage {
+    Dir(PathBuf),
+    Memory(MemoryCache),
+}
+
+/// Kinds of cache entries, which prefix the names of the entries
+const KINDS: [&str; 4] = ["parsed", "validated", "instances", "generated"];
+
+#[derive(Debug, Clone)]
+pub(crate) struct Cache {
+    storage: Storage,
+    /// Names of the entries that were read or written since the cache was created
+    used: Arc<Mutex<BTreeSet<String>>>,
+}
+
+impl Cache {
+    pub(crate) fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            storage: Storage::Dir(dir.into()),
+            used: Default::default(),
+        }
+    }
+
+    pub(crate) fn in_memory(cache: MemoryCache) -> Self {
+        Self {
+            storage: Storage::Memory(cache),
+            used: Default::default(),
+        }
+    }
+
+    /// Name of an entry, which is marked as used
+    fn name(&self, kind: &str, key: u64) -> String {
+        let name = format!("{kind}-{key:016x}");
+        self.used
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(name.clone());
+        name
+    }
+
+    fn read(&self, kind: &str, key: u64) -> Option<String> {
+        let name = self.name(kind, key);
+        match &self.storage {
+            Storage::Dir(dir) => fs::read_to_string(dir.join(name)).ok(),
+            Storage::Memory(cache) => cache
+                .entries
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(&name)
+                .cloned(),
+        }
+    }
+
+    fn write(&self, kind: &str, key: u64, content: &str) {
+        let name = self.name(kind, key);
+        let dir = match &self.storage {
+            Storage::Dir(dir) => dir,
+            Storage::Memory(cache) => {
+                cache
+                    .entries
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .insert(name, content.into());
+                return;
+            }
+        };
+        // Entries are written to a temporary file first and then moved into place,
+        // so that concurrent compilations never read partially written entries
+        let path = dir.join(name);
+        let temporary = path.with_extension(format!("{}.tmp", std::process::id()));
+        if fs::create_dir_all(dir).is_err() || fs::write(&temporary, content).is_err() {
+            return;
+        }
+        if fs::rename(&temporary, &path).is_err() {
+            let _ = fs::remove_file(&temporary);
+        }
+    }
+
+    /// Removes the entries that were neither read nor written since the cache was created.
+    /// Files in the cache directory that are not cache entries, such as the temporary files
+    /// of concurrent compilations, are kept.
+    pub(crate) fn prune(&self) {
+        let used = self.used.lock().unwrap_or_else(PoisonError::into_inner);
+        match &self.storage {
+            Storage::Dir(dir) => {
+                let Ok(entries) = fs::read_dir(dir) else {
+                    return;
+                };
+                for entry in entries.flatten() {
+                    let name = entry.file_name();
+                    let Some(name) = name.to_str() else {
+                        continue;
+                    };
+                    if is_entry(name) && !used.contains(name) {
+                        let _ = fs::remove_file(entry.path());
+                    }
+                }
+            }
+            Storage::Memory(cache) => cache
+                .entries
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .retain(|name, _| used.contains(name)),
+        }
+    }
+
+    /// Key of the parsed definitions of an ASN.1 source
+    pub(crate) fn source_key(source: &str) -> u64 {
+        stable_hash(&[VERSION, "source", source])
+    }
+
+    /// Parsed modules of the ASN.1 source with the given key, if the source was parsed before
+    pub(crate) fn parsed(&self, key: u64) -> Option<Vec<(ModuleHeader, Vec<ToplevelDefinition>)>> {
+        serde_json::from_str(&self.read("parsed", key)?).ok()
+    }
+
+    pub(crate) fn store_parsed(
+        &self,
+        key: u64,
+        modules: &[(ModuleHeader, Vec<ToplevelDefinition>)],
+    ) {
+        if let Ok(json) = serde_json::to_string(modules) {
+            self.write("parsed", key, &json);
+        }
+    }
+
+    /// Validates parsed modules, reusing the validated definitions of the modules that were
+    /// validated before. Only the other modules, along with the modules they import from, and
+    /// the instances of parameterized definitions are passed to `validate` as its scope, see
+    /// [Validator::with_scope](crate::validator::Validator::with_scope).
+    pub(crate) fn validate(
+        &self,
+        modules: Vec<(ModuleHeader, Vec<ToplevelDefinition>)>,
+        validate: impl FnOnce(
+            Vec<(ModuleHeader, Vec<ToplevelDefinition>)>,
+            BTreeSet<String>,
+        ) -> Result<Validated, CompilerError>,
+    ) -> Result<Validated, CompilerError> {
+        let names = modules
+            .iter()
+            .map(|(header, _)| header.name.clone())
+            .collect::<Vec<_>>();
+        let keys = validation_keys(&modules);
+        // Instances may be added to any module, so they are keyed by all modules
+        let instances_key = {
+            let keys = keys
+                .iter()
+                .map(|key| format!("{key:016x}"))
+                .collect::<Vec<_>>();
+            let mut values = vec![VERSION, "instances"];
+            values.extend(keys.iter().map(String::as_str));
+            stable_hash(&values)
+        };
+        let mut cached = keys
+            .iter()
+            .map(|key| {
+                serde_json::from_str::<(ModuleHeader, Vec<ToplevelDefinition>)>(
+                    &self.read("validated", *key)?,
+                )
+                .ok()
+            })
+            .collect::<Vec<_>>();
+        let cached_instances = self
+            .read("instances", instances_key)
+            .and_then(|json| serde_json::from_str::<Vec<(String, ToplevelDefinition)>>(&json).ok());
+
+        let mut fresh = BTreeMap::<String, Vec<ToplevelDefinition>>::new();
+        let mut scope = BTreeSet::new();
+        let (instances, warnings) = match cached_instances {
+            Some(instances) if cached.iter().all(Option::is_some) => (instances, vec![]),
+            _ => {
+                let imports = import_graph(modules.iter().map(|(header, _)| header));
+                for (name, cached) in names.iter().zip(&cached) {
+                    if cached.is_none() {
+                        scope.insert(name.clone());
+                        scope.extend(dependencies(name, &imports).into_iter().map(str::to_owned));
+                    }
+                }
+                let mut headers = BTreeMap::new();
+                let mut sources = BTreeSet::new();
+                for (header, definitions) in &modules {
+                    sources.extend(definitions.iter().map(|d| d.name().clone()));
+                    if scope.contains(&header.name) {
+                        headers.insert(header.name.clone(), header.clone());
+                    }
+                }
+                let (definitions, warnings) = validate(modules, scope.clone())?;
+                let mut instances = vec![];
+                for definition in definitions {
+                    let module = definition
+                        .get_module_header()
+                        .map_or(<_>::default(), |header| header.read().name.clone());
+                    if sources.contains(definition.name()) {
+                        fresh.entry(module).or_default().push(definition);
+                    } else {
+                        instances.push((module, definition));
+                    }
+                }
+                // Warnings are not cached, so modules with warnings are validated every time
+                if warnings.is_empty() {
+                    for (name, key) in names.iter().zip(&keys) {
+                        let Some(header) = headers.get(name) else {
+                            continue;
+                        };
+                        let definitions = fresh.get(name).map_or(&[][..], Vec::as_slice);
+                        let header = definitions
+                            .first()
+                            .and_then(|d| d.get_module_header())
+                            .map_or_else(|| header.clone(), |header| header.read().clone());
+                        if let Ok(json) = serde_json::to_string(&(header, definitions)) {
+                            self.write("validated", *key, &json);
+                        }
+                    }
+                    if let Ok(json) = serde_json::to_string(&instances) {
+                        self.write("instances", instances_key, &json);
+                    }
+                }
+                (instances, warnings)
+            }
+        };
+
+        // The definitions are ordered like those of a validation without a cache,
+        // i.e. the definitions of the modules in source order, followed by the instances
+        let mut headers = BTreeMap::<String, SharedModuleHeader>::new();
+        let mut validated = vec![];
+        for (name, cached) in names.iter().zip(&mut cached) {
+            let definitions = match (scope.contains(name), cached.take()) {
+                (false, Some((header, mut definitions))) => {
+                    let header = SharedModuleHeader::new(header);
+                    for definition in &mut definitions {
+                        definition.set_module_header(header.clone());
+                    }
+                    headers.insert(name.clone(), header);
+                    definitions
+                }
+                _ => fresh.remove(name).unwrap_or_default(),
+            };
+            if let Some(header) = definitions.first().and_then(|d| d.get_module_header()) {
+                headers.entry(name.clone()).or_insert(header);
+            }
+            validated.extend(definitions);
+        }
+        for (module, mut instance) in instances {
+            if let Some(header) = headers.get(&module) {
+                instance.set_module_header(header.clone());
+            }
+            validated.push(instance);
+        }
+        Ok((validated, warnings))
+    }
+
+    /// Generates the bindings of a module, unless the bindings of the module with
+    /// the given key were generated before, see [module_keys]
+    pub(crate) fn generate<B: Backend>(
+        &self,
+        backend: &mut B,
+        key: u64,
+        module: Vec<ToplevelDefinition>,
+    ) -> Result<GeneratedModule, GeneratorError> {
+        if let Some(generated) = self.read("generated", key) {
+            return Ok(GeneratedModule {
+                generated: Some(generated),
+                warnings: vec![],
+            });
+        }
+        let generated_module = backend.generate_module(module)?;
+        // Warnings are not cached, so modules with warnings are generated every time
+        if let (Some(generated), true) = (
+            &generated_module.generated,
+            generated_module.warnings.is_empty(),
+        ) {
+            self.write("generated", key, generated);
+        }
+        Ok(generated_module)
+    }
+}
+
+/// Modules that the given modules import from, by the name of the importing module
+/// Returns `true` if `name` is the name of a cache entry, e.g. `parsed-0123456789abcdef`.
+fn is_entry(name: &str) -> bool {
+    name.rsplit_once('-').is_some_and(|(kind, key)| {
+        KINDS.contains(&kind) && key.len() == 16 && key.chars().all(|c| c.is_ascii_hexdigit())
+    })
+}
+
+fn import_graph<'a>(
+    headers: impl Iterator<Item = &'a ModuleHeader>,
+) -> BTreeMap<&'a str, BTreeSet<&'a str>> {
+    let mut imports = BTreeMap::<&str, BTreeSet<&str>>::new();
+    for header in headers {
+        let modules = imports.entry(&header.name).or_default();
+        for import in &header.imports {
+            modules.insert(&import.global_module_reference.module_reference);
+        }
+    }
+    imports
+}
+
+/// Modules that a module imports from, directly or indirectly
+fn dependencies<'a>(
+    module: &str,
+    imports: &BTreeMap<&'a str, BTreeSet<&'a str>>,
+) -> BTreeSet<&'a str> {
+    let mut dependencies = BTreeSet::new();
+    let mut pending = vec![module];
+    while let Some(module) = pending.pop() {
+        for import in imports.get(module).into_iter().flatten() {
+            if dependencies.insert(*import) {
+                pending.push(import);
+            }
+        }
+    }
+    dependencies
+}
+
+/// Digest of the JSON serialisation of a module, which is stable across compilations
+/// unlike its debug representation
+fn digest(module: &impl serde::Serialize) -> String {
+    let json = serde_json::to_string(module).unwrap_or_default();
+    format!("{:016x}", stable_hash(&[&json]))
+}
+
+/// Keys of the validated definitions of the given parsed modules, in the order of the modules.
+/// The validated definitions of a module depend on its own definitions and on the definitions
+/// of the modules it imports from, directly or indirectly. Since the names of instances of
+/// parameterized definitions must be unique across all modules, they also depend on the names
+/// of all definitions.
+fn validation_keys(modules: &[(ModuleHeader, Vec<ToplevelDefinition>)]) -> Vec<u64> {
+    let digests = modules
+        .iter()
+        .map(|(header, definitions)| (header.name.as_str(), digest(&(header, definitions))))
+        .collect::<BTreeMap<_, _>>();
+    let names = modules
+        .iter()
+        .flat_map(|(_, definitions)| definitions.iter().map(|d| d.name().as_str()))
+        .collect::<BTreeSet<_>>();
+    let names = format!(
+        "{:016x}",
+        stable_hash(&names.into_iter().collect::<Vec<_>>())
+    );
+    let imports = import_graph(modules.iter().map(|(header, _)| header));
+    modules
+        .iter()
+        .map(|(header, _)| {
+            let mut related = dependencies(&header.name, &imports);
+            related.insert(&header.name);
+            let mut values = vec![VERSION, "validated", &names, &header.name];
+            for module in related {
+                values.push(module);
+                values.push(digests.get(module).map_or("", String::as_str));
+            }
+            stable_hash(&values)
+        })
+        .collect()
+}
+
+/// Keys of the generated bindings of the given modules, in the order of the modules.
+/// The generated bindings of a module depend on its own definitions, on the definitions
+/// that it imports, which may in turn depend on further modules, and, for backends that
+/// list back references such as the documentation, on the modules that import from it.
+pub(crate) fn module_keys<B: Backend>(
+    backend: &B,
+    names: &[String],
+    modules: &[Vec<ToplevelDefinition>],
+) -> Vec<u64> {
+    let headers = modules
+        .iter()
+        .filter_map(|definitions| definitions.first()?.get_module_header())
+        .map(|header| header.read().clone())
+        .collect::<Vec<_>>();
+    let digests = names
+        .iter()
+        .zip(modules)
+        .map(|(name, definitions)| {
+            // The module header is not part of the serialisation of its definitions
+            let header = definitions
+                .first()
+                .and_then(|d| d.get_module_header())
+                .map(|header| header.read().clone());
+            (name.as_str(), digest(&(header, definitions)))
+        })
+        .collect::<BTreeMap<_, _>>();
+    let imports = import_graph(headers.iter());
+    let mut importers = BTreeMap::<&str, BTreeSet<&str>>::new();
+    for (module, dependencies) in &imports {
+        for dependency in dependencies {
+            importers.entry(dependency).or_default().insert(module);
+        }
+    }
+
+    let backend_name = std::any::type_name::<B>();
+    let config = format!("{:?}", backend.config());
+    names
+        .iter()
+        .map(|name| {
+            let mut related = dependencies(name, &imports);
+            related.extend(importers.get(name.as_str()).into_iter().flatten().copied());
+            related.insert(name);
+            let mut values = vec![VERSION, "generated", backend_name, &config, name];
+            for module in related {
+                values.push(module);
+                values.push(digests.get(module).map_or("", String::as_str));
+            }
+            stable_hash(&values)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prelude::RasnBackend, validate_sources, AsnSource, ValidationOptions};
+
+    fn module_keys_of(asn: &str) -> BTreeMap<String, u64> {
+        let (definitions, _) = validate_sources(
+            &[AsnSource::Literal(asn.into())],
+            &ValidationOptions::default(),
+        )
+        .unwrap();
+        let mut modules = BTreeMap::<String, Vec<ToplevelDefinition>>::new();
+        for definition in definitions {
+            let name = definition.get_module_header().unwrap().read().name.clone();
+            modules.entry(name).or_default().push(definition);
+        }
+        let (names, modules): (Vec<_>, Vec<_>) = modules.into_iter().unzip();
+        let keys = module_keys(&RasnBackend::default(), &names, &modules);
+        names.into_iter().zip(keys).collect()
+    }
+
+    #[test]
+    fn hashes_are_stable() {
+        assert_eq!(stable_hash(&[]), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(stable_hash(&["a"]), stable_hash(&["a"]));
+        assert_ne!(stable_hash(&["ab", "c"]), stable_hash(&["a", "bc"]));
+    }
+
+    #[test]
+    fn keys_follow_dependencies() {
+        let spec = |base: &str| {
+            format!(
+                r#"Base DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Small ::= INTEGER ({base})
+                END
+
+                User DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    IMPORTS Small FROM Base;
+                    Wrapper ::= SEQUENCE {{ small Small }}
+                END
+
+                Unrelated DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Flag ::= BOOLEAN
+                END"#
+            )
+        };
+        let before = module_keys_of(&spec("0..7"));
+        let after = module_keys_of(&spec("0..15"));
+        assert_ne!(before["Base"], before["User"]);
+        assert_ne!(before["Base"], after["Base"]);
+        assert_ne!(before["User"], after["User"]);
+        assert_eq!(before["Unrelated"], after["Unrelated"]);
+    }
+
+    #[test]
+    fn validation_follows_dependencies() {
+        let spec = |base: &str| {
+            format!(
+                r#"Base DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Small ::= INTEGER ({base})
+                END
+
+                User DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    IMPORTS Small FROM Base;
+                    Wrapper ::= SEQUENCE {{ small Small }}
+                END
+
+                Unrelated DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Pair {{Type}} ::= SEQUENCE {{ first Type, second Type }}
+                    Flags ::= Pair {{BOOLEAN}}
+                END"#
+            )
+        };
+        let dir = std::env::temp_dir().join(format!("rasn-validation-test-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+        let options = ValidationOptions::default();
+        let validate = |asn: &str| {
+            let modules = crate::lexer::asn_spec(asn.into()).unwrap();
+            let expected = crate::validate_modules(modules.clone(), &options).unwrap();
+            let mut validated_scope = None;
+            let validated = cache
+                .validate(modules, |modules, scope| {
+                    validated_scope = Some(scope.clone());
+                    crate::validate_in_scope(modules, &options, Some(scope))
+                })
+                .unwrap();
+            assert_eq!(validated.0, expected.0);
+            for (validated, expected) in validated.0.iter().zip(&expected.0) {
+                assert_eq!(
+                    *validated.get_module_header().unwrap().read(),
+                    *expected.get_module_header().unwrap().read()
+                );
+            }
+            validated_scope.map(|scope| scope.into_iter().collect::<Vec<_>>())
+        };
+        assert_eq!(
+            validate(&spec("0..7")),
+            Some(vec!["Base".into(), "Unrelated".into(), "User".into()])
+        );
+        assert_eq!(validate(&spec("0..7")), None);
+        assert_eq!(
+            validate(&spec("0..15")),
+            Some(vec!["Base".into(), "User".into()])
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn parsed_definitions_round_trip() {
+        let source = r#"Test DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Choice ::= CHOICE { a REAL, b SEQUENCE OF INTEGER (0..255) }
+            Big ::= INTEGER (0..123456789012345678901234567890)
+            default-value Big ::= 42
+            TEST-CLASS ::= CLASS { &id INTEGER UNIQUE, &Type } WITH SYNTAX { ID &id TYPE &Type }
+            Objects TEST-CLASS ::= { { ID 1 TYPE BOOLEAN } | { ID 2 TYPE Choice }, ... }
+        END"#;
+        let dir = std::env::temp_dir().join(format!("rasn-cache-test-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+        let key = Cache::source_key(source);
+        let parsed = crate::lexer::asn_spec(source.into()).unwrap();
+        cache.store_parsed(key, &parsed);
+        assert_eq!(cache.parsed(key), Some(parsed));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn memory_cache_is_shared_by_clones() {
+        let source = "Test DEFINITIONS AUTOMATIC TAGS ::= BEGIN Flag ::= BOOLEAN END";
+        let memory = MemoryCache::default();
+        let key = Cache::source_key(source);
+        let parsed = crate::lexer::asn_spec(source.into()).unwrap();
+        Cache::in_memory(memory.clone()).store_parsed(key, &parsed);
+        assert_eq!(Cache::in_memory(memory).parsed(key), Some(parsed));
+        assert_eq!(Cache::in_memory(MemoryCache::default()).parsed(key), None);
+    }
+
+    #[test]
+    fn prunes_unused_entries() {
+        let spec = |bound: u32| {
+            format!(
+                "Base DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Id ::= INTEGER (0..{bound})
+                END
+                User DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Flag ::= BOOLEAN
+                END"
+            )
+        };
+        let memory = MemoryCache::default();
+        let compile = |asn: &str| {
+            crate::Compiler::<RasnBackend, _>::new()
+                .add_asn_literal(asn)
+                .set_memory_cache(memory.clone())
+                .compile_to_string()
+                .unwrap();
+            memory
+                .entries
+                .lock()
+                .unwrap()
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        let first = compile(&spec(255));
+        let second = compile(&spec(1023));
+        assert_eq!(first.len(), second.len());
+        assert_ne!(first, second);
+        assert_eq!(compile(&spec(1023)), second);
+
+        assert!(is_entry("generated-0123456789abcdef"));
+        assert!(!is_entry("generated-0123456789abcdef.42.tmp"));
+        assert!(!is_entry("notes-0123456789abcdef"));
+    }
+}
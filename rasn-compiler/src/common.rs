@@ -21,3 +21,18 @@ pub const INTERNAL_IO_FIELD_REF_TYPE_NAME_PREFIX: &str = "IOFR$";
 /// to stringified type names of extension groups in `SEQUENCE`s or `SET`s, so that they
 /// can be properly identified by the individual compiler backends.
 pub const INTERNAL_EXTENSION_GROUP_NAME_PREFIX: &str = "ext_group_";
+
+/// Quotes and escapes a string for use in JSON documents.
+pub(crate) fn json_string(string: &str) -> String {
+    let mut json = String::from('"');
+    for c in string.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
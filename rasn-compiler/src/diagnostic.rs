@@ -0,0 +1,440 @@
+//! The `diagnostic` module describes the errors and warnings of the compiler in a
+//! structured form. Every [Diagnostic] carries a stable [DiagnosticCode], an explicit
+//! [Severity], an optional source [Span] and related notes, and can be rendered
+//! for humans, as JSON or as a [SARIF](https://sarifweb.azurewebsites.net/) log.
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    error::CompilerError,
+    intermediate::error::GrammarErrorType,
+    lexer::error::LexerErrorType,
+    prelude::{GeneratorErrorType, LinkerErrorType},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+macro_rules! diagnostic_codes {
+    ($($variant:ident = $code:literal, $name:literal;)*) => {
+        /// Stable identifier of a kind of diagnostic.
+        /// Codes are never reassigned, so that tools can rely on them across releases.
+        /// `RC00xx` codes belong to parsing, `RC01xx` codes to validation,
+        /// `RC02xx` codes to code generation and `RC09xx` codes to limitations of the compiler.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum DiagnosticCode {
+            $($variant,)*
+        }
+
+        impl DiagnosticCode {
+            /// All diagnostic codes in ascending order
+            pub const ALL: &'static [DiagnosticCode] = &[$(DiagnosticCode::$variant,)*];
+
+            /// The code, e.g. `RC0102`
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(DiagnosticCode::$variant => $code,)*
+                }
+            }
+
+            /// Short description of the code, e.g. `unresolved reference`
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(DiagnosticCode::$variant => $name,)*
+                }
+            }
+        }
+    };
+}
+
+diagnostic_codes! {
+    UnexpectedEndOfInput = "RC0001", "unexpected end of input";
+    SyntaxError = "RC0002", "syntax error";
+    UnreadableSource = "RC0003", "unreadable source";
+    InvalidConstraints = "RC0101", "invalid constraints";
+    UnresolvedReference = "RC0102", "unresolved reference";
+    AmbiguousTags = "RC0103", "ambiguous tags";
+    InvalidValue = "RC0104", "invalid value";
+    SyntaxMismatch = "RC0105", "syntax mismatch";
+    TypeMismatch = "RC0106", "type mismatch";
+    MissingClassField = "RC0107", "missing class field";
+    EmptyChoice = "RC0108", "empty choice";
+    InvalidPerVisibleConstraints = "RC0109", "invalid PER-visible constraints";
+    InvalidParameterization = "RC0110", "invalid parameterization";
+    UnexportedImport = "RC0111", "unexported import";
+    InvalidGeneratedCode = "RC0201", "invalid generated code";
+    FormattingFailure = "RC0202", "formatting failure";
+    OutputFailure = "RC0203", "output failure";
+    Unsupported = "RC0901", "unsupported feature";
+    Internal = "RC0999", "internal error";
+}
+
+impl Display for DiagnosticCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for DiagnosticCode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|code| code.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| format!("Unknown diagnostic code {s}"))
+    }
+}
+
+/// Location in an ASN.1 source. Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Span {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            self.file.as_deref().unwrap_or("<literal>"),
+            self.line,
+            self.column
+        )
+    }
+}
+
+/// An error or warning of the compiler.
+///
+/// ### Example
+/// ```
+/// # use rasn_compiler::prelude::*;
+/// let error = LinkerError::new(
+///     Some("Message".into()),
+///     "Missing dependency Header",
+///     LinkerErrorType::MissingDependency,
+/// );
+/// let diagnostic = Diagnostic::warning(&CompilerError::from(error));
+/// assert_eq!(diagnostic.code.as_str(), "RC0102");
+/// assert_eq!(
+///     diagnostic.to_string(),
+///     "warning[RC0102]: Missing dependency Header\n  = note: in definition `Message`"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub code: DiagnosticCode,
+    pub severity: Severity,
+    pub message: String,
+    pub span: Option<Span>,
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn error(error: &CompilerError) -> Self {
+        Self::new(error, Severity::Error)
+    }
+
+    pub fn warning(error: &CompilerError) -> Self {
+        Self::new(error, Severity::Warning)
+    }
+
+    pub fn new(error: &CompilerError, severity: Severity) -> Self {
+        let in_definition = |name: &str| format!("in definition `{name}`");
+        let (message, span, notes) = match error {
+            CompilerError::Lexer(e) => match &e.kind {
+                LexerErrorType::MatchingError(report) => (
+                    String::from("Failed to match ASN.1 syntax"),
+                    Some(Span {
+                        file: report.src_file.clone(),
+                        line: report.line,
+                        column: report.column,
+                    }),
+                    vec![report.reason.clone()],
+                ),
+                _ => (e.to_string(), None, vec![]),
+            },
+            CompilerError::Grammar(e) => (
+                e.details.clone(),
+                e.span.clone(),
+                e.pdu.iter().map(|pdu| in_definition(pdu)).collect(),
+            ),
+            CompilerError::Linker(e) => (
+                e.details.clone(),
+                e.span.clone(),
+                e.pdu
+                    .iter()
+                    .map(|pdu| in_definition(pdu))
+                    .chain(
+                        e.module
+                            .iter()
+                            .map(|module| format!("in module `{module}`")),
+                    )
+                    .collect(),
+            ),
+            CompilerError::Generator(e) => (
+                e.details.clone(),
+                e.top_level_declaration
+                    .as_ref()
+                    .and_then(|tld| tld.get_module_header()?.read().location(tld.name())),
+                e.top_level_declaration
+                    .iter()
+                    .map(|tld| in_definition(tld.name()))
+                    .collect(),
+            ),
+        };
+        Self {
+            code: error.code(),
+            severity,
+            message,
+            span,
+            notes,
+        }
+    }
+
+    /// Renders the diagnostic as a single-line JSON object
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::json!({
+            "code": self.code.as_str(),
+            "name": self.code.name(),
+            "severity": self.severity.to_string(),
+            "message": self.message,
+            "span": self.span,
+            "notes": self.notes,
+        })
+        .to_string()
+    }
+}
+
+impl From<&CompilerError> for Diagnostic {
+    /// Creates a diagnostic with the severity of the error, see [CompilerError::severity]
+    fn from(error: &CompilerError) -> Self {
+        Self::new(error, error.severity())
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}]: {}", self.severity, self.code, self.message)?;
+        if let Some(span) = &self.span {
+            write!(f, "\n  --> {span}")?;
+        }
+        for note in &self.notes {
+            write!(f, "\n  = note: {note}")?;
+        }
+        Ok(())
+    }
+}
+
+impl CompilerError {
+    /// The severity of the error if it is reported along with the results of a compilation,
+    /// as opposed to aborting the compilation. Validation errors reject the definition they
+    /// concern, while the generators report the definitions they skip as warnings.
+    pub fn severity(&self) -> Severity {
+        match self {
+            CompilerError::Lexer(_) => Severity::Error,
+            CompilerError::Grammar(e) => e.severity,
+            CompilerError::Linker(e) => e.severity,
+            CompilerError::Generator(_) => Severity::Warning,
+        }
+    }
+
+    /// The stable diagnostic code of the error
+    pub fn code(&self) -> DiagnosticCode {
+        match self {
+            CompilerError::Lexer(e) => match e.kind {
+                LexerErrorType::NotEnoughData(_) => DiagnosticCode::UnexpectedEndOfInput,
+                LexerErrorType::MatchingError(_) => DiagnosticCode::SyntaxError,
+                LexerErrorType::IO(_) => DiagnosticCode::UnreadableSource,
+            },
+            CompilerError::Grammar(e) => match e.kind {
+                GrammarErrorType::UnpackingError => DiagnosticCode::TypeMismatch,
+                GrammarErrorType::LinkerError => DiagnosticCode::UnresolvedReference,
+                GrammarErrorType::PerVisibleConstraintError => {
+                    DiagnosticCode::InvalidPerVisibleConstraints
+                }
+                GrammarErrorType::NotYetInplemented => DiagnosticCode::Unsupported,
+                GrammarErrorType::SyntaxMismatch => DiagnosticCode::SyntaxMismatch,
+            },
+            CompilerError::Linker(e) => match e.kind {
+                LinkerErrorType::MissingDependency => DiagnosticCode::UnresolvedReference,
+                LinkerErrorType::InvalidConstraintsError => DiagnosticCode::InvalidConstraints,
+                LinkerErrorType::AmbiguousTagsError => DiagnosticCode::AmbiguousTags,
+                LinkerErrorType::InvalidValueError => DiagnosticCode::InvalidValue,
+                LinkerErrorType::InvalidParameterization => DiagnosticCode::InvalidParameterization,
+                LinkerErrorType::UnexportedImport => DiagnosticCode::UnexportedImport,
+                LinkerErrorType::UnreadableSearchPathFile => DiagnosticCode::UnreadableSource,
+                LinkerErrorType::Unknown => DiagnosticCode::Internal,
+            },
+            CompilerError::Generator(e) => match e.kind {
+                GeneratorErrorType::Asn1TypeMismatch => DiagnosticCode::TypeMismatch,
+                GeneratorErrorType::EmptyChoiceType => DiagnosticCode::EmptyChoice,
+                GeneratorErrorType::MissingCustomSyntax | GeneratorErrorType::SyntaxMismatch => {
+                    DiagnosticCode::SyntaxMismatch
+                }
+                GeneratorErrorType::MissingClassKey => DiagnosticCode::MissingClassField,
+                GeneratorErrorType::LexerError => DiagnosticCode::InvalidGeneratedCode,
+                GeneratorErrorType::FormattingError => DiagnosticCode::FormattingFailure,
+                GeneratorErrorType::IO => DiagnosticCode::OutputFailure,
+                GeneratorErrorType::NotYetInplemented | GeneratorErrorType::Unsupported => {
+                    DiagnosticCode::Unsupported
+                }
+                GeneratorErrorType::Unidentified => DiagnosticCode::Internal,
+            },
+        }
+    }
+}
+
+/// Renders diagnostics as a SARIF 2.1.0 log with a single run.
+/// The rules of the run are the diagnostic codes that occur in `diagnostics`.
+#[cfg(feature = "serde")]
+pub fn sarif_log(diagnostics: &[Diagnostic]) -> String {
+    use serde_json::json;
+
+    let mut codes = diagnostics.iter().map(|d| d.code).collect::<Vec<_>>();
+    codes.sort();
+    codes.dedup();
+    let rules = codes
+        .iter()
+        .map(|code| json!({"id": code.as_str(), "shortDescription": {"text": code.name()}}))
+        .collect::<Vec<_>>();
+    let results = diagnostics
+        .iter()
+        .map(|d| {
+            let message = std::iter::once(d.message.as_str())
+                .chain(d.notes.iter().map(String::as_str))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let mut result = json!({
+                "ruleId": d.code.as_str(),
+                "level": d.severity.to_string(),
+                "message": {"text": message},
+            });
+            if let Some(span) = &d.span {
+                result["locations"] = json!([{
+                    "physicalLocation": {
+                        "artifactLocation": {"uri": span.file.as_deref().unwrap_or_default()},
+                        "region": {"startLine": span.line, "startColumn": span.column.max(1)},
+                    }
+                }]);
+            }
+            result
+        })
+        .collect::<Vec<_>>();
+    let log = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "rasn-compiler",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }],
+    });
+    serde_json::to_string_pretty(&log).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{LexerError, ReportData};
+
+    #[test]
+    fn codes_are_unique_and_parseable() {
+        for (i, code) in DiagnosticCode::ALL.iter().enumerate() {
+            assert_eq!(code.as_str().parse::<DiagnosticCode>(), Ok(*code));
+            assert!(DiagnosticCode::ALL[..i]
+                .iter()
+                .all(|other| other.as_str() < code.as_str()));
+        }
+        assert!("RC4242".parse::<DiagnosticCode>().is_err());
+    }
+
+    #[test]
+    fn renders_diagnostics() {
+        let error = CompilerError::from(LexerError {
+            kind: LexerErrorType::MatchingError(ReportData {
+                src_file: Some("spec.asn".into()),
+                context_start_line: 1,
+                context_start_offset: 0,
+                line: 4,
+                offset: 42,
+                column: 7,
+                reason: "Expected \"::=\"".into(),
+                unexpected_eof: false,
+            }),
+        });
+        let diagnostic = Diagnostic::error(&error);
+        assert_eq!(
+            diagnostic.to_string(),
+            "error[RC0002]: Failed to match ASN.1 syntax\n  --> spec.asn:4:7\n  = note: Expected \"::=\""
+        );
+        #[cfg(feature = "serde")]
+        {
+            use serde_json::{json, Value};
+
+            let parse = |json: &str| serde_json::from_str::<Value>(json).unwrap();
+            assert_eq!(
+                parse(&diagnostic.to_json()),
+                json!({
+                    "code": "RC0002",
+                    "name": "syntax error",
+                    "severity": "error",
+                    "message": "Failed to match ASN.1 syntax",
+                    "span": {"file": "spec.asn", "line": 4, "column": 7},
+                    "notes": ["Expected \"::=\""],
+                })
+            );
+            let sarif = parse(&sarif_log(&[diagnostic]));
+            let run = &sarif["runs"][0];
+            assert_eq!(
+                run["tool"]["driver"]["rules"],
+                json!([{"id": "RC0002", "shortDescription": {"text": "syntax error"}}])
+            );
+            assert_eq!(run["results"][0]["ruleId"], "RC0002");
+            assert_eq!(run["results"][0]["level"], "error");
+            assert_eq!(
+                run["results"][0]["locations"][0]["physicalLocation"]["region"],
+                json!({"startLine": 4, "startColumn": 7})
+            );
+        }
+    }
+
+    #[test]
+    fn locates_rejected_definitions() {
+        let (_, errors) = crate::validate_sources(
+            &[crate::AsnSource::Literal(
+                "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+-- A set whose components share a tag
+Set ::= SET { a [0] INTEGER, b [0] BOOLEAN }
+END"
+                .into(),
+            )],
+            &crate::ValidationOptions::default(),
+        )
+        .unwrap();
+        let diagnostic = Diagnostic::from(&errors[0]);
+        assert_eq!(diagnostic.severity, Severity::Error);
+        assert_eq!(diagnostic.span.unwrap().to_string(), "<literal>:3:1");
+    }
+}
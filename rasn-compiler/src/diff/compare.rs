@@ -0,0 +1,716 @@
+//! Classification of the changes between the definitions of two versions of a specification.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    generator::asn1::notation::{
+        constraints_notation, optionality_notation, tag_notation, value_notation,
+    },
+    intermediate::{
+        constraints::{
+            Constraint, ElementOrSetOperation, SetOperation, SetOperator, SubtypeElements,
+        },
+        encoding_rules::per_visible::{per_visible_range_constraints, PerVisibleRangeConstraints},
+        information_object::ASN1Information,
+        types::*,
+        *,
+    },
+};
+
+use super::{
+    Change,
+    Compatibility::{self, Breaking, Compatible},
+};
+
+pub(super) fn compare_definitions(
+    old: &[ToplevelDefinition],
+    new: &[ToplevelDefinition],
+) -> Vec<Change> {
+    let old = index(old);
+    let new = index(new);
+    let mut comparison = Comparison::default();
+    for ((module, name), old_tld) in &old {
+        let path = format!("{module}.{name}");
+        match new.get(&(module.clone(), name.clone())) {
+            Some(new_tld) => comparison.definitions(&path, old_tld, new_tld),
+            None => comparison.push(&path, "definition removed", Breaking, Breaking),
+        }
+    }
+    for (module, name) in new.keys().filter(|key| !old.contains_key(*key)) {
+        comparison.push(
+            &format!("{module}.{name}"),
+            "definition added",
+            Compatible,
+            Compatible,
+        );
+    }
+    comparison.changes
+}
+
+fn index(tlds: &[ToplevelDefinition]) -> BTreeMap<(String, String), &ToplevelDefinition> {
+    tlds.iter()
+        .filter_map(|tld| {
+            let module = tld.get_module_header()?.read().name.clone();
+            Some(((module, tld.name().clone()), tld))
+        })
+        .collect()
+}
+
+fn tagging_environment(tld: &ToplevelDefinition) -> TaggingEnvironment {
+    tld.get_module_header()
+        .map_or(TaggingEnvironment::Explicit, |header| {
+            header.read().tagging_environment
+        })
+}
+
+/// How the order of the components of a constructed type affects the encoding
+#[derive(Clone, Copy, PartialEq)]
+enum Ordering {
+    /// Components are encoded in the order of their definition, e.g. in a `SEQUENCE`
+    Textual,
+    /// Components are identified by their tags, e.g. in a `SET` or `CHOICE`
+    Canonical,
+    /// Items are identified by their values, e.g. in an `ENUMERATED`
+    Value,
+}
+
+/// Components, alternatives or enumerals of a type, split at the extension marker
+struct Components<'a, T> {
+    noun: &'static str,
+    items: &'a [T],
+    extensible: Option<usize>,
+    name: fn(&T) -> &str,
+}
+
+impl<'a, T> Components<'a, T> {
+    fn new(
+        noun: &'static str,
+        items: &'a [T],
+        extensible: Option<usize>,
+        name: fn(&T) -> &str,
+    ) -> Self {
+        Self {
+            noun,
+            items,
+            extensible,
+            name,
+        }
+    }
+
+    fn root(&self) -> &'a [T] {
+        &self.items[..self.extensible.unwrap_or(self.items.len())]
+    }
+
+    fn extension_additions(&self) -> &'a [T] {
+        &self.items[self.extensible.unwrap_or(self.items.len())..]
+    }
+
+    fn find(&self, name: &str) -> Option<&'a T> {
+        self.items.iter().find(|i| (self.name)(i) == name)
+    }
+
+    fn is_root(&self, name: &str) -> bool {
+        self.root().iter().any(|i| (self.name)(i) == name)
+    }
+
+    /// Names of the items of `part` that are also defined in `other`, in the order of `part`
+    fn common_names(&self, part: &'a [T], other: &Self) -> Vec<&'a str> {
+        part.iter()
+            .map(self.name)
+            .filter(|n| other.find(n).is_some())
+            .collect()
+    }
+}
+
+#[derive(Default)]
+struct Comparison {
+    changes: Vec<Change>,
+    old_environment: Option<TaggingEnvironment>,
+    new_environment: Option<TaggingEnvironment>,
+}
+
+impl Comparison {
+    fn push(
+        &mut self,
+        path: &str,
+        description: impl Into<String>,
+        per: Compatibility,
+        ber: Compatibility,
+    ) {
+        self.changes.push(Change {
+            path: path.to_string(),
+            description: description.into(),
+            per,
+            ber,
+        });
+    }
+
+    fn definitions(&mut self, path: &str, old: &ToplevelDefinition, new: &ToplevelDefinition) {
+        self.old_environment = Some(tagging_environment(old));
+        self.new_environment = Some(tagging_environment(new));
+        match (old, new) {
+            (ToplevelDefinition::Type(o), ToplevelDefinition::Type(n)) => {
+                if o.tag != n.tag {
+                    self.retagged(path, o.tag.as_ref(), n.tag.as_ref(), Compatible);
+                }
+                self.types(path, &o.ty, &n.ty);
+            }
+            (ToplevelDefinition::Value(o), ToplevelDefinition::Value(n)) => {
+                self.types(path, &o.associated_type, &n.associated_type);
+                if o.value != n.value {
+                    self.push(
+                        path,
+                        format!(
+                            "value changed from `{}` to `{}`",
+                            value_notation(&o.value),
+                            value_notation(&n.value)
+                        ),
+                        Breaking,
+                        Breaking,
+                    );
+                }
+            }
+            (ToplevelDefinition::Class(o), ToplevelDefinition::Class(n)) => {
+                if o.definition != n.definition {
+                    self.push(path, "class changed", Breaking, Breaking);
+                }
+            }
+            (ToplevelDefinition::Object(o), ToplevelDefinition::Object(n)) => {
+                // Objects may be added to extensible object sets
+                let extended = match (&o.value, &n.value) {
+                    (ASN1Information::ObjectSet(o), ASN1Information::ObjectSet(n))
+                        if o.extensible.is_some() && n.extensible.is_some() =>
+                    {
+                        o.values
+                            .iter()
+                            .all(|v| n.values.contains(v))
+                            .then_some(o.values.len() < n.values.len())
+                    }
+                    _ => None,
+                };
+                match extended {
+                    Some(true) => self.push(
+                        path,
+                        "objects added to the object set",
+                        Compatible,
+                        Compatible,
+                    ),
+                    None if o.value != n.value => {
+                        self.push(path, "information object changed", Breaking, Breaking)
+                    }
+                    _ => (),
+                }
+            }
+            (ToplevelDefinition::Macro(_), ToplevelDefinition::Macro(_)) => (),
+            _ => self.push(
+                path,
+                "definition changed to a different kind of definition",
+                Breaking,
+                Breaking,
+            ),
+        }
+    }
+
+    fn types(&mut self, path: &str, old: &ASN1Type, new: &ASN1Type) {
+        match (old, new) {
+            (ASN1Type::Enumerated(o), ASN1Type::Enumerated(n)) => self.enumerated(path, o, n),
+            (ASN1Type::Choice(o), ASN1Type::Choice(n)) => self.choice(path, o, n),
+            (ASN1Type::Sequence(o), ASN1Type::Sequence(n)) => {
+                self.sequence_or_set(path, Ordering::Textual, o, n)
+            }
+            (ASN1Type::Set(o), ASN1Type::Set(n)) => {
+                self.sequence_or_set(path, Ordering::Canonical, o, n)
+            }
+            (ASN1Type::SequenceOf(o), ASN1Type::SequenceOf(n))
+            | (ASN1Type::SetOf(o), ASN1Type::SetOf(n)) => {
+                self.constraints(path, &o.constraints, &n.constraints);
+                self.types(&format!("{path}[]"), &o.element_type, &n.element_type);
+            }
+            (ASN1Type::ElsewhereDeclaredType(o), ASN1Type::ElsewhereDeclaredType(n))
+                if o.identifier != n.identifier || o.module != n.module =>
+            {
+                self.type_changed(path, old, new)
+            }
+            (ASN1Type::CharacterString(o), ASN1Type::CharacterString(n)) if o.ty != n.ty => {
+                self.type_changed(path, old, new)
+            }
+            (o, n) if std::mem::discriminant(o) == std::mem::discriminant(n) => {
+                if let (Some(o), Some(n)) = (o.constraints(), n.constraints()) {
+                    self.constraints(path, o, n);
+                }
+            }
+            _ => self.type_changed(path, old, new),
+        }
+    }
+
+    fn type_changed(&mut self, path: &str, old: &ASN1Type, new: &ASN1Type) {
+        self.push(
+            path,
+            format!("type changed from `{}` to `{}`", old.as_str(), new.as_str()),
+            Breaking,
+            Breaking,
+        );
+    }
+
+    fn retagged(
+        &mut self,
+        path: &str,
+        old: Option<&AsnTag>,
+        new: Option<&AsnTag>,
+        per: Compatibility,
+    ) {
+        let notation = |tag: Option<&AsnTag>| tag.map_or(String::from("no tag"), tag_notation);
+        self.push(
+            path,
+            format!("retagged from `{}` to `{}`", notation(old), notation(new)),
+            per,
+            Breaking,
+        );
+    }
+
+    /// Compares the PER-visible size or value ranges of two constraint lists
+    /// as well as the extension additions of their element sets.
+    /// Other changes of the constraints are considered breaking.
+    fn constraints(&mut self, path: &str, old: &[Constraint], new: &[Constraint]) {
+        if old == new {
+            return;
+        }
+        let (old_root, old_additions) = split_extension_additions(old);
+        let (new_root, new_additions) = split_extension_additions(new);
+        if old_additions != new_additions {
+            self.extension_additions(path, old, new, &old_additions, &new_additions);
+        }
+        if old_root == new_root {
+            return;
+        }
+        let ranges = per_visible_range_constraints(true, old)
+            .ok()
+            .zip(per_visible_range_constraints(true, new).ok())
+            .filter(|(o, n)| !same_range(o, n));
+        match ranges {
+            Some((o, n)) => self.ranges(path, &o, &n),
+            None => {
+                let notation = |c: &[Constraint]| {
+                    constraints_notation(c, 0).unwrap_or_else(|| String::from("no constraints"))
+                };
+                self.push(
+                    path,
+                    format!(
+                        "constraints changed from `{}` to `{}`",
+                        notation(old),
+                        notation(new)
+                    ),
+                    Breaking,
+                    Breaking,
+                );
+            }
+        }
+    }
+
+    fn ranges(
+        &mut self,
+        path: &str,
+        old: &PerVisibleRangeConstraints,
+        new: &PerVisibleRangeConstraints,
+    ) {
+        let kind = if old.is_size_constraint() || new.is_size_constraint() {
+            "size"
+        } else {
+            "value"
+        };
+        let description = |change: &str| {
+            format!(
+                "{kind} range {change} from `{}` to `{}`",
+                range_notation(old),
+                range_notation(new)
+            )
+        };
+        let (old_min, old_max) = (old.min::<i128>(), old.max::<i128>());
+        let (new_min, new_max) = (new.min::<i128>(), new.max::<i128>());
+        if (old_min, old_max) == (new_min, new_max) {
+            if new.is_extensible() {
+                self.push(path, description("made extensible"), Breaking, Compatible);
+            } else {
+                self.push(path, description("made inextensible"), Breaking, Breaking);
+            }
+            return;
+        }
+        let widened = new_min.is_none_or(|n| old_min.is_some_and(|o| n <= o))
+            && new_max.is_none_or(|n| old_max.is_some_and(|o| n >= o))
+            && (new.is_extensible() || !old.is_extensible());
+        if widened {
+            self.push(path, description("widened"), Breaking, Compatible);
+        } else {
+            self.push(path, description("narrowed"), Breaking, Breaking);
+        }
+    }
+
+    /// Extension additions are not PER-visible, so values that are added to or removed from
+    /// them are encoded the same way by both versions. Only their validity changes.
+    fn extension_additions(
+        &mut self,
+        path: &str,
+        old: &[Constraint],
+        new: &[Constraint],
+        old_additions: &[ElementOrSetOperation],
+        new_additions: &[ElementOrSetOperation],
+    ) {
+        let description = |change: &str| {
+            format!(
+                "extension additions {change} from `{}` to `{}`",
+                constraints_notation(old, 0).unwrap_or_default(),
+                constraints_notation(new, 0).unwrap_or_default()
+            )
+        };
+        let ranges = |additions: &[ElementOrSetOperation]| {
+            additions
+                .iter()
+                .map(integer_ranges)
+                .collect::<Option<Vec<_>>>()
+                .map(|ranges| ranges.concat())
+        };
+        let widened = old_additions.is_empty()
+            || ranges(old_additions)
+                .zip(ranges(new_additions))
+                .is_some_and(|(old, new)| {
+                    old.iter().all(|(old_min, old_max)| {
+                        new.iter().any(|(new_min, new_max)| {
+                            new_min.is_none_or(|n| old_min.is_some_and(|o| n <= o))
+                                && new_max.is_none_or(|n| old_max.is_some_and(|o| n >= o))
+                        })
+                    })
+                });
+        if widened {
+            self.push(path, description("widened"), Compatible, Compatible);
+        } else {
+            self.push(path, description("narrowed"), Breaking, Breaking);
+        }
+    }
+
+    /// Compares the components, alternatives or enumerals of two versions of a type.
+    /// Returns the pairs of items that are defined in both versions.
+    fn components<'a, T>(
+        &mut self,
+        path: &str,
+        ordering: Ordering,
+        old: &Components<'a, T>,
+        new: &Components<'a, T>,
+    ) -> Vec<(&'a T, &'a T)> {
+        let noun = old.noun;
+        match (old.extensible.is_some(), new.extensible.is_some()) {
+            (false, true) => self.push(path, "extension marker added", Breaking, Compatible),
+            (true, false) => self.push(path, "extension marker removed", Breaking, Breaking),
+            _ => (),
+        }
+        let mut pairs = vec![];
+        for item in old.items {
+            let name = (old.name)(item);
+            let item_path = format!("{path}.{name}");
+            let Some(new_item) = new.find(name) else {
+                self.push(&item_path, format!("{noun} removed"), Breaking, Breaking);
+                continue;
+            };
+            match (old.is_root(name), new.is_root(name)) {
+                (true, false) => self.push(
+                    &item_path,
+                    format!("{noun} moved from the root to the extension additions"),
+                    Breaking,
+                    Breaking,
+                ),
+                (false, true) => self.push(
+                    &item_path,
+                    format!("{noun} moved from the extension additions to the root"),
+                    Breaking,
+                    Breaking,
+                ),
+                _ => (),
+            }
+            pairs.push((item, new_item));
+        }
+
+        for item in new
+            .root()
+            .iter()
+            .filter(|i| old.find((new.name)(i)).is_none())
+        {
+            self.push(
+                &format!("{path}.{}", (new.name)(item)),
+                format!("{noun} added to the root"),
+                Breaking,
+                Breaking,
+            );
+        }
+        let additions = new.extension_additions();
+        let last_kept = additions.iter().rposition(|i| {
+            old.extension_additions()
+                .iter()
+                .any(|o| (old.name)(o) == (new.name)(i))
+        });
+        for (position, item) in additions.iter().enumerate() {
+            let name = (new.name)(item);
+            if old.find(name).is_some() {
+                continue;
+            }
+            let item_path = format!("{path}.{name}");
+            if old.extensible.is_none() {
+                self.push(&item_path, format!("{noun} added"), Breaking, Breaking);
+            } else if last_kept.is_some_and(|last| position < last) {
+                self.push(
+                    &item_path,
+                    format!("{noun} inserted before existing extension additions"),
+                    Breaking,
+                    if ordering == Ordering::Textual {
+                        Breaking
+                    } else {
+                        Compatible
+                    },
+                );
+            } else {
+                self.push(
+                    &item_path,
+                    format!("{noun} added as extension addition"),
+                    Compatible,
+                    Compatible,
+                );
+            }
+        }
+
+        if ordering == Ordering::Textual
+            && old.common_names(old.root(), new) != new.common_names(new.root(), old)
+        {
+            self.push(path, format!("root {noun}s reordered"), Breaking, Breaking);
+        }
+        if old.common_names(old.extension_additions(), new)
+            != new.common_names(new.extension_additions(), old)
+        {
+            self.push(
+                path,
+                format!("extension addition {noun}s reordered"),
+                Breaking,
+                if ordering == Ordering::Textual {
+                    Breaking
+                } else {
+                    Compatible
+                },
+            );
+        }
+        pairs
+    }
+
+    /// Compares the effective tags of the components or alternatives of two versions of a type.
+    /// Retagging breaks PER only if the canonical order of the root components changes.
+    fn component_tags<T>(
+        &mut self,
+        path: &str,
+        ordering: Ordering,
+        old: &Components<'_, T>,
+        new: &Components<'_, T>,
+        tag: fn(&T) -> Option<&AsnTag>,
+    ) {
+        let old_tags = effective_tags(old, self.old_environment, tag);
+        let new_tags = effective_tags(new, self.new_environment, tag);
+        let per = if ordering == Ordering::Textual
+            || canonical_order(old.common_names(old.root(), new), &old_tags)
+                == canonical_order(new.common_names(new.root(), old), &new_tags)
+        {
+            Compatible
+        } else {
+            Breaking
+        };
+        for item in old.items {
+            let name = (old.name)(item);
+            if new.find(name).is_none() {
+                continue;
+            }
+            let (old_tag, new_tag) = (old_tags.get(name), new_tags.get(name));
+            if old_tag != new_tag {
+                self.retagged(&format!("{path}.{name}"), old_tag, new_tag, per);
+            }
+        }
+    }
+
+    fn sequence_or_set(
+        &mut self,
+        path: &str,
+        ordering: Ordering,
+        old: &SequenceOrSet,
+        new: &SequenceOrSet,
+    ) {
+        self.constraints(path, &old.constraints, &new.constraints);
+        if old.components_of != new.components_of {
+            self.push(path, "COMPONENTS OF changed", Breaking, Breaking);
+        }
+        let name: fn(&SequenceOrSetMember) -> &str = |m| &m.name;
+        let old = Components::new("component", &old.members, old.extensible, name);
+        let new = Components::new("component", &new.members, new.extensible, name);
+        let pairs = self.components(path, ordering, &old, &new);
+        self.component_tags(path, ordering, &old, &new, |m| m.tag.as_ref());
+        for (o, n) in pairs {
+            let member_path = format!("{path}.{}", o.name);
+            if o.optionality != n.optionality {
+                let notation = |m: &SequenceOrSetMember| {
+                    optionality_notation(&m.optionality).unwrap_or(String::from("mandatory"))
+                };
+                self.push(
+                    &member_path,
+                    format!("changed from `{}` to `{}`", notation(o), notation(n)),
+                    Breaking,
+                    Breaking,
+                );
+            }
+            self.constraints(&member_path, &o.constraints, &n.constraints);
+            self.types(&member_path, &o.ty, &n.ty);
+        }
+    }
+
+    fn choice(&mut self, path: &str, old: &Choice, new: &Choice) {
+        self.constraints(path, &old.constraints, &new.constraints);
+        let name: fn(&ChoiceOption) -> &str = |o| &o.name;
+        let old = Components::new("alternative", &old.options, old.extensible, name);
+        let new = Components::new("alternative", &new.options, new.extensible, name);
+        let pairs = self.components(path, Ordering::Canonical, &old, &new);
+        self.component_tags(path, Ordering::Canonical, &old, &new, |o| o.tag.as_ref());
+        for (o, n) in pairs {
+            let option_path = format!("{path}.{}", o.name);
+            self.constraints(&option_path, &o.constraints, &n.constraints);
+            self.types(&option_path, &o.ty, &n.ty);
+        }
+    }
+
+    fn enumerated(&mut self, path: &str, old: &Enumerated, new: &Enumerated) {
+        self.constraints(path, &old.constraints, &new.constraints);
+        let name: fn(&Enumeral) -> &str = |e| &e.name;
+        let old = Components::new("enumeral", &old.members, old.extensible, name);
+        let new = Components::new("enumeral", &new.members, new.extensible, name);
+        for (o, n) in self.components(path, Ordering::Value, &old, &new) {
+            if o.index != n.index {
+                self.push(
+                    &format!("{path}.{}", o.name),
+                    format!("value changed from {} to {}", o.index, n.index),
+                    Breaking,
+                    Breaking,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the tags of the components or alternatives, including the automatically assigned ones.
+/// Components without a tag are identified by the universal tag of their type.
+fn effective_tags<'a, T>(
+    components: &Components<'a, T>,
+    environment: Option<TaggingEnvironment>,
+    tag: fn(&T) -> Option<&AsnTag>,
+) -> BTreeMap<&'a str, AsnTag> {
+    let automatic = environment == Some(TaggingEnvironment::Automatic)
+        && components.root().iter().all(|c| tag(c).is_none());
+    components
+        .items
+        .iter()
+        .enumerate()
+        .filter_map(|(i, c)| {
+            let tag = if automatic {
+                AsnTag {
+                    environment: TaggingEnvironment::Automatic,
+                    tag_class: TagClass::ContextSpecific,
+                    id: i as u64,
+                }
+            } else {
+                tag(c)?.clone()
+            };
+            Some(((components.name)(c), tag))
+        })
+        .collect()
+}
+
+/// Sorts the names of the root components in the canonical order of their tags.
+/// Components without a tag keep their textual order.
+fn canonical_order<'a>(mut root: Vec<&'a str>, tags: &BTreeMap<&str, AsnTag>) -> Vec<&'a str> {
+    root.sort_by_key(|name| tags.get(name).map(|t| (tag_class_order(t.tag_class), t.id)));
+    root
+}
+
+/// Position of a tag class in the canonical order of tags
+fn tag_class_order(class: TagClass) -> u8 {
+    match class {
+        TagClass::Universal => 0,
+        TagClass::Application => 1,
+        TagClass::ContextSpecific => 2,
+        TagClass::Private => 3,
+    }
+}
+
+/// Separates the extension additions from the root of each element set of a constraint list.
+fn split_extension_additions(
+    constraints: &[Constraint],
+) -> (Vec<Constraint>, Vec<ElementOrSetOperation>) {
+    let mut additions = vec![];
+    let root = constraints
+        .iter()
+        .map(|constraint| match constraint {
+            Constraint::Subtype(set) => {
+                let mut root = set.clone();
+                additions.extend(root.extension_additions.take());
+                Constraint::Subtype(root)
+            }
+            other => other.clone(),
+        })
+        .collect();
+    (root, additions)
+}
+
+/// Returns the integer ranges of a union of integer values and value ranges,
+/// with `None` standing for `MIN` and `MAX` respectively.
+fn integer_ranges(set: &ElementOrSetOperation) -> Option<Vec<(Option<i128>, Option<i128>)>> {
+    let integer = |value: &ASN1Value| match value {
+        ASN1Value::Integer(i) | ASN1Value::LinkedIntValue { value: i, .. } => {
+            i128::try_from(i).ok()
+        }
+        _ => None,
+    };
+    let element_range = |element: &SubtypeElements| match element {
+        SubtypeElements::SingleValue { value, .. } => integer(value).map(|i| (Some(i), Some(i))),
+        SubtypeElements::ValueRange { min, max, .. } => {
+            let bound = |value: &Option<ASN1Value>| {
+                value
+                    .as_ref()
+                    .map(integer)
+                    .map_or(Some(None), |i| i.map(Some))
+            };
+            Some((bound(min)?, bound(max)?))
+        }
+        _ => None,
+    };
+    match set {
+        ElementOrSetOperation::Element(element) => element_range(element).map(|r| vec![r]),
+        ElementOrSetOperation::SetOperation(SetOperation {
+            base,
+            operator: SetOperator::Union,
+            operant,
+        }) => {
+            let mut ranges = integer_ranges(operant)?;
+            ranges.push(element_range(base)?);
+            Some(ranges)
+        }
+        ElementOrSetOperation::SetOperation(_) => None,
+    }
+}
+
+fn same_range(a: &PerVisibleRangeConstraints, b: &PerVisibleRangeConstraints) -> bool {
+    a.min::<i128>() == b.min::<i128>()
+        && a.max::<i128>() == b.max::<i128>()
+        && a.is_extensible() == b.is_extensible()
+}
+
+fn range_notation(range: &PerVisibleRangeConstraints) -> String {
+    format!(
+        "{}..{}{}",
+        range
+            .min::<i128>()
+            .map_or(String::from(MIN), |m| m.to_string()),
+        range
+            .max::<i128>()
+            .map_or(String::from(MAX), |m| m.to_string()),
+        if range.is_extensible() { ", ..." } else { "" }
+    )
+}
@@ -0,0 +1,292 @@
+//! Compatibility check between two versions of an ASN.1 specification.
+//! The validated definitions of both versions are compared and every change is classified
+//! as wire-compatible or breaking, for the packed and the basic encoding rules separately.
+
+use std::{fmt::Display, path::PathBuf};
+
+use crate::{
+    error::CompilerError, intermediate::ToplevelDefinition, validate_sources, AsnSource,
+    ValidationOptions,
+};
+
+mod compare;
+
+/// Whether data encoded with one version of a specification
+/// can be decoded with the other version and vice versa.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compatibility {
+    Compatible,
+    Breaking,
+}
+
+impl Display for Compatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Compatibility::Compatible => write!(f, "compatible"),
+            Compatibility::Breaking => write!(f, "breaking"),
+        }
+    }
+}
+
+/// A change between two versions of a specification.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    /// Path of the changed element, starting with the module name and the definition name,
+    /// e.g. `ETSI-ITS-CDD.ReferencePosition.latitude`. Items of `SEQUENCE OF` and `SET OF`
+    /// types are addressed by `[]`.
+    pub path: String,
+    pub description: String,
+    /// Compatibility of the change with PER encoded data
+    pub per: Compatibility,
+    /// Compatibility of the change with BER encoded data
+    pub ber: Compatibility,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} (PER: {}, BER: {})",
+            self.path, self.description, self.per, self.ber
+        )
+    }
+}
+
+/// The changes between two versions of an ASN.1 specification.
+///
+/// Definitions are matched by their module and name.
+/// The following changes are wire-compatible:
+/// * additions of definitions
+/// * additions of components, alternatives and enumerals after the extension marker
+///   of an extensible type
+/// * widened size and value constraints, for BER only
+/// * widened extension additions of constraints, such as `(0..100, ...)` to `(0..100, ..., 101..200)`
+/// * added extension markers and extensible constraints, for BER only
+///
+/// Retagging breaks BER, and breaks PER only if it changes the order of
+/// the alternatives of a `CHOICE` or the components of a `SET`.
+/// All other changes, such as removed definitions, components or alternatives,
+/// reordered root components, narrowed constraints or changed types,
+/// break the compatibility with both encoding rules.
+/// ### Example
+/// ```
+/// # use rasn_compiler::prelude::{Compatibility, SpecDiff};
+/// let diff = SpecDiff::from_literals(
+///     "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+///         Message ::= SEQUENCE { id INTEGER (0..255), ... }
+///     END",
+///     "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+///         Message ::= SEQUENCE { id INTEGER (0..255), ..., flag BOOLEAN }
+///     END",
+/// ).unwrap();
+/// assert_eq!(diff.changes[0].path, "Example.Message.flag");
+/// assert_eq!(diff.changes[0].per, Compatibility::Compatible);
+/// assert!(diff.is_compatible());
+/// ```
+#[derive(Debug, Default)]
+pub struct SpecDiff {
+    pub changes: Vec<Change>,
+    /// Errors raised while validating the two versions.
+    /// Definitions that fail to validate are not compared, so they appear as removed or added.
+    pub warnings: Vec<CompilerError>,
+}
+
+impl SpecDiff {
+    /// Compares the validated top-level definitions of two versions of a specification.
+    pub fn new(old: &[ToplevelDefinition], new: &[ToplevelDefinition]) -> Self {
+        Self {
+            changes: compare::compare_definitions(old, new),
+            warnings: vec![],
+        }
+    }
+
+    /// Parses, validates and compares two versions of a specification,
+    /// each given by the paths of its ASN.1 modules.
+    pub fn from_paths(
+        old: impl IntoIterator<Item = impl Into<PathBuf>>,
+        new: impl IntoIterator<Item = impl Into<PathBuf>>,
+    ) -> Result<Self, CompilerError> {
+        Self::from_sources(
+            &old.into_iter()
+                .map(|p| AsnSource::Path(p.into()))
+                .collect::<Vec<_>>(),
+            &new.into_iter()
+                .map(|p| AsnSource::Path(p.into()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Parses, validates and compares two versions of a specification,
+    /// each given as ASN.1 source text.
+    pub fn from_literals(
+        old: impl Into<String>,
+        new: impl Into<String>,
+    ) -> Result<Self, CompilerError> {
+        Self::from_sources(
+            &[AsnSource::Literal(old.into())],
+            &[AsnSource::Literal(new.into())],
+        )
+    }
+
+    fn from_sources(old: &[AsnSource], new: &[AsnSource]) -> Result<Self, CompilerError> {
+        let (old, mut warnings) = validate_sources(old, &ValidationOptions::default())?;
+        let (new, mut new_warnings) = validate_sources(new, &ValidationOptions::default())?;
+        warnings.append(&mut new_warnings);
+        Ok(Self {
+            changes: compare::compare_definitions(&old, &new),
+            warnings,
+        })
+    }
+
+    /// Returns `true` if no change breaks the compatibility with PER encoded data.
+    pub fn is_per_compatible(&self) -> bool {
+        self.changes
+            .iter()
+            .all(|c| c.per == Compatibility::Compatible)
+    }
+
+    /// Returns `true` if no change breaks the compatibility with BER encoded data.
+    pub fn is_ber_compatible(&self) -> bool {
+        self.changes
+            .iter()
+            .all(|c| c.ber == Compatibility::Compatible)
+    }
+
+    /// Returns `true` if no change breaks the compatibility with PER or BER encoded data.
+    pub fn is_compatible(&self) -> bool {
+        self.is_per_compatible() && self.is_ber_compatible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SpecDiff;
+
+    fn changes(old: &str, new: &str) -> Vec<String> {
+        let module =
+            |body: &str| format!("Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN\n{body}\nEND");
+        SpecDiff::from_literals(module(old), module(new))
+            .unwrap()
+            .changes
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn classifies_component_changes() {
+        assert_eq!(
+            changes(
+                "Message ::= SEQUENCE { id INTEGER, flag BOOLEAN, ... }",
+                "Message ::= SEQUENCE { flag BOOLEAN, id INTEGER, ..., note UTF8String }"
+            ),
+            vec![
+                "Example.Message.note: component added as extension addition (PER: compatible, BER: compatible)",
+                "Example.Message: root components reordered (PER: breaking, BER: breaking)",
+                "Example.Message.id: retagged from `[0]` to `[1]` (PER: compatible, BER: breaking)",
+                "Example.Message.flag: retagged from `[1]` to `[0]` (PER: compatible, BER: breaking)",
+            ]
+        );
+        assert_eq!(
+            changes(
+                "Message ::= SEQUENCE { id [1] INTEGER, flag [2] BOOLEAN OPTIONAL }",
+                "Message ::= SEQUENCE { id [3] INTEGER, flag [2] BOOLEAN, extra [4] NULL }"
+            ),
+            vec![
+                "Example.Message.extra: component added to the root (PER: breaking, BER: breaking)",
+                "Example.Message.id: retagged from `[1]` to `[3]` (PER: compatible, BER: breaking)",
+                "Example.Message.flag: changed from `OPTIONAL` to `mandatory` (PER: breaking, BER: breaking)",
+            ]
+        );
+    }
+
+    #[test]
+    fn classifies_alternative_changes() {
+        assert_eq!(
+            changes(
+                "Choice ::= CHOICE { a [0] NULL, b [1] NULL, c [2] NULL, ... }",
+                "Choice ::= CHOICE { b [1] NULL, a [0] NULL, ..., d [3] NULL }"
+            ),
+            vec![
+                "Example.Choice.c: alternative removed (PER: breaking, BER: breaking)",
+                "Example.Choice.d: alternative added as extension addition (PER: compatible, BER: compatible)",
+            ]
+        );
+        assert_eq!(
+            changes(
+                "Choice ::= CHOICE { a [0] NULL, b [1] NULL }",
+                "Choice ::= CHOICE { a [2] NULL, b [1] NULL }"
+            ),
+            vec!["Example.Choice.a: retagged from `[0]` to `[2]` (PER: breaking, BER: breaking)",]
+        );
+    }
+
+    #[test]
+    fn classifies_constraint_changes() {
+        assert_eq!(
+            changes(
+                "Narrowed ::= INTEGER (0..255)
+                Widened ::= INTEGER (0..255)
+                Extensible ::= INTEGER (0..255)
+                Size ::= OCTET STRING (SIZE (1..8, ...))",
+                "Narrowed ::= INTEGER (1..255)
+                Widened ::= INTEGER (-1..256)
+                Extensible ::= INTEGER (0..255, ...)
+                Size ::= OCTET STRING (SIZE (1..8, ...))"
+            ),
+            vec![
+                "Example.Extensible: value range made extensible from `0..255` to `0..255, ...` (PER: breaking, BER: compatible)",
+                "Example.Narrowed: value range narrowed from `0..255` to `1..255` (PER: breaking, BER: breaking)",
+                "Example.Widened: value range widened from `0..255` to `-1..256` (PER: breaking, BER: compatible)",
+            ]
+        );
+    }
+
+    #[test]
+    fn classifies_constraint_extension_addition_changes() {
+        assert_eq!(
+            changes(
+                "Added ::= INTEGER (0..100, ...)
+                Widened ::= INTEGER (0..100, ..., 101..200)
+                Narrowed ::= INTEGER (0..100, ..., 101..200 | 300)
+                Unchanged ::= INTEGER (0..100, ..., 101..200)",
+                "Added ::= INTEGER (0..100, ..., 101..200)
+                Widened ::= INTEGER (0..100, ..., 101..MAX)
+                Narrowed ::= INTEGER (0..100, ..., 101..150)
+                Unchanged ::= INTEGER (0..100, ..., 101..200)"
+            ),
+            vec![
+                "Example.Added: extension additions widened from `(0..100, ...)` to `(0..100, ..., 101..200)` (PER: compatible, BER: compatible)",
+                "Example.Narrowed: extension additions narrowed from `(0..100, ..., 101..200 | 300)` to `(0..100, ..., 101..150)` (PER: breaking, BER: breaking)",
+                "Example.Widened: extension additions widened from `(0..100, ..., 101..200)` to `(0..100, ..., 101..MAX)` (PER: compatible, BER: compatible)",
+            ]
+        );
+    }
+
+    #[test]
+    fn classifies_definition_changes() {
+        let diff = SpecDiff::from_literals(
+            "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Old ::= BOOLEAN
+            Color ::= ENUMERATED { red, green, ... }
+            END",
+            "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            New ::= BOOLEAN
+            Color ::= ENUMERATED { red, green, ..., blue }
+            END",
+        )
+        .unwrap();
+        assert_eq!(
+            diff.changes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "Example.Color.blue: enumeral added as extension addition (PER: compatible, BER: compatible)",
+                "Example.Old: definition removed (PER: breaking, BER: breaking)",
+                "Example.New: definition added (PER: compatible, BER: compatible)",
+            ]
+        );
+        assert!(!diff.is_compatible());
+    }
+}
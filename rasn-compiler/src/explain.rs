@@ -0,0 +1,786 @@
+//! Explanation of the encoding of an ASN.1 type. The type is resolved into a tree of its
+//! components, alternatives and elements, each with the type references that lead to its
+//! built-in type, its tags, its effective constraint and the width of its PER encoding.
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::{
+    common::INTERNAL_EXTENSION_GROUP_NAME_PREFIX,
+    error::CompilerError,
+    generator::{
+        asn1::notation::optionality_notation,
+        documentation::catalog::{Catalog, DefinitionId},
+    },
+    intermediate::{
+        constraints::Constraint,
+        effective_constraints::{character_set, EffectiveConstraint, IntegerSet},
+        types::*,
+        *,
+    },
+    validate_sources, AsnSource, ValidationOptions,
+};
+
+/// Maximum number of type references followed when resolving a type
+const MAX_REFERENCE_DEPTH: usize = 32;
+
+/// Width of the unaligned PER encoding (X.691) of a type,
+/// without the encodings of its components, alternatives or elements.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerWidth {
+    /// `true` if the encoding starts with a bit that flags values outside of the extension root
+    pub extension_bit: bool,
+    pub layout: PerLayout,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PerLayout {
+    /// A fixed number of bits, e.g. of a `BOOLEAN` or of an `INTEGER` with a finite range
+    Bits(u32),
+    /// A bit map that flags the presence of the optional root components of a `SEQUENCE` or `SET`
+    Preamble(u32),
+    /// An index that selects the root alternative of a `CHOICE`
+    Index(u32),
+    /// A length of `length` bits, which is `Some(0)` for a fixed size and `None` for a length
+    /// determinant, followed by `element` bits per element or by the encodings of the elements
+    Length {
+        length: Option<u32>,
+        element: Option<u32>,
+    },
+    /// A length determinant followed by the octets of the value,
+    /// e.g. of an unconstrained `INTEGER`, a `REAL` or a `UTF8String`
+    Octets,
+}
+
+impl Display for PerWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.extension_bit {
+            write!(f, "extension bit + ")?;
+        }
+        match self.layout {
+            PerLayout::Bits(1) => write!(f, "1 bit"),
+            PerLayout::Bits(bits) => write!(f, "{bits} bits"),
+            PerLayout::Preamble(bits) => write!(f, "{bits}-bit preamble"),
+            PerLayout::Index(bits) => write!(f, "{bits}-bit index"),
+            PerLayout::Length { length, element } => {
+                match length {
+                    Some(0) => write!(f, "fixed length")?,
+                    Some(bits) => write!(f, "{bits}-bit length")?,
+                    None => write!(f, "length determinant")?,
+                }
+                match element {
+                    Some(1) => write!(f, ", 1 bit per element"),
+                    Some(bits) => write!(f, ", {bits} bits per element"),
+                    None => Ok(()),
+                }
+            }
+            PerLayout::Octets => write!(f, "length determinant, octets"),
+        }
+    }
+}
+
+/// A type in the tree of an explained type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeNode {
+    /// Name of the component or alternative, `item` for the elements of
+    /// `SEQUENCE OF` and `SET OF` types, or the name of the explained type
+    pub name: String,
+    /// Type references that were followed to the built-in type, outermost first
+    pub references: Vec<DefinitionId>,
+    /// Notation of the built-in type, e.g. `INTEGER`, or the name of an unresolved reference
+    pub ty: String,
+    /// `OPTIONAL` or `DEFAULT` of a component
+    pub optionality: Option<String>,
+    /// `true` for components and alternatives after the extension marker
+    pub extension_addition: bool,
+    /// Tags of the encoding, outermost first. Implicit tags replace the tag of the
+    /// tagged type, while explicit tags precede it.
+    pub tags: Vec<AsnTag>,
+    pub constraint: EffectiveConstraint,
+    /// Width of the unaligned PER encoding, or `None` for unresolved references
+    pub per: Option<PerWidth>,
+    /// `true` if the type contains itself and its children are listed by one of its ancestors
+    pub recursive: bool,
+    /// Components, alternatives or elements of the type
+    pub children: Vec<TypeNode>,
+}
+
+impl TypeNode {
+    fn write(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        prefix: &str,
+        child_prefix: &str,
+    ) -> std::fmt::Result {
+        write!(f, "{prefix}{}: ", self.name)?;
+        for reference in &self.references {
+            write!(f, "{} → ", reference.name)?;
+        }
+        write!(f, "{}", self.ty)?;
+        if let Some(constraint) = constraint_notation(&self.constraint) {
+            write!(f, " {constraint}")?;
+        }
+        if let Some(optionality) = &self.optionality {
+            write!(f, " {optionality}")?;
+        }
+        if self.extension_addition {
+            write!(f, " (extension addition)")?;
+        }
+        if !self.tags.is_empty() {
+            let tags = self.tags.iter().map(tag_notation).collect::<Vec<_>>();
+            write!(f, "  {}", tags.join(" "))?;
+        }
+        if let Some(per) = &self.per {
+            write!(f, "  PER: {per}")?;
+        }
+        if self.recursive {
+            write!(f, "  (recursive)")?;
+        }
+        writeln!(f)?;
+        for (i, child) in self.children.iter().enumerate() {
+            let (branch, indentation) = if i + 1 == self.children.len() {
+                ("└─ ", "   ")
+            } else {
+                ("├─ ", "│  ")
+            };
+            child.write(
+                f,
+                &format!("{child_prefix}{branch}"),
+                &format!("{child_prefix}{indentation}"),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The fully resolved tree of an ASN.1 type, for debugging how values of the type are encoded.
+///
+/// ### Example
+/// ```
+/// # use rasn_compiler::prelude::TypeExplanation;
+/// let explanation = TypeExplanation::from_literal(
+///     "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+///         Id ::= INTEGER (0..255)
+///         Message ::= SEQUENCE { id Id, note IA5String (SIZE (1..16)) OPTIONAL, ... }
+///     END",
+///     "Message",
+/// ).unwrap().unwrap();
+/// assert_eq!(
+///     explanation.to_string(),
+///     r#"Example.Message: SEQUENCE  [UNIVERSAL 16]  PER: extension bit + 1-bit preamble
+/// ├─ id: Id → INTEGER (0..255)  [0]  PER: 8 bits
+/// └─ note: IA5String (SIZE (1..16)) OPTIONAL  [1]  PER: 4-bit length, 7 bits per element
+/// "#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeExplanation {
+    pub id: DefinitionId,
+    pub root: TypeNode,
+    /// Errors raised while validating the specification.
+    /// Definitions that fail to validate are not resolved.
+    pub warnings: Vec<CompilerError>,
+}
+
+impl TypeExplanation {
+    /// Explains the type `name` of the validated top-level definitions.
+    /// The name may be qualified with the name of its module, as in `Module.Type`.
+    /// Returns `None` if no type of that name exists, or if types of that name
+    /// exist in several modules and the name is not qualified.
+    pub fn new(tlds: &[ToplevelDefinition], name: &str) -> Option<Self> {
+        let explainer = Explainer::new(tlds);
+        let (module, name) = match name.split_once('.') {
+            Some((module, name)) => (Some(module), name),
+            None => (None, name),
+        };
+        let mut candidates = explainer
+            .types
+            .iter()
+            .filter(|(id, _)| id.name == name && module.is_none_or(|m| m == id.module));
+        let (id, definition) = match (candidates.next(), candidates.next()) {
+            (Some(candidate), None) => candidate,
+            _ => return None,
+        };
+        let mut root = explainer.node(
+            &id.module,
+            definition.tag.clone(),
+            &definition.ty,
+            &[],
+            &mut vec![id.clone()],
+        );
+        root.name = format!("{}.{}", id.module, id.name);
+        Some(Self {
+            id: id.clone(),
+            root,
+            warnings: vec![],
+        })
+    }
+
+    /// Parses and validates a specification given as ASN.1 source text and explains the type `name`.
+    pub fn from_literal(
+        literal: impl Into<String>,
+        name: &str,
+    ) -> Result<Option<Self>, CompilerError> {
+        let (tlds, warnings) = validate_sources(
+            &[AsnSource::Literal(literal.into())],
+            &ValidationOptions::default(),
+        )?;
+        Ok(Self::new(&tlds, name).map(|explanation| Self {
+            warnings,
+            ..explanation
+        }))
+    }
+}
+
+impl Display for TypeExplanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.root.write(f, "", "")
+    }
+}
+
+struct Explainer<'a> {
+    catalog: Catalog,
+    types: BTreeMap<DefinitionId, &'a ToplevelTypeDefinition>,
+    environments: BTreeMap<String, TaggingEnvironment>,
+}
+
+impl<'a> Explainer<'a> {
+    fn new(tlds: &'a [ToplevelDefinition]) -> Self {
+        let mut types = BTreeMap::new();
+        let mut environments = BTreeMap::new();
+        for tld in tlds {
+            let (ToplevelDefinition::Type(definition), Some(header)) =
+                (tld, tld.get_module_header())
+            else {
+                continue;
+            };
+            let header = header.read();
+            environments.insert(header.name.clone(), header.tagging_environment);
+            types.insert(
+                DefinitionId {
+                    module: header.name.clone(),
+                    name: definition.name.clone(),
+                },
+                definition,
+            );
+        }
+        Self {
+            catalog: Catalog::new(tlds),
+            types,
+            environments,
+        }
+    }
+
+    fn resolve(
+        &self,
+        module: &str,
+        identifier: &str,
+    ) -> Option<(DefinitionId, &'a ToplevelTypeDefinition)> {
+        let id = self.catalog.resolve(module, identifier)?;
+        let definition = self.types.get(&id)?;
+        Some((id, definition))
+    }
+
+    /// Explains the type `ty` written in `module` with the given tag and constraints.
+    /// `expanding` holds the definitions whose children are being explained.
+    fn node(
+        &self,
+        module: &str,
+        tag: Option<AsnTag>,
+        ty: &'a ASN1Type,
+        constraints: &'a [Constraint],
+        expanding: &mut Vec<DefinitionId>,
+    ) -> TypeNode {
+        let resolve = |name: &str| self.resolve(module, name).map(|(_, d)| &d.ty);
+        let constraint = EffectiveConstraint::new(ty, &resolve).constrain(constraints, &resolve);
+
+        let mut declared_tags = tag
+            .map(|t| self.with_environment(t, module))
+            .into_iter()
+            .collect::<Vec<_>>();
+        let mut references = vec![];
+        let mut recursive = false;
+        let mut builtin = ty;
+        let mut builtin_module = module.to_string();
+        while let ASN1Type::ElsewhereDeclaredType(reference) = builtin {
+            let Some((id, definition)) = self.resolve(
+                reference.module.as_deref().unwrap_or(&builtin_module),
+                &reference.identifier,
+            ) else {
+                break;
+            };
+            if references.contains(&id) || references.len() == MAX_REFERENCE_DEPTH {
+                break;
+            }
+            recursive = expanding.contains(&id);
+            declared_tags.extend(
+                definition
+                    .tag
+                    .clone()
+                    .map(|t| self.with_environment(t, &id.module)),
+            );
+            builtin = &definition.ty;
+            builtin_module = id.module.clone();
+            references.push(id);
+            if recursive {
+                break;
+            }
+        }
+
+        let children = if recursive {
+            vec![]
+        } else {
+            let expanded = expanding.len();
+            expanding.extend(references.iter().cloned());
+            let children = self.children(&builtin_module, builtin, expanding);
+            expanding.truncate(expanded);
+            children
+        };
+        TypeNode {
+            name: String::new(),
+            references,
+            ty: type_notation(builtin),
+            optionality: None,
+            extension_addition: false,
+            tags: encoding_tags(declared_tags, builtin),
+            per: per_width(builtin, &constraint),
+            constraint,
+            recursive,
+            children,
+        }
+    }
+
+    fn children(
+        &self,
+        module: &str,
+        ty: &'a ASN1Type,
+        expanding: &mut Vec<DefinitionId>,
+    ) -> Vec<TypeNode> {
+        // Like the comparison of versions, automatic tagging only considers the root components
+        let automatic_environment =
+            self.environments.get(module) == Some(&TaggingEnvironment::Automatic);
+        let automatic_tag = |i: usize| AsnTag {
+            environment: TaggingEnvironment::Automatic,
+            tag_class: TagClass::ContextSpecific,
+            id: i as u64,
+        };
+        match ty {
+            ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+                let root = s.extensible.unwrap_or(s.members.len());
+                let automatic =
+                    automatic_environment && s.members[..root].iter().all(|m| m.tag.is_none());
+                // The components of extension addition groups are listed as extension additions
+                s.members
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, m)| match &m.ty {
+                        ASN1Type::Sequence(group)
+                            if m.name.starts_with(INTERNAL_EXTENSION_GROUP_NAME_PREFIX) =>
+                        {
+                            group.members.iter().map(|m| (m, true)).collect()
+                        }
+                        _ => vec![(m, i >= root)],
+                    })
+                    .enumerate()
+                    .map(|(i, (m, extension_addition))| {
+                        let tag = if automatic {
+                            Some(automatic_tag(i))
+                        } else {
+                            m.tag.clone()
+                        };
+                        TypeNode {
+                            name: m.name.clone(),
+                            optionality: optionality_notation(&m.optionality),
+                            extension_addition,
+                            ..self.node(module, tag, &m.ty, &m.constraints, expanding)
+                        }
+                    })
+                    .collect()
+            }
+            ASN1Type::Choice(c) => {
+                let root = c.extensible.unwrap_or(c.options.len());
+                let automatic =
+                    automatic_environment && c.options[..root].iter().all(|o| o.tag.is_none());
+                c.options
+                    .iter()
+                    .enumerate()
+                    .map(|(i, o)| {
+                        let tag = if automatic {
+                            Some(automatic_tag(i))
+                        } else {
+                            o.tag.clone()
+                        };
+                        TypeNode {
+                            name: o.name.clone(),
+                            extension_addition: i >= root,
+                            ..self.node(module, tag, &o.ty, &o.constraints, expanding)
+                        }
+                    })
+                    .collect()
+            }
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => vec![TypeNode {
+                name: String::from("item"),
+                ..self.node(
+                    module,
+                    s.element_tag.clone(),
+                    &s.element_type,
+                    &[],
+                    expanding,
+                )
+            }],
+            _ => vec![],
+        }
+    }
+
+    /// Resolves the tagging environment of a tag written without `IMPLICIT` or `EXPLICIT`
+    /// to the one of its module
+    fn with_environment(&self, tag: AsnTag, module: &str) -> AsnTag {
+        match tag.environment {
+            TaggingEnvironment::Automatic => AsnTag {
+                environment: self
+                    .environments
+                    .get(module)
+                    .copied()
+                    .unwrap_or(TaggingEnvironment::Explicit),
+                ..tag
+            },
+            _ => tag,
+        }
+    }
+}
+
+/// Folds the declared tags of a type, outermost first, onto the universal tag of its built-in type.
+/// Tags of untagged `CHOICE` and open types are always explicit (X.680 §31.2.7).
+fn encoding_tags(declared: Vec<AsnTag>, builtin: &ASN1Type) -> Vec<AsnTag> {
+    let mut tags = universal_tag(builtin)
+        .map(|id| AsnTag {
+            environment: TaggingEnvironment::Implicit,
+            tag_class: TagClass::Universal,
+            id,
+        })
+        .into_iter()
+        .collect::<Vec<_>>();
+    for tag in declared.into_iter().rev() {
+        if tag.environment != TaggingEnvironment::Explicit && !tags.is_empty() {
+            tags.pop();
+            tags.push(AsnTag {
+                environment: TaggingEnvironment::Implicit,
+                ..tag
+            });
+        } else {
+            tags.push(AsnTag {
+                environment: TaggingEnvironment::Explicit,
+                ..tag
+            });
+        }
+    }
+    tags.reverse();
+    tags
+}
+
+/// Number of the universal tag of a built-in type (X.680 §8.4),
+/// or `None` for untagged types such as `CHOICE` and open types
+fn universal_tag(ty: &ASN1Type) -> Option<u64> {
+    let id = match ty {
+        ASN1Type::Boolean(_) => 1,
+        ASN1Type::Integer(_) => 2,
+        ASN1Type::BitString(_) => 3,
+        ASN1Type::OctetString(_) => 4,
+        ASN1Type::Null => 5,
+        ASN1Type::ObjectIdentifier(_) => 6,
+        ASN1Type::ObjectDescriptor(_) => 7,
+        ASN1Type::External => 8,
+        ASN1Type::Real(_) => 9,
+        ASN1Type::Enumerated(_) => 10,
+        ASN1Type::EmbeddedPdv => 11,
+        ASN1Type::RelativeOid(_) => 13,
+        ASN1Type::Time(_) => 14,
+        ASN1Type::Sequence(_) | ASN1Type::SequenceOf(_) => 16,
+        ASN1Type::Set(_) | ASN1Type::SetOf(_) => 17,
+        ASN1Type::UTCTime(_) => 23,
+        ASN1Type::GeneralizedTime(_) => 24,
+        ASN1Type::UnrestrictedCharacterString => 29,
+        ASN1Type::OidIri(_) => 35,
+        ASN1Type::RelativeOidIri(_) => 36,
+        ASN1Type::CharacterString(c) => match c.ty {
+            CharacterStringType::UTF8String => 12,
+            CharacterStringType::NumericString => 18,
+            CharacterStringType::PrintableString => 19,
+            CharacterStringType::TeletexString => 20,
+            CharacterStringType::VideotexString => 21,
+            CharacterStringType::IA5String => 22,
+            CharacterStringType::GraphicString => 25,
+            CharacterStringType::VisibleString => 26,
+            CharacterStringType::GeneralString => 27,
+            CharacterStringType::UniversalString => 28,
+            CharacterStringType::BMPString => 30,
+        },
+        ASN1Type::Choice(_)
+        | ASN1Type::ElsewhereDeclaredType(_)
+        | ASN1Type::ChoiceSelectionType(_)
+        | ASN1Type::ObjectClassField(_)
+        | ASN1Type::Any(_) => return None,
+    };
+    Some(id)
+}
+
+/// Width of the unaligned PER encoding of a built-in type with the given effective constraint
+fn per_width(ty: &ASN1Type, constraint: &EffectiveConstraint) -> Option<PerWidth> {
+    let width = |extension_bit, layout| {
+        Some(PerWidth {
+            extension_bit,
+            layout,
+        })
+    };
+    let range = |set: &Option<IntegerSet>| {
+        let set = set.as_ref()?;
+        set.max()?.checked_sub(set.min()?)?.checked_add(1)
+    };
+    let length = |element| {
+        let length = constraint.size.as_ref().and_then(|size| {
+            // Sizes beyond 64K are encoded with a length determinant (X.691 §11.9.4.1)
+            (size.max()? < 65536).then_some(bits(range(&constraint.size)?))
+        });
+        width(
+            constraint.extensible && constraint.size.is_some(),
+            PerLayout::Length { length, element },
+        )
+    };
+    match ty {
+        ASN1Type::Null => width(false, PerLayout::Bits(0)),
+        ASN1Type::Boolean(_) => width(false, PerLayout::Bits(1)),
+        ASN1Type::Integer(_) => match range(&constraint.values) {
+            Some(range) => width(constraint.extensible, PerLayout::Bits(bits(range))),
+            None => width(constraint.extensible, PerLayout::Octets),
+        },
+        ASN1Type::Enumerated(e) => width(
+            e.extensible.is_some(),
+            PerLayout::Bits(bits(e.extensible.unwrap_or(e.members.len()) as i128)),
+        ),
+        ASN1Type::Choice(c) => width(
+            c.extensible.is_some(),
+            PerLayout::Index(bits(c.extensible.unwrap_or(c.options.len()) as i128)),
+        ),
+        ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+            let root = &s.members[..s.extensible.unwrap_or(s.members.len())];
+            let optional = root
+                .iter()
+                .filter(|m| m.optionality != Optionality::Required)
+                .count();
+            width(s.extensible.is_some(), PerLayout::Preamble(optional as u32))
+        }
+        ASN1Type::BitString(_) => length(Some(1)),
+        ASN1Type::OctetString(_) => length(Some(8)),
+        ASN1Type::SequenceOf(_) | ASN1Type::SetOf(_) => length(None),
+        // Only known-multiplier character strings have PER-visible size and alphabet constraints
+        ASN1Type::CharacterString(c) => match c.ty {
+            CharacterStringType::NumericString
+            | CharacterStringType::PrintableString
+            | CharacterStringType::VisibleString
+            | CharacterStringType::IA5String
+            | CharacterStringType::BMPString => {
+                length(constraint.alphabet.as_ref().map(|a| bits(count(a))))
+            }
+            CharacterStringType::UniversalString => length(Some(32)),
+            _ => width(false, PerLayout::Octets),
+        },
+        ASN1Type::ElsewhereDeclaredType(_) => None,
+        _ => width(false, PerLayout::Octets),
+    }
+}
+
+/// Number of elements of a set
+fn count(set: &IntegerSet) -> i128 {
+    set.intervals()
+        .iter()
+        .map(|(min, max)| max.saturating_sub(*min).saturating_add(1))
+        .fold(0, i128::saturating_add)
+}
+
+/// Number of bits that encode one of `count` values
+fn bits(count: i128) -> u32 {
+    if count <= 1 {
+        0
+    } else {
+        128 - (count - 1).leading_zeros()
+    }
+}
+
+fn type_notation(ty: &ASN1Type) -> String {
+    match ty {
+        ASN1Type::Enumerated(e) => {
+            let mut enumerals = e
+                .members
+                .iter()
+                .map(|m| format!("{}({})", m.name, m.index))
+                .collect::<Vec<_>>();
+            if let Some(extensible) = e.extensible {
+                enumerals.insert(extensible, String::from("..."));
+            }
+            format!("ENUMERATED {{ {} }}", enumerals.join(", "))
+        }
+        ty => ty.as_str().into_owned(),
+    }
+}
+
+fn tag_notation(tag: &AsnTag) -> String {
+    let class = match tag.tag_class {
+        TagClass::Universal => "UNIVERSAL ",
+        TagClass::Application => "APPLICATION ",
+        TagClass::Private => "PRIVATE ",
+        TagClass::ContextSpecific => "",
+    };
+    format!("[{class}{}]", tag.id)
+}
+
+/// Notation of the permitted values, sizes and characters, if any are restricted
+fn constraint_notation(constraint: &EffectiveConstraint) -> Option<String> {
+    let mut parts = vec![];
+    if let Some(values) = &constraint.values {
+        parts.push(values.to_string());
+    }
+    if let Some(size) = &constraint.size {
+        parts.push(format!("SIZE ({size})"));
+    }
+    if let Some(alphabet) = &constraint.alphabet {
+        let default = [
+            CharacterStringType::NumericString,
+            CharacterStringType::PrintableString,
+            CharacterStringType::VisibleString,
+            CharacterStringType::IA5String,
+            CharacterStringType::BMPString,
+        ]
+        .into_iter()
+        .any(|ty| character_set(ty).as_ref() == Some(alphabet));
+        if !default {
+            parts.push(format!("FROM ({})", alphabet_notation(alphabet)));
+        }
+    }
+    if parts.is_empty() {
+        return None;
+    }
+    // The effective constraint is the intersection of the permitted values, sizes and characters
+    let extension = if constraint.extensible { ", ..." } else { "" };
+    let approximation = if constraint.exact {
+        ""
+    } else {
+        " (approximately)"
+    };
+    Some(format!("({}{extension}){approximation}", parts.join(" ^ ")))
+}
+
+fn alphabet_notation(alphabet: &IntegerSet) -> String {
+    let character = |code: i128| match u32::try_from(code).ok().and_then(char::from_u32) {
+        Some(c) if !c.is_control() => format!("\"{c}\""),
+        _ => code.to_string(),
+    };
+    alphabet
+        .intervals()
+        .iter()
+        .map(|(min, max)| {
+            if min == max {
+                character(*min)
+            } else {
+                format!("{}..{}", character(*min), character(*max))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explain(body: &str, name: &str) -> String {
+        TypeExplanation::from_literal(
+            format!(
+                "Other DEFINITIONS EXPLICIT TAGS ::= BEGIN
+                    Flag ::= [APPLICATION 1] BOOLEAN
+                END
+
+                Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    IMPORTS Flag FROM Other;
+                    {body}
+                END"
+            ),
+            name,
+        )
+        .unwrap()
+        .unwrap()
+        .to_string()
+    }
+
+    #[test]
+    fn explains_tags() {
+        assert_eq!(
+            explain(
+                "Choice ::= CHOICE { flag Flag, inner Inner, ..., added NULL }
+                Inner ::= CHOICE { a [5] INTEGER (-1..1), b [APPLICATION 2] IMPLICIT NULL }",
+                "Example.Choice"
+            ),
+            r#"Example.Choice: CHOICE  PER: extension bit + 1-bit index
+├─ flag: Flag → BOOLEAN  [0] [UNIVERSAL 1]  PER: 1 bit
+├─ inner: Inner → CHOICE  [1]  PER: 1-bit index
+│  ├─ a: INTEGER (-1..1)  [5]  PER: 2 bits
+│  └─ b: NULL  [APPLICATION 2]  PER: 0 bits
+└─ added: NULL (extension addition)  [2]  PER: 0 bits
+"#
+        );
+    }
+
+    #[test]
+    fn explains_constraints_and_widths() {
+        assert_eq!(
+            explain(
+                "Message ::= SEQUENCE {
+                    kind ENUMERATED { a, b, c, ..., d },
+                    code NumericString (SIZE (4)),
+                    name PrintableString (FROM (\"A\"..\"Z\")) (SIZE (1..64, ...)),
+                    data OCTET STRING,
+                    items SEQUENCE (SIZE (0..1000)) OF INTEGER (0..1000000) OPTIONAL
+                }",
+                "Message"
+            ),
+            r#"Example.Message: SEQUENCE  [UNIVERSAL 16]  PER: 1-bit preamble
+├─ kind: ENUMERATED { a(0), b(1), c(2), ..., d(3) }  [0]  PER: extension bit + 2 bits
+├─ code: NumericString (SIZE (4))  [1]  PER: fixed length, 4 bits per element
+├─ name: PrintableString (SIZE (1..64) ^ FROM ("A".."Z"), ...)  [2]  PER: extension bit + 6-bit length, 5 bits per element
+├─ data: OCTET STRING  [3]  PER: length determinant, 8 bits per element
+└─ items: SEQUENCE OF (SIZE (0..1000)) OPTIONAL  [4]  PER: 10-bit length
+   └─ item: INTEGER (0..1000000)  [UNIVERSAL 2]  PER: 20 bits
+"#
+        );
+    }
+
+    #[test]
+    fn lists_components_of_extension_groups() {
+        assert_eq!(
+            explain(
+                "Message ::= SEQUENCE { id INTEGER (0..7), ..., [[ a BOOLEAN, b NULL OPTIONAL ]] }",
+                "Message"
+            ),
+            r#"Example.Message: SEQUENCE  [UNIVERSAL 16]  PER: extension bit + 0-bit preamble
+├─ id: INTEGER (0..7)  [0]  PER: 3 bits
+├─ a: BOOLEAN (extension addition)  [1]  PER: 1 bit
+└─ b: NULL OPTIONAL (extension addition)  [2]  PER: 0 bits
+"#
+        );
+    }
+
+    #[test]
+    fn stops_at_recursion() {
+        assert_eq!(
+            explain("Tree ::= SEQUENCE { children SEQUENCE OF Tree }", "Tree"),
+            r#"Example.Tree: SEQUENCE  [UNIVERSAL 16]  PER: 0-bit preamble
+└─ children: SEQUENCE OF  [0]  PER: length determinant
+   └─ item: Tree → SEQUENCE  [UNIVERSAL 16]  PER: 0-bit preamble  (recursive)
+"#
+        );
+        assert!(TypeExplanation::from_literal(
+            "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN Flag ::= BOOLEAN END",
+            "Missing"
+        )
+        .unwrap()
+        .is_none());
+    }
+}
@@ -0,0 +1,109 @@
+//! Extraction of ASN.1 that is embedded in specification text.
+//! 3GPP and ETSI specifications mark their ASN.1 with `-- ASN1START` and `-- ASN1STOP`
+//! comment lines.
+
+const START_MARKER: &str = "ASN1START";
+const STOP_MARKER: &str = "ASN1STOP";
+
+/// Extracts the ASN.1 between `-- ASN1START` and `-- ASN1STOP` marker lines from specification text.
+/// The marker lines and the lines outside of the marked regions are blanked, so that the extracted
+/// ASN.1 keeps the line and column numbers of the specification text.
+/// A region that is not closed extends to the end of the text.
+///
+/// Returns `None` if the text does not contain any marked region.
+///
+/// ### Example
+/// ```
+/// # use rasn_compiler::prelude::extract_marked_asn1;
+/// let spec = "The message is defined as follows:
+/// -- ASN1START
+/// Message ::= INTEGER
+/// -- ASN1STOP
+/// ";
+/// assert_eq!(
+///     extract_marked_asn1(spec).unwrap(),
+///     "\n\nMessage ::= INTEGER\n\n"
+/// );
+/// ```
+pub fn extract_marked_asn1(text: &str) -> Option<String> {
+    let mut extracted = String::with_capacity(text.len());
+    let mut in_region = false;
+    let mut found = false;
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\r', '\n']);
+        if is_marker(content, START_MARKER) {
+            in_region = true;
+            found = true;
+        } else if is_marker(content, STOP_MARKER) {
+            in_region = false;
+        } else if in_region {
+            extracted.push_str(content);
+        }
+        extracted.push_str(&line[content.len()..]);
+    }
+    found.then_some(extracted)
+}
+
+/// Returns `true` for comment lines such as `-- ASN1START` or `--ASN1STOP`.
+fn is_marker(line: &str, marker: &str) -> bool {
+    line.trim_start()
+        .strip_prefix("--")
+        .is_some_and(|comment| comment.trim_start().starts_with(marker))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::{Diagnostic, RasnBackend},
+        Compiler,
+    };
+
+    #[test]
+    fn extracts_marked_regions() {
+        let spec = "4.2 Messages\r\n\
+            -- ASN1START\r\n\
+            Example DEFINITIONS ::= BEGIN\r\n\
+            -- ASN1STOP\r\n\
+            The example starts a module.\r\n\
+            \t--ASN1START\r\n\
+            Id ::= INTEGER\r\n\
+            END\r\n\
+            -- ASN1STOP";
+        assert_eq!(
+            extract_marked_asn1(spec).unwrap(),
+            "\r\n\r\nExample DEFINITIONS ::= BEGIN\r\n\r\n\r\n\r\nId ::= INTEGER\r\nEND\r\n"
+        );
+        assert_eq!(extract_marked_asn1("Id ::= INTEGER"), None);
+    }
+
+    #[test]
+    fn reports_lines_of_specification_text() {
+        let path = std::env::temp_dir().join(format!(
+            "rasn-compiler-extraction-{}.txt",
+            std::process::id()
+        ));
+        std::fs::write(
+            &path,
+            "6.2 Messages
+            -- ASN1START
+            Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            -- ASN1STOP
+            The IE Id is defined as follows.
+            -- ASN1START
+            Id ::= INTEGER (0..7)
+            Message ::= SEQUENCE { id Id
+            END
+            -- ASN1STOP",
+        )
+        .unwrap();
+        let error = Compiler::<RasnBackend, _>::new()
+            .add_marked_asn_by_path(&path)
+            .compile_to_string()
+            .unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        let span = Diagnostic::error(&error).span.unwrap();
+        assert_eq!(span.file, Some(path.to_string_lossy().to_string()));
+        assert_eq!(span.line, 8);
+    }
+}
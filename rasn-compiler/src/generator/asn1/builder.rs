@@ -0,0 +1,259 @@
+use crate::intermediate::{
+    information_object::{
+        ASN1Information, ClassLink, InformationObjectClassField, ObjectClassAssignment,
+        ObjectClassDefn, ToplevelInformationDefinition,
+    },
+    macros::ToplevelMacroDefinition,
+    *,
+};
+
+use super::{notation::*, Asn1};
+use crate::lexer::type_identifier;
+
+impl Asn1 {
+    /// Formats parsed modules in the order of their definitions, separated by a blank line.
+    pub(crate) fn modules_notation(
+        &self,
+        modules: &[(ModuleHeader, Vec<ToplevelDefinition>)],
+    ) -> String {
+        modules
+            .iter()
+            .map(|(header, tlds)| {
+                let definitions = tlds
+                    .iter()
+                    .map(|tld| self.definition_notation(tld))
+                    .collect::<Vec<_>>();
+                Self::module_notation(header, &definitions)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub(crate) fn module_notation(header: &ModuleHeader, definitions: &[String]) -> String {
+        let mut notation = header.name.clone();
+        match &header.module_identifier {
+            Some(DefinitiveIdentifier::DefinitiveOID(oid)) => {
+                notation.push(' ');
+                notation.push_str(&object_identifier_notation(oid));
+            }
+            Some(DefinitiveIdentifier::DefinitiveOIDandIRI { oid, iri }) => {
+                notation.push_str(&format!(" {} \"/{iri}\"", object_identifier_notation(oid)));
+            }
+            None => (),
+        }
+        notation.push_str(&format!("\n{DEFINITIONS}"));
+        if let Some(EncodingReferenceDefault(reference)) = &header.encoding_reference_default {
+            notation.push_str(&format!(" {reference} {INSTRUCTIONS}"));
+        }
+        notation.push_str(match header.tagging_environment {
+            TaggingEnvironment::Automatic => " AUTOMATIC TAGS",
+            TaggingEnvironment::Implicit => " IMPLICIT TAGS",
+            TaggingEnvironment::Explicit => " EXPLICIT TAGS",
+        });
+        if header.extensibility_environment == ExtensibilityEnvironment::Implied {
+            notation.push_str(&format!(" {EXTENSIBILITY_IMPLIED}"));
+        }
+        notation.push_str(&format!(" {ASSIGN}\n{BEGIN}\n"));
+        match &header.exports {
+            Some(Exports::All) => notation.push_str(&format!("\n{EXPORTS} {ALL};\n")),
+            Some(Exports::Identifier(identifiers)) => {
+                notation.push_str(&format!("\n{EXPORTS} {};\n", identifiers.join(", ")))
+            }
+            None => (),
+        }
+        if !header.imports.is_empty() {
+            let imports = header
+                .imports
+                .iter()
+                .map(|import| format!("{}{}", indentation(1), import_notation(import)))
+                .collect::<Vec<_>>()
+                .join("\n");
+            notation.push_str(&format!("\n{IMPORTS}\n{imports};\n"));
+        }
+        for definition in definitions {
+            notation.push('\n');
+            notation.push_str(definition);
+            notation.push('\n');
+        }
+        notation.push_str(&format!("\n{END}\n"));
+        notation
+    }
+
+    pub(crate) fn definition_notation(&self, tld: &ToplevelDefinition) -> String {
+        match tld {
+            ToplevelDefinition::Type(t) => Self::type_definition_notation(t),
+            ToplevelDefinition::Value(v) => Self::value_definition_notation(v),
+            ToplevelDefinition::Class(c) => Self::class_assignment_notation(c),
+            ToplevelDefinition::Object(o) => self.information_definition_notation(o),
+            ToplevelDefinition::Macro(m) => Self::macro_definition_notation(m),
+        }
+    }
+
+    fn type_definition_notation(t: &ToplevelTypeDefinition) -> String {
+        format!(
+            "{}{}{} {ASSIGN} {}{}{}",
+            comments_notation(&t.comments, 0),
+            t.name,
+            t.parameterization
+                .as_ref()
+                .map(|p| format!(" {}", parameters_notation(p)))
+                .unwrap_or_default(),
+            t.tag
+                .as_ref()
+                .map(|tag| tag_notation(tag) + " ")
+                .unwrap_or_default(),
+            type_notation(&t.ty, 0),
+            trailing_comment_notation(t.trailing_comment.as_deref())
+        )
+    }
+
+    fn value_definition_notation(v: &ToplevelValueDefinition) -> String {
+        format!(
+            "{}{}{} {} {ASSIGN} {}{}",
+            comments_notation(&v.comments, 0),
+            v.name,
+            v.parameterization
+                .as_ref()
+                .map(|p| format!(" {}", parameters_notation(p)))
+                .unwrap_or_default(),
+            type_notation(&v.associated_type, 0),
+            value_notation(&v.value),
+            trailing_comment_notation(v.trailing_comment.as_deref())
+        )
+    }
+
+    fn class_assignment_notation(c: &ObjectClassAssignment) -> String {
+        format!(
+            "{}{}{} {ASSIGN} {}{}",
+            comments_notation(&c.comments, 0),
+            c.name,
+            if c.parameterization.parameters.is_empty() {
+                String::new()
+            } else {
+                format!(" {}", parameters_notation(&c.parameterization))
+            },
+            class_notation(&c.definition),
+            trailing_comment_notation(c.trailing_comment.as_deref())
+        )
+    }
+
+    fn information_definition_notation(&self, o: &ToplevelInformationDefinition) -> String {
+        let (class, value) = match (&o.value, &o.class) {
+            (_, ClassLink::ByInstantiation(name, parameters)) => (
+                format!("{name} {}", actual_parameters_notation(parameters, 0)),
+                match &o.value {
+                    ASN1Information::Object(object) => object_notation(&object.fields, 0),
+                    ASN1Information::ObjectSet(set) => object_set_notation(set, 0),
+                },
+            ),
+            (ASN1Information::Object(object), _) => (
+                object.class_name.clone(),
+                object_notation(&object.fields, 0),
+            ),
+            (ASN1Information::ObjectSet(set), ClassLink::ByName(name)) => {
+                (name.clone(), object_set_notation(set, 0))
+            }
+            (ASN1Information::ObjectSet(set), ClassLink::ByReference(definition)) => (
+                self.class_name(definition)
+                    .unwrap_or_else(|| class_notation(definition)),
+                object_set_notation(set, 0),
+            ),
+        };
+        format!(
+            "{}{}{} {class} {ASSIGN} {value}{}",
+            comments_notation(&o.comments, 0),
+            o.name,
+            o.parameterization
+                .as_ref()
+                .map(|p| format!(" {}", parameters_notation(p)))
+                .unwrap_or_default(),
+            trailing_comment_notation(o.trailing_comment.as_deref())
+        )
+    }
+
+    fn macro_definition_notation(m: &ToplevelMacroDefinition) -> String {
+        format!("{} {MACRO} {ASSIGN} {}", m.name, m.substance)
+    }
+
+    /// Returns the name of a class that the validator linked by its definition.
+    fn class_name(&self, definition: &ObjectClassDefn) -> Option<String> {
+        self.classes
+            .iter()
+            .find_map(|(name, d)| (d == definition).then(|| name.clone()))
+    }
+}
+
+fn import_notation(import: &Import) -> String {
+    let reference = &import.global_module_reference;
+    let assigned_identifier = match &reference.assigned_identifier {
+        AssignedIdentifier::ObjectIdentifierValue(oid) => {
+            format!(" {}", object_identifier_notation(oid))
+        }
+        AssignedIdentifier::ExternalValueReference(ExternalValueReference {
+            module_reference,
+            value_reference,
+        }) => format!(" {module_reference}.{value_reference}"),
+        AssignedIdentifier::ValueReference(value_reference) => format!(" {value_reference}"),
+        AssignedIdentifier::ParameterizedValue {
+            value_reference,
+            actual_parameter_list,
+        } => format!(" {value_reference} {actual_parameter_list}"),
+        AssignedIdentifier::Empty => String::new(),
+    };
+    let with = match import.with {
+        Some(With::Successors) => format!(" {WITH_SUCCESSORS}"),
+        Some(With::Descendants) => format!(" {WITH_DESCENDANTS}"),
+        None => String::new(),
+    };
+    format!(
+        "{} {FROM} {}{assigned_identifier}{with}",
+        import.types.join(", "),
+        reference.module_reference
+    )
+}
+
+fn class_notation(class: &ObjectClassDefn) -> String {
+    if type_identifier(TYPE_IDENTIFIER.into()).is_ok_and(|(_, defn)| &defn == class) {
+        return TYPE_IDENTIFIER.to_string();
+    }
+    let name_width = class
+        .fields
+        .iter()
+        .map(|f| f.identifier.identifier().len())
+        .max()
+        .unwrap_or_default();
+    let fields = class
+        .fields
+        .iter()
+        .map(|f| format!("{}{}", indentation(1), class_field_notation(f, name_width)))
+        .collect::<Vec<_>>();
+    let mut notation = if fields.is_empty() {
+        format!("{CLASS} {{}}")
+    } else {
+        format!("{CLASS} {{\n{}\n}}", fields.join(",\n"))
+    };
+    if let Some(syntax) = &class.syntax {
+        notation.push_str(&format!(
+            "\n{WITH_SYNTAX} {{ {} }}",
+            syntax_notation(&syntax.expressions)
+        ));
+    }
+    notation
+}
+
+fn class_field_notation(field: &InformationObjectClassField, name_width: usize) -> String {
+    let mut notation = format!("{:name_width$}", field.identifier.identifier());
+    let suffix = [
+        field.ty.as_ref().map(|ty| type_notation(ty, 1)),
+        field.is_unique.then(|| String::from(UNIQUE)),
+        optionality_notation(&field.optionality),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+    if !suffix.is_empty() {
+        notation.push(' ');
+        notation.push_str(&suffix.join(" "));
+    }
+    notation.trim_end().to_string()
+}
@@ -0,0 +1,192 @@
+use crate::{
+    error::CompilerError,
+    intermediate::{information_object::ObjectClassDefn, *},
+    lexer::asn_spec,
+};
+
+use super::{error::GeneratorError, Backend, GeneratedModule};
+
+mod builder;
+pub(crate) mod notation;
+
+#[derive(Debug, Default, Clone)]
+/// A compiler backend that re-emits ASN.1 definitions as canonically formatted ASN.1.
+/// Members of constructed types are laid out one per line with aligned columns,
+/// comments are preserved, and keyword spacing and extension markers are normalised.
+/// Parsing the formatted output yields the same definitions as parsing the input.
+pub struct Asn1 {
+    config: Config,
+    /// Class assignments of the compilation, used for naming classes
+    /// that the validator has linked by their definition.
+    classes: Vec<(String, ObjectClassDefn)>,
+}
+
+#[derive(Debug, Default, Clone)]
+/// A configuration for the [Asn1] backend
+pub struct Config {}
+
+impl Backend for Asn1 {
+    type Config = Config;
+
+    const FILE_EXTENSION: &'static str = ".asn";
+
+    const SOURCE_ORDER: bool = true;
+
+    fn module_file_name(module_name: &str) -> String {
+        module_name.to_string() + Self::FILE_EXTENSION
+    }
+
+    fn from_config(config: Self::Config) -> Self {
+        Self {
+            config,
+            classes: vec![],
+        }
+    }
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn new(config: Self::Config, _: TaggingEnvironment, _: ExtensibilityEnvironment) -> Self {
+        Self::from_config(config)
+    }
+
+    fn prepare(&mut self, top_level_declarations: &[ToplevelDefinition]) {
+        self.classes = top_level_declarations
+            .iter()
+            .filter_map(|tld| match tld {
+                ToplevelDefinition::Class(c) => Some((c.name.clone(), c.definition.clone())),
+                _ => None,
+            })
+            .collect();
+    }
+
+    fn generate_module(
+        &mut self,
+        tlds: Vec<ToplevelDefinition>,
+    ) -> Result<GeneratedModule, GeneratorError> {
+        let Some(module_ref) = tlds.first().and_then(|tld| tld.get_module_header()) else {
+            return Ok(GeneratedModule::empty());
+        };
+        let definitions = tlds
+            .iter()
+            .map(|tld| self.definition_notation(tld))
+            .collect::<Vec<_>>();
+        let module = module_ref.read();
+        Ok(GeneratedModule {
+            generated: Some(Self::module_notation(&module, &definitions)),
+            warnings: vec![],
+        })
+    }
+
+    fn generate(&self, tld: ToplevelDefinition) -> Result<String, GeneratorError> {
+        Ok(self.definition_notation(&tld))
+    }
+}
+
+/// Formats ASN.1 source text canonically, as the [Asn1] backend prints unvalidated definitions.
+/// Modules keep the order of their definitions and are separated by a blank line.
+/// ### Example
+/// ```
+/// # use rasn_compiler::prelude::format_asn1;
+/// let formatted = format_asn1(
+///     "Example DEFINITIONS AUTOMATIC TAGS::=BEGIN Flag::=BOOLEAN END",
+/// ).unwrap();
+/// assert_eq!(
+///     formatted,
+///     "Example\nDEFINITIONS AUTOMATIC TAGS ::=\nBEGIN\n\nFlag ::= BOOLEAN\n\nEND\n"
+/// );
+/// ```
+pub fn format_asn1(source: &str) -> Result<String, CompilerError> {
+    Ok(Asn1::default().modules_notation(&asn_spec(source.into())?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_asn1, Asn1};
+    use crate::{intermediate::ModuleHeader, lexer::asn_spec};
+
+    /// Every test module that the lexer parses must be formatted to an equivalent module
+    #[test]
+    fn round_trips_test_modules() {
+        let modules = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../rasn-compiler-tests/tests/modules");
+        let formatter = Asn1::default();
+        for entry in std::fs::read_dir(modules).unwrap() {
+            let path = entry.unwrap().path();
+            let source = std::fs::read_to_string(&path).unwrap();
+            let Ok(parsed) = asn_spec(source.as_str().into()) else {
+                continue;
+            };
+            let formatted = formatter.modules_notation(&parsed);
+            let reparsed = asn_spec(formatted.as_str().into())
+                .unwrap_or_else(|e| panic!("{path:?}: {e:?}\n{formatted}"));
+            // The parsed definitions include their comments and those of their members,
+            // but the locations of the definitions change with the formatting
+            let without_locations = |mut modules: Vec<(ModuleHeader, _)>| {
+                for (header, _) in &mut modules {
+                    header.locations.clear();
+                }
+                modules
+            };
+            assert_eq!(
+                without_locations(reparsed.clone()),
+                without_locations(parsed),
+                "{path:?}"
+            );
+            assert_eq!(formatter.modules_notation(&reparsed), formatted, "{path:?}");
+        }
+    }
+
+    #[test]
+    fn numbers_enumerals_as_in_the_source() {
+        let formatted = format_asn1(
+            "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Root ::= ENUMERATED { one(1), two }
+            Extended ::= ENUMERATED { one(1), two(2), ..., three }
+            END",
+        )
+        .unwrap();
+        assert!(formatted.contains("one(1),\n    two(0)\n"), "{formatted}");
+        assert!(
+            formatted.contains("two(2),\n    ...,\n    three(0)\n"),
+            "{formatted}"
+        );
+    }
+
+    #[test]
+    fn formats_idempotently_with_comments() {
+        let source = r#"
+        Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        -- A flag
+        Flag ::= BOOLEAN -- trailing
+        -- A small number
+        Small ::= INTEGER (0..7)
+        Seq ::= SEQUENCE {
+            -- The first member
+            first Small, -- after the first member
+            second Flag OPTIONAL,
+            ...,
+            [[ -- A group
+            third NULL ]]
+        }
+        Alt ::= CHOICE {
+            -- An option
+            a Flag,
+            b Small -- after the last option
+        }
+        END"#;
+        let formatted = format_asn1(source).unwrap();
+        for comment in [
+            "-- A flag\nFlag ::= BOOLEAN -- trailing\n",
+            "-- A small number\n",
+            "    -- The first member\n    first  Small, -- after the first member\n",
+            "    [[\n        -- A group\n        third NULL\n",
+            "    -- An option\n",
+            "    b Small -- after the last option\n",
+        ] {
+            assert!(formatted.contains(comment), "{comment}\n{formatted}");
+        }
+        assert_eq!(format_asn1(&formatted).unwrap(), formatted);
+    }
+}
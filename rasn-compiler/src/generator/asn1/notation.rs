@@ -0,0 +1,890 @@
+//! Renders IR elements in ASN.1 notation.
+//! Every notation re-parses to the IR element it was rendered from.
+//! Notations that may contain constructed types take the indentation level
+//! at which the notation starts, so that nested components line up.
+
+use crate::intermediate::{
+    constraints::*,
+    information_object::{
+        InformationObjectField, InformationObjectFields, ObjectSet, ObjectSetValue,
+        SyntaxApplication, SyntaxExpression, SyntaxToken,
+    },
+    parameterization::{ParameterGovernor, Parameterization},
+    types::*,
+    *,
+};
+use crate::{common::INTERNAL_EXTENSION_GROUP_NAME_PREFIX, lexer::asn1_value};
+
+/// Indentation of a single nesting level
+pub const INDENTATION: &str = "    ";
+
+pub fn indentation(level: usize) -> String {
+    INDENTATION.repeat(level)
+}
+
+/// Returns the comment lines preceding a definition, each terminated by a line break.
+/// Lines that contain the `--` delimiter are rendered as block comments.
+pub fn comments_notation(comments: &str, indent: usize) -> String {
+    if comments.is_empty() {
+        return String::new();
+    }
+    comments
+        .split('\n')
+        .map(|line| format!("{}{}\n", indentation(indent), comment_notation(line)))
+        .collect()
+}
+
+/// Returns a comment following an element on the same line, preceded by a space.
+pub fn trailing_comment_notation(comment: Option<&str>) -> String {
+    comment
+        .map(|comment| format!(" {}", comment_notation(comment)))
+        .unwrap_or_default()
+}
+
+fn comment_notation(line: &str) -> String {
+    if line.contains(LINE_COMMENT) || line.contains('\n') {
+        format!("{BLOCK_COMMENT_START}{line}{BLOCK_COMMENT_END}")
+    } else {
+        format!("{LINE_COMMENT}{line}")
+    }
+}
+
+pub fn tag_notation(tag: &AsnTag) -> String {
+    let class = match tag.tag_class {
+        TagClass::Universal => "UNIVERSAL ",
+        TagClass::Application => "APPLICATION ",
+        TagClass::Private => "PRIVATE ",
+        TagClass::ContextSpecific => "",
+    };
+    let environment = match tag.environment {
+        TaggingEnvironment::Automatic => "",
+        TaggingEnvironment::Implicit => " IMPLICIT",
+        TaggingEnvironment::Explicit => " EXPLICIT",
+    };
+    format!("[{class}{}]{environment}", tag.id)
+}
+
+/// Returns the full notation of a type, including its components and constraints.
+pub fn type_notation(ty: &ASN1Type, indent: usize) -> String {
+    let name = match ty {
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+            return format!(
+                "{} {}OF {}{}",
+                if matches!(ty, ASN1Type::SequenceOf(_)) {
+                    SEQUENCE
+                } else {
+                    SET
+                },
+                constraints_notation(&s.constraints, indent)
+                    .map(|c| c + " ")
+                    .unwrap_or_default(),
+                s.element_tag
+                    .as_ref()
+                    .map(|t| tag_notation(t) + " ")
+                    .unwrap_or_default(),
+                type_notation(&s.element_type, indent)
+            )
+        }
+        ASN1Type::ChoiceSelectionType(c) => {
+            return format!("{} < {}", c.selected_option, c.choice_name)
+        }
+        ASN1Type::Sequence(s) => sequence_or_set_notation(SEQUENCE, s, indent),
+        ASN1Type::Set(s) => sequence_or_set_notation(SET, s, indent),
+        ASN1Type::Choice(c) => choice_notation(c, indent),
+        ASN1Type::Enumerated(e) => enumerated_notation(e, indent),
+        ASN1Type::ElsewhereDeclaredType(e) => format!(
+            "{}{}{}",
+            e.parent.as_deref().unwrap_or_default(),
+            e.module
+                .as_ref()
+                .map(|m| format!("{m}."))
+                .unwrap_or_default(),
+            e.identifier
+        ),
+        ASN1Type::ObjectClassField(f) => format!(
+            "{}{}.{}",
+            f.class,
+            match f.constraints.first() {
+                Some(Constraint::Parameter(parameters)) => {
+                    format!(" {}", actual_parameters_notation(parameters, indent))
+                }
+                _ => String::new(),
+            },
+            f.field_path
+                .iter()
+                .map(|p| p.identifier().clone())
+                .collect::<Vec<_>>()
+                .join(".")
+        ),
+        ASN1Type::Any(Any {
+            defined_by: Some(component),
+        }) => format!("{ANY} {DEFINED_BY} {component}"),
+        ASN1Type::Integer(Integer {
+            distinguished_values: Some(values),
+            ..
+        })
+        | ASN1Type::BitString(BitString {
+            distinguished_values: Some(values),
+            ..
+        }) => format!(
+            "{} {}",
+            ty.as_str(),
+            braced(values.iter().map(|v| format!("{}({})", v.name, v.value)))
+        ),
+        ty => ty.as_str().into_owned(),
+    };
+    let constraints = match ty {
+        ASN1Type::ObjectIdentifier(o)
+        | ASN1Type::RelativeOid(o)
+        | ASN1Type::OidIri(o)
+        | ASN1Type::RelativeOidIri(o) => Some(&o.constraints[..]),
+        ASN1Type::ObjectDescriptor(d) => Some(&d.constraints[..]),
+        ASN1Type::GeneralizedTime(t) => Some(&t.constraints[..]),
+        ASN1Type::UTCTime(t) => Some(&t.constraints[..]),
+        // The actual parameters of a parameterized class precede the field path
+        ASN1Type::ObjectClassField(f) => match f.constraints.split_first() {
+            Some((Constraint::Parameter(_), constraints)) => Some(constraints),
+            _ => Some(&f.constraints[..]),
+        },
+        ty => ty.constraints().map(|c| &c[..]),
+    };
+    match constraints.and_then(|c| constraints_notation(c, indent)) {
+        Some(constraints) => format!("{name} {constraints}"),
+        None => name,
+    }
+}
+
+/// A line within the body of a constructed type
+enum ComponentLine {
+    Plain {
+        comments: String,
+        trailing_comment: Option<String>,
+        text: String,
+    },
+    Aligned {
+        comments: String,
+        trailing_comment: Option<String>,
+        name: String,
+        tag: String,
+        ty: String,
+        suffix: Option<String>,
+    },
+}
+
+impl ComponentLine {
+    fn plain(text: impl Into<String>) -> Self {
+        Self::Plain {
+            comments: String::new(),
+            trailing_comment: None,
+            text: text.into(),
+        }
+    }
+}
+
+/// Lays out components one per line between `open` and `close`,
+/// aligning the names and tags of named components.
+/// Trailing comments follow the separating comma of their component.
+fn components_notation(
+    open: &str,
+    close: &str,
+    lines: Vec<ComponentLine>,
+    indent: usize,
+) -> String {
+    if lines.is_empty() {
+        return format!("{open}{close}");
+    }
+    let (name_width, tag_width) = lines.iter().fold((0, 0), |(n, t), line| match line {
+        ComponentLine::Aligned { name, tag, .. } => (n.max(name.len()), t.max(tag.len())),
+        ComponentLine::Plain { .. } => (n, t),
+    });
+    let last = lines.len() - 1;
+    let body = lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let (comments, trailing_comment, line) = match line {
+                ComponentLine::Plain {
+                    comments,
+                    trailing_comment,
+                    text,
+                } => (comments, trailing_comment, text),
+                ComponentLine::Aligned {
+                    comments,
+                    trailing_comment,
+                    name,
+                    tag,
+                    ty,
+                    suffix,
+                } => {
+                    let mut text = if tag_width == 0 {
+                        format!("{name:name_width$} {ty}")
+                    } else {
+                        format!("{name:name_width$} {tag:tag_width$} {ty}")
+                    };
+                    if let Some(suffix) = suffix {
+                        text.push(' ');
+                        text.push_str(&suffix);
+                    }
+                    (comments, trailing_comment, text)
+                }
+            };
+            format!(
+                "{}{}{line}{}{}",
+                comments_notation(&comments, indent + 1),
+                indentation(indent + 1),
+                if i == last { "" } else { "," },
+                trailing_comment_notation(trailing_comment.as_deref())
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("{open}\n{body}\n{}{close}", indentation(indent))
+}
+
+fn insert_extension_marker<T>(lines: &mut Vec<T>, extensible: Option<usize>, marker: T) {
+    if let Some(index) = extensible {
+        lines.insert(index.min(lines.len()), marker);
+    }
+}
+
+fn sequence_or_set_lines(s: &SequenceOrSet, indent: usize) -> Vec<ComponentLine> {
+    s.components_of
+        .iter()
+        .map(|c| ComponentLine::plain(format!("{COMPONENTS_OF} {c}")))
+        .chain(s.members.iter().map(|m| {
+            match &m.ty {
+                ASN1Type::Sequence(group)
+                    if m.name.starts_with(INTERNAL_EXTENSION_GROUP_NAME_PREFIX) =>
+                {
+                    ComponentLine::Plain {
+                        comments: m.comments.clone(),
+                        trailing_comment: m.trailing_comment.clone(),
+                        text: components_notation(
+                            "[[",
+                            "]]",
+                            sequence_or_set_lines(group, indent + 1),
+                            indent + 1,
+                        ),
+                    }
+                }
+                ty => ComponentLine::Aligned {
+                    comments: m.comments.clone(),
+                    trailing_comment: m.trailing_comment.clone(),
+                    name: m.name.clone(),
+                    tag: m.tag.as_ref().map(tag_notation).unwrap_or_default(),
+                    ty: type_notation(ty, indent + 1),
+                    suffix: [
+                        constraints_notation(&m.constraints, indent + 1),
+                        optionality_notation(&m.optionality),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .reduce(|a, b| format!("{a} {b}")),
+                },
+            }
+        }))
+        .collect()
+}
+
+fn sequence_or_set_notation(keyword: &str, s: &SequenceOrSet, indent: usize) -> String {
+    let mut lines = sequence_or_set_lines(s, indent);
+    insert_extension_marker(&mut lines, s.extensible, ComponentLine::plain(ELLIPSIS));
+    components_notation(&format!("{keyword} {{"), "}", lines, indent)
+}
+
+fn choice_notation(c: &Choice, indent: usize) -> String {
+    let mut lines = c
+        .options
+        .iter()
+        .map(|o| ComponentLine::Aligned {
+            comments: o.comments.clone(),
+            trailing_comment: o.trailing_comment.clone(),
+            name: o.name.clone(),
+            tag: o.tag.as_ref().map(tag_notation).unwrap_or_default(),
+            ty: type_notation(&o.ty, indent + 1),
+            suffix: constraints_notation(&o.constraints, indent + 1),
+        })
+        .collect();
+    insert_extension_marker(&mut lines, c.extensible, ComponentLine::plain(ELLIPSIS));
+    components_notation(&format!("{CHOICE} {{"), "}", lines, indent)
+}
+
+/// Enumerals are laid out one per line with explicit indices,
+/// followed by their description as a trailing comment.
+fn enumerated_notation(e: &Enumerated, indent: usize) -> String {
+    let mut enumerals = e
+        .members
+        .iter()
+        .map(|m| (format!("{}({})", m.name, m.index), m.description.as_deref()))
+        .collect::<Vec<_>>();
+    insert_extension_marker(&mut enumerals, e.extensible, (ELLIPSIS.into(), None));
+    if enumerals.is_empty() {
+        return format!("{ENUMERATED} {{}}");
+    }
+    let last = enumerals.len() - 1;
+    let body = enumerals
+        .into_iter()
+        .enumerate()
+        .map(|(i, (enumeral, description))| {
+            let separator = if i == last { "" } else { "," };
+            format!(
+                "{}{enumeral}{separator}{}",
+                indentation(indent + 1),
+                trailing_comment_notation(description)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("{ENUMERATED} {{\n{body}\n{}}}", indentation(indent))
+}
+
+/// Returns the notation of a list of constraints, or `None` if the list is empty.
+pub fn constraints_notation(constraints: &[Constraint], indent: usize) -> Option<String> {
+    (!constraints.is_empty()).then(|| {
+        constraints
+            .iter()
+            .map(|c| constraint_notation(c, indent))
+            .collect::<Vec<_>>()
+            .join(" ")
+    })
+}
+
+pub fn constraint_notation(constraint: &Constraint, indent: usize) -> String {
+    match constraint {
+        Constraint::Subtype(set) => format!("({})", element_set_specs_notation(set, indent)),
+        Constraint::Table(table) => {
+            let linked = table
+                .linked_fields
+                .iter()
+                .map(|l| format!("@{}{}", ".".repeat(l.level), l.field_name))
+                .collect::<Vec<_>>();
+            if linked.is_empty() {
+                format!("({})", object_set_notation(&table.object_set, indent))
+            } else {
+                format!(
+                    "({}{{{}}})",
+                    object_set_notation(&table.object_set, indent),
+                    linked.join(", ")
+                )
+            }
+        }
+        Constraint::Parameter(parameters) => actual_parameters_notation(parameters, indent),
+        Constraint::Content(ContentConstraint::Containing(ty)) => {
+            format!("({CONTAINING} {})", type_notation(ty, indent))
+        }
+        Constraint::Content(ContentConstraint::EncodedBy(value)) => {
+            format!("({ENCODED_BY} {})", value_notation(value))
+        }
+        Constraint::Content(ContentConstraint::ContainingEncodedBy {
+            containing,
+            encoded_by,
+        }) => format!(
+            "({CONTAINING} {} {ENCODED_BY} {})",
+            type_notation(containing, indent),
+            value_notation(encoded_by)
+        ),
+    }
+}
+
+pub fn element_set_specs_notation(set: &ElementSetSpecs, indent: usize) -> String {
+    let root = with_extension(element_or_set_notation(&set.set, indent), set.extensible);
+    match &set.extension_additions {
+        Some(additions) => format!("{root}, {}", element_or_set_notation(additions, indent)),
+        None => root,
+    }
+}
+
+fn element_or_set_notation(set: &ElementOrSetOperation, indent: usize) -> String {
+    match set {
+        ElementOrSetOperation::Element(e) => subtype_element_notation(e, indent),
+        ElementOrSetOperation::SetOperation(op) => format!(
+            "{} {} {}",
+            subtype_element_notation(&op.base, indent),
+            match op.operator {
+                SetOperator::Intersection => CARET,
+                SetOperator::Union => PIPE,
+                SetOperator::Except => EXCEPT,
+            },
+            element_or_set_notation(&op.operant, indent)
+        ),
+    }
+}
+
+fn with_extension(notation: String, extensible: bool) -> String {
+    if extensible {
+        notation + ", ..."
+    } else {
+        notation
+    }
+}
+
+fn subtype_element_notation(element: &SubtypeElements, indent: usize) -> String {
+    match element {
+        SubtypeElements::SingleValue { value, extensible } => {
+            with_extension(value_notation(value), *extensible)
+        }
+        SubtypeElements::ContainedSubtype {
+            subtype,
+            extensible,
+        } => with_extension(
+            format!("{INCLUDES} {}", type_notation(subtype, indent)),
+            *extensible,
+        ),
+        SubtypeElements::ValueRange {
+            min,
+            max,
+            extensible,
+        } => with_extension(
+            format!(
+                "{}..{}",
+                min.as_ref().map_or(String::from(MIN), value_notation),
+                max.as_ref().map_or(String::from(MAX), value_notation)
+            ),
+            *extensible,
+        ),
+        SubtypeElements::PermittedAlphabet(set) => {
+            format!("{FROM} ({})", element_or_set_notation(set, indent))
+        }
+        SubtypeElements::SizeConstraint(set) => {
+            format!("{SIZE} ({})", element_or_set_notation(set, indent))
+        }
+        SubtypeElements::TypeConstraint(ty) => type_notation(ty, indent),
+        SubtypeElements::SingleTypeConstraint(constraints) => format!(
+            "{WITH_COMPONENT} {}",
+            constraints_notation(constraints, indent).unwrap_or_default()
+        ),
+        SubtypeElements::MultipleTypeConstraints(inner) => {
+            let mut components = vec![];
+            if inner.is_partial {
+                components.push(String::from(ELLIPSIS));
+            }
+            components.extend(inner.constraints.iter().map(|c| {
+                let mut component = c.identifier.clone();
+                if let Some(constraints) = constraints_notation(&c.constraints, indent) {
+                    component.push(' ');
+                    component.push_str(&constraints);
+                }
+                match c.presence {
+                    ComponentPresence::Absent => component.push_str(" ABSENT"),
+                    ComponentPresence::Present => component.push_str(" PRESENT"),
+                    ComponentPresence::Unspecified => (),
+                }
+                component
+            }));
+            format!("{WITH_COMPONENTS} {{ {} }}", components.join(", "))
+        }
+        // The pattern is stored as written, including its escaped quotation marks
+        SubtypeElements::PatternConstraint(p) => format!("{PATTERN} \"{}\"", p.pattern),
+        SubtypeElements::UserDefinedConstraint(u) => {
+            format!("{CONSTRAINED_BY} {{{}}}", u.definition)
+        }
+        SubtypeElements::PropertySettings(settings) => format!(
+            "SETTINGS \"{}\"",
+            settings
+                .property_settings_list
+                .iter()
+                .map(property_setting_notation)
+                .collect::<Vec<_>>()
+                .join(" ")
+        ),
+    }
+}
+
+fn property_setting_notation(pair: &PropertyAndSettingsPair) -> String {
+    fn notation<S: PropertySetting>(setting: &S) -> String {
+        format!("{}={}", S::NAME, setting.setting_name())
+    }
+    match pair {
+        PropertyAndSettingsPair::Basic(s) => notation(s),
+        PropertyAndSettingsPair::Date(s) => notation(s),
+        PropertyAndSettingsPair::Year(s) => notation(s),
+        PropertyAndSettingsPair::Time(s) => notation(s),
+        PropertyAndSettingsPair::LocalOrUtc(s) => notation(s),
+        PropertyAndSettingsPair::IntervalType(s) => notation(s),
+        PropertyAndSettingsPair::StartEndPoint(s) => notation(s),
+        PropertyAndSettingsPair::Recurrence(s) => notation(s),
+        PropertyAndSettingsPair::Midnight(s) => notation(s),
+    }
+}
+
+/// Returns an ASN.1 cstring literal with the content `value`.
+pub fn string_notation(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+pub fn value_notation(value: &ASN1Value) -> String {
+    match value {
+        ASN1Value::All => String::from(ALL),
+        ASN1Value::Null => String::from(NULL),
+        ASN1Value::Boolean(true) => String::from(TRUE),
+        ASN1Value::Boolean(false) => String::from(FALSE),
+        ASN1Value::Choice {
+            variant_name,
+            inner_value,
+            ..
+        } => format!("{variant_name}: {}", value_notation(inner_value)),
+        ASN1Value::SequenceOrSet(elements) => {
+            braced(elements.iter().map(|(name, value)| match name {
+                Some(name) => format!("{name} {}", value_notation(value)),
+                None => value_notation(value),
+            }))
+        }
+        ASN1Value::Integer(i) | ASN1Value::LinkedIntValue { value: i, .. } => i.to_string(),
+        ASN1Value::Real(r) if r.is_nan() => String::from(NOT_A_NUMBER),
+        ASN1Value::Real(r) if r.is_infinite() && r.is_sign_negative() => {
+            String::from(MINUS_INFINITY)
+        }
+        ASN1Value::Real(r) if r.is_infinite() => String::from(PLUS_INFINITY),
+        ASN1Value::Real(r) => real_notation(*r),
+        ASN1Value::String(s) | ASN1Value::LinkedCharStringValue(_, s) => string_notation(s),
+        ASN1Value::Time(t) => format!("\"{t}\""),
+        ASN1Value::BitString(bits) => format!(
+            "'{}'B",
+            bits.iter()
+                .map(|b| if *b { '1' } else { '0' })
+                .collect::<String>()
+        ),
+        ASN1Value::BitStringNamedBits(names) => braced(names.iter().cloned()),
+        ASN1Value::OctetString(bytes) => format!(
+            "'{}'H",
+            bytes.iter().map(|b| format!("{b:02X}")).collect::<String>()
+        ),
+        ASN1Value::EnumeratedValue { enumerable, .. } => enumerable.clone(),
+        ASN1Value::ElsewhereDeclaredValue { parent, identifier }
+        | ASN1Value::LinkedElsewhereDefinedValue {
+            parent, identifier, ..
+        } => format!("{}{identifier}", parent.as_deref().unwrap_or_default()),
+        ASN1Value::ParameterizedValue {
+            identifier,
+            actual_parameters,
+        } => format!(
+            "{identifier} {}",
+            actual_parameters_notation(actual_parameters, 0)
+        ),
+        ASN1Value::ObjectIdentifier(oid) | ASN1Value::LinkedRelativeOidValue(oid) => {
+            object_identifier_notation(oid)
+        }
+        ASN1Value::LinkedNestedValue { value, .. } => value_notation(value),
+        ASN1Value::LinkedStructLikeValue(fields) => braced(
+            fields
+                .iter()
+                .map(|(name, _, value)| format!("{name} {}", value_notation(value.value()))),
+        ),
+        ASN1Value::LinkedArrayLikeValue(items) => {
+            braced(items.iter().map(|item| value_notation(item)))
+        }
+    }
+}
+
+/// Returns the decimal notation of a real value if it reads back unchanged,
+/// and its base 2 sequence notation otherwise.
+fn real_notation(real: f64) -> String {
+    let mut decimal = real.to_string();
+    if !decimal.contains('.') {
+        decimal.push_str(".0");
+    }
+    if let Ok((rest, ASN1Value::Real(parsed))) = asn1_value(decimal.as_str().into()) {
+        if rest.inner().is_empty() && parsed == real {
+            return decimal;
+        }
+    }
+    let bits = real.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i64;
+    let fraction = (bits & ((1 << 52) - 1)) as i64;
+    let (mut mantissa, mut exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    };
+    while mantissa != 0 && mantissa % 2 == 0 {
+        mantissa /= 2;
+        exponent += 1;
+    }
+    if real.is_sign_negative() {
+        mantissa = -mantissa;
+    }
+    format!("{{ mantissa {mantissa}, base 2, exponent {exponent} }}")
+}
+
+pub fn object_identifier_notation(oid: &ObjectIdentifierValue) -> String {
+    format!(
+        "{{ {} }}",
+        oid.0
+            .iter()
+            .map(|arc| match (&arc.name, arc.number) {
+                (Some(name), Some(number)) => format!("{name}({number})"),
+                (Some(name), None) => name.clone(),
+                (None, Some(number)) => number.to_string(),
+                (None, None) => String::new(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    )
+}
+
+fn braced(items: impl Iterator<Item = String>) -> String {
+    let items = items.collect::<Vec<_>>();
+    if items.is_empty() {
+        String::from("{}")
+    } else {
+        format!("{{ {} }}", items.join(", "))
+    }
+}
+
+/// Returns the notation of the actual parameters of an instantiation, such as `{INTEGER, 8}`.
+pub fn actual_parameters_notation(parameters: &[Parameter], indent: usize) -> String {
+    format!(
+        "{{{}}}",
+        parameters
+            .iter()
+            .map(|p| match p {
+                Parameter::ValueParameter(v) => value_notation(v),
+                Parameter::TypeParameter(t) => type_notation(t, indent),
+                Parameter::InformationObjectParameter(fields) => object_notation(fields, indent),
+                Parameter::ObjectSetParameter(set) => object_set_notation(set, indent),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+pub fn object_set_notation(set: &ObjectSet, indent: usize) -> String {
+    let values = set
+        .values
+        .iter()
+        .map(|v| match v {
+            ObjectSetValue::Reference(r) => r.clone(),
+            ObjectSetValue::Inline(fields) => object_notation(fields, indent),
+            ObjectSetValue::Instantiation(r, parameters) => {
+                format!("{r} {}", actual_parameters_notation(parameters, indent))
+            }
+        })
+        .collect::<Vec<_>>();
+    let mut groups = vec![];
+    match set.extensible {
+        Some(index) => {
+            let (root, additions) = values.split_at(index.min(values.len()));
+            if !root.is_empty() {
+                groups.push(root.join(" | "));
+            }
+            groups.push(String::from(ELLIPSIS));
+            if !additions.is_empty() {
+                groups.push(additions.join(" | "));
+            }
+        }
+        None => groups.push(values.join(" | ")),
+    }
+    braced(groups.into_iter().filter(|g| !g.is_empty()))
+}
+
+pub fn object_notation(fields: &InformationObjectFields, indent: usize) -> String {
+    match fields {
+        InformationObjectFields::DefaultSyntax(fields) => braced(fields.iter().map(|f| match f {
+            InformationObjectField::TypeField(t) => {
+                format!("{} {}", t.identifier, type_notation(&t.ty, indent))
+            }
+            InformationObjectField::FixedValueField(v) => {
+                format!("{} {}", v.identifier, value_notation(&v.value))
+            }
+            InformationObjectField::ObjectSetField(s) => {
+                format!("{} {}", s.identifier, object_set_notation(&s.value, indent))
+            }
+        })),
+        InformationObjectFields::CustomSyntax(applications) => {
+            let tokens = applications
+                .iter()
+                .map(|a| match a {
+                    SyntaxApplication::ObjectSetDeclaration(set) => {
+                        object_set_notation(set, indent)
+                    }
+                    SyntaxApplication::ValueReference(v) => value_notation(v),
+                    SyntaxApplication::TypeReference(t) => type_notation(t, indent),
+                    SyntaxApplication::Comma => String::from(","),
+                    SyntaxApplication::Literal(l) => l.clone(),
+                    SyntaxApplication::LiteralOrTypeReference(r) => {
+                        type_notation(&ASN1Type::ElsewhereDeclaredType(r.clone()), indent)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+                .replace(" ,", ",");
+            format!("{{ {tokens} }}")
+        }
+    }
+}
+
+pub fn parameters_notation(parameterization: &Parameterization) -> String {
+    format!(
+        "{{ {} }}",
+        parameterization
+            .parameters
+            .iter()
+            .map(|p| match &p.param_governor {
+                ParameterGovernor::None => p.dummy_reference.clone(),
+                ParameterGovernor::TypeOrClass(ty) => {
+                    format!("{} : {}", type_notation(ty, 0), p.dummy_reference)
+                }
+                ParameterGovernor::Class(class) => format!("{class} : {}", p.dummy_reference),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+pub fn syntax_notation(expressions: &[SyntaxExpression]) -> String {
+    expressions
+        .iter()
+        .map(|expression| match expression {
+            SyntaxExpression::Required(SyntaxToken::Field(field)) => field.identifier().clone(),
+            SyntaxExpression::Required(token) => token.as_str().to_string(),
+            SyntaxExpression::Optional(inner) => format!("[{}]", syntax_notation(inner)),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(" ,", ",")
+}
+
+pub fn optionality_notation(optionality: &Optionality<ASN1Value>) -> Option<String> {
+    match optionality {
+        Optionality::Required => None,
+        Optionality::Optional => Some(String::from(OPTIONAL)),
+        Optionality::Default(value) => Some(format!("{DEFAULT} {}", value_notation(value))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_constraints() {
+        assert_eq!(
+            constraint_notation(
+                &Constraint::Subtype(ElementSetSpecs {
+                    set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
+                        ElementOrSetOperation::Element(SubtypeElements::ValueRange {
+                            min: Some(ASN1Value::Integer(1.into())),
+                            max: None,
+                            extensible: true
+                        })
+                    ))),
+                    extensible: false,
+                    extension_additions: None
+                }),
+                0
+            ),
+            "(SIZE (1..MAX, ...))"
+        );
+        assert_eq!(
+            constraint_notation(
+                &Constraint::Subtype(ElementSetSpecs {
+                    set: ElementOrSetOperation::SetOperation(SetOperation {
+                        base: SubtypeElements::PermittedAlphabet(Box::new(
+                            ElementOrSetOperation::Element(SubtypeElements::ValueRange {
+                                min: Some(ASN1Value::String("a".into())),
+                                max: Some(ASN1Value::String("z".into())),
+                                extensible: false
+                            })
+                        )),
+                        operator: SetOperator::Intersection,
+                        operant: Box::new(ElementOrSetOperation::Element(
+                            SubtypeElements::SizeConstraint(Box::new(
+                                ElementOrSetOperation::Element(SubtypeElements::SingleValue {
+                                    value: ASN1Value::Integer(4.into()),
+                                    extensible: false
+                                })
+                            ))
+                        ))
+                    }),
+                    extensible: true,
+                    extension_additions: None
+                }),
+                0
+            ),
+            r#"(FROM ("a".."z") ^ SIZE (4), ...)"#
+        );
+    }
+
+    #[test]
+    fn formats_values() {
+        assert_eq!(
+            value_notation(&ASN1Value::BitString(vec![true, false])),
+            "'10'B"
+        );
+        assert_eq!(
+            value_notation(&ASN1Value::OctetString(vec![1, 171])),
+            "'01AB'H"
+        );
+        assert_eq!(
+            value_notation(&ASN1Value::String("say \"hi\"".into())),
+            r#""say ""hi""""#
+        );
+        assert_eq!(
+            value_notation(&ASN1Value::SequenceOrSet(vec![
+                (Some("a".into()), Box::new(ASN1Value::Boolean(true))),
+                (
+                    Some("b".into()),
+                    Box::new(ASN1Value::Choice {
+                        type_name: None,
+                        variant_name: "c".into(),
+                        inner_value: Box::new(ASN1Value::Null)
+                    })
+                )
+            ])),
+            "{ a TRUE, b c: NULL }"
+        );
+        assert_eq!(
+            value_notation(&ASN1Value::ObjectIdentifier(ObjectIdentifierValue(vec![
+                ObjectIdentifierArc::from(("iso", 1)),
+                ObjectIdentifierArc::from(3)
+            ]))),
+            "{ iso(1) 3 }"
+        );
+        assert_eq!(value_notation(&ASN1Value::Real(1.5)), "1.5");
+        assert_eq!(
+            value_notation(&ASN1Value::Real(1.05)),
+            "{ mantissa 4728779608739021, base 2, exponent -52 }"
+        );
+    }
+
+    #[test]
+    fn formats_constructed_types() {
+        assert_eq!(
+            type_notation(
+                &ASN1Type::Sequence(SequenceOrSet {
+                    components_of: vec![],
+                    extensible: Some(1),
+                    constraints: vec![],
+                    members: vec![
+                        SequenceOrSetMember {
+                            name: "id".into(),
+                            tag: Some(AsnTag {
+                                environment: TaggingEnvironment::Automatic,
+                                tag_class: TagClass::ContextSpecific,
+                                id: 0
+                            }),
+                            ty: ASN1Type::Integer(Integer::default()),
+                            optionality: Optionality::Required,
+                            is_recursive: false,
+                            constraints: vec![],
+                            comments: String::new(),
+                            trailing_comment: None,
+                        },
+                        SequenceOrSetMember {
+                            name: "flag".into(),
+                            tag: None,
+                            ty: ASN1Type::Boolean(Boolean::default()),
+                            optionality: Optionality::Default(ASN1Value::Boolean(false)),
+                            is_recursive: false,
+                            constraints: vec![],
+                            comments: String::new(),
+                            trailing_comment: None,
+                        }
+                    ]
+                }),
+                0
+            ),
+            "SEQUENCE {\n    id   [0] INTEGER,\n    ...,\n    flag     BOOLEAN DEFAULT FALSE\n}"
+        );
+    }
+}
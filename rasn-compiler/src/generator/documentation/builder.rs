@@ -0,0 +1,864 @@
+use crate::intermediate::{
+    constraints::Constraint,
+    encoding_rules::{
+        bit_length,
+        per_visible::{
+            per_visible_range_constraints, CharsetSubset, PerVisibleAlphabetConstraints,
+            PerVisibleRangeConstraints,
+        },
+    },
+    information_object::{ASN1Information, ClassLink, ObjectClassDefn},
+    parameterization::Parameterization,
+    *,
+};
+
+use crate::validator::tagging::effective_tags;
+
+use super::{
+    catalog::{DefinitionId, DefinitionKind},
+    notation::*,
+    template::{Block, Format, Inline, ListItem, Page},
+    Documentation,
+};
+
+/// Sections of a module page in the order they are rendered
+const SECTIONS: [(&str, &[DefinitionKind]); 5] = [
+    ("Types", &[DefinitionKind::Type]),
+    ("Values", &[DefinitionKind::Value]),
+    ("Information object classes", &[DefinitionKind::Class]),
+    (
+        "Information objects and object sets",
+        &[DefinitionKind::Object, DefinitionKind::ObjectSet],
+    ),
+    ("Macros", &[DefinitionKind::Macro]),
+];
+
+type Row = Vec<Vec<Inline>>;
+
+fn row(label: &str, content: Vec<Inline>) -> Row {
+    vec![vec![Inline::text(label)], content]
+}
+
+fn heading(level: u8, text: impl Into<String>, anchor: Option<String>) -> Block {
+    Block::Heading {
+        level,
+        text: text.into(),
+        anchor,
+    }
+}
+
+fn properties(rows: Vec<Row>) -> Option<Block> {
+    (!rows.is_empty()).then(|| Block::Table {
+        header: vec![String::from("Property"), String::from("Value")],
+        rows,
+    })
+}
+
+fn comment_blocks(comments: &str) -> Vec<Block> {
+    comments
+        .split("\n\n")
+        .filter_map(|paragraph| {
+            let text = paragraph
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ");
+            (!text.is_empty()).then(|| Block::Paragraph(vec![Inline::text(text)]))
+        })
+        .collect()
+}
+
+fn separated(items: Vec<Vec<Inline>>, separator: &str) -> Vec<Inline> {
+    let mut inlines = vec![];
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            inlines.push(Inline::text(separator));
+        }
+        inlines.extend(item);
+    }
+    inlines
+}
+
+fn range_notation(range: &PerVisibleRangeConstraints) -> Option<String> {
+    let bound =
+        |b: Option<i128>, unbounded: &str| b.map_or(unbounded.to_string(), |b| b.to_string());
+    let (min, max) = (range.min::<i128>(), range.max::<i128>());
+    if min.is_none() && max.is_none() {
+        return None;
+    }
+    Some(format!(
+        "{}..{}{}",
+        bound(min, MIN),
+        bound(max, MAX),
+        if range.is_extensible() { ", ..." } else { "" }
+    ))
+}
+
+fn bit_width_notation(range: &PerVisibleRangeConstraints) -> Option<String> {
+    match (range.min::<i128>(), range.max::<i128>()) {
+        (Some(min), Some(max)) if min <= max => {
+            let width = bit_length(min, max);
+            Some(format!(
+                "{width} bit{}{}",
+                if width == 1 { "" } else { "s" },
+                if range.is_extensible() {
+                    " (plus extension bit)"
+                } else {
+                    ""
+                }
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn alphabet_notation(
+    string_type: CharacterStringType,
+    constraints: &[Constraint],
+) -> Option<String> {
+    let mut alphabet = PerVisibleAlphabetConstraints::default_for(string_type);
+    for c in constraints {
+        if let Some(mut p) = PerVisibleAlphabetConstraints::try_new(c, string_type).ok()? {
+            alphabet += &mut p;
+        }
+    }
+    alphabet.finalize();
+    let subsets = alphabet
+        .charset_subsets()
+        .iter()
+        .map(|subset| match subset {
+            CharsetSubset::Single(c) => string_notation(&c.to_string()),
+            CharsetSubset::Range { from, to } => format!(
+                "{}..{}",
+                from.map_or(MIN.to_string(), |c| string_notation(&c.to_string())),
+                to.map_or(MAX.to_string(), |c| string_notation(&c.to_string()))
+            ),
+        })
+        .collect::<Vec<_>>();
+    (!subsets.is_empty()).then(|| format!("FROM ({})", subsets.join(" | ")))
+}
+
+impl<F: Format> Documentation<F> {
+    fn href(&self, from_module: &str, to: &DefinitionId) -> String {
+        if from_module == to.module {
+            format!("#{}", to.name)
+        } else {
+            format!("{}#{}", Self::module_page_name(&to.module), to.name)
+        }
+    }
+
+    fn module_page_name(module: &str) -> String {
+        <Self as crate::generator::Backend>::module_file_name(module)
+    }
+
+    /// Links `identifier` to its definition, if the reference can be resolved.
+    fn reference(&self, module: &str, identifier: &str) -> Inline {
+        match self.catalog.resolve(module, identifier) {
+            Some(id) => Inline::link(identifier, self.href(module, &id)),
+            None => Inline::code(identifier),
+        }
+    }
+
+    fn definition_link(&self, from_module: &str, id: &DefinitionId) -> Inline {
+        let text = if from_module == id.module {
+            id.name.clone()
+        } else {
+            format!("{}.{}", id.module, id.name)
+        };
+        Inline::link(text, self.href(from_module, id))
+    }
+
+    fn type_inlines(&self, module: &str, ty: &ASN1Type) -> Vec<Inline> {
+        let with_constraints = |mut inlines: Vec<Inline>, constraints: &[Constraint]| {
+            if let Some(c) = constraints_notation(constraints, 0) {
+                inlines.push(Inline::text(" "));
+                inlines.push(Inline::code(c));
+            }
+            inlines
+        };
+        match ty {
+            ASN1Type::ElsewhereDeclaredType(e) => {
+                let target = match &e.module {
+                    Some(external) => self.catalog.resolve(external, &e.identifier),
+                    None => self.catalog.resolve(module, &e.identifier),
+                };
+                let reference = match target {
+                    Some(id) => Inline::link(&e.identifier, self.href(module, &id)),
+                    None => Inline::code(&e.identifier),
+                };
+                with_constraints(vec![reference], &e.constraints)
+            }
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+                let mut inlines = vec![Inline::code(format!(
+                    "{} {}OF",
+                    if matches!(ty, ASN1Type::SequenceOf(_)) {
+                        SEQUENCE
+                    } else {
+                        SET
+                    },
+                    constraints_notation(&s.constraints, 0)
+                        .map(|c| c + " ")
+                        .unwrap_or_default(),
+                ))];
+                inlines.push(Inline::text(" "));
+                inlines.extend(self.type_inlines(module, &s.element_type));
+                inlines
+            }
+            ASN1Type::ObjectClassField(f) => with_constraints(
+                vec![
+                    self.reference(module, &f.class),
+                    Inline::code(
+                        f.field_path
+                            .iter()
+                            .map(|p| format!(".{}", p.identifier()))
+                            .collect::<String>(),
+                    ),
+                ],
+                &f.constraints,
+            ),
+            ASN1Type::ChoiceSelectionType(c) => vec![
+                Inline::code(format!("{} <", c.selected_option)),
+                Inline::text(" "),
+                self.reference(module, &c.choice_name),
+            ],
+            ty => vec![Inline::code(type_notation(ty))],
+        }
+    }
+
+    /// Rows describing the effect of the PER-visible constraints on the encoding of `ty`
+    fn per_visible_rows(&self, module: &str, ty: &ASN1Type) -> Vec<Row> {
+        let Some((resolved, constraints)) = self.catalog.resolve_type(module, ty) else {
+            return vec![];
+        };
+        let mut rows = vec![];
+        let mut push = |label: &str, text: Option<String>| {
+            if let Some(text) = text {
+                rows.push(row(label, vec![Inline::code(text)]))
+            }
+        };
+        let size_range = || {
+            per_visible_range_constraints(false, &constraints)
+                .ok()
+                .filter(|r| r.is_size_constraint())
+        };
+        match resolved {
+            ASN1Type::Integer(_) => {
+                if let Ok(range) = per_visible_range_constraints(true, &constraints) {
+                    push("PER value range", range_notation(&range));
+                    push("PER bit width", bit_width_notation(&range));
+                }
+            }
+            ASN1Type::Enumerated(e) => {
+                let range = PerVisibleRangeConstraints::from(e);
+                push("PER index range", range_notation(&range));
+                push("PER bit width", bit_width_notation(&range));
+            }
+            ASN1Type::Choice(c) => {
+                let range = PerVisibleRangeConstraints::from(c);
+                push("PER index range", range_notation(&range));
+                push("PER bit width", bit_width_notation(&range));
+            }
+            ASN1Type::CharacterString(s) => {
+                if let Some(range) = size_range() {
+                    push("PER size range", range_notation(&range));
+                }
+                push(
+                    "PER permitted alphabet",
+                    alphabet_notation(s.ty, &constraints),
+                );
+            }
+            ASN1Type::BitString(_)
+            | ASN1Type::OctetString(_)
+            | ASN1Type::SequenceOf(_)
+            | ASN1Type::SetOf(_) => {
+                if let Some(range) = size_range() {
+                    push("PER size range", range_notation(&range));
+                    push("PER length bit width", bit_width_notation(&range));
+                }
+            }
+            _ => (),
+        }
+        rows
+    }
+
+    fn type_rows(&self, module: &str, ty: &ASN1Type) -> Vec<Row> {
+        let mut rows = vec![row("Type", self.type_inlines(module, ty))];
+        if let ASN1Type::ElsewhereDeclaredType(_) = ty {
+            if let Some((resolved, constraints)) = self.catalog.resolve_type(module, ty) {
+                rows.push(row("Resolved type", vec![Inline::code(resolved.as_str())]));
+                if let Some(c) = constraints_notation(&constraints, 0) {
+                    rows.push(row("Effective constraints", vec![Inline::code(c)]));
+                }
+            }
+        }
+        rows.extend(self.per_visible_rows(module, ty));
+        rows
+    }
+
+    fn member_item(
+        &self,
+        module: &str,
+        name: &str,
+        tag: Option<&AsnTag>,
+        ty: &ASN1Type,
+        constraints: &[Constraint],
+        suffix: Option<String>,
+    ) -> ListItem {
+        let mut content = vec![Inline::code(name), Inline::text(" ")];
+        if let Some(tag) = tag {
+            content.push(Inline::code(tag_notation(tag)));
+            content.push(Inline::text(" "));
+        }
+        content.extend(self.type_inlines(module, ty));
+        if let Some(c) = constraints_notation(constraints, 0) {
+            content.push(Inline::text(" "));
+            content.push(Inline::code(c));
+        }
+        if let Some(suffix) = suffix {
+            content.push(Inline::text(" "));
+            content.push(Inline::code(suffix));
+        }
+        ListItem {
+            content,
+            children: self.component_items(module, ty),
+        }
+    }
+
+    /// Returns the effective tags of the components or alternatives of a constructed type
+    /// of `module`, including automatically assigned tags if `numbered` in textual order
+    fn member_tags(
+        &self,
+        module: &str,
+        members: Vec<(&str, Option<&AsnTag>, &ASN1Type)>,
+        extensible: Option<usize>,
+        numbered: bool,
+    ) -> Vec<Option<AsnTag>> {
+        let environment = self
+            .catalog
+            .modules
+            .get(module)
+            .map_or(TaggingEnvironment::Explicit, |m| m.tagging_environment);
+        if !numbered {
+            return members.iter().map(|(_, tag, _)| tag.cloned()).collect();
+        }
+        effective_tags(&members, extensible.unwrap_or(members.len()), environment)
+    }
+
+    /// Returns the component tree of constructed types
+    fn component_items(&self, module: &str, ty: &ASN1Type) -> Vec<ListItem> {
+        let extension_marker = || ListItem {
+            content: vec![Inline::code("...")],
+            children: vec![],
+        };
+        let mut items = match ty {
+            ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+                let mut items = s
+                    .components_of
+                    .iter()
+                    .map(|c| ListItem {
+                        content: vec![
+                            Inline::code("COMPONENTS OF"),
+                            Inline::text(" "),
+                            self.reference(module, c),
+                        ],
+                        children: vec![],
+                    })
+                    .collect::<Vec<_>>();
+                let tags = self.member_tags(
+                    module,
+                    s.members
+                        .iter()
+                        .map(|m| (m.name.as_str(), m.tag.as_ref(), &m.ty))
+                        .collect(),
+                    s.extensible,
+                    // Automatic tags are assigned after the expansion of `COMPONENTS OF`
+                    s.components_of.is_empty(),
+                );
+                items.extend(s.members.iter().zip(tags).map(|(m, tag)| {
+                    self.member_item(
+                        module,
+                        &m.name,
+                        tag.as_ref(),
+                        &m.ty,
+                        &m.constraints,
+                        optionality_notation(&m.optionality),
+                    )
+                }));
+                if let Some(index) = s.extensible {
+                    items.insert(
+                        (index + s.components_of.len()).min(items.len()),
+                        extension_marker(),
+                    );
+                }
+                items
+            }
+            ASN1Type::Choice(c) => {
+                let tags = self.member_tags(
+                    module,
+                    c.options
+                        .iter()
+                        .map(|o| (o.name.as_str(), o.tag.as_ref(), &o.ty))
+                        .collect(),
+                    c.extensible,
+                    true,
+                );
+                let mut items = c
+                    .options
+                    .iter()
+                    .zip(tags)
+                    .map(|(o, tag)| {
+                        self.member_item(module, &o.name, tag.as_ref(), &o.ty, &o.constraints, None)
+                    })
+                    .collect::<Vec<_>>();
+                if let Some(index) = c.extensible {
+                    items.insert(index.min(items.len()), extension_marker());
+                }
+                items
+            }
+            ASN1Type::Enumerated(e) => {
+                let mut items = e
+                    .members
+                    .iter()
+                    .map(|m| ListItem {
+                        content: vec![
+                            Inline::code(format!("{}({})", m.name, m.index)),
+                            Inline::text(
+                                m.description
+                                    .as_ref()
+                                    .map(|d| format!(" {d}"))
+                                    .unwrap_or_default(),
+                            ),
+                        ],
+                        children: vec![],
+                    })
+                    .collect::<Vec<_>>();
+                if let Some(index) = e.extensible {
+                    items.insert(index.min(items.len()), extension_marker());
+                }
+                items
+            }
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+                self.component_items(module, &s.element_type)
+            }
+            _ => vec![],
+        };
+        items.retain(|item| !item.content.is_empty());
+        items
+    }
+
+    fn used_by_blocks(&self, id: &DefinitionId) -> Vec<Block> {
+        let users = self
+            .catalog
+            .used_by(id)
+            .map(|user| vec![self.definition_link(&id.module, user)])
+            .collect::<Vec<_>>();
+        if users.is_empty() {
+            return vec![];
+        }
+        let mut inlines = vec![Inline::text("Used by: ")];
+        inlines.extend(separated(users, ", "));
+        vec![Block::Paragraph(inlines)]
+    }
+
+    fn containment_blocks(&self, id: &DefinitionId) -> Vec<Block> {
+        let paths = self.catalog.containment_paths(id);
+        if paths.is_empty() {
+            return vec![Block::Paragraph(vec![Inline::text(
+                "Containment: not contained in any other type",
+            )])];
+        }
+        vec![
+            Block::Paragraph(vec![Inline::text("Containment:")]),
+            Block::List(
+                paths
+                    .iter()
+                    .map(|path| ListItem {
+                        content: separated(
+                            path.iter()
+                                .map(|step| vec![self.definition_link(&id.module, step)])
+                                .collect(),
+                            " › ",
+                        ),
+                        children: vec![],
+                    })
+                    .collect(),
+            ),
+        ]
+    }
+
+    fn parameter_rows(&self, parameterization: Option<&Parameterization>) -> Vec<Row> {
+        parameterization
+            .filter(|p| !p.parameters.is_empty())
+            .map(|p| row("Parameters", vec![Inline::code(parameters_notation(p))]))
+            .into_iter()
+            .collect()
+    }
+
+    fn class_blocks(&self, module: &str, definition: &ObjectClassDefn) -> Vec<Block> {
+        let mut blocks = vec![Block::Table {
+            header: vec![
+                String::from("Field"),
+                String::from("Type"),
+                String::from("Properties"),
+            ],
+            rows: definition
+                .fields
+                .iter()
+                .map(|field| {
+                    let properties = [
+                        field.is_unique.then(|| String::from("UNIQUE")),
+                        optionality_notation(&field.optionality),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                    vec![
+                        vec![Inline::code(field.identifier.identifier())],
+                        field
+                            .ty
+                            .as_ref()
+                            .map(|ty| self.type_inlines(module, ty))
+                            .unwrap_or_default(),
+                        if properties.is_empty() {
+                            vec![]
+                        } else {
+                            vec![Inline::code(properties)]
+                        },
+                    ]
+                })
+                .collect(),
+        }];
+        if let Some(syntax) = &definition.syntax {
+            blocks.push(Block::Paragraph(vec![Inline::text("Syntax:")]));
+            blocks.push(Block::Code(format!(
+                "WITH SYNTAX {{ {} }}",
+                syntax_notation(&syntax.expressions)
+            )));
+        }
+        blocks
+    }
+
+    /// Returns the documentation of a single top-level definition
+    pub(super) fn definition_blocks(&self, module: &str, tld: &ToplevelDefinition) -> Vec<Block> {
+        let id = DefinitionId {
+            module: module.to_string(),
+            name: tld.name().clone(),
+        };
+        let kind = self.catalog.kind(&id).unwrap_or(DefinitionKind::Type);
+        let mut blocks = vec![heading(3, tld.name(), Some(tld.name().clone()))];
+        match tld {
+            ToplevelDefinition::Type(t) => {
+                blocks.extend(comment_blocks(&t.comments));
+                let mut rows = vec![];
+                if let Some(tag) = &t.tag {
+                    rows.push(row("Tag", vec![Inline::code(tag_notation(tag))]));
+                }
+                rows.extend(self.type_rows(module, &t.ty));
+                rows.extend(self.parameter_rows(t.parameterization.as_ref()));
+                blocks.extend(properties(rows));
+                let components = self.component_items(module, &t.ty);
+                if !components.is_empty() {
+                    blocks.push(Block::Paragraph(vec![Inline::text("Components:")]));
+                    blocks.push(Block::List(components));
+                }
+                blocks.extend(self.containment_blocks(&id));
+            }
+            ToplevelDefinition::Value(v) => {
+                blocks.extend(comment_blocks(&v.comments));
+                let mut rows = vec![row("Type", self.type_inlines(module, &v.associated_type))];
+                rows.extend(self.parameter_rows(v.parameterization.as_ref()));
+                blocks.extend(properties(rows));
+                blocks.push(Block::Code(format!(
+                    "{} {} ::= {}",
+                    v.name,
+                    type_notation(&v.associated_type),
+                    value_notation(&v.value)
+                )));
+            }
+            ToplevelDefinition::Class(c) => {
+                blocks.extend(comment_blocks(&c.comments));
+                blocks.extend(properties(self.parameter_rows(Some(&c.parameterization))));
+                blocks.extend(self.class_blocks(module, &c.definition));
+            }
+            ToplevelDefinition::Object(o) => {
+                blocks.extend(comment_blocks(&o.comments));
+                let mut rows = vec![row("Kind", vec![Inline::text(kind.label())])];
+                match &o.class {
+                    ClassLink::ByName(class) | ClassLink::ByInstantiation(class, _) => {
+                        rows.push(row("Class", vec![self.reference(module, class)]))
+                    }
+                    ClassLink::ByReference(_) => (),
+                }
+                rows.extend(self.parameter_rows(o.parameterization.as_ref()));
+                blocks.extend(properties(rows));
+                if let ClassLink::ByReference(definition) = &o.class {
+                    blocks.extend(self.class_blocks(module, definition));
+                }
+                blocks.push(Block::Code(match &o.value {
+                    ASN1Information::Object(object) => format!(
+                        "{} {} ::= {}",
+                        o.name,
+                        object.class_name,
+                        object_notation(&object.fields, 0)
+                    ),
+                    ASN1Information::ObjectSet(set) => {
+                        let class = match &o.class {
+                            ClassLink::ByName(class) | ClassLink::ByInstantiation(class, _) => {
+                                class.as_str()
+                            }
+                            ClassLink::ByReference(_) => "CLASS",
+                        };
+                        format!("{} {class} ::= {}", o.name, object_set_notation(set, 0))
+                    }
+                }));
+            }
+            ToplevelDefinition::Macro(_) => blocks.push(Block::Paragraph(vec![Inline::text(
+                "Macro definitions are not documented in detail.",
+            )])),
+        }
+        blocks.extend(self.used_by_blocks(&id));
+        blocks
+    }
+
+    fn module_property_rows(&self, header: &ModuleHeader) -> Vec<Row> {
+        let mut rows = vec![];
+        if let Some(identifier) = &header.module_identifier {
+            let (oid, iri) = match identifier {
+                DefinitiveIdentifier::DefinitiveOID(oid) => (oid, None),
+                DefinitiveIdentifier::DefinitiveOIDandIRI { oid, iri } => (oid, Some(iri)),
+            };
+            rows.push(row(
+                "Object identifier",
+                vec![Inline::code(object_identifier_notation(oid))],
+            ));
+            if let Some(iri) = iri {
+                rows.push(row("IRI", vec![Inline::code(iri)]));
+            }
+        }
+        rows.push(row(
+            "Tagging",
+            vec![Inline::code(match header.tagging_environment {
+                TaggingEnvironment::Automatic => "AUTOMATIC TAGS",
+                TaggingEnvironment::Implicit => "IMPLICIT TAGS",
+                TaggingEnvironment::Explicit => "EXPLICIT TAGS",
+            })],
+        ));
+        if header.extensibility_environment == ExtensibilityEnvironment::Implied {
+            rows.push(row(
+                "Extensibility",
+                vec![Inline::code("EXTENSIBILITY IMPLIED")],
+            ));
+        }
+        rows.push(row(
+            "Exports",
+            match &header.exports {
+                None | Some(Exports::All) => vec![Inline::code("ALL")],
+                Some(Exports::Identifier(identifiers)) => separated(
+                    identifiers
+                        .iter()
+                        .map(|i| vec![self.reference(&header.name, i)])
+                        .collect(),
+                    ", ",
+                ),
+            },
+        ));
+        rows
+    }
+
+    fn module_link(&self, module: &str) -> Inline {
+        if self.catalog.modules.contains_key(module) {
+            Inline::link(module, Self::module_page_name(module))
+        } else {
+            Inline::Code(format!("{module} (unresolved)"))
+        }
+    }
+
+    fn import_blocks(&self, header: &ModuleHeader) -> Vec<Block> {
+        let mut blocks = vec![heading(2, "Imports", Some(String::from("imports")))];
+        if header.imports.is_empty() {
+            blocks.push(Block::Paragraph(vec![Inline::text(
+                "This module imports nothing.",
+            )]));
+        } else {
+            blocks.push(Block::List(
+                header
+                    .imports
+                    .iter()
+                    .map(|import| {
+                        let imported = &import.global_module_reference.module_reference;
+                        let mut content = vec![self.module_link(imported)];
+                        if let Some(with) = &import.with {
+                            content.push(Inline::text(" "));
+                            content.push(Inline::code(match with {
+                                With::Successors => "WITH SUCCESSORS",
+                                With::Descendants => "WITH DESCENDANTS",
+                            }));
+                        }
+                        content.push(Inline::text(": "));
+                        content.extend(separated(
+                            import
+                                .types
+                                .iter()
+                                .map(|symbol| vec![self.reference(&header.name, symbol)])
+                                .collect(),
+                            ", ",
+                        ));
+                        ListItem {
+                            content,
+                            children: vec![],
+                        }
+                    })
+                    .collect(),
+            ));
+        }
+        let importers = self
+            .catalog
+            .imported_by(&header.name)
+            .map(|m| vec![self.module_link(m)])
+            .collect::<Vec<_>>();
+        if !importers.is_empty() {
+            let mut inlines = vec![Inline::text("Imported by: ")];
+            inlines.extend(separated(importers, ", "));
+            blocks.push(Block::Paragraph(inlines));
+        }
+        blocks
+    }
+
+    pub(super) fn module_page(&self, header: &ModuleHeader, tlds: &[ToplevelDefinition]) -> Page {
+        let mut blocks = vec![
+            Block::Paragraph(vec![Inline::Link {
+                text: String::from("Index"),
+                href: <Self as crate::generator::Backend>::module_file_name("index"),
+                code: false,
+            }]),
+            heading(1, header.name.clone(), None),
+        ];
+        blocks.extend(properties(self.module_property_rows(header)));
+        blocks.extend(self.import_blocks(header));
+        for (title, kinds) in SECTIONS {
+            let definitions = tlds
+                .iter()
+                .filter(|tld| {
+                    let id = DefinitionId {
+                        module: header.name.clone(),
+                        name: tld.name().clone(),
+                    };
+                    self.catalog.kind(&id).is_some_and(|k| kinds.contains(&k))
+                })
+                .collect::<Vec<_>>();
+            if definitions.is_empty() {
+                continue;
+            }
+            blocks.push(heading(2, title, None));
+            for tld in definitions {
+                blocks.extend(self.definition_blocks(&header.name, tld));
+            }
+        }
+        Page {
+            title: header.name.clone(),
+            blocks,
+        }
+    }
+
+    pub(super) fn index_page(&self) -> Page {
+        let title = self
+            .config
+            .title
+            .clone()
+            .unwrap_or_else(|| String::from("ASN.1 Documentation"));
+        let mut blocks = vec![heading(1, title.clone(), None)];
+        blocks.push(heading(2, "Modules", Some(String::from("modules"))));
+        blocks.push(Block::Table {
+            header: vec![
+                String::from("Module"),
+                String::from("Object identifier"),
+                String::from("Definitions"),
+            ],
+            rows: self
+                .catalog
+                .modules
+                .iter()
+                .map(|(name, module)| {
+                    vec![
+                        vec![self.module_link(name)],
+                        module
+                            .module_identifier
+                            .as_ref()
+                            .map(|identifier| {
+                                let oid = match identifier {
+                                    DefinitiveIdentifier::DefinitiveOID(oid)
+                                    | DefinitiveIdentifier::DefinitiveOIDandIRI { oid, .. } => oid,
+                                };
+                                vec![Inline::code(object_identifier_notation(oid))]
+                            })
+                            .unwrap_or_default(),
+                        vec![Inline::text(module.definitions.len().to_string())],
+                    ]
+                })
+                .collect(),
+        });
+        blocks.push(heading(
+            2,
+            "Import graph",
+            Some(String::from("import-graph")),
+        ));
+        blocks.push(Block::List(
+            self.catalog
+                .modules
+                .iter()
+                .map(|(name, module)| {
+                    let mut content = vec![self.module_link(name)];
+                    if module.imports.is_empty() {
+                        content.push(Inline::text(" imports nothing"));
+                    } else {
+                        content.push(Inline::text(" imports "));
+                        content.extend(separated(
+                            module
+                                .imports
+                                .iter()
+                                .map(|(imported, _)| vec![self.module_link(imported)])
+                                .collect(),
+                            ", ",
+                        ));
+                    }
+                    ListItem {
+                        content,
+                        children: vec![],
+                    }
+                })
+                .collect(),
+        ));
+        blocks.push(heading(2, "Definitions", Some(String::from("definitions"))));
+        let mut definitions = self
+            .catalog
+            .modules
+            .iter()
+            .flat_map(|(module, entry)| {
+                entry
+                    .definitions
+                    .iter()
+                    .map(move |(name, kind)| (name, module, *kind))
+            })
+            .collect::<Vec<_>>();
+        definitions.sort_by(|a, b| {
+            a.0.to_lowercase()
+                .cmp(&b.0.to_lowercase())
+                .then(a.1.cmp(b.1))
+        });
+        blocks.push(Block::List(
+            definitions
+                .into_iter()
+                .map(|(name, module, kind)| ListItem {
+                    content: vec![
+                        Inline::link(name, format!("{}#{name}", Self::module_page_name(module))),
+                        Inline::text(format!(" ({} in {module})", kind.label())),
+                    ],
+                    children: vec![],
+                })
+                .collect(),
+        ));
+        Page { title, blocks }
+    }
+}
@@ -0,0 +1,253 @@
+//! Cross-module view of an ASN.1 specification that resolves references
+//! between top-level definitions and collects the back-references.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::intermediate::{
+    constraints::*, information_object::ASN1Information, references::definition_references, *,
+};
+
+/// Maximum number of containment paths listed per type
+const MAX_CONTAINMENT_PATHS: usize = 16;
+
+/// Maximum number of type references followed when resolving a type
+const MAX_REFERENCE_DEPTH: usize = 32;
+
+/// Identifies a top-level definition by its module and name.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DefinitionId {
+    pub module: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DefinitionKind {
+    Type,
+    Value,
+    Class,
+    Object,
+    ObjectSet,
+    Macro,
+}
+
+impl DefinitionKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DefinitionKind::Type => "type",
+            DefinitionKind::Value => "value",
+            DefinitionKind::Class => "information object class",
+            DefinitionKind::Object => "information object",
+            DefinitionKind::ObjectSet => "information object set",
+            DefinitionKind::Macro => "macro",
+        }
+    }
+
+    pub(crate) fn of(tld: &ToplevelDefinition) -> Self {
+        match tld {
+            ToplevelDefinition::Type(_) => DefinitionKind::Type,
+            ToplevelDefinition::Value(_) => DefinitionKind::Value,
+            ToplevelDefinition::Class(_) => DefinitionKind::Class,
+            ToplevelDefinition::Object(o) => match o.value {
+                ASN1Information::Object(_) => DefinitionKind::Object,
+                ASN1Information::ObjectSet(_) => DefinitionKind::ObjectSet,
+            },
+            ToplevelDefinition::Macro(_) => DefinitionKind::Macro,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ModuleEntry {
+    pub module_identifier: Option<DefinitiveIdentifier>,
+    pub tagging_environment: TaggingEnvironment,
+    pub definitions: BTreeMap<String, DefinitionKind>,
+    /// Imported modules with the imported symbols
+    pub imports: Vec<(String, Vec<String>)>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Catalog {
+    pub modules: BTreeMap<String, ModuleEntry>,
+    types: BTreeMap<DefinitionId, ASN1Type>,
+    used_by: BTreeMap<DefinitionId, BTreeSet<DefinitionId>>,
+}
+
+impl Catalog {
+    pub fn new(tlds: &[ToplevelDefinition]) -> Self {
+        let mut catalog = Catalog::default();
+        for tld in tlds {
+            let Some(header) = tld.get_module_header() else {
+                continue;
+            };
+            let header = header.read();
+            let entry = catalog
+                .modules
+                .entry(header.name.clone())
+                .or_insert_with(|| ModuleEntry {
+                    module_identifier: header.module_identifier.clone(),
+                    tagging_environment: header.tagging_environment,
+                    definitions: BTreeMap::new(),
+                    imports: header
+                        .imports
+                        .iter()
+                        .map(|i| {
+                            (
+                                i.global_module_reference.module_reference.clone(),
+                                i.types.clone(),
+                            )
+                        })
+                        .collect(),
+                });
+            entry
+                .definitions
+                .insert(tld.name().clone(), DefinitionKind::of(tld));
+            if let ToplevelDefinition::Type(t) = tld {
+                catalog.types.insert(
+                    DefinitionId {
+                        module: header.name.clone(),
+                        name: t.name.clone(),
+                    },
+                    t.ty.clone(),
+                );
+            }
+        }
+        for tld in tlds {
+            let Some(header) = tld.get_module_header() else {
+                continue;
+            };
+            let user = DefinitionId {
+                module: header.read().name.clone(),
+                name: tld.name().clone(),
+            };
+            for reference in definition_references(tld) {
+                if let Some(target) = catalog.resolve(&user.module, &reference) {
+                    if target != user {
+                        catalog
+                            .used_by
+                            .entry(target)
+                            .or_default()
+                            .insert(user.clone());
+                    }
+                }
+            }
+        }
+        catalog
+    }
+
+    pub fn kind(&self, id: &DefinitionId) -> Option<DefinitionKind> {
+        self.modules
+            .get(&id.module)
+            .and_then(|m| m.definitions.get(&id.name))
+            .copied()
+    }
+
+    /// Resolves a reference to `identifier` made in `module`.
+    /// Local definitions take precedence over imports.
+    /// References that are neither defined locally nor imported resolve
+    /// to the only module defining `identifier`, if it is unambiguous.
+    pub fn resolve(&self, module: &str, identifier: &str) -> Option<DefinitionId> {
+        let id = |module: &str| DefinitionId {
+            module: module.to_string(),
+            name: identifier.to_string(),
+        };
+        let entry = self.modules.get(module)?;
+        if entry.definitions.contains_key(identifier) {
+            return Some(id(module));
+        }
+        if let Some((imported, _)) = entry
+            .imports
+            .iter()
+            .find(|(_, symbols)| symbols.iter().any(|s| s == identifier))
+        {
+            return self
+                .modules
+                .get(imported)
+                .is_some_and(|m| m.definitions.contains_key(identifier))
+                .then(|| id(imported));
+        }
+        let mut candidates = self
+            .modules
+            .iter()
+            .filter(|(_, m)| m.definitions.contains_key(identifier));
+        match (candidates.next(), candidates.next()) {
+            (Some((name, _)), None) => Some(id(name)),
+            _ => None,
+        }
+    }
+
+    /// Follows the type references starting at `ty` in `module` to the referenced built-in type.
+    /// Returns the built-in type and the constraints of all types along the way, or `None`
+    /// if a reference cannot be resolved.
+    pub fn resolve_type<'a>(
+        &'a self,
+        module: &str,
+        ty: &'a ASN1Type,
+    ) -> Option<(&'a ASN1Type, Vec<Constraint>)> {
+        let mut module = module.to_string();
+        let mut ty = ty;
+        let mut constraints = vec![];
+        for _ in 0..MAX_REFERENCE_DEPTH {
+            let ASN1Type::ElsewhereDeclaredType(reference) = ty else {
+                constraints.extend(ty.constraints().into_iter().flatten().cloned());
+                return Some((ty, constraints));
+            };
+            constraints.extend(reference.constraints.iter().cloned());
+            let id = self.resolve(
+                reference.module.as_deref().unwrap_or(&module),
+                &reference.identifier,
+            )?;
+            ty = self.types.get(&id)?;
+            module = id.module;
+        }
+        None
+    }
+
+    pub fn used_by(&self, id: &DefinitionId) -> impl Iterator<Item = &DefinitionId> {
+        self.used_by.get(id).into_iter().flatten()
+    }
+
+    /// Modules that import from `module`
+    pub fn imported_by<'a>(&'a self, module: &'a str) -> impl Iterator<Item = &'a String> {
+        self.modules
+            .iter()
+            .filter(move |(_, m)| m.imports.iter().any(|(i, _)| i == module))
+            .map(|(name, _)| name)
+    }
+
+    /// Returns the paths from top-level types that are not contained in any other type
+    /// down to the type `id`. Paths through recursive types end at the first repetition.
+    pub fn containment_paths(&self, id: &DefinitionId) -> Vec<Vec<DefinitionId>> {
+        let mut paths = vec![];
+        self.collect_containment_paths(vec![id.clone()], &mut paths);
+        paths
+    }
+
+    fn collect_containment_paths(
+        &self,
+        path: Vec<DefinitionId>,
+        paths: &mut Vec<Vec<DefinitionId>>,
+    ) {
+        if paths.len() >= MAX_CONTAINMENT_PATHS {
+            return;
+        }
+        let Some(current) = path.last() else {
+            return;
+        };
+        let parents = self
+            .used_by(current)
+            .filter(|p| self.kind(p) == Some(DefinitionKind::Type) && !path.contains(p))
+            .cloned()
+            .collect::<Vec<_>>();
+        if parents.is_empty() {
+            if path.len() > 1 {
+                paths.push(path.into_iter().rev().collect());
+            }
+            return;
+        }
+        for parent in parents {
+            let mut extended = path.clone();
+            extended.push(parent);
+            self.collect_containment_paths(extended, paths);
+        }
+    }
+}
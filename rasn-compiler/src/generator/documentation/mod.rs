@@ -0,0 +1,147 @@
+use std::marker::PhantomData;
+
+use self::catalog::Catalog;
+use crate::intermediate::*;
+
+pub use self::template::{Format, Html, Markdown};
+
+use super::{
+    error::{GeneratorError, GeneratorErrorType},
+    Backend, GeneratedModule,
+};
+
+mod builder;
+pub(crate) mod catalog;
+mod notation;
+mod template;
+
+#[derive(Debug, Default, Clone)]
+/// A compiler backend that generates hyperlinked documentation for ASN.1 specifications.
+/// Every module is documented on its own page, listing its types, values,
+/// information object classes, objects and object sets together with their comments,
+/// tags, constraints and PER-visible encoding properties.
+/// Cross references, back references and the module import graph are rendered as links.
+/// The output format is selected by the type parameter, see [Html] and [Markdown].
+/// For a browsable static site, every module should be written to its own file.
+pub struct Documentation<F: Format> {
+    config: Config,
+    catalog: Catalog,
+    format: PhantomData<F>,
+}
+
+#[derive(Debug, Default, Clone)]
+/// A configuration for the [Documentation] backend
+pub struct Config {
+    /// Title of the index page. Defaults to "ASN.1 Documentation".
+    pub title: Option<String>,
+}
+
+impl<F: Format> Backend for Documentation<F> {
+    type Config = Config;
+
+    const FILE_EXTENSION: &'static str = F::FILE_EXTENSION;
+
+    fn from_config(config: Self::Config) -> Self {
+        Self {
+            config,
+            catalog: Catalog::default(),
+            format: PhantomData,
+        }
+    }
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn new(config: Self::Config, _: TaggingEnvironment, _: ExtensibilityEnvironment) -> Self {
+        Self::from_config(config)
+    }
+
+    fn prepare(&mut self, top_level_declarations: &[ToplevelDefinition]) {
+        self.catalog = Catalog::new(top_level_declarations);
+    }
+
+    fn generate_index(&self) -> Option<String> {
+        Some(F::render(&self.index_page()))
+    }
+
+    fn generate_module(
+        &mut self,
+        tlds: Vec<ToplevelDefinition>,
+    ) -> Result<GeneratedModule, GeneratorError> {
+        let Some(module_ref) = tlds.first().and_then(|tld| tld.get_module_header()) else {
+            return Ok(GeneratedModule::empty());
+        };
+        let module = module_ref.read();
+        Ok(GeneratedModule {
+            generated: Some(F::render(&self.module_page(&module, &tlds))),
+            warnings: vec![],
+        })
+    }
+
+    fn generate(&self, tld: ToplevelDefinition) -> Result<String, GeneratorError> {
+        let module = tld.get_module_header().ok_or_else(|| {
+            GeneratorError::new(
+                Some(tld.clone()),
+                "Cannot document a definition outside of a module.",
+                GeneratorErrorType::Unidentified,
+            )
+        })?;
+        let module = module.read();
+        Ok(F::render(&template::Page {
+            title: tld.name().clone(),
+            blocks: self.definition_blocks(&module.name, &tld),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MarkdownCompiler;
+
+    #[test]
+    fn links_definitions_across_modules() {
+        let generated = MarkdownCompiler::new()
+            .add_asn_literal(
+                r#"Base DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Counter ::= INTEGER (0..255)
+                END
+
+                Messages DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                IMPORTS Counter FROM Base;
+                -- A message
+                Message ::= SEQUENCE { count Counter (0..15), flag BOOLEAN OPTIONAL }
+                Envelope ::= SEQUENCE OF Message
+                END"#,
+            )
+            .compile_to_string()
+            .unwrap()
+            .generated;
+        assert!(generated.contains("[`Base`](Base.md) imports nothing"));
+        assert!(generated.contains("[`Messages`](Messages.md) imports [`Base`](Base.md)"));
+        assert!(generated.contains("| PER value range | `0..255` |\n| PER bit width | `8 bits` |"));
+        assert!(generated.contains("Used by: [`Messages.Message`](Messages.md#Message)"));
+        assert!(generated.contains("A message"));
+        assert!(generated.contains(
+            "- `count` `[0]` [`Counter`](Base.md#Counter) `(0..15)`\n- `flag` `[1]` `BOOLEAN` `OPTIONAL`"
+        ));
+        assert!(generated.contains("- [`Envelope`](#Envelope) › [`Message`](#Message)"));
+    }
+
+    #[test]
+    fn renders_effective_tags() {
+        let generated = MarkdownCompiler::new()
+            .add_asn_literal(
+                r#"Automatic DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Choice ::= CHOICE { a BOOLEAN, b INTEGER, ..., c NULL }
+                Tagged ::= SEQUENCE { a [5] BOOLEAN, b INTEGER }
+                END"#,
+            )
+            .compile_to_string()
+            .unwrap()
+            .generated;
+        assert!(generated
+            .contains("- `a` `[0]` `BOOLEAN`\n- `b` `[1]` `INTEGER`\n- `...`\n- `c` `[2]` `NULL`"));
+        assert!(generated.contains("- `a` `[5]` `BOOLEAN`\n- `b` `INTEGER`"));
+    }
+}
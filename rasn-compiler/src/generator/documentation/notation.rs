@@ -0,0 +1,91 @@
+//! Renders IR elements in ASN.1 notation.
+//! Apart from the compact type notation, the notation of the [Asn1](crate::generator::asn1::Asn1)
+//! backend is used.
+
+use crate::intermediate::{types::*, *};
+
+pub use crate::generator::asn1::notation::{
+    constraints_notation, object_identifier_notation, object_notation, object_set_notation,
+    optionality_notation, parameters_notation, string_notation, syntax_notation, tag_notation,
+    value_notation,
+};
+
+/// Returns the notation of a type reference or built-in type, including its constraints.
+/// The components of constructed types are omitted.
+pub fn type_notation(ty: &ASN1Type) -> String {
+    let name = match ty {
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+            return format!(
+                "{} {}OF {}",
+                if matches!(ty, ASN1Type::SequenceOf(_)) {
+                    SEQUENCE
+                } else {
+                    SET
+                },
+                constraints_notation(&s.constraints, 0)
+                    .map(|c| c + " ")
+                    .unwrap_or_default(),
+                type_notation(&s.element_type)
+            )
+        }
+        ASN1Type::ChoiceSelectionType(c) => {
+            return format!("{} < {}", c.selected_option, c.choice_name)
+        }
+        ASN1Type::ObjectClassField(f) => format!(
+            "{}.{}",
+            f.class,
+            f.field_path
+                .iter()
+                .map(|p| p.identifier().clone())
+                .collect::<Vec<_>>()
+                .join(".")
+        ),
+        ASN1Type::Integer(Integer {
+            distinguished_values: Some(values),
+            ..
+        })
+        | ASN1Type::BitString(BitString {
+            distinguished_values: Some(values),
+            ..
+        }) => format!(
+            "{} {{ {} }}",
+            ty.as_str(),
+            values
+                .iter()
+                .map(|v| format!("{}({})", v.name, v.value))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        ty => ty.as_str().into_owned(),
+    };
+    match ty.constraints().and_then(|c| constraints_notation(c, 0)) {
+        Some(constraints) => format!("{name} {constraints}"),
+        None => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_tags_and_types() {
+        assert_eq!(
+            tag_notation(&AsnTag {
+                environment: TaggingEnvironment::Implicit,
+                tag_class: TagClass::Application,
+                id: 3
+            }),
+            "[APPLICATION 3] IMPLICIT"
+        );
+        assert_eq!(
+            type_notation(&ASN1Type::SequenceOf(SequenceOrSetOf {
+                constraints: vec![],
+                element_type: Box::new(ASN1Type::Integer(Integer::from((0, 7, false)))),
+                element_tag: None,
+                is_recursive: false
+            })),
+            "SEQUENCE OF INTEGER (0..7)"
+        );
+    }
+}
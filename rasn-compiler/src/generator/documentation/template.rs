@@ -0,0 +1,364 @@
+//! Output-format independent page model and its HTML and Markdown renderings.
+
+use std::fmt::Debug;
+
+#[derive(Debug, Default)]
+pub struct Page {
+    pub title: String,
+    pub blocks: Vec<Block>,
+}
+
+#[derive(Debug)]
+pub enum Block {
+    Heading {
+        level: u8,
+        text: String,
+        anchor: Option<String>,
+    },
+    Paragraph(Vec<Inline>),
+    Code(String),
+    List(Vec<ListItem>),
+    Table {
+        header: Vec<String>,
+        rows: Vec<Vec<Vec<Inline>>>,
+    },
+}
+
+#[derive(Debug, Default)]
+pub struct ListItem {
+    pub content: Vec<Inline>,
+    pub children: Vec<ListItem>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Inline {
+    Text(String),
+    Code(String),
+    Link {
+        text: String,
+        href: String,
+        code: bool,
+    },
+}
+
+impl Inline {
+    pub fn text(text: impl Into<String>) -> Self {
+        Inline::Text(text.into())
+    }
+
+    pub fn code(code: impl Into<String>) -> Self {
+        Inline::Code(code.into())
+    }
+
+    pub fn link(text: impl Into<String>, href: impl Into<String>) -> Self {
+        Inline::Link {
+            text: text.into(),
+            href: href.into(),
+            code: true,
+        }
+    }
+}
+
+/// Output format of the documentation backend
+pub trait Format: Debug + Default + Clone + Send {
+    const FILE_EXTENSION: &'static str;
+
+    fn render(page: &Page) -> String;
+}
+
+/// Renders the documentation as static HTML pages
+#[derive(Debug, Default, Clone)]
+pub struct Html;
+
+/// Renders the documentation as Markdown pages
+#[derive(Debug, Default, Clone)]
+pub struct Markdown;
+
+const STYLESHEET: &str = r#"body { font-family: sans-serif; max-width: 72em; margin: 2em auto; padding: 0 1em; color: #222; }
+code, pre { font-family: monospace; background: #f4f4f4; }
+pre { padding: .5em; overflow-x: auto; }
+table { border-collapse: collapse; margin: .5em 0; }
+th, td { border: 1px solid #ccc; padding: .2em .5em; text-align: left; vertical-align: top; }
+h2 { border-bottom: 1px solid #ccc; }
+h3 { margin-top: 2em; }
+a { color: #0550ae; text-decoration: none; }
+a:hover { text-decoration: underline; }"#;
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn html_inlines(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(t) => escape_html(t),
+            Inline::Code(c) => format!("<code>{}</code>", escape_html(c)),
+            Inline::Link { text, href, code } => {
+                let text = escape_html(text);
+                format!(
+                    r#"<a href="{}">{}</a>"#,
+                    escape_html(href),
+                    if *code {
+                        format!("<code>{text}</code>")
+                    } else {
+                        text
+                    }
+                )
+            }
+        })
+        .collect()
+}
+
+fn html_list(items: &[ListItem], out: &mut String) {
+    out.push_str("<ul>\n");
+    for item in items {
+        out.push_str("<li>");
+        out.push_str(&html_inlines(&item.content));
+        if !item.children.is_empty() {
+            out.push('\n');
+            html_list(&item.children, out);
+        }
+        out.push_str("</li>\n");
+    }
+    out.push_str("</ul>\n");
+}
+
+impl Format for Html {
+    const FILE_EXTENSION: &'static str = ".html";
+
+    fn render(page: &Page) -> String {
+        let mut out = format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{STYLESHEET}\n</style>\n</head>\n<body>\n",
+            escape_html(&page.title)
+        );
+        for block in &page.blocks {
+            match block {
+                Block::Heading {
+                    level,
+                    text,
+                    anchor,
+                } => out.push_str(&format!(
+                    "<h{level}{}>{}</h{level}>\n",
+                    anchor
+                        .as_ref()
+                        .map(|a| format!(" id=\"{}\"", escape_html(a)))
+                        .unwrap_or_default(),
+                    escape_html(text)
+                )),
+                Block::Paragraph(inlines) => {
+                    out.push_str(&format!("<p>{}</p>\n", html_inlines(inlines)))
+                }
+                Block::Code(code) => {
+                    out.push_str(&format!("<pre><code>{}</code></pre>\n", escape_html(code)))
+                }
+                Block::List(items) => html_list(items, &mut out),
+                Block::Table { header, rows } => {
+                    out.push_str("<table>\n");
+                    if !header.is_empty() {
+                        out.push_str("<tr>");
+                        for cell in header {
+                            out.push_str(&format!("<th>{}</th>", escape_html(cell)));
+                        }
+                        out.push_str("</tr>\n");
+                    }
+                    for row in rows {
+                        out.push_str("<tr>");
+                        for cell in row {
+                            out.push_str(&format!("<td>{}</td>", html_inlines(cell)));
+                        }
+                        out.push_str("</tr>\n");
+                    }
+                    out.push_str("</table>\n");
+                }
+            }
+        }
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '#' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push(' '),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn markdown_code_span(code: &str) -> String {
+    // A code span is delimited by a backtick string longer than any run inside it
+    let longest_run = code
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    let fence = "`".repeat(longest_run + 1);
+    let padding = if code.starts_with('`') || code.ends_with('`') {
+        " "
+    } else {
+        ""
+    };
+    format!("{fence}{padding}{code}{padding}{fence}")
+}
+
+/// Pipes must be escaped even inside code spans when they appear in table cells
+fn markdown_inlines(inlines: &[Inline], in_table: bool) -> String {
+    let code_span = |code: &str| {
+        let span = markdown_code_span(&code.replace('\n', " "));
+        if in_table {
+            span.replace('|', "\\|")
+        } else {
+            span
+        }
+    };
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(t) => escape_markdown(t),
+            Inline::Code(c) => code_span(c),
+            Inline::Link { text, href, code } => format!(
+                "[{}]({href})",
+                if *code {
+                    code_span(text)
+                } else {
+                    escape_markdown(text)
+                }
+            ),
+        })
+        .collect()
+}
+
+fn markdown_list(items: &[ListItem], depth: usize, out: &mut String) {
+    for item in items {
+        out.push_str(&format!(
+            "{}- {}\n",
+            "  ".repeat(depth),
+            markdown_inlines(&item.content, false)
+        ));
+        markdown_list(&item.children, depth + 1, out);
+    }
+}
+
+impl Format for Markdown {
+    const FILE_EXTENSION: &'static str = ".md";
+
+    fn render(page: &Page) -> String {
+        let mut blocks = vec![];
+        for block in &page.blocks {
+            blocks.push(match block {
+                Block::Heading {
+                    level,
+                    text,
+                    anchor,
+                } => format!(
+                    "{}{} {}",
+                    anchor
+                        .as_ref()
+                        .map(|a| format!("<a id=\"{a}\"></a>\n\n"))
+                        .unwrap_or_default(),
+                    "#".repeat(*level as usize),
+                    escape_markdown(text)
+                ),
+                Block::Paragraph(inlines) => markdown_inlines(inlines, false),
+                Block::Code(code) => format!("```asn1\n{code}\n```"),
+                Block::List(items) => {
+                    let mut out = String::new();
+                    markdown_list(items, 0, &mut out);
+                    out.trim_end().to_string()
+                }
+                Block::Table { header, rows } => {
+                    let columns = header
+                        .len()
+                        .max(rows.iter().map(Vec::len).max().unwrap_or_default());
+                    let mut out = format!(
+                        "| {} |\n|{}\n",
+                        (0..columns)
+                            .map(|i| header
+                                .get(i)
+                                .map(|h| escape_markdown(h))
+                                .unwrap_or_default())
+                            .collect::<Vec<_>>()
+                            .join(" | "),
+                        " --- |".repeat(columns)
+                    );
+                    for row in rows {
+                        out.push_str(&format!(
+                            "| {} |\n",
+                            row.iter()
+                                .map(|cell| markdown_inlines(cell, true))
+                                .collect::<Vec<_>>()
+                                .join(" | ")
+                        ));
+                    }
+                    out.trim_end().to_string()
+                }
+            });
+        }
+        blocks.join("\n\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page() -> Page {
+        Page {
+            title: "Test".into(),
+            blocks: vec![
+                Block::Heading {
+                    level: 2,
+                    text: "Types".into(),
+                    anchor: Some("types".into()),
+                },
+                Block::Paragraph(vec![
+                    Inline::text("a <b> & "),
+                    Inline::link("Ref", "Other.html#Ref"),
+                ]),
+                Block::Table {
+                    header: vec!["Property".into(), "Value".into()],
+                    rows: vec![vec![
+                        vec![Inline::text("Constraints")],
+                        vec![Inline::code("(0..`7 | 9)")],
+                    ]],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn renders_html() {
+        let html = Html::render(&page());
+        assert!(html.contains("<title>Test</title>"));
+        assert!(html.contains(r#"<h2 id="types">Types</h2>"#));
+        assert!(html
+            .contains(r#"<p>a &lt;b&gt; &amp; <a href="Other.html#Ref"><code>Ref</code></a></p>"#));
+        assert!(html.contains("<td><code>(0..`7 | 9)</code></td>"));
+    }
+
+    #[test]
+    fn renders_markdown() {
+        assert_eq!(
+            Markdown::render(&page()),
+            "<a id=\"types\"></a>\n\n## Types\n\na \\<b\\> & [`Ref`](Other.html#Ref)\n\n| Property | Value |\n| --- | --- |\n| Constraints | ``(0..`7 \\| 9)`` |\n"
+        );
+    }
+}
@@ -0,0 +1,310 @@
+//! Module import graph and definition dependency graph of an ASN.1 specification.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::generator::documentation::catalog::Catalog;
+use crate::intermediate::references::definition_references;
+use crate::intermediate::*;
+
+pub use crate::generator::documentation::catalog::{DefinitionId, DefinitionKind};
+
+/// The module import graph and the definition dependency graph of an ASN.1 specification.
+/// Definitions are ordered by module and name.
+/// Modules and definitions that are part of a cycle are marked as cyclic,
+/// as are the imports and dependencies that close the cycle.
+/// Imports of modules or symbols that are not part of the specification
+/// and references that cannot be resolved are recorded as unresolved.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DependencyGraph {
+    /// Modules of the specification, followed by the imported modules that are missing from it
+    pub modules: Vec<ModuleNode>,
+    pub imports: Vec<ImportEdge>,
+    pub definitions: Vec<DefinitionNode>,
+    pub dependencies: Vec<DependencyEdge>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleNode {
+    pub name: String,
+    /// `false` if the module is imported, but not part of the specification
+    pub resolved: bool,
+    /// `true` if the module is part of an import cycle
+    pub cyclic: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportEdge {
+    pub importing: String,
+    pub imported: String,
+    pub symbols: Vec<String>,
+    /// Imported symbols that the imported module does not define
+    pub unresolved: Vec<String>,
+    pub cyclic: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefinitionNode {
+    pub id: DefinitionId,
+    pub kind: DefinitionKind,
+    /// References to types, classes and object sets that cannot be resolved
+    pub unresolved: Vec<String>,
+    /// `true` if the definition depends on itself, directly or through other definitions
+    pub cyclic: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DependencyEdge {
+    pub user: DefinitionId,
+    pub dependency: DefinitionId,
+    pub cyclic: bool,
+}
+
+impl DependencyGraph {
+    pub fn new(tlds: &[ToplevelDefinition]) -> Self {
+        let catalog = Catalog::new(tlds);
+        let mut graph = DependencyGraph::default();
+
+        let mut missing_modules = BTreeSet::new();
+        for (name, entry) in &catalog.modules {
+            graph.modules.push(ModuleNode {
+                name: name.clone(),
+                resolved: true,
+                cyclic: false,
+            });
+            for (imported, symbols) in &entry.imports {
+                let definitions = catalog.modules.get(imported).map(|m| &m.definitions);
+                if definitions.is_none() {
+                    missing_modules.insert(imported.clone());
+                }
+                graph.imports.push(ImportEdge {
+                    importing: name.clone(),
+                    imported: imported.clone(),
+                    symbols: symbols.clone(),
+                    unresolved: symbols
+                        .iter()
+                        .filter(|s| !definitions.is_some_and(|d| d.contains_key(*s)))
+                        .cloned()
+                        .collect(),
+                    cyclic: false,
+                });
+            }
+        }
+        graph
+            .modules
+            .extend(missing_modules.into_iter().map(|name| ModuleNode {
+                name,
+                resolved: false,
+                cyclic: false,
+            }));
+
+        for tld in tlds {
+            let Some(header) = tld.get_module_header() else {
+                continue;
+            };
+            let id = DefinitionId {
+                module: header.read().name.clone(),
+                name: tld.name().clone(),
+            };
+            let dummy_references = dummy_references(tld);
+            let references = definition_references(tld);
+            let mut dependencies = BTreeSet::new();
+            let mut unresolved = BTreeSet::new();
+            for reference in references {
+                if dummy_references.contains(&reference.as_str()) {
+                    continue;
+                }
+                match catalog.resolve(&id.module, &reference) {
+                    Some(dependency) => {
+                        dependencies.insert(dependency);
+                    }
+                    None if is_unresolved_reference(&reference) => {
+                        unresolved.insert(reference);
+                    }
+                    None => (),
+                }
+            }
+            graph
+                .dependencies
+                .extend(dependencies.into_iter().map(|dependency| DependencyEdge {
+                    user: id.clone(),
+                    dependency,
+                    cyclic: false,
+                }));
+            graph.definitions.push(DefinitionNode {
+                kind: catalog.kind(&id).unwrap_or(DefinitionKind::Type),
+                id,
+                unresolved: unresolved.into_iter().collect(),
+                cyclic: false,
+            });
+        }
+
+        graph.definitions.sort_by(|a, b| a.id.cmp(&b.id));
+        graph.mark_cycles();
+        graph
+    }
+
+    fn mark_cycles(&mut self) {
+        let modules = self
+            .modules
+            .iter()
+            .enumerate()
+            .map(|(i, m)| (&m.name, i))
+            .collect::<BTreeMap<_, _>>();
+        let imports = self
+            .imports
+            .iter()
+            .map(|i| (modules[&i.importing], modules[&i.imported]))
+            .collect::<Vec<_>>();
+        let (cyclic_modules, cyclic_imports) = cycles(self.modules.len(), &imports);
+
+        let definitions = self
+            .definitions
+            .iter()
+            .enumerate()
+            .map(|(i, d)| (&d.id, i))
+            .collect::<BTreeMap<_, _>>();
+        let dependencies = self
+            .dependencies
+            .iter()
+            .map(|d| (definitions[&d.user], definitions[&d.dependency]))
+            .collect::<Vec<_>>();
+        let (cyclic_definitions, cyclic_dependencies) =
+            cycles(self.definitions.len(), &dependencies);
+
+        for (module, cyclic) in self.modules.iter_mut().zip(cyclic_modules) {
+            module.cyclic = cyclic;
+        }
+        for (import, cyclic) in self.imports.iter_mut().zip(cyclic_imports) {
+            import.cyclic = cyclic;
+        }
+        for (definition, cyclic) in self.definitions.iter_mut().zip(cyclic_definitions) {
+            definition.cyclic = cyclic;
+        }
+        for (dependency, cyclic) in self.dependencies.iter_mut().zip(cyclic_dependencies) {
+            dependency.cyclic = cyclic;
+        }
+    }
+}
+
+/// Names of the dummy parameters of a parameterized definition,
+/// which its body references without them being defined anywhere.
+fn dummy_references(tld: &ToplevelDefinition) -> Vec<&str> {
+    let parameterization = match tld {
+        ToplevelDefinition::Type(t) => t.parameterization.as_ref(),
+        ToplevelDefinition::Value(v) => v.parameterization.as_ref(),
+        ToplevelDefinition::Class(c) => Some(&c.parameterization),
+        ToplevelDefinition::Object(o) => o.parameterization.as_ref(),
+        ToplevelDefinition::Macro(_) => None,
+    };
+    parameterization
+        .into_iter()
+        .flat_map(|p| &p.parameters)
+        .map(|p| p.dummy_reference.as_str())
+        .collect()
+}
+
+/// Value references also name enumerals and named numbers that are not top-level definitions,
+/// so only unresolved references that start with an upper-case letter are reported.
+/// Built-in classes do not need to be defined.
+fn is_unresolved_reference(reference: &str) -> bool {
+    reference.starts_with(|c: char| c.is_ascii_uppercase())
+        && ![TYPE_IDENTIFIER, ABSTRACT_SYNTAX].contains(&reference)
+}
+
+/// Marks the nodes and edges of a directed graph that are part of a cycle.
+/// A node is cyclic if its strongly connected component has more than one node
+/// or if it has an edge to itself. An edge is cyclic if it connects two nodes
+/// of the same cyclic component.
+fn cycles(node_count: usize, edges: &[(usize, usize)]) -> (Vec<bool>, Vec<bool>) {
+    let mut successors = vec![vec![]; node_count];
+    for (from, to) in edges {
+        successors[*from].push(*to);
+    }
+    let components = strongly_connected_components(&successors);
+    let mut component_sizes = BTreeMap::<usize, usize>::new();
+    for component in &components {
+        *component_sizes.entry(*component).or_default() += 1;
+    }
+    let mut cyclic_nodes = components
+        .iter()
+        .map(|c| component_sizes[c] > 1)
+        .collect::<Vec<_>>();
+    for (from, to) in edges {
+        if from == to {
+            cyclic_nodes[*from] = true;
+        }
+    }
+    let cyclic_edges = edges
+        .iter()
+        .map(|(from, to)| components[*from] == components[*to] && cyclic_nodes[*from])
+        .collect();
+    (cyclic_nodes, cyclic_edges)
+}
+
+/// Assigns every node the index of its strongly connected component, using Tarjan's algorithm.
+/// The depth-first search keeps its own call stack, because the dependency chains
+/// of large specifications can be deeper than the thread's stack allows.
+fn strongly_connected_components(successors: &[Vec<usize>]) -> Vec<usize> {
+    const UNVISITED: usize = usize::MAX;
+    let node_count = successors.len();
+    let mut index = vec![UNVISITED; node_count];
+    let mut lowlink = vec![0; node_count];
+    let mut on_stack = vec![false; node_count];
+    let mut components = vec![UNVISITED; node_count];
+    let mut stack = vec![];
+    let mut next_index = 0;
+    let mut next_component = 0;
+    for root in 0..node_count {
+        if index[root] != UNVISITED {
+            continue;
+        }
+        // Nodes being visited, with the position of the next successor to visit
+        let mut calls = vec![(root, 0)];
+        while let Some((node, position)) = calls.pop() {
+            if position == 0 {
+                index[node] = next_index;
+                lowlink[node] = next_index;
+                next_index += 1;
+                stack.push(node);
+                on_stack[node] = true;
+            }
+            if let Some(&successor) = successors[node].get(position) {
+                calls.push((node, position + 1));
+                if index[successor] == UNVISITED {
+                    calls.push((successor, 0));
+                } else if on_stack[successor] {
+                    lowlink[node] = lowlink[node].min(index[successor]);
+                }
+                continue;
+            }
+            if lowlink[node] == index[node] {
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    components[member] = next_component;
+                    if member == node {
+                        break;
+                    }
+                }
+                next_component += 1;
+            }
+            if let Some(&(caller, _)) = calls.last() {
+                lowlink[caller] = lowlink[caller].min(lowlink[node]);
+            }
+        }
+    }
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cycles;
+
+    #[test]
+    fn marks_cycles() {
+        // 0 -> 1 -> 2 -> 0 is a cycle, 3 refers to itself, 4 only uses the cycle
+        let edges = [(0, 1), (1, 2), (2, 0), (3, 3), (4, 0), (2, 5)];
+        let (nodes, edges) = cycles(6, &edges);
+        assert_eq!(nodes, vec![true, true, true, true, false, false]);
+        assert_eq!(edges, vec![true, true, true, true, false, false]);
+    }
+}
@@ -0,0 +1,212 @@
+//! Graphviz DOT and JSON renderings of the dependency graphs.
+
+use std::fmt::Debug;
+
+use super::dependencies::{DefinitionId, DependencyGraph};
+use crate::common::json_string;
+
+/// Attributes of cyclic nodes and edges
+const CYCLIC: &str = "color=red, fontcolor=red";
+/// Attributes of unresolved nodes and edges
+const UNRESOLVED: &str = "color=orange, fontcolor=orange, style=dashed";
+
+pub trait Format: Debug + Default + Clone + Send {
+    const FILE_EXTENSION: &'static str;
+
+    fn render(graph: &DependencyGraph) -> String;
+}
+
+/// Renders the module import graph and the definition dependency graph
+/// as two Graphviz digraphs. Cyclic modules, definitions and edges are red,
+/// unresolved ones orange and dashed.
+#[derive(Debug, Default, Clone)]
+pub struct Dot;
+
+/// Renders the dependency graphs as a JSON document
+/// with the `modules`, `imports`, `definitions` and `dependencies` of the specification.
+#[derive(Debug, Default, Clone)]
+pub struct Json;
+
+impl Format for Dot {
+    const FILE_EXTENSION: &'static str = ".dot";
+
+    fn render(graph: &DependencyGraph) -> String {
+        let mut dot = String::from("digraph modules {\n    node [shape=box];\n");
+        for module in &graph.modules {
+            dot.push_str(&format!(
+                "    {}{};\n",
+                dot_id(&module.name),
+                attributes(module.cyclic, !module.resolved, None)
+            ));
+        }
+        for import in &graph.imports {
+            let unresolved = (!import.unresolved.is_empty()).then(|| import.unresolved.join(", "));
+            dot.push_str(&format!(
+                "    {} -> {}{};\n",
+                dot_id(&import.importing),
+                dot_id(&import.imported),
+                attributes(import.cyclic, unresolved.is_some(), unresolved.as_deref())
+            ));
+        }
+        dot.push_str("}\n\ndigraph definitions {\n    node [shape=box];\n");
+        let mut module = None;
+        for definition in &graph.definitions {
+            if module != Some(&definition.id.module) {
+                if module.is_some() {
+                    dot.push_str("    }\n");
+                }
+                module = Some(&definition.id.module);
+                dot.push_str(&format!(
+                    "    subgraph {} {{\n        label={};\n",
+                    dot_id(&format!("cluster_{}", definition.id.module)),
+                    dot_id(&definition.id.module)
+                ));
+            }
+            dot.push_str(&format!(
+                "        {}{};\n",
+                definition_dot_id(&definition.id),
+                attributes(definition.cyclic, false, Some(&definition.id.name))
+            ));
+        }
+        if module.is_some() {
+            dot.push_str("    }\n");
+        }
+        let mut unresolved = graph
+            .definitions
+            .iter()
+            .flat_map(|d| &d.unresolved)
+            .collect::<Vec<_>>();
+        unresolved.sort();
+        unresolved.dedup();
+        for reference in unresolved {
+            dot.push_str(&format!(
+                "    {}{};\n",
+                dot_id(&format!("?{reference}")),
+                attributes(false, true, Some(reference))
+            ));
+        }
+        for dependency in &graph.dependencies {
+            dot.push_str(&format!(
+                "    {} -> {}{};\n",
+                definition_dot_id(&dependency.user),
+                definition_dot_id(&dependency.dependency),
+                attributes(dependency.cyclic, false, None)
+            ));
+        }
+        for definition in &graph.definitions {
+            for reference in &definition.unresolved {
+                dot.push_str(&format!(
+                    "    {} -> {}{};\n",
+                    definition_dot_id(&definition.id),
+                    dot_id(&format!("?{reference}")),
+                    attributes(false, true, None)
+                ));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl Format for Json {
+    const FILE_EXTENSION: &'static str = ".json";
+
+    fn render(graph: &DependencyGraph) -> String {
+        let modules = graph.modules.iter().map(|m| {
+            format!(
+                r#"{{"name": {}, "resolved": {}, "cyclic": {}}}"#,
+                json_string(&m.name),
+                m.resolved,
+                m.cyclic
+            )
+        });
+        let imports = graph.imports.iter().map(|i| {
+            format!(
+                r#"{{"importing": {}, "imported": {}, "symbols": {}, "unresolved": {}, "cyclic": {}}}"#,
+                json_string(&i.importing),
+                json_string(&i.imported),
+                json_strings(&i.symbols),
+                json_strings(&i.unresolved),
+                i.cyclic
+            )
+        });
+        let definitions = graph.definitions.iter().map(|d| {
+            format!(
+                r#"{{"module": {}, "name": {}, "kind": {}, "unresolved": {}, "cyclic": {}}}"#,
+                json_string(&d.id.module),
+                json_string(&d.id.name),
+                json_string(d.kind.label()),
+                json_strings(&d.unresolved),
+                d.cyclic
+            )
+        });
+        let dependencies = graph.dependencies.iter().map(|d| {
+            format!(
+                r#"{{"user": {}, "dependency": {}, "cyclic": {}}}"#,
+                json_definition_id(&d.user),
+                json_definition_id(&d.dependency),
+                d.cyclic
+            )
+        });
+        format!(
+            "{{\n{},\n{},\n{},\n{}\n}}\n",
+            json_array("modules", modules),
+            json_array("imports", imports),
+            json_array("definitions", definitions),
+            json_array("dependencies", dependencies)
+        )
+    }
+}
+
+fn attributes(cyclic: bool, unresolved: bool, label: Option<&str>) -> String {
+    let mut attributes = vec![];
+    if let Some(label) = label {
+        attributes.push(format!("label={}", dot_id(label)));
+    }
+    if unresolved {
+        attributes.push(UNRESOLVED.to_string());
+    } else if cyclic {
+        attributes.push(CYCLIC.to_string());
+    }
+    if attributes.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", attributes.join(", "))
+    }
+}
+
+fn dot_id(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn definition_dot_id(id: &DefinitionId) -> String {
+    dot_id(&format!("{}.{}", id.module, id.name))
+}
+
+fn json_array(key: &str, items: impl Iterator<Item = String>) -> String {
+    let items = items.map(|item| format!("    {item}")).collect::<Vec<_>>();
+    if items.is_empty() {
+        format!("  \"{key}\": []")
+    } else {
+        format!("  \"{key}\": [\n{}\n  ]", items.join(",\n"))
+    }
+}
+
+fn json_strings(strings: &[String]) -> String {
+    format!(
+        "[{}]",
+        strings
+            .iter()
+            .map(|s| json_string(s))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn json_definition_id(id: &DefinitionId) -> String {
+    format!(
+        r#"{{"module": {}, "name": {}}}"#,
+        json_string(&id.module),
+        json_string(&id.name)
+    )
+}
@@ -0,0 +1,133 @@
+use std::marker::PhantomData;
+
+use crate::intermediate::*;
+
+pub use self::dependencies::{
+    DefinitionId, DefinitionKind, DefinitionNode, DependencyEdge, DependencyGraph, ImportEdge,
+    ModuleNode,
+};
+pub use self::format::{Dot, Format, Json};
+
+use super::{error::GeneratorError, Backend, GeneratedModule};
+
+mod dependencies;
+mod format;
+
+#[derive(Debug, Default, Clone)]
+/// A compiler backend that exports the module import graph and the definition dependency graph
+/// of an ASN.1 specification, see [DependencyGraph].
+/// Import cycles, reference cycles, unresolved imports and unresolved references are highlighted.
+/// The whole specification is exported as a single graph document,
+/// whose format is selected by the type parameter, see [Dot] and [Json].
+pub struct Graph<F: Format> {
+    config: Config,
+    graph: DependencyGraph,
+    format: PhantomData<F>,
+}
+
+#[derive(Debug, Default, Clone)]
+/// A configuration for the [Graph] backend
+pub struct Config {}
+
+impl<F: Format> Graph<F> {
+    /// The dependency graph of the compiled specification
+    pub fn graph(&self) -> &DependencyGraph {
+        &self.graph
+    }
+}
+
+impl<F: Format> Backend for Graph<F> {
+    type Config = Config;
+
+    const FILE_EXTENSION: &'static str = F::FILE_EXTENSION;
+
+    fn from_config(config: Self::Config) -> Self {
+        Self {
+            config,
+            graph: DependencyGraph::default(),
+            format: PhantomData,
+        }
+    }
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn new(config: Self::Config, _: TaggingEnvironment, _: ExtensibilityEnvironment) -> Self {
+        Self::from_config(config)
+    }
+
+    fn prepare(&mut self, top_level_declarations: &[ToplevelDefinition]) {
+        self.graph = DependencyGraph::new(top_level_declarations);
+    }
+
+    fn generate_index(&self) -> Option<String> {
+        Some(F::render(&self.graph))
+    }
+
+    fn generate_module(
+        &mut self,
+        _tlds: Vec<ToplevelDefinition>,
+    ) -> Result<GeneratedModule, GeneratorError> {
+        Ok(GeneratedModule::empty())
+    }
+
+    fn generate(&self, tld: ToplevelDefinition) -> Result<String, GeneratorError> {
+        Ok(F::render(&DependencyGraph::new(&[tld])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DotCompiler, JsonGraphCompiler};
+
+    const SPEC: &str = r#"Base DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        IMPORTS Envelope FROM Messages Missing FROM Elsewhere;
+        Counter ::= INTEGER (0..255)
+        Tree ::= SEQUENCE { children SEQUENCE OF Tree, envelope Envelope OPTIONAL }
+        END
+
+        Messages DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        IMPORTS Counter, Tree FROM Base;
+        Message ::= SEQUENCE { count Counter, tree Tree }
+        Envelope ::= SEQUENCE OF Message
+        END"#;
+
+    #[test]
+    fn exports_dot_graphs() {
+        let generated = DotCompiler::new()
+            .add_asn_literal(SPEC)
+            .compile_to_string()
+            .unwrap()
+            .generated;
+        assert!(generated.contains(r#""Base" -> "Messages" [color=red, fontcolor=red];"#));
+        assert!(generated.contains(
+            r#""Base" -> "Elsewhere" [label="Missing", color=orange, fontcolor=orange, style=dashed];"#
+        ));
+        assert!(
+            generated.contains(r#""Elsewhere" [color=orange, fontcolor=orange, style=dashed];"#)
+        );
+        assert!(generated.contains(r#""Base.Tree" -> "Base.Tree" [color=red, fontcolor=red];"#));
+        assert!(generated.contains(r#""Messages.Message" -> "Base.Counter";"#));
+        assert!(generated.contains(r#""Base.Counter" [label="Counter"];"#));
+    }
+
+    #[test]
+    fn exports_json_graphs() {
+        let generated = JsonGraphCompiler::new()
+            .add_asn_literal(SPEC)
+            .compile_to_string()
+            .unwrap()
+            .generated;
+        assert!(generated.contains(
+            r#"{"importing": "Base", "imported": "Elsewhere", "symbols": ["Missing"], "unresolved": ["Missing"], "cyclic": false}"#
+        ));
+        assert!(generated.contains(r#"{"name": "Elsewhere", "resolved": false, "cyclic": false}"#));
+        assert!(generated.contains(
+            r#"{"module": "Base", "name": "Tree", "kind": "type", "unresolved": [], "cyclic": true}"#
+        ));
+        assert!(generated.contains(
+            r#"{"user": {"module": "Messages", "name": "Envelope"}, "dependency": {"module": "Messages", "name": "Message"}, "cyclic": true}"#
+        ));
+    }
+}
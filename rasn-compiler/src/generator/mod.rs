@@ -11,13 +11,20 @@ use crate::{
 
 use self::error::GeneratorError;
 
+pub mod asn1;
+pub mod documentation;
 pub mod error;
+pub mod graph;
+pub mod python;
 pub mod rasn;
 pub mod typescript;
 
 /// Implementors of the `Backend` trait can be used
 /// as a backend to the compiler in order to create bindings
 /// for other frameworks and languages than the default backend.
+/// ASN.1 modules are generated in parallel, each thread using its own
+/// clone of the backend after [Backend::prepare] was called, which is why
+/// compiling with a backend requires it to be `Clone + Send`.
 pub trait Backend: Default {
     type Config: Default + Debug;
 
@@ -25,6 +32,16 @@ pub trait Backend: Default {
     /// For example: `.ts` for Typescript, `.rs` for Rasn bindings.
     const FILE_EXTENSION: &'static str;
 
+    /// Whether modules and their definitions are generated in the order of the ASN.1 sources.
+    /// Otherwise, they are generated in the order of their names.
+    const SOURCE_ORDER: bool = false;
+
+    /// Name of the output file for a single ASN.1 module,
+    /// used when every module is written to its own file.
+    fn module_file_name(module_name: &str) -> String {
+        module_name.replace('-', "_") + Self::FILE_EXTENSION
+    }
+
     /// generates bindings for an ASN.1 module
     /// ### Params
     /// - `top_level_declarations` vector of [ToplevelDefinition]s that are defined in the ASN.1 module
@@ -33,6 +50,17 @@ pub trait Backend: Default {
         top_level_declarations: Vec<ToplevelDefinition>,
     ) -> Result<GeneratedModule, GeneratorError>;
 
+    /// Gives the backend access to all validated top-level definitions of a compilation
+    /// before any module is generated, e.g. for resolving references across modules.
+    fn prepare(&mut self, _top_level_declarations: &[ToplevelDefinition]) {}
+
+    /// Generates output that does not belong to a single ASN.1 module, such as an index page.
+    /// The index is written to `index` + [Backend::FILE_EXTENSION] when every module is written
+    /// to its own file, and precedes the generated modules otherwise.
+    fn generate_index(&self) -> Option<String> {
+        None
+    }
+
     /// generates bindings for a single ASN.1 item
     /// ### Params
     /// - `tld` [ToplevelDefinition] for which the bindings should be generated
@@ -0,0 +1,214 @@
+use crate::intermediate::{
+    types::{Choice, Enumerated, Optionality, SequenceOrSet},
+    ASN1Type, ToplevelDefinition, ToplevelValueDefinition,
+};
+
+use super::{template::*, utils::*, Python};
+use crate::generator::error::{GeneratorError, GeneratorErrorType};
+
+impl Python {
+    /// Generates the python bindings for the type `ty` named `name`.
+    /// Bindings for inline inner types precede the returned class.
+    pub(crate) fn generate_type(
+        &self,
+        name: &str,
+        comments: &str,
+        ty: &ASN1Type,
+    ) -> Result<String, GeneratorError> {
+        let docs = format_docstring(comments, 4);
+        let mut nested = vec![];
+        let class = match ty {
+            ASN1Type::Sequence(seq) | ASN1Type::Set(seq) => {
+                self.generate_sequence_or_set(&docs, name, seq, &mut nested)?
+            }
+            ASN1Type::Choice(choice) => self.generate_choice(&docs, name, choice, &mut nested)?,
+            ASN1Type::Enumerated(enumerated) => self.generate_enumerated(&docs, name, enumerated),
+            ty => self.generate_newtype(&docs, name, ty, &mut nested)?,
+        };
+        nested.push(class);
+        Ok(nested.join("\n\n\n"))
+    }
+
+    fn generate_sequence_or_set(
+        &self,
+        docs: &str,
+        name: &str,
+        seq: &SequenceOrSet,
+        nested: &mut Vec<String>,
+    ) -> Result<String, GeneratorError> {
+        let fields = sequence_or_set_members(seq)
+            .map(|(member, is_addition)| {
+                let field = to_python_snake_case(&member.name);
+                let key = to_python_string(&member.name);
+                let py_type =
+                    self.resolve_type(&member.ty, &self.inner_name(name, &member.name), nested)?;
+                let self_field = format!("self.{field}");
+                let checks = self.constraint_checks(
+                    &format!("{name}.{}", member.name),
+                    &self_field,
+                    &member.ty,
+                    0,
+                )?;
+                let to_jer = format!("result[{key}] = {}", py_type.encode_jer(&self_field, 0));
+                let from_jer = format!(
+                    "kwargs[\"{field}\"] = {}",
+                    py_type.decode_jer(&format!("value[{key}]"), 0)
+                );
+                let annotation = py_type.annotation();
+                Ok(match &member.optionality {
+                    Optionality::Required if !is_addition => FieldTemplate {
+                        declaration: if py_type == PyType::NoneType {
+                            format!("{field}: None = None")
+                        } else {
+                            format!("{field}: {annotation}")
+                        },
+                        checks,
+                        to_jer: vec![to_jer],
+                        from_jer: vec![from_jer],
+                    },
+                    optionality => {
+                        let declaration = match optionality {
+                            Optionality::Default(value) => {
+                                let default = self.value_to_python(value, &member.ty, &py_type)?;
+                                if py_type.is_immutable() {
+                                    format!("{field}: {annotation} = {default}")
+                                } else {
+                                    format!(
+                                        "{field}: {annotation} = _dc.field(default_factory=lambda: {default})"
+                                    )
+                                }
+                            }
+                            _ => format!("{field}: _t.Optional[{annotation}] = None"),
+                        };
+                        FieldTemplate {
+                            declaration,
+                            checks: if checks.is_empty() {
+                                checks
+                            } else {
+                                vec![
+                                    format!("if {self_field} is not None:"),
+                                    indent(&checks, 1),
+                                ]
+                            },
+                            to_jer: vec![
+                                format!("if {self_field} is not None:"),
+                                format!("    {to_jer}"),
+                            ],
+                            from_jer: vec![format!("if {key} in value:"), format!("    {from_jer}")],
+                        }
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, GeneratorError>>()?;
+        Ok(sequence_or_set_template(docs, name, fields))
+    }
+
+    fn generate_choice(
+        &self,
+        docs: &str,
+        name: &str,
+        choice: &Choice,
+        nested: &mut Vec<String>,
+    ) -> Result<String, GeneratorError> {
+        if choice.options.is_empty() {
+            return Err(GeneratorError::new(
+                None,
+                &format!("CHOICE type {name} does not define any alternatives!"),
+                GeneratorErrorType::EmptyChoiceType,
+            ));
+        }
+        let mut union = vec![];
+        let alternatives = choice
+            .options
+            .iter()
+            .map(|option| {
+                let py_type =
+                    self.resolve_type(&option.ty, &self.inner_name(name, &option.name), nested)?;
+                let annotation = py_type.annotation();
+                if !union.contains(&annotation) {
+                    union.push(annotation);
+                }
+                Ok(AlternativeTemplate {
+                    asn1_name: to_python_string(&option.name),
+                    checks: self.constraint_checks(
+                        &format!("{name}.{}", option.name),
+                        "self.value",
+                        &option.ty,
+                        0,
+                    )?,
+                    to_jer: py_type.encode_jer("self.value", 0),
+                    from_jer: py_type.decode_jer("inner", 0),
+                })
+            })
+            .collect::<Result<Vec<_>, GeneratorError>>()?;
+        Ok(choice_template(
+            docs,
+            name,
+            &choice_alternatives(choice).join(", "),
+            &union.join(", "),
+            alternatives,
+        ))
+    }
+
+    fn generate_enumerated(&self, docs: &str, name: &str, enumerated: &Enumerated) -> String {
+        enumerated_template(
+            docs,
+            name,
+            enum_members(enumerated)
+                .into_iter()
+                .map(|(member, asn1_name)| (member, to_python_string(&asn1_name)))
+                .collect(),
+        )
+    }
+
+    fn generate_newtype(
+        &self,
+        docs: &str,
+        name: &str,
+        ty: &ASN1Type,
+        nested: &mut Vec<String>,
+    ) -> Result<String, GeneratorError> {
+        let py_type = self.resolve_type(ty, &format!("Anonymous{name}"), nested)?;
+        Ok(newtype_template(
+            docs,
+            name,
+            &py_type.annotation(),
+            if py_type == PyType::NoneType {
+                " = None"
+            } else {
+                ""
+            },
+            self.constraint_checks(name, "self.value", ty, 0)?,
+            &py_type.encode_jer("self.value", 0),
+            &py_type.decode_jer("value", 0),
+        ))
+    }
+
+    pub(crate) fn generate_value(
+        &self,
+        tld: ToplevelValueDefinition,
+    ) -> Result<String, GeneratorError> {
+        let name = to_python_const_case(&tld.name);
+        let mut nested = vec![];
+        let py_type = self.resolve_type(
+            &tld.associated_type,
+            &to_python_title_case(&tld.name),
+            &mut nested,
+        )?;
+        match self.value_to_python(&tld.value, &tld.associated_type, &py_type) {
+            Ok(value) => {
+                nested.push(value_template(
+                    &format_comments(&tld.comments),
+                    &name,
+                    &py_type.annotation(),
+                    &value,
+                ));
+                Ok(nested.join("\n\n\n"))
+            }
+            Err(mut e) => {
+                e.top_level_declaration = Some(Box::new(ToplevelDefinition::Value(tld)));
+                Err(e)
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use self::{template::module_template, utils::*};
+use crate::{
+    error::CompilerError,
+    intermediate::{references::value_references, *},
+};
+
+use super::{
+    error::{GeneratorError, GeneratorErrorType},
+    Backend, GeneratedModule,
+};
+
+mod builder;
+mod template;
+mod utils;
+
+#[derive(Debug, Default, Clone)]
+/// A compiler backend that generates python dataclasses for ASN.1 data elements.
+/// The generated classes check their constraints on construction and
+/// convert from and to ITU X.697 JSON Encoding Rules values
+/// with default encoding instructions.
+/// The bindings require python 3.10 or later.
+pub struct Python {
+    config: Config,
+}
+
+#[derive(Debug, Default, Clone)]
+/// A configuration for the [Python] backend
+pub struct Config {}
+
+impl Backend for Python {
+    type Config = Config;
+
+    const FILE_EXTENSION: &'static str = ".py";
+
+    fn module_file_name(module_name: &str) -> String {
+        to_python_snake_case(module_name)
+            .trim_end_matches('_')
+            .to_string()
+            + Self::FILE_EXTENSION
+    }
+
+    fn from_config(config: Self::Config) -> Self {
+        Self { config }
+    }
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn new(config: Self::Config, _: TaggingEnvironment, _: ExtensibilityEnvironment) -> Self {
+        Self::from_config(config)
+    }
+
+    fn generate_module(
+        &mut self,
+        tlds: Vec<ToplevelDefinition>,
+    ) -> Result<GeneratedModule, GeneratorError> {
+        if let Some(module_ref) = tlds.first().and_then(|tld| tld.get_module_header()) {
+            let module = module_ref.read();
+            let imports = module
+                .imports
+                .iter()
+                .filter_map(|import| {
+                    let usages = import
+                        .types
+                        .iter()
+                        .filter(|usage| {
+                            !usage.contains("{}")
+                                && !usage.chars().all(|c| c.is_uppercase() || c == '-')
+                        })
+                        .map(|usage| {
+                            if usage.starts_with(|c: char| c.is_lowercase()) {
+                                to_python_const_case(usage)
+                            } else {
+                                to_python_title_case(usage)
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    (!usages.is_empty()).then(|| {
+                        format!(
+                            "from {} import {}\n",
+                            Self::module_file_name(
+                                &import.global_module_reference.module_reference
+                            )
+                            .trim_end_matches(Self::FILE_EXTENSION),
+                            usages.join(", ")
+                        )
+                    })
+                })
+                .collect::<String>();
+            let (values, types): (Vec<_>, Vec<_>) = tlds
+                .into_iter()
+                .partition(|tld| matches!(tld, ToplevelDefinition::Value(_)));
+            let values = order_by_references(values);
+            let mut warnings = vec![];
+            let mut generate_all = |tlds: Vec<ToplevelDefinition>| {
+                tlds.into_iter()
+                    .filter_map(|tld| match self.generate(tld) {
+                        Ok(s) if s.is_empty() => None,
+                        Ok(s) => Some(s),
+                        Err(e) => {
+                            warnings.push(CompilerError::from(e));
+                            None
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n\n\n")
+            };
+            let types = generate_all(types);
+            let values = generate_all(values);
+            Ok(GeneratedModule {
+                generated: Some(module_template(
+                    &format!("# Generated bindings for ASN.1 module {}\n\n", module.name),
+                    &imports,
+                    &types,
+                    &values,
+                )),
+                warnings,
+            })
+        } else {
+            Ok(GeneratedModule::empty())
+        }
+    }
+
+    fn generate(&self, tld: ToplevelDefinition) -> Result<String, GeneratorError> {
+        match tld {
+            ToplevelDefinition::Type(t) => {
+                if t.parameterization.is_some() {
+                    return Ok(String::new());
+                }
+                self.generate_type(&to_python_title_case(&t.name), &t.comments, &t.ty)
+                    .map_err(|mut e| {
+                        e.top_level_declaration = Some(Box::new(ToplevelDefinition::Type(t)));
+                        e
+                    })
+            }
+            ToplevelDefinition::Value(v) => {
+                if v.parameterization.is_some() {
+                    return Ok(String::new());
+                }
+                self.generate_value(v)
+            }
+            ToplevelDefinition::Macro(_) => Err(GeneratorError {
+                kind: GeneratorErrorType::NotYetInplemented,
+                details: "MACROs are currently unsupported!".to_string(),
+                top_level_declaration: Some(Box::new(tld)),
+            }),
+            _ => Ok(String::new()),
+        }
+    }
+}
+
+/// Orders value definitions so that every value is defined before it is referenced,
+/// since python evaluates module-level assignments in order.
+/// Values with cyclic references keep their original order.
+fn order_by_references(mut values: Vec<ToplevelDefinition>) -> Vec<ToplevelDefinition> {
+    let mut ordered = Vec::with_capacity(values.len());
+    while !values.is_empty() {
+        let pending = values.iter().map(|v| v.name().clone()).collect::<Vec<_>>();
+        let (ready, blocked): (Vec<_>, Vec<_>) = values.into_iter().partition(|v| {
+            let references = match v {
+                ToplevelDefinition::Value(v) => value_references(&v.value),
+                _ => vec![],
+            };
+            references
+                .iter()
+                .all(|r| r == v.name() || !pending.contains(r))
+        });
+        if ready.is_empty() {
+            ordered.extend(blocked);
+            break;
+        }
+        ordered.extend(ready);
+        values = blocked;
+    }
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, process::Command};
+
+    use crate::PyCompiler;
+
+    #[test]
+    fn decodes_jer_without_extension_additions() {
+        let generated = PyCompiler::new()
+            .add_asn_literal(
+                "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Seq ::= SEQUENCE {
+                    a INTEGER,
+                    ...,
+                    b BOOLEAN,
+                    [[ c INTEGER (0..7), d OCTET STRING ]]
+                }
+                END",
+            )
+            .compile_to_string()
+            .unwrap()
+            .generated;
+        for expected in [
+            "b: _t.Optional[bool] = None",
+            "c: _t.Optional[int] = None",
+            "d: _t.Optional[bytes] = None",
+            "if \"d\" in value:",
+        ] {
+            assert!(generated.contains(expected), "{expected}\n{generated}");
+        }
+        // The bindings are only run where a python interpreter is installed
+        let Ok(mut python) = Command::new("python3")
+            .arg("-")
+            .stdin(std::process::Stdio::piped())
+            .spawn()
+        else {
+            return;
+        };
+        write!(
+            python.stdin.take().unwrap(),
+            "{generated}\n\
+            seq = Seq.from_jer({{'a': 1, 'd': 'AB'}})\n\
+            assert seq == Seq(a=1, d=b'\\xab'), seq\n\
+            assert seq.to_jer() == {{'a': 1, 'd': 'AB'}}, seq.to_jer()\n"
+        )
+        .unwrap();
+        assert!(python.wait().unwrap().success());
+    }
+}
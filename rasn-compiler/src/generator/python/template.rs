@@ -0,0 +1,238 @@
+pub const MODULE_PREAMBLE: &str = r#"from __future__ import annotations
+
+import dataclasses as _dc
+import enum as _enum
+import re as _re
+import typing as _t
+"#;
+
+pub const RUNTIME_HELPERS: &str = r#"def _check_range(path, value, lower, upper):
+    if (lower is not None and value < lower) or (upper is not None and value > upper):
+        raise ValueError(f"{path}: {value!r} is outside of the permitted range {lower}..{upper}")
+
+
+def _check_size(path, value, lower, upper):
+    size = len(value)
+    if (lower is not None and size < lower) or (upper is not None and size > upper):
+        raise ValueError(f"{path}: size {size} is outside of the permitted range {lower}..{upper}")
+
+
+def _check_alphabet(path, value, pattern):
+    if _re.fullmatch(pattern, value) is None:
+        raise ValueError(f"{path}: {value!r} contains characters outside of the permitted alphabet")
+
+
+def _bits_to_hex(bits):
+    padded = bits + "0" * (-len(bits) % 8)
+    return "".join(f"{int(padded[i:i + 8], 2):02X}" for i in range(0, len(padded), 8))
+
+
+def _bits_to_jer(bits, fixed_size):
+    if fixed_size:
+        return _bits_to_hex(bits)
+    return {"value": _bits_to_hex(bits), "length": len(bits)}
+
+
+def _bits_from_jer(value, size=None):
+    if isinstance(value, dict):
+        value, size = value["value"], value["length"]
+    bits = "".join(f"{byte:08b}" for byte in bytes.fromhex(value))
+    return bits if size is None else bits[:size]
+
+
+def _to_jer(value):
+    if hasattr(value, "to_jer"):
+        return value.to_jer()
+    if isinstance(value, bytes):
+        return value.hex().upper()
+    if isinstance(value, list):
+        return [_to_jer(item) for item in value]
+    return value
+"#;
+
+pub fn module_template(comments: &str, imports: &str, types: &str, values: &str) -> String {
+    let mut module = format!("{comments}{MODULE_PREAMBLE}");
+    if !imports.is_empty() {
+        module.push('\n');
+        module.push_str(imports);
+    }
+    module.push_str("\n\n");
+    module.push_str(RUNTIME_HELPERS);
+    for section in [types, values] {
+        if !section.is_empty() {
+            module.push_str("\n\n");
+            module.push_str(section);
+        }
+    }
+    module
+}
+
+fn post_init(checks: &[String]) -> Vec<String> {
+    if checks.is_empty() {
+        vec![]
+    } else {
+        vec![
+            String::new(),
+            String::from("def __post_init__(self):"),
+            super::utils::indent(checks, 1),
+        ]
+    }
+}
+
+fn class_template(header: &[&str], docs: &str, body: Vec<String>) -> String {
+    let mut class = header.join("\n");
+    class.push('\n');
+    class.push_str(docs);
+    if !docs.is_empty() {
+        class.push('\n');
+    }
+    class.push_str(&super::utils::indent(&body, 1));
+    class
+}
+
+pub struct FieldTemplate {
+    pub declaration: String,
+    pub checks: Vec<String>,
+    pub to_jer: Vec<String>,
+    pub from_jer: Vec<String>,
+}
+
+pub fn sequence_or_set_template(docs: &str, name: &str, fields: Vec<FieldTemplate>) -> String {
+    let mut body = vec![];
+    let mut checks = vec![];
+    let mut to_jer = vec![String::from("result = {}")];
+    let mut from_jer = vec![String::from("kwargs = {}")];
+    for mut field in fields {
+        body.push(field.declaration);
+        checks.append(&mut field.checks);
+        to_jer.append(&mut field.to_jer);
+        from_jer.append(&mut field.from_jer);
+    }
+    to_jer.push(String::from("return result"));
+    from_jer.push(String::from("return cls(**kwargs)"));
+    body.append(&mut post_init(&checks));
+    body.extend([
+        String::new(),
+        String::from("def to_jer(self) -> _t.Dict[str, _t.Any]:"),
+        super::utils::indent(&to_jer, 1),
+        String::new(),
+        String::from("@classmethod"),
+        format!("def from_jer(cls, value: _t.Dict[str, _t.Any]) -> {name}:"),
+        super::utils::indent(&from_jer, 1),
+    ]);
+    class_template(
+        &["@_dc.dataclass(kw_only=True)", &format!("class {name}:")],
+        docs,
+        body,
+    )
+}
+
+pub struct AlternativeTemplate {
+    pub asn1_name: String,
+    pub checks: Vec<String>,
+    pub to_jer: String,
+    pub from_jer: String,
+}
+
+pub fn choice_template(
+    docs: &str,
+    name: &str,
+    literals: &str,
+    union: &str,
+    alternatives: Vec<AlternativeTemplate>,
+) -> String {
+    let mut checks = vec![];
+    let mut to_jer = vec![];
+    let mut from_jer = vec![String::from("((alternative, inner),) = value.items()")];
+    for (index, alternative) in alternatives.into_iter().enumerate() {
+        let keyword = if index == 0 { "if" } else { "elif" };
+        checks.push(format!(
+            "{keyword} self.alternative == {}:",
+            alternative.asn1_name
+        ));
+        checks.push(if alternative.checks.is_empty() {
+            String::from("    pass")
+        } else {
+            super::utils::indent(&alternative.checks, 1)
+        });
+        to_jer.push(format!("if self.alternative == {}:", alternative.asn1_name));
+        to_jer.push(format!(
+            "    return {{{}: {}}}",
+            alternative.asn1_name, alternative.to_jer
+        ));
+        from_jer.push(format!("if alternative == {}:", alternative.asn1_name));
+        from_jer.push(format!(
+            "    return cls({}, {})",
+            alternative.asn1_name, alternative.from_jer
+        ));
+    }
+    checks.push(String::from("else:"));
+    checks.push(format!(
+        "    raise ValueError(f\"{name}: unknown alternative {{self.alternative!r}}\")"
+    ));
+    to_jer.push(format!(
+        "raise ValueError(f\"{name}: unknown alternative {{self.alternative!r}}\")"
+    ));
+    from_jer.push(format!(
+        "raise ValueError(f\"{name}: unknown alternative {{alternative!r}}\")"
+    ));
+    let mut body = vec![
+        format!("alternative: _t.Literal[{literals}]"),
+        format!("value: _t.Union[{union}]"),
+    ];
+    body.append(&mut post_init(&checks));
+    body.extend([
+        String::new(),
+        String::from("def to_jer(self) -> _t.Dict[str, _t.Any]:"),
+        super::utils::indent(&to_jer, 1),
+        String::new(),
+        String::from("@classmethod"),
+        format!("def from_jer(cls, value: _t.Dict[str, _t.Any]) -> {name}:"),
+        super::utils::indent(&from_jer, 1),
+    ]);
+    class_template(&["@_dc.dataclass", &format!("class {name}:")], docs, body)
+}
+
+pub fn enumerated_template(docs: &str, name: &str, members: Vec<(String, String)>) -> String {
+    let mut body = members
+        .into_iter()
+        .map(|(member, asn1_name)| format!("{member} = {asn1_name}"))
+        .collect::<Vec<_>>();
+    body.extend([
+        String::new(),
+        String::from("def to_jer(self) -> str:"),
+        String::from("    return self.value"),
+        String::new(),
+        String::from("@classmethod"),
+        format!("def from_jer(cls, value: str) -> {name}:"),
+        String::from("    return cls(value)"),
+    ]);
+    class_template(&[&format!("class {name}(_enum.Enum):")], docs, body)
+}
+
+pub fn newtype_template(
+    docs: &str,
+    name: &str,
+    annotation: &str,
+    default: &str,
+    checks: Vec<String>,
+    to_jer: &str,
+    from_jer: &str,
+) -> String {
+    let mut body = vec![format!("value: {annotation}{default}")];
+    body.append(&mut post_init(&checks));
+    body.extend([
+        String::new(),
+        String::from("def to_jer(self) -> _t.Any:"),
+        format!("    return {to_jer}"),
+        String::new(),
+        String::from("@classmethod"),
+        format!("def from_jer(cls, value: _t.Any) -> {name}:"),
+        format!("    return cls({from_jer})"),
+    ]);
+    class_template(&["@_dc.dataclass", &format!("class {name}:")], docs, body)
+}
+
+pub fn value_template(comments: &str, name: &str, annotation: &str, value: &str) -> String {
+    format!("{comments}{name}: {annotation} = {value}")
+}
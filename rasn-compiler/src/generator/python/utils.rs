@@ -0,0 +1,764 @@
+use crate::intermediate::{
+    constraints::Constraint,
+    encoding_rules::per_visible::{
+        per_visible_range_constraints, CharsetSubset, PerVisibleAlphabetConstraints,
+    },
+    types::{BitString, Choice, Enumerated, SequenceOrSet, SequenceOrSetMember},
+    ASN1Type, ASN1Value, CharacterStringType,
+};
+
+use super::Python;
+use crate::common::INTERNAL_EXTENSION_GROUP_NAME_PREFIX;
+use crate::generator::error::{GeneratorError, GeneratorErrorType};
+
+const PYTHON_KEYWORDS: [&str; 35] = [
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+pub(crate) const INNER_TYPE_COMMENT: &str = " Inner type ";
+
+fn escape_keyword(identifier: String) -> String {
+    if PYTHON_KEYWORDS.contains(&identifier.as_str()) {
+        identifier + "_"
+    } else {
+        identifier
+    }
+}
+
+pub fn to_python_title_case(input: &str) -> String {
+    let input = input
+        .replace('-', "_")
+        .chars()
+        .fold(String::new(), |mut acc, c| {
+            if acc.is_empty() && c.is_lowercase() {
+                acc.push(c.to_ascii_uppercase());
+            } else if acc.ends_with('_') {
+                acc.pop();
+                acc.push(c.to_ascii_uppercase());
+            } else {
+                acc.push(c);
+            }
+            acc
+        });
+    escape_keyword(input)
+}
+
+pub fn to_python_snake_case(input: &str) -> String {
+    let input = input.replace('-', "_");
+    let mut lowercase = String::with_capacity(input.len());
+    let peekable = &mut input.chars().peekable();
+    while let Some(c) = peekable.next() {
+        if c.is_lowercase() || c == '_' || c.is_numeric() {
+            lowercase.push(c);
+            if c != '_' && peekable.peek().is_some_and(|next| next.is_uppercase()) {
+                lowercase.push('_');
+            }
+        } else {
+            lowercase.push(c.to_ascii_lowercase());
+        }
+    }
+    escape_keyword(lowercase)
+}
+
+pub fn to_python_const_case(input: &str) -> String {
+    to_python_snake_case(input)
+        .trim_end_matches('_')
+        .to_uppercase()
+}
+
+/// Returns a python string literal with the content `value`.
+pub fn to_python_string(value: &str) -> String {
+    let mut literal = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_control() => literal.push_str(&format!("\\U{:08x}", c as u32)),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+pub fn format_docstring(comments: &str, indentation: usize) -> String {
+    if comments.trim().is_empty() || comments == INNER_TYPE_COMMENT {
+        return String::new();
+    }
+    let indentation = " ".repeat(indentation);
+    let escaped = comments
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(&format!("\n{indentation}"));
+    format!("{indentation}\"\"\"{escaped}\"\"\"\n")
+}
+
+pub fn format_comments(comments: &str) -> String {
+    if comments.trim().is_empty() {
+        String::new()
+    } else {
+        comments
+            .lines()
+            .map(|l| format!("#{l}\n"))
+            .collect::<String>()
+    }
+}
+
+/// Indents every line of `lines` by `level` levels of four spaces.
+pub fn indent(lines: &[String], level: usize) -> String {
+    let indentation = "    ".repeat(level);
+    lines
+        .iter()
+        .flat_map(|l| {
+            if l.is_empty() {
+                vec![""]
+            } else {
+                l.lines().collect()
+            }
+        })
+        .map(|l| {
+            if l.is_empty() {
+                String::from("\n")
+            } else {
+                format!("{indentation}{l}\n")
+            }
+        })
+        .collect()
+}
+
+/// Python representation of an ASN.1 type, as used in type hints and JER conversions.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PyType {
+    Int,
+    Float,
+    Bool,
+    Str,
+    NoneType,
+    Bytes,
+    /// BIT STRINGs are represented as strings of `0` and `1` characters
+    Bits {
+        fixed_size: Option<i128>,
+    },
+    Any,
+    /// A generated dataclass or enum providing `to_jer` and `from_jer` methods
+    Class(String),
+    List(Box<PyType>),
+}
+
+impl PyType {
+    pub fn annotation(&self) -> String {
+        match self {
+            PyType::Int => "int".into(),
+            PyType::Float => "float".into(),
+            PyType::Bool => "bool".into(),
+            PyType::Str | PyType::Bits { .. } => "str".into(),
+            PyType::NoneType => "None".into(),
+            PyType::Bytes => "bytes".into(),
+            PyType::Any => "_t.Any".into(),
+            PyType::Class(name) => name.clone(),
+            PyType::List(item) => format!("_t.List[{}]", item.annotation()),
+        }
+    }
+
+    /// Returns `true` if values of the type can safely be shared, i.e. used as dataclass field defaults.
+    pub fn is_immutable(&self) -> bool {
+        matches!(
+            self,
+            PyType::Int
+                | PyType::Float
+                | PyType::Bool
+                | PyType::Str
+                | PyType::NoneType
+                | PyType::Bits { .. }
+        )
+    }
+
+    /// Returns a python expression converting the python value `expr` into its JER representation.
+    pub fn encode_jer(&self, expr: &str, depth: usize) -> String {
+        match self {
+            PyType::Int | PyType::Float | PyType::Bool | PyType::Str | PyType::Any => expr.into(),
+            PyType::NoneType => "None".into(),
+            PyType::Bytes => format!("{expr}.hex().upper()"),
+            PyType::Bits { fixed_size } => {
+                format!(
+                    "_bits_to_jer({expr}, {})",
+                    python_bool(fixed_size.is_some())
+                )
+            }
+            PyType::Class(_) => format!("{expr}.to_jer()"),
+            PyType::List(item) => {
+                let item_expr = format!("_item{depth}");
+                format!(
+                    "[{} for {item_expr} in {expr}]",
+                    item.encode_jer(&item_expr, depth + 1)
+                )
+            }
+        }
+    }
+
+    /// Returns a python expression converting the JER representation `expr` into a python value.
+    pub fn decode_jer(&self, expr: &str, depth: usize) -> String {
+        match self {
+            PyType::Int | PyType::Float | PyType::Bool | PyType::Str | PyType::Any => expr.into(),
+            PyType::NoneType => "None".into(),
+            PyType::Bytes => format!("bytes.fromhex({expr})"),
+            PyType::Bits { fixed_size } => format!(
+                "_bits_from_jer({expr}, {})",
+                fixed_size.map_or(String::from("None"), |s| s.to_string())
+            ),
+            PyType::Class(name) => format!("{name}.from_jer({expr})"),
+            PyType::List(item) => {
+                let item_expr = format!("_item{depth}");
+                format!(
+                    "[{} for {item_expr} in {expr}]",
+                    item.decode_jer(&item_expr, depth + 1)
+                )
+            }
+        }
+    }
+}
+
+fn python_bool(value: bool) -> &'static str {
+    if value {
+        "True"
+    } else {
+        "False"
+    }
+}
+
+/// Lower and upper bound of a value range or size constraint.
+pub type Bounds = (Option<i128>, Option<i128>);
+
+/// Returns the effective, non-extensible bounds of a value range or size constraint.
+/// Returns `None` if the constraints do not restrict the value.
+pub fn bounds(signed: bool, constraints: &[Constraint]) -> Result<Option<Bounds>, GeneratorError> {
+    if constraints.is_empty() {
+        return Ok(None);
+    }
+    let per_visible = per_visible_range_constraints(signed, constraints)?;
+    if per_visible.is_extensible() {
+        return Ok(None);
+    }
+    Ok(
+        match (per_visible.min::<i128>(), per_visible.max::<i128>()) {
+            (None, None) => None,
+            (Some(0), None) if !signed => None,
+            bounds => Some(bounds),
+        },
+    )
+}
+
+pub fn fixed_size(bit_string: &BitString) -> Result<Option<i128>, GeneratorError> {
+    Ok(match bounds(false, &bit_string.constraints)? {
+        Some((Some(min), Some(max))) if min == max => Some(min),
+        _ => None,
+    })
+}
+
+/// Returns a regular expression matching strings that conform to the permitted alphabet
+/// constraints in `constraints`. Returns `None` if the alphabet is not restricted.
+pub fn alphabet_pattern(
+    string_type: CharacterStringType,
+    constraints: &[Constraint],
+) -> Result<Option<String>, GeneratorError> {
+    if constraints
+        .iter()
+        .any(|c| matches!(c, Constraint::Subtype(s) if s.extensible))
+    {
+        return Ok(None);
+    }
+    let mut permitted_alphabet = PerVisibleAlphabetConstraints::default_for(string_type);
+    for c in constraints {
+        if let Some(mut p) = PerVisibleAlphabetConstraints::try_new(c, string_type)? {
+            permitted_alphabet += &mut p
+        }
+    }
+    permitted_alphabet.finalize();
+    if permitted_alphabet.charset_subsets().is_empty() {
+        return Ok(None);
+    }
+    let escape = |c: char| format!("\\U{:08x}", c as u32);
+    let class = permitted_alphabet
+        .charset_subsets()
+        .iter()
+        .map(|subset| match subset {
+            CharsetSubset::Single(c) => escape(*c),
+            CharsetSubset::Range { from, to } => format!(
+                "{}-{}",
+                escape(from.unwrap_or('\0')),
+                escape(to.unwrap_or(char::MAX))
+            ),
+        })
+        .collect::<String>();
+    Ok(Some(format!("r\"[{class}]*\"")))
+}
+
+/// Returns a python expression for the JER representation of an ASN.1 value.
+pub fn value_to_jer(value: &ASN1Value) -> Result<String, GeneratorError> {
+    match value {
+        ASN1Value::Null => Ok("None".into()),
+        ASN1Value::Boolean(b) => Ok(python_bool(*b).into()),
+        ASN1Value::Integer(i) | ASN1Value::LinkedIntValue { value: i, .. } => Ok(i.to_string()),
+        ASN1Value::Real(r) if r.is_nan() => Ok("float(\"nan\")".into()),
+        ASN1Value::Real(r) if r.is_infinite() => Ok(format!(
+            "float(\"{}inf\")",
+            if r.is_sign_negative() { "-" } else { "" }
+        )),
+        ASN1Value::Real(r) => Ok(format!("{r:?}")),
+        ASN1Value::String(s) | ASN1Value::LinkedCharStringValue(_, s) | ASN1Value::Time(s) => {
+            Ok(to_python_string(s))
+        }
+        ASN1Value::OctetString(o) => Ok(to_python_string(
+            &o.iter().map(|b| format!("{b:02X}")).collect::<String>(),
+        )),
+        ASN1Value::BitString(b) => Ok(format!(
+            "{{\"value\": {}, \"length\": {}}}",
+            to_python_string(
+                &b.chunks(8)
+                    .map(|chunk| {
+                        let byte = chunk
+                            .iter()
+                            .enumerate()
+                            .fold(0u8, |acc, (i, bit)| acc | ((*bit as u8) << (7 - i)));
+                        format!("{byte:02X}")
+                    })
+                    .collect::<String>()
+            ),
+            b.len()
+        )),
+        ASN1Value::EnumeratedValue { enumerable, .. } => Ok(to_python_string(enumerable)),
+        ASN1Value::Choice {
+            variant_name,
+            inner_value,
+            ..
+        } => Ok(format!(
+            "{{{}: {}}}",
+            to_python_string(variant_name),
+            value_to_jer(inner_value)?
+        )),
+        ASN1Value::LinkedStructLikeValue(fields) => fields
+            .iter()
+            .map(|(name, ty, value)| {
+                match value.value() {
+                    ASN1Value::ElsewhereDeclaredValue { identifier, .. }
+                        if is_enumeral(ty, identifier) =>
+                    {
+                        Ok(to_python_string(identifier))
+                    }
+                    ASN1Value::SequenceOrSet(bits)
+                        if bits.is_empty() && matches!(ty, ASN1Type::BitString(_)) =>
+                    {
+                        Ok(String::from("\"\""))
+                    }
+                    value => value_to_jer(value),
+                }
+                .map(|v| format!("{}: {v}", to_python_string(name)))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|fields| format!("{{{}}}", fields.join(", "))),
+        ASN1Value::SequenceOrSet(elements) if elements.iter().all(|(name, _)| name.is_some()) => {
+            elements
+                .iter()
+                .map(|(name, value)| {
+                    value_to_jer(value).map(|v| {
+                        format!("{}: {v}", to_python_string(name.as_deref().unwrap_or("")))
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(|fields| format!("{{{}}}", fields.join(", ")))
+        }
+        ASN1Value::SequenceOrSet(elements) => elements
+            .iter()
+            .map(|(_, value)| value_to_jer(value))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|items| format!("[{}]", items.join(", "))),
+        ASN1Value::LinkedArrayLikeValue(items) => items
+            .iter()
+            .map(|v| value_to_jer(v))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|items| format!("[{}]", items.join(", "))),
+        ASN1Value::ObjectIdentifier(oid) | ASN1Value::LinkedRelativeOidValue(oid) => oid
+            .0
+            .iter()
+            .map(|arc| {
+                arc.number.map(|n| n.to_string()).ok_or_else(|| {
+                    GeneratorError::new(
+                        None,
+                        "Missing Object Identifier arc number.",
+                        GeneratorErrorType::Unidentified,
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|arcs| to_python_string(&arcs.join("."))),
+        ASN1Value::LinkedNestedValue { value, .. } => value_to_jer(value),
+        ASN1Value::ElsewhereDeclaredValue { identifier, .. }
+        | ASN1Value::LinkedElsewhereDefinedValue { identifier, .. } => {
+            Ok(format!("_to_jer({})", to_python_const_case(identifier)))
+        }
+        ASN1Value::BitStringNamedBits(_) => Err(GeneratorError::new(
+            None,
+            "Named bits should be resolved by this point!",
+            GeneratorErrorType::Unidentified,
+        )),
+        ASN1Value::ParameterizedValue { identifier, .. } => Err(GeneratorError::new(
+            None,
+            &format!("Parameterized value {identifier} should be instantiated by this point!"),
+            GeneratorErrorType::Unidentified,
+        )),
+        ASN1Value::All => Err(GeneratorError::new(
+            None,
+            "ALL values cannot be represented in JER!",
+            GeneratorErrorType::Unsupported,
+        )),
+    }
+}
+
+/// Returns `true` if `identifier` names a member of the ENUMERATED type `ty`.
+fn is_enumeral(ty: &ASN1Type, identifier: &str) -> bool {
+    matches!(ty, ASN1Type::Enumerated(e) if e.members.iter().any(|m| m.name == identifier))
+}
+
+impl Python {
+    /// Resolves the python representation of `ty`.
+    /// Bindings for inner types declared inline are appended to `nested`,
+    /// named `inner_name`.
+    pub(crate) fn resolve_type(
+        &self,
+        ty: &ASN1Type,
+        inner_name: &str,
+        nested: &mut Vec<String>,
+    ) -> Result<PyType, GeneratorError> {
+        Ok(match ty {
+            ASN1Type::Null => PyType::NoneType,
+            ASN1Type::Boolean(_) => PyType::Bool,
+            ASN1Type::Integer(_) => PyType::Int,
+            ASN1Type::Real(_) => PyType::Float,
+            ASN1Type::BitString(b) => PyType::Bits {
+                fixed_size: fixed_size(b)?,
+            },
+            ASN1Type::OctetString(_) => PyType::Bytes,
+            ASN1Type::CharacterString(_)
+            | ASN1Type::Time(_)
+            | ASN1Type::GeneralizedTime(_)
+            | ASN1Type::UTCTime(_)
+            | ASN1Type::ObjectIdentifier(_)
+            | ASN1Type::RelativeOid(_)
+            | ASN1Type::OidIri(_)
+            | ASN1Type::RelativeOidIri(_)
+            | ASN1Type::ObjectDescriptor(_) => PyType::Str,
+            ASN1Type::Enumerated(_)
+            | ASN1Type::Choice(_)
+            | ASN1Type::Sequence(_)
+            | ASN1Type::Set(_) => {
+                nested.push(self.generate_type(inner_name, INNER_TYPE_COMMENT, ty)?);
+                PyType::Class(inner_name.to_string())
+            }
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => PyType::List(Box::new(
+                self.resolve_type(&s.element_type, &format!("Anonymous{inner_name}"), nested)?,
+            )),
+            ASN1Type::ElsewhereDeclaredType(e) => {
+                PyType::Class(to_python_title_case(&e.identifier))
+            }
+            ASN1Type::ObjectClassField(_)
+            | ASN1Type::EmbeddedPdv
+            | ASN1Type::External
+            | ASN1Type::UnrestrictedCharacterString
+            | ASN1Type::Any(_) => PyType::Any,
+            ASN1Type::ChoiceSelectionType(_) => {
+                return Err(GeneratorError::new(
+                    None,
+                    "Choice selection type should have been resolved at this point!",
+                    GeneratorErrorType::Asn1TypeMismatch,
+                ))
+            }
+        })
+    }
+
+    /// Returns python statements that check whether the python value `expr` satisfies
+    /// the constraints of `ty`. Extensible constraints are not checked.
+    pub(crate) fn constraint_checks(
+        &self,
+        label: &str,
+        expr: &str,
+        ty: &ASN1Type,
+        depth: usize,
+    ) -> Result<Vec<String>, GeneratorError> {
+        let label_literal = to_python_string(label);
+        let format_bound = |b: Option<i128>| b.map_or(String::from("None"), |b| b.to_string());
+        let mut checks = vec![];
+        match ty {
+            ASN1Type::Integer(i) => {
+                if let Some((min, max)) = bounds(true, &i.constraints)? {
+                    checks.push(format!(
+                        "_check_range({label_literal}, {expr}, {}, {})",
+                        format_bound(min),
+                        format_bound(max)
+                    ));
+                }
+            }
+            ASN1Type::BitString(_)
+            | ASN1Type::OctetString(_)
+            | ASN1Type::CharacterString(_)
+            | ASN1Type::SequenceOf(_)
+            | ASN1Type::SetOf(_) => {
+                if let Some((min, max)) = bounds(false, ty.constraints().unwrap_or(&vec![]))? {
+                    checks.push(format!(
+                        "_check_size({label_literal}, {expr}, {}, {})",
+                        format_bound(min),
+                        format_bound(max)
+                    ));
+                }
+                if let ASN1Type::CharacterString(c) = ty {
+                    if let Some(pattern) = alphabet_pattern(c.ty, &c.constraints)? {
+                        checks.push(format!(
+                            "_check_alphabet({label_literal}, {expr}, {pattern})"
+                        ));
+                    }
+                }
+                if let ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) = ty {
+                    let item = format!("_item{depth}");
+                    let item_checks = self.constraint_checks(
+                        &format!("{label}[]"),
+                        &item,
+                        &s.element_type,
+                        depth + 1,
+                    )?;
+                    if !item_checks.is_empty() {
+                        checks.push(format!("for {item} in {expr}:"));
+                        checks.push(indent(&item_checks, 1));
+                    }
+                }
+            }
+            _ => (),
+        }
+        Ok(checks)
+    }
+
+    /// Returns a python expression for an ASN.1 value of the python type `py_type`.
+    pub(crate) fn value_to_python(
+        &self,
+        value: &ASN1Value,
+        ty: &ASN1Type,
+        py_type: &PyType,
+    ) -> Result<String, GeneratorError> {
+        match value {
+            ASN1Value::SequenceOrSet(bits)
+                if bits.is_empty() && matches!(py_type, PyType::Bits { .. }) =>
+            {
+                Ok(String::from("\"\""))
+            }
+            ASN1Value::ElsewhereDeclaredValue { identifier, .. } if is_enumeral(ty, identifier) => {
+                Ok(py_type.decode_jer(&to_python_string(identifier), 0))
+            }
+            ASN1Value::ElsewhereDeclaredValue { identifier, .. }
+            | ASN1Value::LinkedElsewhereDefinedValue { identifier, .. } => {
+                Ok(to_python_const_case(identifier))
+            }
+            ASN1Value::LinkedNestedValue { value, .. } => self.value_to_python(value, ty, py_type),
+            v => Ok(py_type.decode_jer(&value_to_jer(v)?, 0)),
+        }
+    }
+
+    pub(crate) fn inner_name(&self, parent_name: &str, name: &str) -> String {
+        format!("{parent_name}{}", to_python_title_case(name))
+    }
+}
+
+pub fn enum_members(enumerated: &Enumerated) -> Vec<(String, String)> {
+    enumerated
+        .members
+        .iter()
+        .map(|m| (to_python_snake_case(&m.name), m.name.clone()))
+        .collect()
+}
+
+/// Returns the members of a SEQUENCE or SET, along with whether they are extension additions.
+/// The members of extension addition groups are flattened into the SEQUENCE or SET,
+/// as they are in its JER encoding.
+pub fn sequence_or_set_members(
+    seq: &SequenceOrSet,
+) -> impl Iterator<Item = (&SequenceOrSetMember, bool)> {
+    seq.members.iter().enumerate().flat_map(|(i, member)| {
+        let is_addition = seq.extensible.is_some_and(|index| i >= index);
+        match &member.ty {
+            ASN1Type::Sequence(group)
+                if member
+                    .name
+                    .starts_with(INTERNAL_EXTENSION_GROUP_NAME_PREFIX) =>
+            {
+                group.members.iter().map(|m| (m, true)).collect::<Vec<_>>()
+            }
+            _ => vec![(member, is_addition)],
+        }
+    })
+}
+
+pub fn choice_alternatives(choice: &Choice) -> Vec<String> {
+    choice
+        .options
+        .iter()
+        .map(|o| to_python_string(&o.name))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::intermediate::{
+        constraints::{ElementOrSetOperation, ElementSetSpecs, SubtypeElements},
+        types::{CharacterString, Integer},
+        ObjectIdentifierArc, ObjectIdentifierValue, StructLikeFieldValue,
+    };
+
+    use super::*;
+
+    #[test]
+    fn converts_identifiers() {
+        assert_eq!(to_python_title_case("ITS-Container"), "ITSContainer");
+        assert_eq!(to_python_title_case("station-type"), "StationType");
+        assert_eq!(to_python_snake_case("stationID"), "station_id");
+        assert_eq!(to_python_snake_case("from"), "from_");
+        assert_eq!(to_python_snake_case("ITS-Container"), "its_container");
+        assert_eq!(to_python_const_case("max-length"), "MAX_LENGTH");
+        assert_eq!(to_python_const_case("pass"), "PASS");
+    }
+
+    #[test]
+    fn escapes_strings() {
+        assert_eq!(to_python_string(r#"say "hi"\"#), r#""say \"hi\"\\""#);
+        assert_eq!(to_python_string("a\nb"), r#""a\nb""#);
+    }
+
+    #[test]
+    fn converts_values_to_jer() {
+        assert_eq!(value_to_jer(&ASN1Value::Null).unwrap(), "None");
+        assert_eq!(value_to_jer(&ASN1Value::Boolean(true)).unwrap(), "True");
+        assert_eq!(
+            value_to_jer(&ASN1Value::Integer((-3).into())).unwrap(),
+            "-3"
+        );
+        assert_eq!(value_to_jer(&ASN1Value::Real(1.5)).unwrap(), "1.5");
+        assert_eq!(
+            value_to_jer(&ASN1Value::OctetString(vec![0, 255])).unwrap(),
+            r#""00FF""#
+        );
+        assert_eq!(
+            value_to_jer(&ASN1Value::BitString(vec![true, true, true, true])).unwrap(),
+            r#"{"value": "F0", "length": 4}"#
+        );
+        assert_eq!(
+            value_to_jer(&ASN1Value::EnumeratedValue {
+                enumerated: "Color".into(),
+                enumerable: "dark-red".into()
+            })
+            .unwrap(),
+            r#""dark-red""#
+        );
+        assert_eq!(
+            value_to_jer(&ASN1Value::LinkedStructLikeValue(vec![(
+                String::from("field"),
+                ASN1Type::Integer(Integer::default()),
+                StructLikeFieldValue::Explicit(Box::new(ASN1Value::Choice {
+                    type_name: None,
+                    variant_name: "option".into(),
+                    inner_value: Box::new(ASN1Value::Integer(42.into()))
+                }))
+            )]))
+            .unwrap(),
+            r#"{"field": {"option": 42}}"#
+        );
+        assert_eq!(
+            value_to_jer(&ASN1Value::ObjectIdentifier(ObjectIdentifierValue(vec![
+                ObjectIdentifierArc::from(1),
+                ObjectIdentifierArc::from(2),
+                ObjectIdentifierArc::from(3),
+            ])))
+            .unwrap(),
+            r#""1.2.3""#
+        );
+        assert_eq!(
+            value_to_jer(&ASN1Value::ElsewhereDeclaredValue {
+                parent: None,
+                identifier: "other-value".into()
+            })
+            .unwrap(),
+            "_to_jer(OTHER_VALUE)"
+        );
+    }
+
+    #[test]
+    fn converts_nested_lists() {
+        let ty = PyType::List(Box::new(PyType::List(Box::new(PyType::Class(
+            "Item".into(),
+        )))));
+        assert_eq!(ty.annotation(), "_t.List[_t.List[Item]]");
+        assert_eq!(
+            ty.encode_jer("self.value", 0),
+            "[[_item1.to_jer() for _item1 in _item0] for _item0 in self.value]"
+        );
+        assert_eq!(
+            ty.decode_jer("value", 0),
+            "[[Item.from_jer(_item1) for _item1 in _item0] for _item0 in value]"
+        );
+    }
+
+    #[test]
+    fn formats_constraint_checks() {
+        let backend = Python::default();
+        assert_eq!(
+            backend
+                .constraint_checks(
+                    "Test",
+                    "self.value",
+                    &ASN1Type::Integer(Integer::from((0, 255, false))),
+                    0
+                )
+                .unwrap(),
+            vec![String::from(r#"_check_range("Test", self.value, 0, 255)"#)]
+        );
+        assert!(backend
+            .constraint_checks(
+                "Test",
+                "self.value",
+                &ASN1Type::Integer(Integer::from((0, 255, true))),
+                0
+            )
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            backend
+                .constraint_checks(
+                    "Test",
+                    "self.value",
+                    &ASN1Type::CharacterString(CharacterString {
+                        constraints: vec![Constraint::Subtype(ElementSetSpecs {
+                            set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(
+                                Box::new(ElementOrSetOperation::Element(
+                                    SubtypeElements::ValueRange {
+                                        min: Some(ASN1Value::Integer(1.into())),
+                                        max: Some(ASN1Value::Integer(8.into())),
+                                        extensible: false
+                                    }
+                                ))
+                            )),
+                            extensible: false,
+                            extension_additions: None
+                        })],
+                        ty: CharacterStringType::IA5String
+                    }),
+                    0
+                )
+                .unwrap(),
+            vec![String::from(r#"_check_size("Test", self.value, 1, 8)"#)]
+        );
+    }
+}
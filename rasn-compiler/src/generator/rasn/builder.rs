@@ -1,4 +1,4 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use std::collections::BTreeMap;
 
@@ -70,9 +70,15 @@ impl Rasn {
                         top_level_declaration: None,
                     }),
                     ASN1Type::ObjectIdentifier(_) => self.generate_oid(t),
-                    ASN1Type::ObjectClassField(_) | ASN1Type::EmbeddedPdv | ASN1Type::External => {
-                        self.generate_any(t)
-                    }
+                    ASN1Type::RelativeOid(_)
+                    | ASN1Type::OidIri(_)
+                    | ASN1Type::RelativeOidIri(_)
+                    | ASN1Type::ObjectDescriptor(_) => self.generate_universally_tagged(t),
+                    ASN1Type::ObjectClassField(_)
+                    | ASN1Type::EmbeddedPdv
+                    | ASN1Type::External
+                    | ASN1Type::UnrestrictedCharacterString
+                    | ASN1Type::Any(_) => self.generate_any(t),
                     ASN1Type::GeneralizedTime(_) => self.generate_generalized_time(t),
                     ASN1Type::UTCTime(_) => self.generate_utc_time(t),
                     ASN1Type::ChoiceSelectionType(_) => Err(GeneratorError {
@@ -83,8 +89,14 @@ impl Rasn {
                     }),
                 }
             }
-            ToplevelDefinition::Value(v) => self.generate_value(v),
+            ToplevelDefinition::Value(v) => {
+                if v.parameterization.is_some() {
+                    return Ok(TokenStream::new());
+                }
+                self.generate_value(v)
+            }
             ToplevelDefinition::Class(_) => Ok(TokenStream::new()),
+            ToplevelDefinition::Object(o) if o.parameterization.is_some() => Ok(TokenStream::new()),
             ToplevelDefinition::Object(o) => match o.value {
                 ASN1Information::ObjectSet(_) => self.generate_information_object_set(o),
                 ASN1Information::Object(_) => Ok(TokenStream::new()),
@@ -418,6 +430,14 @@ impl Rasn {
                     )
                 }
             }
+            ASN1Value::LinkedRelativeOidValue(_) if ty.is_builtin_type() => call_template!(
+                self,
+                lazy_static_value_template,
+                tld,
+                quote!(OctetString),
+                self.value_to_tokens(&tld.value, None)?,
+                self.config.no_std_compliant_bindings
+            ),
             ASN1Value::ObjectIdentifier(_) if ty.is_builtin_type() => call_template!(
                 self,
                 lazy_static_value_template,
@@ -430,9 +450,9 @@ impl Rasn {
                 let ty_ts = match cs_ty {
                     CharacterStringType::NumericString => quote!(NumericString),
                     CharacterStringType::VisibleString => quote!(VisibleString),
-                    CharacterStringType::IA5String => quote!(IA5String),
-                    CharacterStringType::UTF8String => quote!(UTF8String),
-                    CharacterStringType::BMPString => quote!(BMPString),
+                    CharacterStringType::IA5String => quote!(Ia5String),
+                    CharacterStringType::UTF8String => quote!(Utf8String),
+                    CharacterStringType::BMPString => quote!(BmpString),
                     CharacterStringType::PrintableString => quote!(PrintableString),
                     CharacterStringType::GeneralString => quote!(GeneralString),
                     CharacterStringType::GraphicString => quote!(GraphicString),
@@ -483,6 +503,7 @@ impl Rasn {
             | ASN1Value::Time(_)
             | ASN1Value::LinkedCharStringValue(_, _)
             | ASN1Value::ObjectIdentifier(_)
+            | ASN1Value::LinkedRelativeOidValue(_)
             | ASN1Value::LinkedArrayLikeValue(_)
             | ASN1Value::ElsewhereDeclaredValue { .. }
             | ASN1Value::OctetString(_) => call_template!(
@@ -562,6 +583,32 @@ impl Rasn {
         }
     }
 
+    pub(crate) fn generate_universally_tagged(
+        &self,
+        tld: ToplevelTypeDefinition,
+    ) -> Result<TokenStream, GeneratorError> {
+        let Some((inner, universal_tag)) = Self::universally_tagged(&tld.ty) else {
+            return self.type_mismatch_error(
+                tld,
+                "RELATIVE-OID, OID-IRI, RELATIVE-OID-IRI, or ObjectDescriptor",
+            );
+        };
+        let (name, mut annotations) = self.format_name_and_common_annotations(&tld)?;
+        if tld.tag.is_none() {
+            let universal_tag = Literal::u64_unsuffixed(universal_tag);
+            annotations.push(quote!(tag(universal, #universal_tag)));
+        }
+        if let ASN1Type::ObjectDescriptor(descriptor) = &tld.ty {
+            annotations.push(self.format_range_annotations(false, &descriptor.constraints)?);
+        }
+        Ok(typealias_template(
+            self.format_comments(&tld.comments)?,
+            name,
+            inner,
+            self.join_annotations(annotations, false, true)?,
+        ))
+    }
+
     pub(crate) fn generate_null(
         &self,
         tld: ToplevelTypeDefinition,
@@ -764,6 +811,8 @@ impl Rasn {
                         acc
                     })
                 };
+                let mut class_fields = class_fields;
+                class_fields.append_all(self.format_defined_by_decoders(&tld.name, seq)?);
                 let formatted_members =
                     self.format_sequence_or_set_members(seq, &name.to_string())?;
                 let mut annotations = vec![
@@ -790,7 +839,7 @@ impl Rasn {
                     self.join_annotations(annotations, false, true)?,
                     self.format_default_methods(&seq.members, &name.to_string())?,
                     self.format_new_impl(&name, formatted_members.name_types),
-                    self.format_default_impl(&tld.name, &seq.members),
+                    self.format_default_impl(&name.to_string(), &seq.members),
                     class_fields,
                 ))
             }
@@ -818,6 +867,7 @@ impl Rasn {
             ASN1Type::ElsewhereDeclaredType(_) => None,
             n => Some(
                 self.generate_tld(ToplevelDefinition::Type(ToplevelTypeDefinition {
+                    trailing_comment: None,
                     parameterization: None,
                     comments: format!(
                         " Anonymous {} OF member ",
@@ -865,7 +915,7 @@ impl Rasn {
         if let ASN1Information::ObjectSet(o) = &tld.value {
             let class: &ObjectClassDefn = match tld.class {
                 ClassLink::ByReference(ref c) => c,
-                ClassLink::ByName(_) => {
+                ClassLink::ByName(_) | ClassLink::ByInstantiation(..) => {
                     return Err(GeneratorError::new(
                         None,
                         "Missing class link in Information Object Set",
@@ -877,11 +927,13 @@ impl Rasn {
                 .values
                 .iter()
                 .map(|v| match v {
-                    ObjectSetValue::Reference(r) => Err(GeneratorError::new(
-                        None,
-                        &format!("Could not resolve reference of Information Object Set {r}"),
-                        GeneratorErrorType::MissingClassKey,
-                    )),
+                    ObjectSetValue::Reference(r) | ObjectSetValue::Instantiation(r, _) => {
+                        Err(GeneratorError::new(
+                            None,
+                            &format!("Could not resolve reference of Information Object Set {r}"),
+                            GeneratorErrorType::MissingClassKey,
+                        ))
+                    }
                     ObjectSetValue::Inline(InformationObjectFields::CustomSyntax(_)) => {
                         Err(GeneratorError::new(
                             Some(ToplevelDefinition::Object(tld.clone())),
@@ -8,7 +8,7 @@ use std::{
 };
 
 use crate::{error::CompilerError, intermediate::*};
-use proc_macro2::TokenStream;
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
 
 #[cfg(target_family = "wasm")]
@@ -23,7 +23,7 @@ mod builder;
 mod template;
 mod utils;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 /// A compiler backend that generates bindings to be used with
 /// the `rasn` framework for rust.
 pub struct Rasn {
@@ -33,7 +33,7 @@ pub struct Rasn {
 }
 
 #[cfg_attr(target_family = "wasm", wasm_bindgen(getter_with_clone))]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 /// A configuration for the [Rasn] backend
 pub struct Config {
     /// ASN.1 Open Types are represented as the `rasn::types::Any` type,
@@ -69,6 +69,29 @@ pub struct Config {
     pub type_annotations: Vec<String>,
     /// Create bindings for a `no_std` environment
     pub no_std_compliant_bindings: bool,
+    /// Generate the items of definitions that their ASN.1 module does not export
+    /// as `pub(crate)` instead of `pub`, so that the public API of the bindings
+    /// matches the exports of the specification.
+    pub restrict_unexported_visibility: bool,
+    /// Types of `ANY DEFINED BY` values, keyed on the OBJECT IDENTIFIER value of the
+    /// component that the `ANY` is defined by. For every `ANY DEFINED BY` component with
+    /// entries in this table, the compiler generates an enum of the listed types and a
+    /// method that decodes the `ANY` value as the type that the identifier selects.
+    #[cfg_attr(target_family = "wasm", wasm_bindgen(skip))]
+    pub any_defined_by_types: Vec<DefinedByType>,
+}
+
+/// An entry of the [Config::any_defined_by_types] table
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefinedByType {
+    /// Identifier of the component that the `ANY` is defined by, such as `algorithm`.
+    /// The identifier can be qualified by the name of its SEQUENCE or SET,
+    /// such as `AlgorithmIdentifier.algorithm`.
+    pub component: String,
+    /// The OBJECT IDENTIFIER value in dotted notation, such as `1.2.840.113549.1.1.1`
+    pub identifier: String,
+    /// The ASN.1 type of the `ANY` value, such as `NULL` or `RSAPublicKey`
+    pub ty: String,
 }
 
 #[cfg(target_family = "wasm")]
@@ -82,6 +105,7 @@ impl Config {
         generate_from_impls: Option<bool>,
         custom_imports: Option<Box<[String]>>,
         type_annotations: Option<Box<[String]>>,
+        restrict_unexported_visibility: Option<bool>,
     ) -> Self {
         Self {
             opaque_open_types,
@@ -91,6 +115,8 @@ impl Config {
             custom_imports: custom_imports.map_or(Vec::new(), |c| c.into_vec()),
             type_annotations: type_annotations
                 .map_or(Config::default().type_annotations, |c| c.into_vec()),
+            restrict_unexported_visibility: restrict_unexported_visibility.unwrap_or(false),
+            any_defined_by_types: Vec::new(),
         }
     }
 }
@@ -102,6 +128,8 @@ impl Default for Config {
             default_wildcard_imports: false,
             generate_from_impls: false,
             no_std_compliant_bindings: false,
+            restrict_unexported_visibility: false,
+            any_defined_by_types: Vec::new(),
             custom_imports: Vec::default(),
             type_annotations: vec![String::from(
                 "#[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq, Eq, Hash)]",
@@ -143,7 +171,7 @@ impl Backend for Rasn {
         tlds: Vec<ToplevelDefinition>,
     ) -> Result<GeneratedModule, GeneratorError> {
         if let Some(module_ref) = tlds.first().and_then(|tld| tld.get_module_header()) {
-            let module = module_ref.borrow();
+            let module = module_ref.read();
             self.tagging_environment = module.tagging_environment;
             self.extensibility_environment = module.extensibility_environment;
             let name = self.to_rust_snake_case(&module.name);
@@ -184,7 +212,13 @@ impl Backend for Rasn {
             });
             let (pdus, warnings): (Vec<TokenStream>, Vec<CompilerError>) =
                 tlds.into_iter().fold((vec![], vec![]), |mut acc, tld| {
+                    let restricted =
+                        self.config.restrict_unexported_visibility && !module.exports(tld.name());
                     match self.generate_tld(tld) {
+                        Ok(s) if restricted => {
+                            acc.0.push(restrict_visibility(s));
+                            acc
+                        }
                         Ok(s) => {
                             acc.0.push(s);
                             acc
@@ -200,10 +234,15 @@ impl Backend for Rasn {
             } else {
                 quote!(std::sync::LazyLock)
             };
+            // Exported types may contain types that are not exported
+            let allow_private_interfaces = self
+                .config
+                .restrict_unexported_visibility
+                .then(|| quote!(private_interfaces,));
             Ok(GeneratedModule {
                 generated: Some(quote! {
                 #[allow(non_camel_case_types, non_snake_case, non_upper_case_globals, unused,
-                        clippy::too_many_arguments,)]
+                        clippy::too_many_arguments, #allow_private_interfaces)]
                 pub mod #name {
                     extern crate alloc;
 
@@ -237,6 +276,26 @@ impl Backend for Rasn {
     }
 }
 
+/// Replaces the `pub` visibility of the items in `tokens` with `pub(crate)`.
+/// Visibilities nested in the items, such as those of fields and methods, are kept.
+fn restrict_visibility(tokens: TokenStream) -> TokenStream {
+    let mut restricted = TokenStream::new();
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        let is_pub = matches!(&token, TokenTree::Ident(ident) if ident == "pub");
+        restricted.extend([token]);
+        if is_pub
+            && !matches!(
+                tokens.peek(),
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis
+            )
+        {
+            restricted.extend(quote!((crate)));
+        }
+    }
+    restricted
+}
+
 impl Rasn {
     fn get_rustfmt_path() -> Result<PathBuf, Box<dyn Error>> {
         // Try ~/.cargo/bin/rustfmt style paths first
@@ -301,3 +360,38 @@ impl Rasn {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Config;
+    use crate::{prelude::RasnBackend, Compiler};
+
+    #[test]
+    fn restricts_visibility_of_unexported_definitions() {
+        let generated = Compiler::<RasnBackend, _>::new_with_config(Config {
+            restrict_unexported_visibility: true,
+            ..Default::default()
+        })
+        .add_asn_literal(
+            "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            EXPORTS Message;
+            Message ::= SEQUENCE { id Id, kind CHOICE { a BOOLEAN, b NULL } }
+            Id ::= INTEGER (0..255)
+            maxId INTEGER ::= 255
+            END",
+        )
+        .compile_to_string()
+        .unwrap()
+        .generated
+        .replace(char::is_whitespace, "");
+        for expected in [
+            "pubstructMessage{pubid:Id,pubkind:MessageKind,}",
+            "pub(crate)structId(pubu8);",
+            "pubenumMessageKind{",
+            "pubfnnew(id:Id,kind:MessageKind)",
+            "pub(crate)staticMAX_ID:",
+        ] {
+            assert!(generated.contains(expected), "{expected}\n{generated}");
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use std::{ops::Not, str::FromStr};
 
+use num::BigInt;
+
 use proc_macro2::{Ident, Literal, Punct, Spacing, Span, TokenStream};
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use types::{BitString, OctetString, Optionality};
@@ -13,7 +15,7 @@ use crate::{
             per_visible_range_constraints, CharsetSubset, PerVisibleAlphabetConstraints,
         },
         information_object::{InformationObjectField, ObjectClassDefn},
-        types::{Choice, ChoiceOption, Enumerated, SequenceOrSet, SequenceOrSetMember},
+        types::{Any, Choice, ChoiceOption, Enumerated, SequenceOrSet, SequenceOrSetMember},
         ASN1Type, ASN1Value, AsnTag, CharacterStringType, IntegerType, TagClass,
         TaggingEnvironment, ToplevelDefinition, ToplevelTypeDefinition,
     },
@@ -32,8 +34,6 @@ macro_rules! error {
     };
 }
 
-pub(crate) use error;
-
 use self::types::{CharacterString, Constrainable};
 
 use super::*;
@@ -341,6 +341,7 @@ impl Rasn {
                         self.generate_tld(ToplevelDefinition::Type(ToplevelTypeDefinition {
                             parameterization: None,
                             comments: INNER_TYPE_COMMENT.into(),
+                            trailing_comment: None,
                             name: self.inner_name(&m.name, parent_name).to_string(),
                             ty: m.ty.clone(),
                             tag: None,
@@ -434,6 +435,7 @@ impl Rasn {
                         self.generate_tld(ToplevelDefinition::Type(ToplevelTypeDefinition {
                             parameterization: None,
                             comments: INNER_TYPE_COMMENT.into(),
+                            trailing_comment: None,
                             name: self.inner_name(&o.name, parent_name).to_string(),
                             ty: o.ty.clone(),
                             tag: None,
@@ -570,6 +572,12 @@ impl Rasn {
             }
             ASN1Type::Real(_) => (vec![], quote!(f64)),
             ASN1Type::ObjectIdentifier(o) => (o.constraints.clone(), quote!(ObjectIdentifier)),
+            ASN1Type::RelativeOid(_)
+            | ASN1Type::OidIri(_)
+            | ASN1Type::RelativeOidIri(_)
+            | ASN1Type::ObjectDescriptor(_) => {
+                (vec![], self.inner_name(name, parent_name).to_token_stream())
+            }
             ASN1Type::BitString(b) => (b.constraints.clone(), quote!(BitString)),
             ASN1Type::OctetString(o) => (o.constraints.clone(), quote!(OctetString)),
             ASN1Type::GeneralizedTime(o) => (o.constraints.clone(), quote!(GeneralizedTime)),
@@ -617,9 +625,11 @@ impl Rasn {
                 };
                 (e.constraints.clone(), tokenized)
             }
-            ASN1Type::ObjectClassField(_) | ASN1Type::EmbeddedPdv | ASN1Type::External => {
-                (vec![], quote!(Any))
-            }
+            ASN1Type::ObjectClassField(_)
+            | ASN1Type::EmbeddedPdv
+            | ASN1Type::External
+            | ASN1Type::UnrestrictedCharacterString
+            | ASN1Type::Any(_) => (vec![], quote!(Any)),
             ASN1Type::ChoiceSelectionType(_) => unreachable!(),
         })
     }
@@ -708,6 +718,144 @@ impl Rasn {
         Ok(output)
     }
 
+    /// Formats an enum of the types that `ANY DEFINED BY` components can hold and a decode method
+    /// for each component, if [Config::any_defined_by_types] lists types for the component.
+    pub(crate) fn format_defined_by_decoders(
+        &self,
+        parent_name: &str,
+        seq: &SequenceOrSet,
+    ) -> Result<TokenStream, GeneratorError> {
+        let mut output = TokenStream::new();
+        for member in &seq.members {
+            let ASN1Type::Any(Any {
+                defined_by: Some(component),
+            }) = &member.ty
+            else {
+                continue;
+            };
+            let qualified_component = format!("{parent_name}.{component}");
+            let entries = self
+                .config
+                .any_defined_by_types
+                .iter()
+                .filter(|e| e.component == *component || e.component == qualified_component)
+                .collect::<Vec<_>>();
+            // The defining component must be a mandatory OBJECT IDENTIFIER to select a type
+            let Some(defining) = seq.members.iter().find(|m| m.name == *component) else {
+                continue;
+            };
+            let defining_field = self.to_rust_snake_case(&defining.name);
+            let identifier = match (&defining.ty, &defining.optionality) {
+                _ if entries.is_empty() => continue,
+                (ASN1Type::ObjectIdentifier(_), Optionality::Required) => {
+                    quote!(&self.#defining_field)
+                }
+                (ASN1Type::ElsewhereDeclaredType(_), Optionality::Required) => {
+                    quote!(&self.#defining_field.0)
+                }
+                _ => continue,
+            };
+
+            let mut variants = Vec::<(TokenStream, TokenStream, Vec<TokenStream>)>::new();
+            for entry in entries {
+                let arcs = entry
+                    .identifier
+                    .split('.')
+                    .map(|arc| arc.trim().parse::<u32>().map(Literal::u32_unsuffixed))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| {
+                        error!(
+                            SyntaxMismatch,
+                            "Invalid OBJECT IDENTIFIER {} for ANY DEFINED BY {}!",
+                            entry.identifier,
+                            entry.component
+                        )
+                    })?;
+                let ty = match crate::lexer::asn1_type(entry.ty.as_str().into()) {
+                    Ok((rest, ty)) if rest.inner().trim().is_empty() => ty,
+                    _ => {
+                        return Err(error!(
+                            SyntaxMismatch,
+                            "Invalid type {} for ANY DEFINED BY {}!", entry.ty, entry.component
+                        ))
+                    }
+                };
+                let type_name = ty.as_str();
+                let variant_name = if type_name.chars().any(char::is_lowercase) {
+                    self.to_rust_title_case(&type_name)
+                } else {
+                    self.to_rust_title_case(&type_name.to_lowercase().replace(' ', "_"))
+                };
+                let pattern = quote!([#(#arcs),*]);
+                match variants
+                    .iter_mut()
+                    .find(|(name, ..)| name.to_string() == variant_name.to_string())
+                {
+                    Some((.., patterns)) => patterns.push(pattern),
+                    None => variants.push((variant_name, self.type_to_tokens(&ty)?, vec![pattern])),
+                }
+            }
+
+            let name = self.to_rust_title_case(parent_name);
+            let enum_name = format_ident!(
+                "{name}_{}",
+                self.to_rust_title_case(&member.name).to_string()
+            );
+            let decode_fn = format_ident!("decode_{}", self.to_rust_snake_case(&member.name));
+            let any_field = self.to_rust_snake_case(&member.name);
+            let enum_variants = variants
+                .iter()
+                .map(|(variant, ty, _)| quote!(#variant(#ty),));
+            let match_arms = variants.iter().map(|(variant, _, patterns)| {
+                quote!(#(#patterns)|* => Ok(decoder.codec().decode_from_binary(any.as_bytes()).map(#enum_name::#variant)?),)
+            });
+            let decode = quote! {
+                match identifier {
+                    #(#match_arms)*
+                    _ => Err(rasn::error::DecodeError::from_kind(
+                        rasn::error::DecodeErrorKind::Custom {
+                            msg: alloc::format!("Unknown identifier of ANY DEFINED BY component."),
+                        },
+                        decoder.codec()
+                    ).into())
+                }
+            };
+            let (return_type, body) = if member.optionality == Optionality::Required {
+                (
+                    quote!(#enum_name),
+                    quote! {
+                        let any = &self.#any_field;
+                        #decode
+                    },
+                )
+            } else {
+                (
+                    quote!(Option<#enum_name>),
+                    quote! {
+                        let Some(any) = &self.#any_field else {
+                            return Ok(None);
+                        };
+                        #decode.map(Some)
+                    },
+                )
+            };
+            output.append_all(quote! {
+                #[derive(Debug, Clone, PartialEq)]
+                pub enum #enum_name {
+                    #(#enum_variants)*
+                }
+
+                impl #name {
+                    pub fn #decode_fn<D: Decoder>(&self, decoder: &mut D) -> Result<#return_type, D::Error> {
+                        let identifier: &[u32] = #identifier;
+                        #body
+                    }
+                }
+            });
+        }
+        Ok(output)
+    }
+
     pub(crate) fn type_to_tokens(&self, ty: &ASN1Type) -> Result<TokenStream, GeneratorError> {
         match ty {
             ASN1Type::Null => Ok(quote!(())),
@@ -754,7 +902,16 @@ impl Rasn {
             )),
             ASN1Type::GeneralizedTime(_) => Ok(quote!(GeneralizedTime)),
             ASN1Type::UTCTime(_) => Ok(quote!(UtcTime)),
-            ASN1Type::EmbeddedPdv | ASN1Type::External => Ok(quote!(Any)),
+            ASN1Type::RelativeOid(_)
+            | ASN1Type::OidIri(_)
+            | ASN1Type::RelativeOidIri(_)
+            | ASN1Type::ObjectDescriptor(_) => Ok(Self::universally_tagged(ty)
+                .map(|(inner, _)| inner)
+                .unwrap_or_default()),
+            ASN1Type::EmbeddedPdv
+            | ASN1Type::External
+            | ASN1Type::UnrestrictedCharacterString
+            | ASN1Type::Any(_) => Ok(quote!(Any)),
             ASN1Type::ChoiceSelectionType(c) => {
                 let choice = self.to_rust_title_case(&c.choice_name);
                 let option = self.to_rust_enum_identifier(&c.selected_option);
@@ -830,7 +987,7 @@ impl Rasn {
                 }
             }
             ASN1Value::Boolean(b) => Ok(b.to_token_stream()),
-            ASN1Value::Integer(i) => Ok(Literal::i128_unsuffixed(*i).to_token_stream()),
+            ASN1Value::Integer(i) => Ok(integer_literal(i)?.to_token_stream()),
             ASN1Value::String(s) => Ok(s.to_token_stream()),
             ASN1Value::Real(r) => Ok(r.to_token_stream()),
             ASN1Value::BitStringNamedBits(_) => Err(GeneratorError {
@@ -854,7 +1011,17 @@ impl Rasn {
             | ASN1Value::ElsewhereDeclaredValue { identifier: e, .. } => {
                 Ok(self.to_rust_const_case(e).to_token_stream())
             }
+            ASN1Value::ParameterizedValue { identifier, .. } => Err(error!(
+                Unidentified,
+                "Parameterized value {identifier} should be instantiated by this point!"
+            )),
             ASN1Value::ObjectIdentifier(oid) => self.format_oid(oid),
+            ASN1Value::LinkedRelativeOidValue(oid) => {
+                let bytes = relative_oid_content(oid)?
+                    .into_iter()
+                    .map(Literal::u8_unsuffixed);
+                Ok(quote!(<OctetString as From<&'static [u8]>>::from(&[#(#bytes),*])))
+            }
             ASN1Value::Time(t) => match type_name {
                 Some(time_type) => Ok(quote!(#t.parse::<#time_type>().unwrap())),
                 None => Ok(quote!(#t.parse::<_>().unwrap())),
@@ -885,13 +1052,10 @@ impl Rasn {
             ASN1Value::LinkedIntValue {
                 integer_type,
                 value,
-            } => {
-                let val = Literal::i128_unsuffixed(*value);
-                match integer_type {
-                    IntegerType::Unbounded => Ok(quote!(Integer::from(#val))),
-                    _ => Ok(val.to_token_stream()),
-                }
-            }
+            } => match integer_type {
+                IntegerType::Unbounded => Ok(big_integer_tokens(value)),
+                _ => Ok(integer_literal(value)?.to_token_stream()),
+            },
             ASN1Value::LinkedCharStringValue(string_type, value) => {
                 let val = value.to_token_stream();
                 match string_type {
@@ -929,12 +1093,28 @@ impl Rasn {
         }
     }
 
+    /// Returns the rasn type that encodes like `ty` and the universal tag of `ty`,
+    /// if rasn does not provide a type for `ty`.
+    pub(crate) fn universally_tagged(ty: &ASN1Type) -> Option<(TokenStream, u64)> {
+        match ty {
+            ASN1Type::ObjectDescriptor(_) => Some((quote!(GraphicString), 7)),
+            ASN1Type::RelativeOid(_) => Some((quote!(OctetString), 13)),
+            ASN1Type::OidIri(_) => Some((quote!(Utf8String), 35)),
+            ASN1Type::RelativeOidIri(_) => Some((quote!(Utf8String), 36)),
+            _ => None,
+        }
+    }
+
     pub(crate) fn needs_unnesting(ty: &ASN1Type) -> bool {
         match ty {
             ASN1Type::Enumerated(_)
             | ASN1Type::Choice(_)
             | ASN1Type::Sequence(_)
-            | ASN1Type::Set(_) => true,
+            | ASN1Type::Set(_)
+            | ASN1Type::RelativeOid(_)
+            | ASN1Type::OidIri(_)
+            | ASN1Type::RelativeOidIri(_)
+            | ASN1Type::ObjectDescriptor(_) => true,
             ASN1Type::SequenceOf(SequenceOrSetOf {
                 element_type,
                 element_tag,
@@ -1159,7 +1339,7 @@ impl Rasn {
                 }
             }
         }
-        field_index_map.sort_by(|&(a, _), &(b, _)| a.cmp(&b));
+        field_index_map.sort_by_key(|&(a, _)| a);
         let types = field_index_map.into_iter().collect();
         match key {
             Some(k) => Ok((k, types)),
@@ -1408,14 +1588,10 @@ impl ASN1Type {
                     acc.max_restrictive(c.integer_constraints())
                 }) != IntegerType::Unbounded
             }
-            ASN1Type::Choice(c) => c
-                .options
-                .iter()
-                .fold(true, |acc, opt| opt.ty.is_const_type() && acc),
-            ASN1Type::Set(s) | ASN1Type::Sequence(s) => s
-                .members
-                .iter()
-                .fold(true, |acc, m| m.ty.is_const_type() && acc),
+            ASN1Type::Choice(c) => c.options.iter().all(|opt| opt.ty.is_const_type()),
+            ASN1Type::Set(s) | ASN1Type::Sequence(s) => {
+                s.members.iter().all(|m| m.ty.is_const_type())
+            }
             ASN1Type::SetOf(s) | ASN1Type::SequenceOf(s) => s.element_type.is_const_type(),
             _ => false,
         }
@@ -1444,6 +1620,58 @@ impl BitString {
     }
 }
 
+/// Tokenizes an INTEGER value as a literal of a primitive integer type.
+fn integer_literal(value: &BigInt) -> Result<Literal, GeneratorError> {
+    i128::try_from(value)
+        .map(Literal::i128_unsuffixed)
+        .map_err(|_| {
+            error!(
+                Unsupported,
+                "INTEGER value {value} exceeds the range of primitive integer types!"
+            )
+        })
+}
+
+/// Tokenizes an INTEGER value as an arbitrary-size `Integer`.
+/// Values beyond the range of `i128` are restored from their two's complement octets.
+fn big_integer_tokens(value: &BigInt) -> TokenStream {
+    match i128::try_from(value) {
+        Ok(value) => {
+            let value = Literal::i128_unsuffixed(value);
+            quote!(Integer::from(#value))
+        }
+        Err(_) => {
+            let octets = value
+                .to_signed_bytes_be()
+                .into_iter()
+                .map(Literal::u8_unsuffixed);
+            quote!(<Integer as IntegerType>::try_from_signed_bytes(&[#(#octets),*], rasn::Codec::Ber).unwrap())
+        }
+    }
+}
+
+/// Returns the contents octets of the BER encoding of a RELATIVE-OID value.
+/// Each arc is encoded in base 128, with the most significant bit set on all but its last octet.
+fn relative_oid_content(oid: &ObjectIdentifierValue) -> Result<Vec<u8>, GeneratorError> {
+    let mut content = vec![];
+    for arc in &oid.0 {
+        let mut number = arc.number.ok_or_else(|| {
+            error!(
+                NotYetInplemented,
+                "RELATIVE-OID arcs without a number are currently unsupported!"
+            )
+        })?;
+        let mut octets = vec![(number & 0x7f) as u8];
+        number >>= 7;
+        while number > 0 {
+            octets.push((number & 0x7f) as u8 | 0x80);
+            number >>= 7;
+        }
+        content.extend(octets.iter().rev());
+    }
+    Ok(content)
+}
+
 #[cfg(test)]
 mod tests {
     use quote::quote;
@@ -1534,26 +1762,30 @@ mod tests {
                                     constraints: vec![]
                                 }),
                                 optionality: Optionality::Optional,
-                                constraints: vec![]
+                                constraints: vec![],
+                                comments: String::new(),
+                                trailing_comment: None,
                             },
                             SequenceOrSetMember {
+                                trailing_comment: None,
                                 is_recursive: false,
                                 name: "testMember1".into(),
                                 tag: None,
                                 ty: ASN1Type::Integer(Integer {
                                     distinguished_values: None,
                                     constraints: vec![Constraint::Subtype(ElementSetSpecs {
-                            extensible: false,
+                            extensible: false, extension_additions: None,
                             set: crate::intermediate::constraints::ElementOrSetOperation::Element(
                                 crate::intermediate::constraints::SubtypeElements::SingleValue {
-                                    value: ASN1Value::Integer(4),
+                                    value: ASN1Value::Integer(4.into()),
                                     extensible: true
                                 }
                             )
                         })]
                                 }),
-                                optionality: Optionality::Default(ASN1Value::Integer(4)),
-                                constraints: vec![]
+                                optionality: Optionality::Default(ASN1Value::Integer(4.into())),
+                                constraints: vec![],
+                                comments: String::new(),
                             }
                         ]
                     },
@@ -1628,7 +1860,9 @@ is_recursive: false,
                             ty: ASN1Type::Boolean(Boolean {
                                 constraints: vec![]
                             }),
-                            constraints: vec![]
+                            constraints: vec![],
+comments: String::new(),
+trailing_comment: None,
                         },
                         ChoiceOption {
 is_recursive: false,
@@ -1637,16 +1871,18 @@ is_recursive: false,
                             ty: ASN1Type::Integer(Integer {
                                 distinguished_values: None,
                                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
-                                    extensible: false,
+                                    extensible: false, extension_additions: None,
                                     set: crate::intermediate::constraints::ElementOrSetOperation::Element(
                                         crate::intermediate::constraints::SubtypeElements::SingleValue {
-                                            value: ASN1Value::Integer(4),
+                                            value: ASN1Value::Integer(4.into()),
                                             extensible: true
                                         }
                                     )
                                 })]
                             }),
-                            constraints: vec![]
+                            constraints: vec![],
+comments: String::new(),
+trailing_comment: None,
                         }
                     ]
                 },
@@ -1751,13 +1987,14 @@ is_recursive: false,
                         constraints::SubtypeElements::SizeConstraint(Box::new(
                             constraints::ElementOrSetOperation::Element(
                                 constraints::SubtypeElements::SingleValue {
-                                    value: ASN1Value::Integer(4),
+                                    value: ASN1Value::Integer(4.into()),
                                     extensible: false,
                                 },
                             ),
                         )),
                     ),
                     extensible: false,
+                    extension_additions: None,
                 })],
             }
             .fixed_size(),
@@ -1770,14 +2007,15 @@ is_recursive: false,
                         constraints::SubtypeElements::SizeConstraint(Box::new(
                             constraints::ElementOrSetOperation::Element(
                                 constraints::SubtypeElements::ValueRange {
-                                    min: Some(ASN1Value::Integer(1)),
-                                    max: Some(ASN1Value::Integer(4)),
+                                    min: Some(ASN1Value::Integer(1.into())),
+                                    max: Some(ASN1Value::Integer(4.into())),
                                     extensible: false
                                 }
                             ),
                         )),
                     ),
                     extensible: false,
+                    extension_additions: None,
                 })],
             }
             .fixed_size(),
@@ -10,7 +10,7 @@ mod builder;
 mod template;
 mod utils;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 /// A compiler backend that generates typescript type definitions for
 /// ASN.1 data elements encoded using ITU X.697 JSON Encoding Rules
 /// with default encoding instructions
@@ -18,7 +18,7 @@ pub struct Typescript {
     config: Config,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 /// A configuration for the [Typescript] backend
 pub struct Config {}
 
@@ -44,7 +44,7 @@ impl Backend for Typescript {
         tlds: Vec<ToplevelDefinition>,
     ) -> Result<GeneratedModule, GeneratorError> {
         if let Some(module_ref) = tlds.first().and_then(|tld| tld.get_module_header()) {
-            let module = module_ref.borrow();
+            let module = module_ref.read();
             let namespace = to_jer_identifier(&module.name);
             let imports = module
                 .imports
@@ -123,11 +123,17 @@ impl Backend for Typescript {
                     ASN1Type::Choice(_) => self.generate_choice(t),
                     ASN1Type::Time(_) => unimplemented!("rasn does not support TIME types yet!"),
                     ASN1Type::Real(_) => self.generate_number_like(t),
-                    ASN1Type::ObjectClassField(_) | ASN1Type::EmbeddedPdv | ASN1Type::External => {
-                        self.generate_any(t)
-                    }
+                    ASN1Type::ObjectClassField(_)
+                    | ASN1Type::EmbeddedPdv
+                    | ASN1Type::External
+                    | ASN1Type::UnrestrictedCharacterString
+                    | ASN1Type::Any(_) => self.generate_any(t),
                     ASN1Type::OctetString(_) => self.generate_octet_string(t),
                     ASN1Type::ObjectIdentifier(_)
+                    | ASN1Type::RelativeOid(_)
+                    | ASN1Type::OidIri(_)
+                    | ASN1Type::RelativeOidIri(_)
+                    | ASN1Type::ObjectDescriptor(_)
                     | ASN1Type::GeneralizedTime(_)
                     | ASN1Type::CharacterString(_)
                     | ASN1Type::UTCTime(_) => self.generate_string_like(t),
@@ -139,7 +145,12 @@ impl Backend for Typescript {
                     }),
                 }
             }
-            ToplevelDefinition::Value(v) => self.generate_value(v),
+            ToplevelDefinition::Value(v) => {
+                if v.parameterization.is_some() {
+                    return Ok(String::new());
+                }
+                self.generate_value(v)
+            }
             ToplevelDefinition::Macro(_) => Err(GeneratorError {
                 kind: GeneratorErrorType::NotYetInplemented,
                 details: "MACROs are currently unsupported!".to_string(),
@@ -27,7 +27,11 @@ pub fn type_to_tokens(ty: &ASN1Type) -> String {
         | ASN1Type::Time(_)
         | ASN1Type::UTCTime(_)
         | ASN1Type::GeneralizedTime(_)
-        | ASN1Type::ObjectIdentifier(_) => String::from("string"),
+        | ASN1Type::ObjectIdentifier(_)
+        | ASN1Type::RelativeOid(_)
+        | ASN1Type::OidIri(_)
+        | ASN1Type::RelativeOidIri(_)
+        | ASN1Type::ObjectDescriptor(_) => String::from("string"),
         ASN1Type::Enumerated(e) => e
             .members
             .iter()
@@ -126,6 +130,13 @@ pub fn value_to_tokens(value: &ASN1Value) -> Result<String, GeneratorError> {
             details: "Named bits should be resolved by this point!".into(),
             kind: crate::prelude::GeneratorErrorType::Unidentified,
         }),
+        ASN1Value::ParameterizedValue { identifier, .. } => Err(GeneratorError {
+            top_level_declaration: None,
+            details: format!(
+                "Parameterized value {identifier} should be instantiated by this point!"
+            ),
+            kind: crate::prelude::GeneratorErrorType::Unidentified,
+        }),
         ASN1Value::BitString(b) => {
             let value = b.chunks(8).fold(String::new(), |mut value, bits| {
                 let mut bits = bits.to_vec();
@@ -158,7 +169,7 @@ pub fn value_to_tokens(value: &ASN1Value) -> Result<String, GeneratorError> {
         }
         ASN1Value::LinkedElsewhereDefinedValue { identifier: e, .. }
         | ASN1Value::ElsewhereDeclaredValue { identifier: e, .. } => Ok(to_jer_identifier(e)),
-        ASN1Value::ObjectIdentifier(oid) => oid
+        ASN1Value::ObjectIdentifier(oid) | ASN1Value::LinkedRelativeOidValue(oid) => oid
             .0
             .iter()
             .try_fold(String::from("\""), |mut acc, arc| {
@@ -243,11 +254,14 @@ mod tests {
     fn tokenizes_values() {
         assert_eq!(value_to_tokens(&ASN1Value::Null).unwrap(), "null");
         assert_eq!(value_to_tokens(&ASN1Value::Boolean(true)).unwrap(), "true");
-        assert_eq!(value_to_tokens(&ASN1Value::Integer(123)).unwrap(), "123");
+        assert_eq!(
+            value_to_tokens(&ASN1Value::Integer(123.into())).unwrap(),
+            "123"
+        );
         assert_eq!(
             value_to_tokens(&ASN1Value::LinkedIntValue {
                 integer_type: IntegerType::Int16,
-                value: 123
+                value: 123.into()
             })
             .unwrap(),
             "123"
@@ -282,7 +296,7 @@ mod tests {
                 value_to_tokens(&ASN1Value::LinkedStructLikeValue(vec![(
                     String::from("field"),
                     ASN1Type::Integer(Integer::default()),
-                    StructLikeFieldValue::Explicit(Box::new(ASN1Value::Integer(42)))
+                    StructLikeFieldValue::Explicit(Box::new(ASN1Value::Integer(42.into())))
                 )]))
                 .unwrap()
             ),
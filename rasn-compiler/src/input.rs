@@ -242,7 +242,7 @@ impl<'a> Input<'a> {
             let line_breaks = consumed.match_indices('\n');
             let last_line_break = line_breaks.clone().next_back();
             let column = if let Some(last) = last_line_break {
-                consumed_len - last.0 + 1 // because we're 1-indexing
+                consumed_len - last.0 // columns start at 1 after the line break
             } else {
                 self.column + consumed_len
             };
@@ -433,13 +433,13 @@ mod tests {
     #[test]
     fn tracks_line_breaks() {
         let input = Input::from("test1\n  test2").slice(6..);
-        assert_eq!(input.with_line_column_and_offset(2, 2, 6), input);
+        assert_eq!(input.with_line_column_and_offset(2, 1, 6), input);
     }
 
     #[test]
     fn tracks_multiple_line_breaks() {
         let input = Input::from("test1\n  test2\n  test3").slice(14..);
-        assert_eq!(input.with_line_column_and_offset(3, 2, 14), input);
+        assert_eq!(input.with_line_column_and_offset(3, 1, 14), input);
     }
 
     #[test]
@@ -448,6 +448,6 @@ mod tests {
             "test1\n  test2\n  test3",
         ))
         .unwrap();
-        assert_eq!(remaining.with_line_column_and_offset(3, 4, 16), remaining);
+        assert_eq!(remaining.with_line_column_and_offset(3, 3, 16), remaining);
     }
 }
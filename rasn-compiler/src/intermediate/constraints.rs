@@ -5,10 +5,12 @@ use std::error::Error;
 use super::{
     error::{GrammarError, GrammarErrorType},
     information_object::{InformationObjectFields, ObjectSet},
+    utils::saturating_i128,
     ASN1Type, ASN1Value, IntegerType,
 };
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OptionalMarker();
 
 impl From<&str> for OptionalMarker {
@@ -18,9 +20,11 @@ impl From<&str> for OptionalMarker {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RangeSeperator();
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExtensionMarker();
 
 /// X.680 49.6 Constraint specification.
@@ -29,6 +33,7 @@ pub struct ExtensionMarker();
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Constraint {
     Subtype(ElementSetSpecs),
     /// A TableConstraint as specified in X.682 9.
@@ -46,16 +51,16 @@ impl Constraint {
         if let Ok((cmin, cmax, extensible)) = self.unpack_as_value_range() {
             is_extensible = is_extensible || extensible;
             if let Some(ASN1Value::Integer(i)) = cmin {
-                min = (*i).min(min);
+                min = saturating_i128(i).min(min);
             };
             if let Some(ASN1Value::Integer(i)) = cmax {
-                max = (*i).max(max);
+                max = saturating_i128(i).max(max);
             };
         } else if let Ok((val, extensible)) = self.unpack_as_strict_value() {
             is_extensible = is_extensible || extensible;
             if let ASN1Value::Integer(i) = val {
-                min = (*i).min(min);
-                max = (*i).max(max);
+                min = saturating_i128(i).min(min);
+                max = saturating_i128(i).max(max);
             };
         };
         if min > max || is_extensible {
@@ -121,6 +126,7 @@ impl Constraint {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ContentConstraint {
     /// **X.682 11.4** _The abstract value of the octet string or bit string is the encoding of an
     /// (any) abstract value of "Type" that is produced by the encoding rules that are applied to
@@ -143,6 +149,7 @@ pub enum ContentConstraint {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Parameter {
     ValueParameter(ASN1Value),
     TypeParameter(ASN1Type),
@@ -153,6 +160,7 @@ pub enum Parameter {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SetOperator {
     Intersection,
     Union,
@@ -160,6 +168,7 @@ pub enum SetOperator {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CompositeConstraint {
     pub base_constraint: Box<Constraint>,
     pub operation: Vec<(SetOperator, Box<Constraint>)>,
@@ -195,6 +204,7 @@ impl
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ComponentPresence {
     Absent,
     Present,
@@ -204,6 +214,7 @@ pub enum ComponentPresence {
 /// Representation of a component constraint used for subtyping
 /// in ASN1 specifications
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InnerTypeConstraint {
     pub is_partial: bool,
     pub constraints: Vec<NamedConstraint>,
@@ -212,6 +223,7 @@ pub struct InnerTypeConstraint {
 /// Representation of a single component within a component constraint
 /// in ASN1 specifications
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NamedConstraint {
     pub identifier: String,
     pub constraints: Vec<Constraint>,
@@ -221,6 +233,7 @@ pub struct NamedConstraint {
 /// Representation of a range constraint used for subtyping
 /// in ASN1 specifications
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValueConstraint {
     pub min_value: Option<ASN1Value>,
     pub max_value: Option<ASN1Value>,
@@ -271,6 +284,7 @@ impl From<(ASN1Value, RangeSeperator, ASN1Value, ExtensionMarker)> for ValueCons
 /// in ASN1 specifications
 /// _See: ITU-T X.682 (02/2021) 10_
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableConstraint {
     pub object_set: ObjectSet,
     pub linked_fields: Vec<RelationalConstraint>,
@@ -288,6 +302,7 @@ impl From<(ObjectSet, Option<Vec<RelationalConstraint>>)> for TableConstraint {
 /// Representation of a table's relational constraint
 /// _See: ITU-T X.682 (02/2021) 10.7_
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RelationalConstraint {
     pub field_name: String,
     /// The level is null if the field is in the outermost object set of the declaration.
@@ -307,6 +322,7 @@ impl From<(usize, &str)> for RelationalConstraint {
 /// Representation of a pattern constraint
 /// _See: ITU-T X.680 (02/2021) 51.9_
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PatternConstraint {
     pub pattern: String,
 }
@@ -322,6 +338,7 @@ impl From<&str> for PatternConstraint {
 /// Representation of a user-defined constraint
 /// _See: ITU-T X.682 (02/2021) 9_
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UserDefinedConstraint {
     pub definition: String,
 }
@@ -337,6 +354,7 @@ impl From<&str> for UserDefinedConstraint {
 /// Representation of a property settings constraint
 /// _See: ITU-T X.680 (02/2021) 51.10_
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PropertySettings {
     pub property_settings_list: Vec<PropertyAndSettingsPair>,
 }
@@ -350,6 +368,7 @@ impl From<Vec<&str>> for PropertySettings {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PropertyAndSettingsPair {
     Basic(BasicSettings),
     Date(DateSettings),
@@ -398,6 +417,7 @@ pub trait PropertySetting {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BasicSettings {
     Date,
     Time,
@@ -463,6 +483,7 @@ impl PropertySetting for DateSettings {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DateSettings {
     Century,
     Year,
@@ -499,6 +520,7 @@ impl PropertySetting for YearSettings {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum YearSettings {
     Basic,
     Proleptic,
@@ -542,6 +564,7 @@ impl PropertySetting for TimeSettings {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TimeSettings {
     Hour,
     HourMinute,
@@ -575,6 +598,7 @@ impl PropertySetting for LocalOrUtcSettings {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LocalOrUtcSettings {
     Local,
     Utc,
@@ -607,6 +631,7 @@ impl PropertySetting for IntervalTypeSettings {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IntervalTypeSettings {
     StartAndEnd,
     Duration,
@@ -638,6 +663,7 @@ impl PropertySetting for StartEndPointSettings {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StartEndPointSettings {
     Date,
     Time,
@@ -666,6 +692,7 @@ impl PropertySetting for RecurrenceSettings {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RecurrenceSettings {
     Unlimited,
     Recurrences(usize),
@@ -693,6 +720,7 @@ impl PropertySetting for MidnightSettings {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MidnightSettings {
     StartOfDay,
     EndOfDay,
@@ -701,6 +729,7 @@ pub enum MidnightSettings {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SubtypeElements {
     SingleValue {
         value: ASN1Value,
@@ -759,9 +788,12 @@ impl From<(Option<ExtensionMarker>, Vec<NamedConstraint>)> for SubtypeElements {
 /// *50.1* _In some notations a set of elements of some identified type or information object class
 /// (the governor) can be specified. In such cases, the notation "ElementSetSpec" is used._
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ElementSetSpecs {
     pub set: ElementOrSetOperation,
     pub extensible: bool,
+    /// The elements added after the extension marker, e.g. `101..200` in `(0..100, ..., 101..200)`
+    pub extension_additions: Option<ElementOrSetOperation>,
 }
 
 impl From<(ElementOrSetOperation, Option<ExtensionMarker>)> for ElementSetSpecs {
@@ -769,6 +801,7 @@ impl From<(ElementOrSetOperation, Option<ExtensionMarker>)> for ElementSetSpecs
         Self {
             set: value.0,
             extensible: value.1.is_some(),
+            extension_additions: None,
         }
     }
 }
@@ -776,12 +809,14 @@ impl From<(ElementOrSetOperation, Option<ExtensionMarker>)> for ElementSetSpecs
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ElementOrSetOperation {
     Element(SubtypeElements),
     SetOperation(SetOperation),
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetOperation {
     pub base: SubtypeElements, //TODO: Handle exclusions
     pub operator: SetOperator,
@@ -0,0 +1,569 @@
+//! The `effective_constraints` module combines the subtype constraints of an ASN.1 type
+//! into its effective constraint (X.680 §51.8). The effective constraint follows the
+//! chain of referenced types and contained subtypes, applies the constraints serially
+//! and evaluates the set arithmetic of each constraint, independently for the
+//! values, the sizes and the permitted alphabet of the type.
+//! Unlike the PER-visible constraints (X.691 §10.3), the effective constraint
+//! is independent of the encoding rules.
+use std::fmt::Display;
+
+use super::{
+    constraints::{Constraint, ElementOrSetOperation, SetOperation, SetOperator, SubtypeElements},
+    utils::saturating_i128,
+    ASN1Type, ASN1Value, CharacterStringType, DeclarationElsewhere, MAX, MIN,
+    NUMERIC_STRING_CHARSET, PRINTABLE_STRING_CHARSET,
+};
+
+/// Maximum number of type references that are followed to compute an effective constraint
+const MAX_REFERENCE_DEPTH: usize = 32;
+
+/// A set of integers, stored as ordered, disjoint and non-adjacent closed intervals.
+/// The bounds `i128::MIN` and `i128::MAX` stand for `MIN` and `MAX`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegerSet {
+    intervals: Vec<(i128, i128)>,
+}
+
+impl IntegerSet {
+    pub fn all() -> Self {
+        Self {
+            intervals: vec![(i128::MIN, i128::MAX)],
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self { intervals: vec![] }
+    }
+
+    /// The integers from `min` to `max`, where `None` stands for `MIN` or `MAX`
+    pub fn range(min: Option<i128>, max: Option<i128>) -> Self {
+        Self::new(vec![(min.unwrap_or(i128::MIN), max.unwrap_or(i128::MAX))])
+    }
+
+    pub fn single(value: i128) -> Self {
+        Self::new(vec![(value, value)])
+    }
+
+    fn new(mut intervals: Vec<(i128, i128)>) -> Self {
+        intervals.retain(|(min, max)| min <= max);
+        intervals.sort();
+        let mut merged: Vec<(i128, i128)> = Vec::with_capacity(intervals.len());
+        for (min, max) in intervals {
+            match merged.last_mut() {
+                Some((_, last_max)) if min <= last_max.saturating_add(1) => {
+                    *last_max = max.max(*last_max)
+                }
+                _ => merged.push((min, max)),
+            }
+        }
+        Self { intervals: merged }
+    }
+
+    /// The closed intervals of the set in ascending order
+    pub fn intervals(&self) -> &[(i128, i128)] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: i128) -> bool {
+        self.intervals
+            .iter()
+            .any(|(min, max)| *min <= value && value <= *max)
+    }
+
+    /// The least element, or `None` if the set is empty or unbounded below
+    pub fn min(&self) -> Option<i128> {
+        self.intervals
+            .first()
+            .map(|(min, _)| *min)
+            .filter(|min| *min != i128::MIN)
+    }
+
+    /// The greatest element, or `None` if the set is empty or unbounded above
+    pub fn max(&self) -> Option<i128> {
+        self.intervals
+            .last()
+            .map(|(_, max)| *max)
+            .filter(|max| *max != i128::MAX)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new([self.intervals.clone(), other.intervals.clone()].concat())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        for (min, max) in &self.intervals {
+            for (other_min, other_max) in &other.intervals {
+                intervals.push((*min.max(other_min), *max.min(other_max)));
+            }
+        }
+        Self::new(intervals)
+    }
+
+    /// The elements of `self` that are not elements of `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+
+    fn complement(&self) -> Self {
+        let mut intervals = vec![];
+        let mut next = Some(i128::MIN);
+        for (min, max) in &self.intervals {
+            if let Some(next) = next.filter(|next| next < min) {
+                intervals.push((next, min - 1));
+            }
+            next = max.checked_add(1);
+        }
+        if let Some(next) = next {
+            intervals.push((next, i128::MAX));
+        }
+        Self::new(intervals)
+    }
+}
+
+impl Display for IntegerSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "{{}}");
+        }
+        let bound = |b: i128| match b {
+            i128::MIN => String::from(MIN),
+            i128::MAX => String::from(MAX),
+            b => b.to_string(),
+        };
+        let intervals = self
+            .intervals
+            .iter()
+            .map(|(min, max)| {
+                if min == max {
+                    bound(*min)
+                } else {
+                    format!("{}..{}", bound(*min), bound(*max))
+                }
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", intervals.join(" | "))
+    }
+}
+
+/// The effective constraint of an ASN.1 type, i.e. the values, sizes and characters
+/// that the root of its combined constraints permits.
+/// `None` stands for an unconstrained dimension.
+/// Dimensions are evaluated independently, so a union of constraints on different
+/// dimensions, such as `(SIZE (1) | FROM ("a"))`, is approximated by a superset.
+///
+/// ### Example
+/// ```
+/// # use rasn_compiler::prelude::ir::*;
+/// let ty = ASN1Type::Integer(Integer {
+///     constraints: vec![Constraint::Subtype(ElementSetSpecs {
+///         set: ElementOrSetOperation::SetOperation(SetOperation {
+///             base: SubtypeElements::ValueRange {
+///                 min: Some(ASN1Value::Integer(0.into())),
+///                 max: Some(ASN1Value::Integer(10.into())),
+///                 extensible: false,
+///             },
+///             operator: SetOperator::Union,
+///             operant: Box::new(ElementOrSetOperation::Element(SubtypeElements::SingleValue {
+///                 value: ASN1Value::Integer(20.into()),
+///                 extensible: false,
+///             })),
+///         }),
+///         extensible: false,
+///         extension_additions: None,
+///     })],
+///     distinguished_values: None,
+/// });
+/// let effective = EffectiveConstraint::new(&ty, &|_| None);
+/// assert_eq!(effective.values.unwrap().to_string(), "0..10 | 20");
+/// assert!(effective.exact);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectiveConstraint {
+    /// Permitted values of `INTEGER` types
+    pub values: Option<IntegerSet>,
+    /// Permitted sizes of string and collection types
+    pub size: Option<IntegerSet>,
+    /// Code points of the permitted characters of character string types
+    pub alphabet: Option<IntegerSet>,
+    /// `true` if the last serially applied constraint is extensible
+    pub extensible: bool,
+    /// `false` if parts of the constraints could not be evaluated,
+    /// in which case the permitted sets are supersets of the actual ones
+    pub exact: bool,
+}
+
+impl Default for EffectiveConstraint {
+    fn default() -> Self {
+        Self {
+            values: None,
+            size: None,
+            alphabet: None,
+            extensible: false,
+            exact: true,
+        }
+    }
+}
+
+impl EffectiveConstraint {
+    /// Computes the effective constraint of a type.
+    /// `resolve` looks up the types of type references by name.
+    pub fn new<'a>(ty: &'a ASN1Type, resolve: &dyn Fn(&str) -> Option<&'a ASN1Type>) -> Self {
+        Self::of_type(ty, resolve, 0)
+    }
+
+    /// Computes the effective constraint of a list of serially applied constraints
+    /// on an otherwise unconstrained type.
+    pub fn from_constraints<'a>(
+        constraints: &'a [Constraint],
+        resolve: &dyn Fn(&str) -> Option<&'a ASN1Type>,
+    ) -> Self {
+        constraints
+            .iter()
+            .fold(Self::default(), |effective, constraint| {
+                effective.serially(constraint, resolve, 0)
+            })
+    }
+
+    /// Applies a further constraint to the effective constraint,
+    /// e.g. the constraint of a `SEQUENCE` component on the effective constraint of its type.
+    pub fn constrain<'a>(
+        self,
+        constraints: &'a [Constraint],
+        resolve: &dyn Fn(&str) -> Option<&'a ASN1Type>,
+    ) -> Self {
+        constraints.iter().fold(self, |effective, constraint| {
+            effective.serially(constraint, resolve, 0)
+        })
+    }
+
+    /// `true` if the effective constraint permits no value at all
+    pub fn is_empty(&self) -> bool {
+        [&self.values, &self.size, &self.alphabet]
+            .into_iter()
+            .flatten()
+            .any(IntegerSet::is_empty)
+    }
+
+    /// `true` if the constraint permits the integer
+    pub fn permits_integer(&self, value: i128) -> bool {
+        self.values.as_ref().is_none_or(|v| v.contains(value))
+    }
+
+    /// `true` if the constraint permits a string, or a value of the given size
+    pub fn permits_size(&self, size: usize) -> bool {
+        self.size.as_ref().is_none_or(|s| s.contains(size as i128))
+    }
+
+    /// `true` if the constraint permits all characters of the string
+    /// and the number of its characters
+    pub fn permits_string(&self, string: &str) -> bool {
+        self.permits_size(string.chars().count())
+            && self
+                .alphabet
+                .as_ref()
+                .is_none_or(|a| string.chars().all(|c| a.contains(c as i128)))
+    }
+
+    fn of_type<'a>(
+        ty: &'a ASN1Type,
+        resolve: &dyn Fn(&str) -> Option<&'a ASN1Type>,
+        depth: usize,
+    ) -> Self {
+        let base = match ty {
+            ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+                parent: None,
+                identifier,
+                ..
+            }) => match resolve(identifier) {
+                Some(referenced) if depth < MAX_REFERENCE_DEPTH => {
+                    Self::of_type(referenced, resolve, depth + 1)
+                }
+                _ => Self::inexact(),
+            },
+            ASN1Type::ElsewhereDeclaredType(_) => Self::inexact(),
+            ASN1Type::CharacterString(c) => Self {
+                alphabet: character_set(c.ty),
+                ..Default::default()
+            },
+            _ => Self::default(),
+        };
+        ty.constraints()
+            .into_iter()
+            .flatten()
+            .fold(base, |effective, constraint| {
+                effective.serially(constraint, resolve, depth)
+            })
+    }
+
+    fn inexact() -> Self {
+        Self {
+            exact: false,
+            ..Default::default()
+        }
+    }
+
+    /// Applies a constraint serially. Only subtype constraints restrict the permitted sets,
+    /// and the extensibility of a serially constrained type is the one of the last constraint.
+    fn serially<'a>(
+        self,
+        constraint: &'a Constraint,
+        resolve: &dyn Fn(&str) -> Option<&'a ASN1Type>,
+        depth: usize,
+    ) -> Self {
+        match constraint {
+            Constraint::Subtype(set) => {
+                let mut next = element_set(&set.set, false, resolve, depth);
+                next.extensible |= set.extensible;
+                Self {
+                    extensible: next.extensible,
+                    ..self.intersection(next)
+                }
+            }
+            Constraint::Parameter(_) => Self {
+                exact: false,
+                ..self
+            },
+            Constraint::Table(_) | Constraint::Content(_) => self,
+        }
+    }
+
+    fn intersection(self, other: Self) -> Self {
+        let intersect = |a: Option<IntegerSet>, b: Option<IntegerSet>| match (a, b) {
+            (Some(a), Some(b)) => Some(a.intersection(&b)),
+            (a, b) => a.or(b),
+        };
+        Self {
+            values: intersect(self.values, other.values),
+            size: intersect(self.size, other.size),
+            alphabet: intersect(self.alphabet, other.alphabet),
+            extensible: self.extensible || other.extensible,
+            exact: self.exact && other.exact,
+        }
+    }
+
+    fn union(self, other: Self) -> Self {
+        let restricted = |c: &Self| [c.values.is_some(), c.size.is_some(), c.alphabet.is_some()];
+        let single_dimension = restricted(&self) == restricted(&other)
+            && restricted(&self).iter().filter(|r| **r).count() == 1;
+        let unite = |a: Option<IntegerSet>, b: Option<IntegerSet>| Some(a?.union(&b?));
+        Self {
+            exact: self.exact
+                && other.exact
+                && (single_dimension || self.is_unrestricted() || other.is_unrestricted()),
+            values: unite(self.values, other.values),
+            size: unite(self.size, other.size),
+            alphabet: unite(self.alphabet, other.alphabet),
+            extensible: self.extensible || other.extensible,
+        }
+    }
+
+    fn difference(self, other: Self) -> Self {
+        let restrictions = [&other.values, &other.size, &other.alphabet]
+            .into_iter()
+            .flatten()
+            .count();
+        if !other.exact || restrictions != 1 {
+            return Self {
+                exact: false,
+                ..self
+            };
+        }
+        let subtract = |a: Option<IntegerSet>, b: Option<IntegerSet>| match b {
+            Some(b) => Some(a.unwrap_or_else(IntegerSet::all).difference(&b)),
+            None => a,
+        };
+        Self {
+            values: subtract(self.values, other.values),
+            size: subtract(self.size, other.size),
+            alphabet: subtract(self.alphabet, other.alphabet),
+            extensible: self.extensible || other.extensible,
+            exact: self.exact,
+        }
+    }
+
+    fn is_unrestricted(&self) -> bool {
+        self.values.is_none() && self.size.is_none() && self.alphabet.is_none()
+    }
+}
+
+/// Evaluates a set of subtype elements. In an alphabet, values denote characters,
+/// and their permitted code points are stored as `values`.
+fn element_set<'a>(
+    set: &'a ElementOrSetOperation,
+    alphabet: bool,
+    resolve: &dyn Fn(&str) -> Option<&'a ASN1Type>,
+    depth: usize,
+) -> EffectiveConstraint {
+    match set {
+        ElementOrSetOperation::Element(e) => element(e, alphabet, resolve, depth),
+        ElementOrSetOperation::SetOperation(SetOperation {
+            base,
+            operator,
+            operant,
+        }) => {
+            let base = element(base, alphabet, resolve, depth);
+            let operant = element_set(operant, alphabet, resolve, depth);
+            match operator {
+                SetOperator::Union => base.union(operant),
+                SetOperator::Intersection => base.intersection(operant),
+                SetOperator::Except => base.difference(operant),
+            }
+        }
+    }
+}
+
+fn element<'a>(
+    element: &'a SubtypeElements,
+    alphabet: bool,
+    resolve: &dyn Fn(&str) -> Option<&'a ASN1Type>,
+    depth: usize,
+) -> EffectiveConstraint {
+    let values = |values: Option<IntegerSet>| match values {
+        Some(values) => EffectiveConstraint {
+            values: Some(values),
+            ..Default::default()
+        },
+        None => EffectiveConstraint::inexact(),
+    };
+    let mut effective = match element {
+        SubtypeElements::SingleValue { value, .. } if alphabet => values(
+            string(value)
+                .map(|s| IntegerSet::new(s.chars().map(|c| (c as i128, c as i128)).collect())),
+        ),
+        SubtypeElements::SingleValue { value, .. } => {
+            values(integer(value).map(IntegerSet::single))
+        }
+        SubtypeElements::ValueRange { min, max, .. } => {
+            let bound = |bound: &Option<ASN1Value>| match bound {
+                None => Some(None),
+                Some(value) if alphabet => character(value).map(Some),
+                Some(value) => integer(value).map(Some),
+            };
+            values(
+                bound(min)
+                    .zip(bound(max))
+                    .map(|(min, max)| IntegerSet::range(min, max)),
+            )
+        }
+        SubtypeElements::SizeConstraint(size) if !alphabet => {
+            let size = element_set(size, false, resolve, depth);
+            EffectiveConstraint {
+                exact: size.exact && size.size.is_none() && size.alphabet.is_none(),
+                size: size.values,
+                extensible: size.extensible,
+                ..Default::default()
+            }
+        }
+        SubtypeElements::PermittedAlphabet(characters) if !alphabet => {
+            let characters = element_set(characters, true, resolve, depth);
+            EffectiveConstraint {
+                exact: characters.exact,
+                alphabet: characters.values,
+                extensible: characters.extensible,
+                ..Default::default()
+            }
+        }
+        SubtypeElements::ContainedSubtype { subtype, .. } if depth < MAX_REFERENCE_DEPTH => {
+            let contained = EffectiveConstraint::of_type(subtype, resolve, depth + 1);
+            if alphabet {
+                // A contained character string type contributes its characters
+                values(contained.alphabet)
+            } else {
+                EffectiveConstraint {
+                    extensible: false,
+                    ..contained
+                }
+            }
+        }
+        _ => EffectiveConstraint::inexact(),
+    };
+    if let SubtypeElements::SingleValue {
+        extensible: true, ..
+    }
+    | SubtypeElements::ValueRange {
+        extensible: true, ..
+    }
+    | SubtypeElements::ContainedSubtype {
+        extensible: true, ..
+    } = element
+    {
+        effective.extensible = true;
+    }
+    effective
+}
+
+fn integer(value: &ASN1Value) -> Option<i128> {
+    match value {
+        ASN1Value::Integer(i) | ASN1Value::LinkedIntValue { value: i, .. } => {
+            Some(saturating_i128(i))
+        }
+        ASN1Value::LinkedNestedValue { value, .. } => integer(value),
+        _ => None,
+    }
+}
+
+fn string(value: &ASN1Value) -> Option<&str> {
+    match value {
+        ASN1Value::String(s) | ASN1Value::LinkedCharStringValue(_, s) => Some(s),
+        ASN1Value::LinkedNestedValue { value, .. } => string(value),
+        _ => None,
+    }
+}
+
+fn character(value: &ASN1Value) -> Option<i128> {
+    let mut chars = string(value)?.chars();
+    chars
+        .next()
+        .filter(|_| chars.next().is_none())
+        .map(|c| c as i128)
+}
+
+/// Code points of the characters of a character string type,
+/// or `None` if the type does not restrict its characters to a known set
+pub fn character_set(ty: CharacterStringType) -> Option<IntegerSet> {
+    let characters =
+        |chars: &[char]| IntegerSet::new(chars.iter().map(|c| (*c as i128, *c as i128)).collect());
+    match ty {
+        CharacterStringType::NumericString => Some(characters(&NUMERIC_STRING_CHARSET)),
+        CharacterStringType::PrintableString => Some(characters(&PRINTABLE_STRING_CHARSET)),
+        CharacterStringType::VisibleString => Some(IntegerSet::range(Some(0x20), Some(0x7E))),
+        CharacterStringType::IA5String => Some(IntegerSet::range(Some(0), Some(0x7F))),
+        CharacterStringType::BMPString => Some(IntegerSet::range(Some(0), Some(0xFFFF))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_integer_sets() {
+        let set = IntegerSet::range(Some(0), Some(10)).union(&IntegerSet::single(11));
+        assert_eq!(set.intervals(), &[(0, 11)]);
+        let set = set.difference(&IntegerSet::range(Some(3), Some(5)));
+        assert_eq!(set.to_string(), "0..2 | 6..11");
+        assert_eq!(
+            set.intersection(&IntegerSet::range(Some(8), None))
+                .to_string(),
+            "8..11"
+        );
+        assert_eq!(
+            IntegerSet::range(None, Some(-1)).difference(&IntegerSet::all()),
+            IntegerSet::empty()
+        );
+        assert_eq!(
+            IntegerSet::single(5)
+                .difference(&IntegerSet::single(5))
+                .to_string(),
+            "{}"
+        );
+        assert_eq!(IntegerSet::range(Some(1), None).to_string(), "1..MAX");
+        assert_eq!(IntegerSet::range(Some(1), None).max(), None);
+    }
+}
@@ -123,7 +123,7 @@ impl PerVisibleAlphabetConstraints {
                         .chars()
                         .map(|c| find_char_index(&string_type.character_set(), c).map(|i| (i, c)))
                         .collect::<Result<Vec<(usize, char)>, _>>()?;
-                    char_subset.sort_by(|(a, _), (b, _)| a.cmp(b));
+                    char_subset.sort_by_key(|(a, _)| *a);
                     Ok(Some(PerVisibleAlphabetConstraints {
                         string_type,
                         character_by_index: char_subset
@@ -559,13 +559,13 @@ fn fold_constraint_set(
                             &format!(
                                 "Empty intersection result for {:?} and {:?}",
                                 v1,
-                                ASN1Value::Integer(*i2)
+                                ASN1Value::Integer(i2.clone())
                             ),
                             GrammarErrorType::UnpackingError,
                         ))
                     } else {
                         Ok(Some(SubtypeElements::SingleValue {
-                            value: ASN1Value::Integer(*i2),
+                            value: ASN1Value::Integer(i2.clone()),
                             extensible: *x1 || *x2,
                         }))
                     }
@@ -699,8 +699,8 @@ fn fold_constraint_set(
                 | (ASN1Value::Integer(_), ASN1Value::String(_)) => Ok(None),
                 (ASN1Value::Integer(v1_int), ASN1Value::Integer(v2_int)) => {
                     Ok(Some(SubtypeElements::ValueRange {
-                        min: Some(ASN1Value::Integer(*v2_int.min(v1_int))),
-                        max: Some(ASN1Value::Integer(*v2_int.max(v1_int))),
+                        min: Some(ASN1Value::Integer(v2_int.min(v1_int).clone())),
+                        max: Some(ASN1Value::Integer(v2_int.max(v1_int).clone())),
                         extensible: *x1 || x2,
                     }))
                 }
@@ -836,7 +836,7 @@ fn intersect_single_and_range(
             }))
         }
         (ASN1Value::Integer(v), _, _, extensible, _) => Ok(Some(SubtypeElements::SingleValue {
-            value: ASN1Value::Integer(*v),
+            value: ASN1Value::Integer(v.clone()),
             extensible,
         })),
         (_, _, _, true, _) => Ok(None),
@@ -936,6 +936,7 @@ mod tests {
             PerVisibleAlphabetConstraints::try_new(
                 &Constraint::Subtype(ElementSetSpecs {
                     extensible: false,
+                    extension_additions: None,
                     set: ElementOrSetOperation::Element(SubtypeElements::SingleValue {
                         value: ASN1Value::String("ABCDEF".to_owned()),
                         extensible: false
@@ -965,6 +966,7 @@ mod tests {
             PerVisibleAlphabetConstraints::try_new(
                 &Constraint::Subtype(ElementSetSpecs {
                     extensible: false,
+                    extension_additions: None,
                     set: ElementOrSetOperation::Element(SubtypeElements::SingleValue {
                         value: ASN1Value::String("132".to_owned()),
                         extensible: false
@@ -993,6 +995,7 @@ mod tests {
             PerVisibleAlphabetConstraints::try_new(
                 &Constraint::Subtype(ElementSetSpecs {
                     extensible: false,
+                    extension_additions: None,
                     set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
                         min: Some(ASN1Value::String("A".to_owned())),
                         max: Some(ASN1Value::String("F".to_owned())),
@@ -1019,6 +1022,7 @@ mod tests {
             PerVisibleAlphabetConstraints::try_new(
                 &Constraint::Subtype(ElementSetSpecs {
                     extensible: false,
+                    extension_additions: None,
                     set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
                         min: None,
                         max: Some(ASN1Value::String("3".to_owned())),
@@ -1215,13 +1219,13 @@ mod tests {
             fold_constraint_set(
                 &SetOperation {
                     base: SubtypeElements::SingleValue {
-                        value: ASN1Value::Integer(4),
+                        value: ASN1Value::Integer(4.into()),
                         extensible: false
                     },
                     operator: SetOperator::Intersection,
                     operant: Box::new(ElementOrSetOperation::Element(
                         SubtypeElements::SingleValue {
-                            value: ASN1Value::Integer(4),
+                            value: ASN1Value::Integer(4.into()),
                             extensible: true
                         }
                     ))
@@ -1231,7 +1235,7 @@ mod tests {
             .unwrap()
             .unwrap(),
             SubtypeElements::SingleValue {
-                value: ASN1Value::Integer(4),
+                value: ASN1Value::Integer(4.into()),
                 extensible: true
             }
         );
@@ -1243,14 +1247,14 @@ mod tests {
             fold_constraint_set(
                 &SetOperation {
                     base: SubtypeElements::ValueRange {
-                        min: Some(ASN1Value::Integer(-1)),
-                        max: Some(ASN1Value::Integer(3)),
+                        min: Some(ASN1Value::Integer((-1).into())),
+                        max: Some(ASN1Value::Integer(3.into())),
                         extensible: false
                     },
                     operator: SetOperator::Intersection,
                     operant: Box::new(ElementOrSetOperation::Element(
                         SubtypeElements::SingleValue {
-                            value: ASN1Value::Integer(2),
+                            value: ASN1Value::Integer(2.into()),
                             extensible: false
                         }
                     ))
@@ -1260,7 +1264,7 @@ mod tests {
             .unwrap()
             .unwrap(),
             SubtypeElements::SingleValue {
-                value: ASN1Value::Integer(2),
+                value: ASN1Value::Integer(2.into()),
                 extensible: false
             }
         );
@@ -1268,14 +1272,14 @@ mod tests {
             fold_constraint_set(
                 &SetOperation {
                     base: SubtypeElements::ValueRange {
-                        min: Some(ASN1Value::Integer(-1)),
-                        max: Some(ASN1Value::Integer(5)),
+                        min: Some(ASN1Value::Integer((-1).into())),
+                        max: Some(ASN1Value::Integer(5.into())),
                         extensible: false
                     },
                     operator: SetOperator::Union,
                     operant: Box::new(ElementOrSetOperation::Element(
                         SubtypeElements::SingleValue {
-                            value: ASN1Value::Integer(-3),
+                            value: ASN1Value::Integer((-3).into()),
                             extensible: false
                         }
                     ))
@@ -1285,8 +1289,8 @@ mod tests {
             .unwrap()
             .unwrap(),
             SubtypeElements::ValueRange {
-                min: Some(ASN1Value::Integer(-3)),
-                max: Some(ASN1Value::Integer(5)),
+                min: Some(ASN1Value::Integer((-3).into())),
+                max: Some(ASN1Value::Integer(5.into())),
                 extensible: false
             }
         )
@@ -1298,15 +1302,15 @@ mod tests {
             fold_constraint_set(
                 &SetOperation {
                     base: SubtypeElements::ValueRange {
-                        min: Some(ASN1Value::Integer(-2)),
-                        max: Some(ASN1Value::Integer(3)),
+                        min: Some(ASN1Value::Integer((-2).into())),
+                        max: Some(ASN1Value::Integer(3.into())),
                         extensible: false
                     },
                     operator: SetOperator::Intersection,
                     operant: Box::new(ElementOrSetOperation::Element(
                         SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(-5)),
-                            max: Some(ASN1Value::Integer(1)),
+                            min: Some(ASN1Value::Integer((-5).into())),
+                            max: Some(ASN1Value::Integer(1.into())),
                             extensible: false
                         }
                     ))
@@ -1316,8 +1320,8 @@ mod tests {
             .unwrap()
             .unwrap(),
             SubtypeElements::ValueRange {
-                min: Some(ASN1Value::Integer(-2)),
-                max: Some(ASN1Value::Integer(1)),
+                min: Some(ASN1Value::Integer((-2).into())),
+                max: Some(ASN1Value::Integer(1.into())),
                 extensible: false
             }
         );
@@ -1325,15 +1329,15 @@ mod tests {
             fold_constraint_set(
                 &SetOperation {
                     base: SubtypeElements::ValueRange {
-                        min: Some(ASN1Value::Integer(-2)),
-                        max: Some(ASN1Value::Integer(3)),
+                        min: Some(ASN1Value::Integer((-2).into())),
+                        max: Some(ASN1Value::Integer(3.into())),
                         extensible: false
                     },
                     operator: SetOperator::Union,
                     operant: Box::new(ElementOrSetOperation::Element(
                         SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(-1)),
-                            max: Some(ASN1Value::Integer(5)),
+                            min: Some(ASN1Value::Integer((-1).into())),
+                            max: Some(ASN1Value::Integer(5.into())),
                             extensible: false
                         }
                     ))
@@ -1343,8 +1347,8 @@ mod tests {
             .unwrap()
             .unwrap(),
             SubtypeElements::ValueRange {
-                min: Some(ASN1Value::Integer(-2)),
-                max: Some(ASN1Value::Integer(5)),
+                min: Some(ASN1Value::Integer((-2).into())),
+                max: Some(ASN1Value::Integer(5.into())),
                 extensible: false
             }
         )
@@ -1354,7 +1358,7 @@ mod tests {
     fn folds_single_value_mixed_constraints() {
         let set_op = |op: SetOperator| SetOperation {
             base: SubtypeElements::SingleValue {
-                value: ASN1Value::Integer(4),
+                value: ASN1Value::Integer(4.into()),
                 extensible: false,
             },
             operator: op,
@@ -1370,7 +1374,7 @@ mod tests {
                 .unwrap()
                 .unwrap(),
             SubtypeElements::SingleValue {
-                value: ASN1Value::Integer(4),
+                value: ASN1Value::Integer(4.into()),
                 extensible: false
             }
         );
@@ -1404,8 +1408,8 @@ mod tests {
     fn folds_range_value_mixed_constraints() {
         let set_op = |op| SetOperation {
             base: SubtypeElements::ValueRange {
-                min: Some(ASN1Value::Integer(-1)),
-                max: Some(ASN1Value::Integer(3)),
+                min: Some(ASN1Value::Integer((-1).into())),
+                max: Some(ASN1Value::Integer(3.into())),
                 extensible: false,
             },
             operator: op,
@@ -1441,8 +1445,8 @@ mod tests {
                 .unwrap()
                 .unwrap(),
             SubtypeElements::ValueRange {
-                min: Some(ASN1Value::Integer(-1)),
-                max: Some(ASN1Value::Integer(3)),
+                min: Some(ASN1Value::Integer((-1).into())),
+                max: Some(ASN1Value::Integer(3.into())),
                 extensible: false,
             }
         );
@@ -1456,8 +1460,8 @@ mod tests {
     fn folds_range_values_mixed_constraints() {
         let set_op = |op| SetOperation {
             base: SubtypeElements::ValueRange {
-                min: Some(ASN1Value::Integer(-2)),
-                max: Some(ASN1Value::Integer(3)),
+                min: Some(ASN1Value::Integer((-2).into())),
+                max: Some(ASN1Value::Integer(3.into())),
                 extensible: false,
             },
             operator: op,
@@ -1487,8 +1491,8 @@ mod tests {
                 .unwrap()
                 .unwrap(),
             SubtypeElements::ValueRange {
-                min: Some(ASN1Value::Integer(-2)),
-                max: Some(ASN1Value::Integer(3)),
+                min: Some(ASN1Value::Integer((-2).into())),
+                max: Some(ASN1Value::Integer(3.into())),
                 extensible: false,
             }
         );
@@ -3,11 +3,17 @@ use std::{
     fmt::{Display, Formatter, Result},
 };
 
+use crate::diagnostic::{Severity, Span};
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct GrammarError {
     pub details: String,
     pub kind: GrammarErrorType,
     pub pdu: Option<String>,
+    /// Errors reject the definition that they concern, while warnings leave it in place.
+    pub severity: Severity,
+    /// Location of the definition that the error concerns
+    pub span: Option<Span>,
 }
 
 impl GrammarError {
@@ -16,6 +22,8 @@ impl GrammarError {
             details: data_details.into(),
             kind,
             pdu: None,
+            severity: Severity::Error,
+            span: None,
         }
     }
 
@@ -24,9 +32,17 @@ impl GrammarError {
             details: "Not yet implemented!".into(),
             kind: GrammarErrorType::NotYetInplemented,
             pdu: None,
+            severity: Severity::Error,
+            span: None,
         }
     }
 
+    /// Reports the error with the given severity.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
     pub fn contextualize(&mut self, pdu: &str) {
         self.pdu = Some(pdu.into());
     }
@@ -10,13 +10,17 @@ use super::{constraints::*, *};
 ///
 /// **X.683 9.2** _Referencing parameterized definitions: ParameterizedObjectClassAssignment._
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectClassAssignment {
     pub comments: String,
+    /// Comment on the same line after the assignment
+    pub trailing_comment: Option<String>,
     /// A objectclassreference.
     pub name: String,
     pub parameterization: Parameterization,
     pub definition: ObjectClassDefn,
-    pub module_header: Option<Rc<RefCell<ModuleHeader>>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub module_header: Option<SharedModuleHeader>,
 }
 
 impl ObjectClassAssignment {
@@ -26,19 +30,24 @@ impl ObjectClassAssignment {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ToplevelInformationDefinition {
     pub comments: String,
+    /// Comment on the same line after the assignment
+    pub trailing_comment: Option<String>,
     pub name: String,
     pub parameterization: Option<Parameterization>,
     pub class: ClassLink,
     pub value: ASN1Information,
-    pub module_header: Option<Rc<RefCell<ModuleHeader>>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub module_header: Option<SharedModuleHeader>,
 }
 
 impl From<(&str, ASN1Information, &str)> for ToplevelInformationDefinition {
     fn from(value: (&str, ASN1Information, &str)) -> Self {
         Self {
             comments: String::new(),
+            trailing_comment: None,
             name: value.0.to_owned(),
             parameterization: None,
             class: ClassLink::ByName(value.2.to_owned()),
@@ -51,9 +60,13 @@ impl From<(&str, ASN1Information, &str)> for ToplevelInformationDefinition {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ClassLink {
     ByName(String),
     ByReference(ObjectClassDefn),
+    /// A reference to a parameterized class with its actual parameters, such as `CLASS-NAME { INTEGER }`.
+    /// The validator replaces it by a reference to the instance of the class.
+    ByInstantiation(String, Vec<Parameter>),
 }
 
 impl ToplevelInformationDefinition {
@@ -67,7 +80,7 @@ impl
         Vec<&str>,
         &str,
         Option<Parameterization>,
-        &str,
+        ClassLink,
         InformationObjectFields,
     )> for ToplevelInformationDefinition
 {
@@ -76,17 +89,22 @@ impl
             Vec<&str>,
             &str,
             Option<Parameterization>,
-            &str,
+            ClassLink,
             InformationObjectFields,
         ),
     ) -> Self {
+        let class_name = match &value.3 {
+            ClassLink::ByName(name) | ClassLink::ByInstantiation(name, _) => name.clone(),
+            ClassLink::ByReference(_) => String::new(),
+        };
         Self {
             comments: value.0.join("\n"),
+            trailing_comment: None,
             name: value.1.into(),
-            class: ClassLink::ByName(value.3.into()),
+            class: value.3,
             parameterization: value.2,
             value: ASN1Information::Object(InformationObject {
-                class_name: value.3.into(),
+                class_name,
                 fields: value.4,
             }),
             module_header: None,
@@ -94,15 +112,30 @@ impl
     }
 }
 
-impl From<(Vec<&str>, &str, Option<Parameterization>, &str, ObjectSet)>
-    for ToplevelInformationDefinition
+impl
+    From<(
+        Vec<&str>,
+        &str,
+        Option<Parameterization>,
+        ClassLink,
+        ObjectSet,
+    )> for ToplevelInformationDefinition
 {
-    fn from(value: (Vec<&str>, &str, Option<Parameterization>, &str, ObjectSet)) -> Self {
+    fn from(
+        value: (
+            Vec<&str>,
+            &str,
+            Option<Parameterization>,
+            ClassLink,
+            ObjectSet,
+        ),
+    ) -> Self {
         Self {
             comments: value.0.join("\n"),
+            trailing_comment: None,
             name: value.1.into(),
             parameterization: value.2,
-            class: ClassLink::ByName(value.3.into()),
+            class: value.3,
             value: ASN1Information::ObjectSet(value.4),
             module_header: None,
         }
@@ -113,6 +146,7 @@ impl From<(Vec<&str>, &str, Option<Parameterization>, &str, ObjectSet)>
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ASN1Information {
     ObjectSet(ObjectSet),
     Object(InformationObject),
@@ -121,6 +155,7 @@ pub enum ASN1Information {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SyntaxExpression {
     Required(SyntaxToken),
     Optional(Vec<SyntaxExpression>),
@@ -129,6 +164,7 @@ pub enum SyntaxExpression {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SyntaxApplication {
     ObjectSetDeclaration(ObjectSet),
     ValueReference(ASN1Value),
@@ -233,6 +269,7 @@ impl SyntaxApplication {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SyntaxToken {
     Literal(String),
     Comma,
@@ -274,6 +311,7 @@ impl From<&str> for SyntaxToken {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InformationObjectSyntax {
     pub expressions: Vec<SyntaxExpression>,
 }
@@ -313,6 +351,7 @@ impl InformationObjectSyntax {
 /// Allows the definer to provide the field specifications, and optionally a syntax list. The
 /// definer may also specify semantics associated with the definition of the class.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectClassDefn {
     /// Named field specifications, as defined in 9.4.
     pub fields: Vec<InformationObjectClassField>,
@@ -342,6 +381,7 @@ impl
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InformationObjectClassField {
     pub identifier: ObjectFieldIdentifier,
     pub ty: Option<ASN1Type>,
@@ -377,6 +417,7 @@ impl
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ObjectFieldIdentifier {
     SingleValue(String),
     MultipleValue(String),
@@ -392,6 +433,7 @@ impl ObjectFieldIdentifier {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InformationObject {
     pub class_name: String,
     pub fields: InformationObjectFields,
@@ -400,6 +442,7 @@ pub struct InformationObject {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InformationObjectFields {
     DefaultSyntax(Vec<InformationObjectField>),
     CustomSyntax(Vec<SyntaxApplication>),
@@ -408,9 +451,13 @@ pub enum InformationObjectFields {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ObjectSetValue {
     Reference(String),
     Inline(InformationObjectFields),
+    /// A reference to a parameterized object set with its actual parameters, such as `Set { object }`.
+    /// The validator replaces it by a reference to the instance of the object set.
+    Instantiation(String, Vec<Parameter>),
 }
 
 impl From<&str> for ObjectSetValue {
@@ -426,6 +473,7 @@ impl From<InformationObjectFields> for ObjectSetValue {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectSet {
     pub values: Vec<ObjectSetValue>,
     pub extensible: Option<usize>,
@@ -457,6 +505,7 @@ impl
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InformationObjectField {
     TypeField(TypeField),
     FixedValueField(FixedValueField),
@@ -475,6 +524,7 @@ impl InformationObjectField {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FixedValueField {
     pub identifier: String,
     pub value: ASN1Value,
@@ -490,6 +540,7 @@ impl From<(ObjectFieldIdentifier, ASN1Value)> for InformationObjectField {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TypeField {
     pub identifier: String,
     pub ty: ASN1Type,
@@ -505,6 +556,7 @@ impl From<(ObjectFieldIdentifier, ASN1Type)> for InformationObjectField {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectSetField {
     pub identifier: String,
     pub value: ObjectSet,
@@ -523,6 +575,7 @@ impl From<(ObjectFieldIdentifier, ObjectSet)> for InformationObjectField {
 /// _The type that is referenced by this notation depends on the category of the field name. For
 /// the different categories of field names, 14.2 to 14.5 specify the type that is referenced._
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectClassFieldType {
     pub class: String,
     pub field_path: Vec<ObjectFieldIdentifier>,
@@ -543,12 +596,33 @@ impl ObjectClassFieldType {
     }
 }
 
-impl From<(&str, Vec<ObjectFieldIdentifier>, Option<Vec<Constraint>>)> for ObjectClassFieldType {
-    fn from(value: (&str, Vec<ObjectFieldIdentifier>, Option<Vec<Constraint>>)) -> Self {
+impl
+    From<(
+        &str,
+        Option<Vec<Parameter>>,
+        Vec<ObjectFieldIdentifier>,
+        Option<Vec<Constraint>>,
+    )> for ObjectClassFieldType
+{
+    /// The actual parameters of a parameterized class are kept as a [Constraint::Parameter],
+    /// as they are for references to parameterized types.
+    fn from(
+        value: (
+            &str,
+            Option<Vec<Parameter>>,
+            Vec<ObjectFieldIdentifier>,
+            Option<Vec<Constraint>>,
+        ),
+    ) -> Self {
         Self {
             class: value.0.into(),
-            field_path: value.1,
-            constraints: value.2.unwrap_or_default(),
+            field_path: value.2,
+            constraints: value
+                .1
+                .map(Constraint::Parameter)
+                .into_iter()
+                .chain(value.3.unwrap_or_default())
+                .collect(),
         }
     }
 }
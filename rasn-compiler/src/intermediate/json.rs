@@ -0,0 +1,140 @@
+//! The `json` module exports the intermediate representation as JSON, for tooling
+//! that is not written in Rust. It is available with the `serde` feature.
+//!
+//! # Schema
+//! The export is a single object with the schema version and the exported modules:
+//! ```json
+//! {
+//!   "version": 1,
+//!   "modules": [
+//!     {
+//!       "header": { "name": "Module-A", "imports": [], "exports": "All", ... },
+//!       "definitions": [
+//!         { "Type": { "name": "Small", "ty": { "Integer": { ... } }, ... } }
+//!       ]
+//!     }
+//!   ]
+//! }
+//! ```
+//! * `version` is [`IR_JSON_VERSION`], which is incremented on every change of the schema
+//!   that is not backwards compatible.
+//! * Every module header is exported exactly once, in `header`. A definition belongs to the
+//!   module that lists it, so the `module_header` field that definitions share in the IR
+//!   is not exported. Definitions without a module header are listed in a module whose
+//!   `header` is `null`.
+//! * Structs are objects whose keys are the names of the fields of the Rust type,
+//!   e.g. [`ModuleHeader`] or [`ToplevelTypeDefinition`](super::ToplevelTypeDefinition).
+//!   `Option`s that are `None` are `null`.
+//! * Enums are externally tagged: variants without data are strings such as `"Null"`,
+//!   variants with data are objects with the variant name as the only key,
+//!   e.g. `{ "Boolean": { "constraints": [] } }` for [`ASN1Type::Boolean`](super::ASN1Type::Boolean).
+//! * Integer values, which may exceed the range of JSON numbers, are decimal strings.
+use serde::{Serialize, Serializer};
+
+use super::{SharedModuleHeader, ToplevelDefinition};
+
+/// Version of the schema of the JSON export of the intermediate representation
+pub const IR_JSON_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct IrJson<'a> {
+    version: u32,
+    modules: Vec<IrJsonModule<'a>>,
+}
+
+#[derive(Serialize)]
+struct IrJsonModule<'a> {
+    #[serde(serialize_with = "serialize_header")]
+    header: Option<SharedModuleHeader>,
+    definitions: Vec<&'a ToplevelDefinition>,
+}
+
+/// Exports the given definitions, grouped by the modules they belong to, as JSON.
+/// See the [module documentation](self) for the schema.
+pub fn to_ir_json(definitions: &[ToplevelDefinition]) -> String {
+    let mut modules: Vec<IrJsonModule> = vec![];
+    for definition in definitions {
+        let header = definition.get_module_header();
+        let module = modules.iter_mut().find(|m| match (&m.header, &header) {
+            (Some(a), Some(b)) => a.ptr_eq(b),
+            (None, None) => true,
+            _ => false,
+        });
+        match module {
+            Some(module) => module.definitions.push(definition),
+            None => modules.push(IrJsonModule {
+                header,
+                definitions: vec![definition],
+            }),
+        }
+    }
+    serde_json::to_string(&IrJson {
+        version: IR_JSON_VERSION,
+        modules,
+    })
+    .expect("the IR does not contain maps with non-string keys")
+}
+
+fn serialize_header<S: Serializer>(
+    header: &Option<SharedModuleHeader>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    header
+        .as_ref()
+        .map(|h| h.read())
+        .as_deref()
+        .serialize(serializer)
+}
+
+/// (De)serializes integers as decimal strings
+pub(crate) mod big_int {
+    use num::BigInt;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &BigInt,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BigInt, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{validate_sources, AsnSource, ValidationOptions};
+
+    #[test]
+    fn exports_shared_headers_once() {
+        let (definitions, _) = validate_sources(
+            &[AsnSource::Literal(
+                r#"Module-A DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Small ::= INTEGER (0..7)
+                    huge INTEGER ::= 123456789012345678901234567890
+                END
+
+                Module-B DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    IMPORTS Small FROM Module-A;
+                    Item ::= SEQUENCE { small Small, flag BOOLEAN OPTIONAL }
+                END"#
+                    .into(),
+            )],
+            &ValidationOptions::default(),
+        )
+        .unwrap();
+        let json = to_ir_json(&definitions);
+        assert!(json.starts_with(r#"{"version":1,"modules":[{"header":{"name":"Module-A""#));
+        assert_eq!(json.matches(r#""header":{"name":"Module-B""#).count(), 1);
+        assert!(!json.contains("module_header"));
+        assert!(json.contains(r#""value":"123456789012345678901234567890""#));
+        assert!(json.contains(r#""imports":[{"types":["Small"]"#));
+    }
+}
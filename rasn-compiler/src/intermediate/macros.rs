@@ -1,19 +1,21 @@
-use std::cell::RefCell;
-use std::rc::Rc;
-
-use crate::intermediate::ModuleHeader;
+use crate::intermediate::SharedModuleHeader;
 use crate::lexer::macros::MacroDefinition;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ToplevelMacroDefinition {
     pub name: String,
-    pub module_header: Option<Rc<RefCell<ModuleHeader>>>,
+    /// Source text of the macro substance, i.e. its `BEGIN ... END` body or the referenced macro
+    pub substance: String,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub module_header: Option<SharedModuleHeader>,
 }
 
 impl From<MacroDefinition<'_>> for ToplevelMacroDefinition {
     fn from(macro_def: MacroDefinition<'_>) -> Self {
         ToplevelMacroDefinition {
             name: macro_def.name.to_string(),
+            substance: macro_def.substance_source.to_string(),
             module_header: None,
         }
     }
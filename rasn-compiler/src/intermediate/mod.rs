@@ -7,17 +7,29 @@
 //! be relevant for decoding and encoding in any of the common encoding rules
 //! (inner type constraints are such an example).
 pub mod constraints;
+pub mod effective_constraints;
 pub mod encoding_rules;
 pub mod error;
 pub mod information_object;
+#[cfg(feature = "serde")]
+pub mod json;
 pub mod macros;
 pub mod parameterization;
+pub mod references;
 pub mod types;
 pub mod utils;
+pub mod visit;
 
-use std::{borrow::Cow, cell::RefCell, collections::BTreeMap, ops::Add, rc::Rc};
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    ops::Add,
+    sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use num::BigInt;
 
-use crate::common::INTERNAL_IO_FIELD_REF_TYPE_NAME_PREFIX;
+use crate::{common::INTERNAL_IO_FIELD_REF_TYPE_NAME_PREFIX, diagnostic::Span};
 use constraints::Constraint;
 use error::{GrammarError, GrammarErrorType};
 use information_object::{
@@ -78,6 +90,10 @@ pub const ALL: &str = "ALL";
 pub const SET: &str = "SET";
 pub const OBJECT_IDENTIFIER: &str = "OBJECT IDENTIFIER";
 pub const COMPONENTS_OF: &str = "COMPONENTS OF";
+pub const OBJECT_DESCRIPTOR: &str = "ObjectDescriptor";
+pub const CHARACTER_STRING: &str = "CHARACTER STRING";
+pub const ANY: &str = "ANY";
+pub const DEFINED_BY: &str = "DEFINED BY";
 
 // Tagging tokens
 pub const UNIVERSAL: &str = "UNIVERSAL";
@@ -243,6 +259,7 @@ macro_rules! grammar_error {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EncodingReferenceDefault(pub String);
 
 impl From<&str> for EncodingReferenceDefault {
@@ -254,6 +271,7 @@ impl From<&str> for EncodingReferenceDefault {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TaggingEnvironment {
     Automatic,
     #[default]
@@ -277,6 +295,7 @@ impl Add<&TaggingEnvironment> for &TaggingEnvironment {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ExtensibilityEnvironment {
     Implied,
     #[default]
@@ -288,6 +307,7 @@ pub enum ExtensibilityEnvironment {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum With {
     Successors,
     Descendants,
@@ -296,6 +316,7 @@ pub enum With {
 /// Represents a global module reference as specified in
 /// Rec. ITU-T X.680 (02/2021)
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExternalValueReference {
     pub module_reference: String,
     pub value_reference: String,
@@ -304,6 +325,7 @@ pub struct ExternalValueReference {
 /// Represents a global module reference as specified in
 /// Rec. ITU-T X.680 (02/2021)
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GlobalModuleReference {
     pub module_reference: String,
     pub assigned_identifier: AssignedIdentifier,
@@ -323,6 +345,7 @@ impl From<(&str, AssignedIdentifier)> for GlobalModuleReference {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AssignedIdentifier {
     ObjectIdentifierValue(ObjectIdentifierValue),
     ExternalValueReference(ExternalValueReference),
@@ -337,6 +360,7 @@ pub enum AssignedIdentifier {
 /// Represents a module import as specified in
 /// Rec. ITU-T X.680 (02/2021) § 13.16
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Import {
     pub types: Vec<String>,
     pub global_module_reference: GlobalModuleReference,
@@ -364,6 +388,7 @@ impl From<(Vec<&str>, (GlobalModuleReference, Option<&str>))> for Import {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Exports {
     Identifier(Vec<String>),
     All,
@@ -380,6 +405,7 @@ impl From<Vec<&str>> for Exports {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DefinitiveIdentifier {
     DefinitiveOID(ObjectIdentifierValue),
     DefinitiveOIDandIRI {
@@ -404,6 +430,7 @@ impl From<(ObjectIdentifierValue, Option<&str>)> for DefinitiveIdentifier {
 /// Represents a module header as specified in
 /// Rec. ITU-T X.680 (02/2021) § 13
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModuleHeader {
     pub name: String,
     pub module_identifier: Option<DefinitiveIdentifier>,
@@ -412,9 +439,34 @@ pub struct ModuleHeader {
     pub extensibility_environment: ExtensibilityEnvironment,
     pub imports: Vec<Import>,
     pub exports: Option<Exports>,
+    /// Path of the file that the module was read from
+    pub source_file: Option<String>,
+    /// Lines and columns of the assignments of the module's definitions, by name
+    pub locations: BTreeMap<String, (usize, usize)>,
 }
 
 impl ModuleHeader {
+    /// Returns the location of the assignment of a definition of the module, if it is known.
+    pub fn location(&self, name: &str) -> Option<Span> {
+        let (line, column) = *self.locations.get(name)?;
+        Some(Span {
+            file: self.source_file.clone(),
+            line,
+            column,
+        })
+    }
+
+    /// Returns `true` if the module exports the given identifier.
+    /// Modules without an `EXPORTS` clause export all of their definitions.
+    pub fn exports(&self, identifier: &str) -> bool {
+        match &self.exports {
+            Some(Exports::Identifier(identifiers)) => identifiers
+                .iter()
+                .any(|e| e.trim_end_matches("{}") == identifier.trim_end_matches("{}")),
+            Some(Exports::All) | None => true,
+        }
+    }
+
     /// Returns an import that matches a given identifier, if present.
     pub fn find_import(&self, identifier: &str) -> Option<&String> {
         self.imports
@@ -423,6 +475,38 @@ impl ModuleHeader {
     }
 }
 
+/// A module header that is shared by the top-level definitions of a module.
+/// Clones refer to the same header, which can be read and modified from any thread.
+#[derive(Debug, Clone)]
+pub struct SharedModuleHeader(Arc<RwLock<ModuleHeader>>);
+
+impl SharedModuleHeader {
+    pub fn new(header: ModuleHeader) -> Self {
+        Self(Arc::new(RwLock::new(header)))
+    }
+
+    /// Locks the header for reading.
+    pub fn read(&self) -> RwLockReadGuard<'_, ModuleHeader> {
+        self.0.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Locks the header for modification.
+    pub fn write(&self) -> RwLockWriteGuard<'_, ModuleHeader> {
+        self.0.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns `true` if both refer to the same header.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl PartialEq for SharedModuleHeader {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other) || *self.read() == *other.read()
+    }
+}
+
 impl
     From<(
         &str,
@@ -463,6 +547,8 @@ impl
             extensibility_environment,
             exports: value.3,
             imports: value.4.unwrap_or_default(),
+            source_file: None,
+            locations: BTreeMap::new(),
         }
     }
 }
@@ -470,6 +556,7 @@ impl
 /// Represents an object identifier value as specified in
 /// Rec. ITU-T X.680 (02/2021) §32
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectIdentifierValue(pub Vec<ObjectIdentifierArc>);
 
 impl From<Vec<ObjectIdentifierArc>> for ObjectIdentifierValue {
@@ -481,6 +568,7 @@ impl From<Vec<ObjectIdentifierArc>> for ObjectIdentifierValue {
 /// Represents a single arc of an object identifier value
 /// as specified in Rec. ITU-T X.680 (02/2021) §32
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectIdentifierArc {
     pub name: Option<String>,
     pub number: Option<u128>,
@@ -558,6 +646,7 @@ impl From<(&str, u128)> for ObjectIdentifierArc {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ToplevelDefinition {
     /// Definition for a custom type based on ASN.1's built-in type.
     Type(ToplevelTypeDefinition),
@@ -588,7 +677,7 @@ impl ToplevelDefinition {
         }
     }
 
-    pub(crate) fn set_module_header(&mut self, module_header: Rc<RefCell<ModuleHeader>>) {
+    pub(crate) fn set_module_header(&mut self, module_header: SharedModuleHeader) {
         match self {
             ToplevelDefinition::Type(ref mut t) => {
                 t.module_header = Some(module_header);
@@ -608,7 +697,7 @@ impl ToplevelDefinition {
         }
     }
 
-    pub(crate) fn get_module_header(&self) -> Option<Rc<RefCell<ModuleHeader>>> {
+    pub(crate) fn get_module_header(&self) -> Option<SharedModuleHeader> {
         match self {
             ToplevelDefinition::Type(ref t) => t.module_header.as_ref().cloned(),
             ToplevelDefinition::Value(ref v) => v.module_header.as_ref().cloned(),
@@ -653,13 +742,14 @@ impl ToplevelDefinition {
     ///     ToplevelDefinition::Value(
     ///         ToplevelValueDefinition {
     ///             comments: String::from("Comments from the ASN.1 spec"),
+    ///             trailing_comment: None,
     ///             parameterization: None,
     ///             name: String::from("the-answer"),
     ///             associated_type: ASN1Type::Integer(Integer {
     ///                 constraints: vec![],
     ///                 distinguished_values: None,
     ///             }),
-    ///             value: ASN1Value::Integer(42),
+    ///             value: ASN1Value::Integer(42.into()),
     ///             module_header: None,
     ///         }
     ///     ).name(),
@@ -675,24 +765,39 @@ impl ToplevelDefinition {
             ToplevelDefinition::Macro(v) => &v.name,
         }
     }
+
+    pub(crate) fn name_mut(&mut self) -> &mut String {
+        match self {
+            ToplevelDefinition::Class(c) => &mut c.name,
+            ToplevelDefinition::Object(o) => &mut o.name,
+            ToplevelDefinition::Type(t) => &mut t.name,
+            ToplevelDefinition::Value(v) => &mut v.name,
+            ToplevelDefinition::Macro(v) => &mut v.name,
+        }
+    }
 }
 
 /// Represents a top-level definition of a value
 /// using a custom or built-in ASN.1 type.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ToplevelValueDefinition {
     pub comments: String,
+    /// Comment on the same line after the assignment
+    pub trailing_comment: Option<String>,
     pub name: String,
     pub associated_type: ASN1Type,
     pub parameterization: Option<Parameterization>,
     pub value: ASN1Value,
-    pub module_header: Option<Rc<RefCell<ModuleHeader>>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub module_header: Option<SharedModuleHeader>,
 }
 
 impl From<(&str, ASN1Value, ASN1Type)> for ToplevelValueDefinition {
     fn from(value: (&str, ASN1Value, ASN1Type)) -> Self {
         Self {
             comments: String::new(),
+            trailing_comment: None,
             name: value.0.to_owned(),
             associated_type: value.2.to_owned(),
             parameterization: None,
@@ -722,6 +827,7 @@ impl
     ) -> Self {
         Self {
             comments: value.0.join("\n"),
+            trailing_comment: None,
             name: value.1.into(),
             parameterization: value.2,
             associated_type: value.3,
@@ -732,13 +838,17 @@ impl
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ToplevelTypeDefinition {
     pub comments: String,
+    /// Comment on the same line after the assignment
+    pub trailing_comment: Option<String>,
     pub tag: Option<AsnTag>,
     pub name: String,
     pub ty: ASN1Type,
     pub parameterization: Option<Parameterization>,
-    pub module_header: Option<Rc<RefCell<ModuleHeader>>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub module_header: Option<SharedModuleHeader>,
 }
 
 impl ToplevelTypeDefinition {
@@ -751,6 +861,7 @@ impl From<(&str, ASN1Type)> for ToplevelTypeDefinition {
     fn from(value: (&str, ASN1Type)) -> Self {
         Self {
             comments: String::new(),
+            trailing_comment: None,
             tag: None,
             name: value.0.to_owned(),
             ty: value.1,
@@ -778,6 +889,7 @@ impl
     ) -> Self {
         Self {
             comments: value.0.join("\n"),
+            trailing_comment: None,
             name: value.1.into(),
             parameterization: value.2,
             ty: value.3 .1,
@@ -793,6 +905,7 @@ impl
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ASN1Type {
     Null,
     Boolean(Boolean),
@@ -813,9 +926,15 @@ pub enum ASN1Type {
     ElsewhereDeclaredType(DeclarationElsewhere),
     ChoiceSelectionType(ChoiceSelectionType),
     ObjectIdentifier(ObjectIdentifier),
+    RelativeOid(ObjectIdentifier),
+    OidIri(ObjectIdentifier),
+    RelativeOidIri(ObjectIdentifier),
+    ObjectDescriptor(ObjectDescriptor),
     ObjectClassField(ObjectClassFieldType),
     EmbeddedPdv,
     External,
+    UnrestrictedCharacterString,
+    Any(Any),
 }
 
 impl ASN1Type {
@@ -885,6 +1004,10 @@ impl ASN1Type {
             }
             ASN1Type::ChoiceSelectionType(_) => todo!(),
             ASN1Type::ObjectIdentifier(_) => Cow::Borrowed(OBJECT_IDENTIFIER),
+            ASN1Type::RelativeOid(_) => Cow::Borrowed(RELATIVE_OID),
+            ASN1Type::OidIri(_) => Cow::Borrowed(OID_IRI),
+            ASN1Type::RelativeOidIri(_) => Cow::Borrowed(RELATIVE_OID_IRI),
+            ASN1Type::ObjectDescriptor(_) => Cow::Borrowed(OBJECT_DESCRIPTOR),
             ASN1Type::ObjectClassField(ifr) => Cow::Owned(format!(
                 "{INTERNAL_IO_FIELD_REF_TYPE_NAME_PREFIX}{}${}",
                 ifr.class,
@@ -892,6 +1015,8 @@ impl ASN1Type {
             )),
             ASN1Type::EmbeddedPdv => Cow::Borrowed(EMBEDDED_PDV),
             ASN1Type::External => Cow::Borrowed(EXTERNAL),
+            ASN1Type::UnrestrictedCharacterString => Cow::Borrowed(CHARACTER_STRING),
+            ASN1Type::Any(_) => Cow::Borrowed(ANY),
         }
     }
 
@@ -1033,6 +1158,7 @@ pub const PRINTABLE_STRING_CHARSET: [char; 74] = [
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CharacterStringType {
     NumericString,
     VisibleString,
@@ -1088,6 +1214,7 @@ impl From<&str> for CharacterStringType {
 
 /// Representation of common integer types
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IntegerType {
     Int8,
     Uint8,
@@ -1144,6 +1271,7 @@ impl IntegerType {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ASN1Value {
     All,
     Null,
@@ -1156,7 +1284,7 @@ pub enum ASN1Value {
     /// In ASN.1, value definitions are ambiguous between SEQUENCE, SET, SEQUENCE OF, and SET OF
     /// For example, `{ my-elem FALSE }` could be a value of all four types
     SequenceOrSet(Vec<(Option<String>, Box<ASN1Value>)>),
-    Integer(i128),
+    Integer(#[cfg_attr(feature = "serde", serde(with = "json::big_int"))] BigInt),
     Real(f64),
     String(String),
     BitString(Vec<bool>),
@@ -1171,6 +1299,12 @@ pub enum ASN1Value {
         parent: Option<String>,
         identifier: String,
     },
+    /// A reference to a parameterized value with its actual parameters, such as `value { 7 }`.
+    /// The validator replaces it by a reference to the instance of the parameterized value.
+    ParameterizedValue {
+        identifier: String,
+        actual_parameters: Vec<constraints::Parameter>,
+    },
     ObjectIdentifier(ObjectIdentifierValue),
     /// In ASN1 value declarations, the value type is not straighforward to parse.
     /// For example, in the following ASN1
@@ -1192,7 +1326,8 @@ pub enum ASN1Value {
     /// Integer values need type information that will not always be picked up by the lexer on first pass.
     LinkedIntValue {
         integer_type: IntegerType,
-        value: i128,
+        #[cfg_attr(feature = "serde", serde(with = "json::big_int"))]
+        value: BigInt,
     },
     /// Struct-like values such as SEQUENCE values need type information that will not always be picked up by the lexer on first pass.
     /// Contains a vector of the struct-like's fields, with the field name, the field type, and the field value as a tuple
@@ -1201,6 +1336,8 @@ pub enum ASN1Value {
     LinkedArrayLikeValue(Vec<Box<ASN1Value>>),
     /// Character string values such as UTF8String values need type information that will not always be picked up by the lexer on first pass.
     LinkedCharStringValue(CharacterStringType, String),
+    /// RELATIVE-OID values share their notation with OBJECT IDENTIFIER values, so the lexer cannot tell them apart on first pass.
+    LinkedRelativeOidValue(ObjectIdentifierValue),
     LinkedElsewhereDefinedValue {
         parent: Option<String>,
         identifier: String,
@@ -1212,6 +1349,7 @@ pub enum ASN1Value {
 #[cfg_attr(test, derive(EnumDebug))]
 #[cfg_attr(not(test), derive(Debug))]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StructLikeFieldValue {
     Explicit(Box<ASN1Value>),
     Implicit(Box<ASN1Value>),
@@ -1270,9 +1408,9 @@ impl ASN1Value {
         match (self, other, char_set) {
             (ASN1Value::Integer(s), ASN1Value::Integer(o), _) => {
                 if getting_mininum {
-                    Ok(ASN1Value::Integer(*s.min(o)))
+                    Ok(ASN1Value::Integer(s.min(o).clone()))
                 } else {
-                    Ok(ASN1Value::Integer(*s.max(o)))
+                    Ok(ASN1Value::Integer(s.max(o).clone()))
                 }
             }
             (ASN1Value::String(s), ASN1Value::String(o), Some(set)) => {
@@ -1313,9 +1451,17 @@ impl ASN1Value {
         }
     }
 
+    /// Unwraps an INTEGER value that fits into an `i128`.
+    /// Use [ASN1Value::unwrap_as_big_integer] for values of arbitrary size.
     pub fn unwrap_as_integer(&self) -> Result<i128, GrammarError> {
+        i128::try_from(self.unwrap_as_big_integer()?).map_err(|_| {
+            grammar_error!(UnpackingError, "Cannot unwrap {self:?} as 128-bit integer!")
+        })
+    }
+
+    pub fn unwrap_as_big_integer(&self) -> Result<&BigInt, GrammarError> {
         if let ASN1Value::Integer(i) = self {
-            Ok(*i)
+            Ok(i)
         } else {
             Err(grammar_error!(
                 UnpackingError,
@@ -1329,6 +1475,7 @@ impl ASN1Value {
 /// some other part of the ASN1 specification that is
 /// being parsed or in one of its imports.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeclarationElsewhere {
     /// Chain of parent declaration leading back to a basic ASN1 type
     pub parent: Option<String>,
@@ -1351,6 +1498,7 @@ impl From<(Option<&str>, &str, Option<Vec<Constraint>>)> for DeclarationElsewher
 
 /// Tag classes
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TagClass {
     Universal,
     Application,
@@ -1360,6 +1508,7 @@ pub enum TagClass {
 
 /// Representation of a tag
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AsnTag {
     pub environment: TaggingEnvironment,
     pub tag_class: TagClass,
@@ -1,6 +1,7 @@
 use super::ASN1Type;
 
 #[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Parameterization {
     pub parameters: Vec<ParameterizationArgument>,
 }
@@ -12,6 +13,7 @@ impl From<Vec<ParameterizationArgument>> for Parameterization {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParameterizationArgument {
     pub dummy_reference: String,
     pub param_governor: ParameterGovernor,
@@ -45,6 +47,7 @@ impl From<(&str, &str)> for ParameterizationArgument {
 }
 
 #[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ParameterGovernor {
     #[default]
     None,
@@ -0,0 +1,114 @@
+//! The `references` module collects the identifiers that IR nodes refer to,
+//! such as the types, values, classes and object sets that a definition depends on.
+//! The identifiers are collected as they are written in the specification,
+//! so resolving them to definitions is up to the caller.
+use super::{
+    information_object::{
+        ASN1Information, ClassLink, ObjectSet, ObjectSetValue, ToplevelInformationDefinition,
+    },
+    visit::{
+        walk_declaration_elsewhere, walk_information, walk_object_set,
+        walk_toplevel_information_definition, walk_type, walk_value, Visitor,
+    },
+    ASN1Type, ASN1Value, DeclarationElsewhere, ToplevelDefinition,
+};
+
+/// Collects the identifiers referred to by the visited nodes in the order of their occurrence.
+#[derive(Debug, Default)]
+pub struct ReferenceCollector {
+    pub references: Vec<String>,
+}
+
+impl Visitor for ReferenceCollector {
+    fn visit_toplevel_information_definition(&mut self, node: &ToplevelInformationDefinition) {
+        if let ClassLink::ByName(class) | ClassLink::ByInstantiation(class, _) = &node.class {
+            self.references.push(class.clone());
+        }
+        walk_toplevel_information_definition(self, node);
+    }
+
+    fn visit_type(&mut self, node: &ASN1Type) {
+        match node {
+            ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+                self.references.extend(s.components_of.iter().cloned())
+            }
+            ASN1Type::ChoiceSelectionType(c) => self.references.push(c.choice_name.clone()),
+            ASN1Type::ObjectClassField(f) => self.references.push(f.class.clone()),
+            _ => (),
+        }
+        walk_type(self, node);
+    }
+
+    fn visit_declaration_elsewhere(&mut self, node: &DeclarationElsewhere) {
+        self.references.push(node.identifier.clone());
+        walk_declaration_elsewhere(self, node);
+    }
+
+    fn visit_value(&mut self, node: &ASN1Value) {
+        if let ASN1Value::ElsewhereDeclaredValue { identifier, .. }
+        | ASN1Value::LinkedElsewhereDefinedValue { identifier, .. }
+        | ASN1Value::ParameterizedValue { identifier, .. } = node
+        {
+            self.references.push(identifier.clone());
+        }
+        walk_value(self, node);
+    }
+
+    fn visit_information(&mut self, node: &ASN1Information) {
+        if let ASN1Information::Object(object) = node {
+            self.references.push(object.class_name.clone());
+        }
+        walk_information(self, node);
+    }
+
+    fn visit_object_set(&mut self, node: &ObjectSet) {
+        for value in &node.values {
+            if let ObjectSetValue::Reference(r) | ObjectSetValue::Instantiation(r, _) = value {
+                self.references.push(r.clone());
+            }
+        }
+        walk_object_set(self, node);
+    }
+}
+
+/// Returns the identifiers that a top-level definition refers to.
+pub fn definition_references(tld: &ToplevelDefinition) -> Vec<String> {
+    let mut collector = ReferenceCollector::default();
+    collector.visit_toplevel_definition(tld);
+    collector.references
+}
+
+/// Returns the identifiers that a value refers to.
+pub fn value_references(value: &ASN1Value) -> Vec<String> {
+    let mut collector = ReferenceCollector::default();
+    collector.visit_value(value);
+    collector.references
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::asn_spec;
+
+    #[test]
+    fn collects_references_of_definitions() {
+        let definitions = asn_spec(
+            r#"Test DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Item ::= SEQUENCE { id Id DEFAULT first-id, kind CHOICE { a Id, b Other } }
+            Id ::= INTEGER (0..max-id)
+            Other ::= SEQUENCE OF Id
+            END"#
+                .into(),
+        )
+        .unwrap()
+        .into_iter()
+        .flat_map(|(_, definitions)| definitions)
+        .collect::<Vec<_>>();
+        let references = |name: &str| {
+            definition_references(definitions.iter().find(|d| d.name() == name).unwrap())
+        };
+        assert_eq!(references("Item"), vec!["Id", "first-id", "Id", "Other"]);
+        assert_eq!(references("Id"), vec!["max-id"]);
+        assert_eq!(references("Other"), vec!["Id"]);
+    }
+}
@@ -11,6 +11,7 @@ use super::{constraints::*, *};
 
 /// Defines the optionality of a field.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Optionality<T> {
     /// All definitions are required to specify this field.
     Required,
@@ -95,6 +96,7 @@ constrainable!(Time);
 /// with corresponding constraints.
 /// *As defined in Rec. ITU-T X.680 (02/2021) §18*
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Boolean {
     pub constraints: Vec<Constraint>,
 }
@@ -111,6 +113,7 @@ impl From<Option<Vec<Constraint>>> for Boolean {
 /// with corresponding constraints and distinguished values.
 /// *As defined in Rec. ITU-T X.680 (02/2021) §19*
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Integer {
     pub constraints: Vec<Constraint>,
     pub distinguished_values: Option<Vec<DistinguishedValue>>,
@@ -133,11 +136,12 @@ impl From<(i128, i128, bool)> for Integer {
         Self {
             constraints: vec![Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                    min: Some(ASN1Value::Integer(value.0)),
-                    max: Some(ASN1Value::Integer(value.1)),
+                    min: Some(ASN1Value::Integer(value.0.into())),
+                    max: Some(ASN1Value::Integer(value.1.into())),
                     extensible: value.2,
                 }),
                 extensible: value.2,
+                extension_additions: None,
             })],
             distinguished_values: None,
         }
@@ -149,11 +153,12 @@ impl From<(Option<i128>, Option<i128>, bool)> for Integer {
         Self {
             constraints: vec![Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                    min: value.0.map(ASN1Value::Integer),
-                    max: value.1.map(ASN1Value::Integer),
+                    min: value.0.map(|i| ASN1Value::Integer(i.into())),
+                    max: value.1.map(|i| ASN1Value::Integer(i.into())),
                     extensible: value.2,
                 }),
                 extensible: value.2,
+                extension_additions: None,
             })],
             distinguished_values: None,
         }
@@ -185,6 +190,7 @@ impl
 /// with corresponding constraints.
 /// *As defined in Rec. ITU-T X.680 (02/2021) §21*
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Real {
     pub constraints: Vec<Constraint>,
 }
@@ -201,6 +207,7 @@ impl From<Option<Vec<Constraint>>> for Real {
 /// with corresponding constraints.
 /// *As defined in Rec. ITU-T X.680 (02/2021) §46*
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GeneralizedTime {
     pub constraints: Vec<Constraint>,
 }
@@ -209,6 +216,7 @@ pub struct GeneralizedTime {
 /// data element with corresponding constraints.
 /// *As defined in Rec. ITU-T X.680 (02/2021) §47*
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UTCTime {
     pub constraints: Vec<Constraint>,
 }
@@ -217,6 +225,7 @@ pub struct UTCTime {
 /// with corresponding constraints.
 /// *As defined in Rec. ITU-T X.680 (02/2021) §23*
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OctetString {
     pub constraints: Vec<Constraint>,
 }
@@ -234,6 +243,7 @@ impl From<Option<Vec<Constraint>>> for OctetString {
 /// defining the individual bits.
 /// *As defined in Rec. ITU-T X.680 (02/2021) §22*
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BitString {
     pub constraints: Vec<Constraint>,
     pub distinguished_values: Option<Vec<DistinguishedValue>>,
@@ -251,7 +261,11 @@ impl From<(Option<Vec<DistinguishedValue>>, Option<Vec<Constraint>>)> for BitStr
 /// Representation of an ASN1 OBJECT IDENTIFIER data element
 /// with corresponding constraints.
 /// *As defined in Rec. ITU-T X.680 (02/2021) §32*
+///
+/// RELATIVE-OID, OID-IRI and RELATIVE-OID-IRI data elements
+/// share this representation. *See Rec. ITU-T X.680 (02/2021) §33 - §35*
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectIdentifier {
     pub constraints: Vec<Constraint>,
 }
@@ -264,10 +278,41 @@ impl From<Option<Vec<Constraint>>> for ObjectIdentifier {
     }
 }
 
+/// Representation of an ASN1 ObjectDescriptor data element
+/// with corresponding constraints. An ObjectDescriptor is a
+/// GraphicString with its own universal tag.
+/// *As defined in Rec. ITU-T X.680 (02/2021) §48*
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ObjectDescriptor {
+    pub constraints: Vec<Constraint>,
+}
+
+impl From<Option<Vec<Constraint>>> for ObjectDescriptor {
+    fn from(value: Option<Vec<Constraint>>) -> Self {
+        ObjectDescriptor {
+            constraints: value.unwrap_or_default(),
+        }
+    }
+}
+
+/// Representation of an ASN.1:1990 ANY data element.
+/// `ANY` was superseded by open types in later versions of X.680,
+/// but is still used by many older specifications.
+/// *As defined in Rec. CCITT X.208 (1988) §27*
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Any {
+    /// The identifier of the component whose value determines the type of the `ANY`
+    /// value, if the type is declared as `ANY DEFINED BY component`.
+    pub defined_by: Option<String>,
+}
+
 /// Representation of an ASN1 TIME data element
 /// with corresponding constraints.
 /// *As defined in Rec. ITU-T X.680 (02/2021) §38*
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Time {
     pub constraints: Vec<Constraint>,
 }
@@ -285,6 +330,7 @@ impl From<Option<Vec<Constraint>>> for Time {
 /// include IA5String, UTF8String, VideotexString.
 /// *As defined in Rec. ITU-T X.680 (02/2021) §39-*§44
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CharacterString {
     pub constraints: Vec<Constraint>,
     pub ty: CharacterStringType,
@@ -307,6 +353,7 @@ impl From<(&str, Option<Vec<Constraint>>)> for CharacterString {
 /// or `ASN1Type::SequenceOf(SequenceOrSetOf { .. })`).
 /// *As defined in Rec. ITU-T X.680 (02/2021) §26 and §28*
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SequenceOrSetOf {
     pub constraints: Vec<Constraint>,
     /// [ASN.1 type](ASN1Type) of the individual elements of the collection
@@ -346,6 +393,7 @@ impl From<(Option<Vec<Constraint>>, (Option<AsnTag>, ASN1Type))> for SequenceOrS
 /// or `ASN1Type::Sequence(SequenceOrSet { .. })`).
 /// *As defined in Rec. ITU-T X.680 (02/2021) §25 and §27*
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SequenceOrSet {
     pub components_of: Vec<String>,
     pub extensible: Option<usize>,
@@ -446,6 +494,7 @@ impl
 #[cfg_attr(not(test), derive(Debug))]
 #[allow(clippy::large_enum_variant)]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SequenceComponent {
     Member(SequenceOrSetMember),
     ComponentsOf(String),
@@ -475,21 +524,25 @@ pub enum SequenceComponent {
 ///         constraints: vec![
 ///             Constraint::Subtype(ElementSetSpecs {
 ///                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-///                     min: Some(ASN1Value::Integer(0)),
-///                     max: Some(ASN1Value::Integer(2)),
+///                     min: Some(ASN1Value::Integer(0.into())),
+///                     max: Some(ASN1Value::Integer(2.into())),
 ///                     extensible: false
 ///                 }),
-///                 extensible: false
+///                 extensible: false,
+///                 extension_additions: None,
 ///            })
 ///         ],
 ///         distinguished_values: None,
 ///     }),
-///     optionality: Optionality::Default(ASN1Value::Integer(1)),
-///     constraints: vec![]
+///     optionality: Optionality::Default(ASN1Value::Integer(1.into())),
+///     constraints: vec![],
+///     comments: String::new(),
+///     trailing_comment: None,
 /// }
 /// # ;
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SequenceOrSetMember {
     pub name: String,
     pub tag: Option<AsnTag>,
@@ -497,6 +550,10 @@ pub struct SequenceOrSetMember {
     pub optionality: Optionality<ASN1Value>,
     pub is_recursive: bool,
     pub constraints: Vec<Constraint>,
+    /// Comments preceding the member, one line per comment
+    pub comments: String,
+    /// Comment on the same line after the member
+    pub trailing_comment: Option<String>,
 }
 
 impl MemberOrOption for SequenceOrSetMember {
@@ -548,6 +605,8 @@ impl
             optionality: value.4,
             is_recursive: false,
             constraints: value.3.unwrap_or_default(),
+            comments: String::new(),
+            trailing_comment: None,
         }
     }
 }
@@ -556,6 +615,7 @@ impl
 /// with corresponding members and extension information.
 /// *As defined in Rec. ITU-T X.680 (02/2021) §29*
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Choice {
     pub extensible: Option<usize>,
     pub options: Vec<ChoiceOption>,
@@ -615,17 +675,24 @@ impl
 ///     ty: ASN1Type::Boolean(Boolean {
 ///         constraints: vec![]
 ///     }),
-///     constraints: vec![]
+///     constraints: vec![],
+///     comments: String::new(),
+///     trailing_comment: None,
 /// }
 /// # ;
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChoiceOption {
     pub name: String,
     pub tag: Option<AsnTag>,
     pub ty: ASN1Type,
     pub constraints: Vec<Constraint>,
     pub is_recursive: bool,
+    /// Comments preceding the option, one line per comment
+    pub comments: String,
+    /// Comment on the same line after the option
+    pub trailing_comment: Option<String>,
 }
 
 impl MemberOrOption for ChoiceOption {
@@ -660,6 +727,8 @@ impl From<(&str, Option<AsnTag>, ASN1Type, Option<Vec<Constraint>>)> for ChoiceO
             ty: value.2,
             constraints: value.3.unwrap_or_default(),
             is_recursive: false,
+            comments: String::new(),
+            trailing_comment: None,
         }
     }
 }
@@ -668,6 +737,7 @@ impl From<(&str, Option<AsnTag>, ASN1Type, Option<Vec<Constraint>>)> for ChoiceO
 /// with corresponding enumerals and extension information.
 /// *As defined in Rec. ITU-T X.680 (02/2021) §20*
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Enumerated {
     pub members: Vec<Enumeral>,
     pub extensible: Option<usize>,
@@ -719,6 +789,7 @@ impl
 /// # ;
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Enumeral {
     pub name: String,
     pub description: Option<String>,
@@ -729,13 +800,15 @@ pub struct Enumeral {
 /// as seen in some INTEGER and BIT STRING declarations
 /// *As defined in Rec. ITU-T X.680 (02/2021) §19.5 and §22.4*
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DistinguishedValue {
     pub name: String,
-    pub value: i128,
+    #[cfg_attr(feature = "serde", serde(with = "super::json::big_int"))]
+    pub value: BigInt,
 }
 
-impl From<(&str, i128)> for DistinguishedValue {
-    fn from(value: (&str, i128)) -> Self {
+impl From<(&str, BigInt)> for DistinguishedValue {
+    fn from(value: (&str, BigInt)) -> Self {
         Self {
             name: value.0.into(),
             value: value.1,
@@ -746,6 +819,7 @@ impl From<(&str, i128)> for DistinguishedValue {
 /// Representation of a ASN1 selection type as used with ASN1 CHOICEs
 /// *As defined in Rec. ITU-T X.680 (02/2021) §30*
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChoiceSelectionType {
     pub choice_name: String,
     pub selected_option: String,
@@ -15,3 +15,13 @@ macro_rules! get_declaration {
 }
 
 pub(crate) use get_declaration;
+
+/// Converts an INTEGER value to an `i128`, saturating at `i128::MIN` and `i128::MAX`.
+/// Constraint arithmetic uses these bounds for `MIN` and `MAX`, which is where
+/// values beyond 128 bits belong for the purpose of choosing a representation.
+pub(crate) fn saturating_i128(value: &num::BigInt) -> i128 {
+    i128::try_from(value).unwrap_or(match value.sign() {
+        num::bigint::Sign::Minus => i128::MIN,
+        _ => i128::MAX,
+    })
+}
@@ -0,0 +1,1107 @@
+//! The `visit` module provides a traversal API for the intermediate representation.
+//! [`Visitor`] walks the IR by shared reference, for analyses that collect
+//! information from a parsed or validated specification, while [`VisitorMut`]
+//! hands out mutable references for IR rewrites.
+//! Both traits come with default implementations for every IR node, so that
+//! implementors only need to override the methods for the nodes they care about.
+use super::{
+    constraints::{
+        Constraint, ContentConstraint, ElementOrSetOperation, NamedConstraint, Parameter,
+        SubtypeElements, TableConstraint,
+    },
+    information_object::{
+        ASN1Information, ClassLink, InformationObjectClassField, InformationObjectField,
+        InformationObjectFields, ObjectClassAssignment, ObjectClassDefn, ObjectSet, ObjectSetValue,
+        SyntaxApplication, ToplevelInformationDefinition,
+    },
+    macros::ToplevelMacroDefinition,
+    parameterization::{ParameterGovernor, Parameterization},
+    types::{ChoiceOption, DistinguishedValue, Enumeral, Optionality, SequenceOrSetMember},
+    ASN1Type, ASN1Value, DeclarationElsewhere, StructLikeFieldValue, ToplevelDefinition,
+    ToplevelTypeDefinition, ToplevelValueDefinition,
+};
+
+/// Read-only traversal of the intermediate representation.
+///
+/// Every method has a default implementation that calls the matching `walk_*`
+/// function, which in turn visits the children of the node. Implementors override
+/// the methods for the nodes they are interested in and call the `walk_*`
+/// function themselves if the traversal should continue below that node.
+pub trait Visitor {
+    fn visit_toplevel_definition(&mut self, node: &ToplevelDefinition) {
+        walk_toplevel_definition(self, node)
+    }
+
+    fn visit_toplevel_type_definition(&mut self, node: &ToplevelTypeDefinition) {
+        walk_toplevel_type_definition(self, node)
+    }
+
+    fn visit_toplevel_value_definition(&mut self, node: &ToplevelValueDefinition) {
+        walk_toplevel_value_definition(self, node)
+    }
+
+    fn visit_object_class_assignment(&mut self, node: &ObjectClassAssignment) {
+        walk_object_class_assignment(self, node)
+    }
+
+    fn visit_toplevel_information_definition(&mut self, node: &ToplevelInformationDefinition) {
+        walk_toplevel_information_definition(self, node)
+    }
+
+    fn visit_toplevel_macro_definition(&mut self, _node: &ToplevelMacroDefinition) {}
+
+    fn visit_parameterization(&mut self, node: &Parameterization) {
+        walk_parameterization(self, node)
+    }
+
+    fn visit_type(&mut self, node: &ASN1Type) {
+        walk_type(self, node)
+    }
+
+    fn visit_declaration_elsewhere(&mut self, node: &DeclarationElsewhere) {
+        walk_declaration_elsewhere(self, node)
+    }
+
+    fn visit_sequence_or_set_member(&mut self, node: &SequenceOrSetMember) {
+        walk_sequence_or_set_member(self, node)
+    }
+
+    fn visit_choice_option(&mut self, node: &ChoiceOption) {
+        walk_choice_option(self, node)
+    }
+
+    fn visit_enumeral(&mut self, _node: &Enumeral) {}
+
+    fn visit_distinguished_value(&mut self, _node: &DistinguishedValue) {}
+
+    fn visit_value(&mut self, node: &ASN1Value) {
+        walk_value(self, node)
+    }
+
+    fn visit_constraint(&mut self, node: &Constraint) {
+        walk_constraint(self, node)
+    }
+
+    fn visit_element_or_set_operation(&mut self, node: &ElementOrSetOperation) {
+        walk_element_or_set_operation(self, node)
+    }
+
+    fn visit_subtype_elements(&mut self, node: &SubtypeElements) {
+        walk_subtype_elements(self, node)
+    }
+
+    fn visit_named_constraint(&mut self, node: &NamedConstraint) {
+        walk_named_constraint(self, node)
+    }
+
+    fn visit_table_constraint(&mut self, node: &TableConstraint) {
+        walk_table_constraint(self, node)
+    }
+
+    fn visit_content_constraint(&mut self, node: &ContentConstraint) {
+        walk_content_constraint(self, node)
+    }
+
+    fn visit_parameter(&mut self, node: &Parameter) {
+        walk_parameter(self, node)
+    }
+
+    fn visit_object_class_defn(&mut self, node: &ObjectClassDefn) {
+        walk_object_class_defn(self, node)
+    }
+
+    fn visit_information_object_class_field(&mut self, node: &InformationObjectClassField) {
+        walk_information_object_class_field(self, node)
+    }
+
+    fn visit_information(&mut self, node: &ASN1Information) {
+        walk_information(self, node)
+    }
+
+    fn visit_information_object_fields(&mut self, node: &InformationObjectFields) {
+        walk_information_object_fields(self, node)
+    }
+
+    fn visit_information_object_field(&mut self, node: &InformationObjectField) {
+        walk_information_object_field(self, node)
+    }
+
+    fn visit_syntax_application(&mut self, node: &SyntaxApplication) {
+        walk_syntax_application(self, node)
+    }
+
+    fn visit_object_set(&mut self, node: &ObjectSet) {
+        walk_object_set(self, node)
+    }
+}
+
+pub fn walk_toplevel_definition<V: Visitor + ?Sized>(visitor: &mut V, node: &ToplevelDefinition) {
+    match node {
+        ToplevelDefinition::Type(t) => visitor.visit_toplevel_type_definition(t),
+        ToplevelDefinition::Value(v) => visitor.visit_toplevel_value_definition(v),
+        ToplevelDefinition::Class(c) => visitor.visit_object_class_assignment(c),
+        ToplevelDefinition::Object(o) => visitor.visit_toplevel_information_definition(o),
+        ToplevelDefinition::Macro(m) => visitor.visit_toplevel_macro_definition(m),
+    }
+}
+
+pub fn walk_toplevel_type_definition<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ToplevelTypeDefinition,
+) {
+    if let Some(parameterization) = &node.parameterization {
+        visitor.visit_parameterization(parameterization);
+    }
+    visitor.visit_type(&node.ty);
+}
+
+pub fn walk_toplevel_value_definition<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ToplevelValueDefinition,
+) {
+    if let Some(parameterization) = &node.parameterization {
+        visitor.visit_parameterization(parameterization);
+    }
+    visitor.visit_type(&node.associated_type);
+    visitor.visit_value(&node.value);
+}
+
+pub fn walk_object_class_assignment<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ObjectClassAssignment,
+) {
+    visitor.visit_parameterization(&node.parameterization);
+    visitor.visit_object_class_defn(&node.definition);
+}
+
+pub fn walk_toplevel_information_definition<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ToplevelInformationDefinition,
+) {
+    if let Some(parameterization) = &node.parameterization {
+        visitor.visit_parameterization(parameterization);
+    }
+    match &node.class {
+        ClassLink::ByReference(class) => visitor.visit_object_class_defn(class),
+        ClassLink::ByInstantiation(_, parameters) => {
+            for parameter in parameters {
+                visitor.visit_parameter(parameter);
+            }
+        }
+        ClassLink::ByName(_) => (),
+    }
+    visitor.visit_information(&node.value);
+}
+
+pub fn walk_parameterization<V: Visitor + ?Sized>(visitor: &mut V, node: &Parameterization) {
+    for argument in &node.parameters {
+        if let ParameterGovernor::TypeOrClass(ty) = &argument.param_governor {
+            visitor.visit_type(ty);
+        }
+    }
+}
+
+pub fn walk_type<V: Visitor + ?Sized>(visitor: &mut V, node: &ASN1Type) {
+    let constraints = match node {
+        ASN1Type::Null
+        | ASN1Type::EmbeddedPdv
+        | ASN1Type::External
+        | ASN1Type::UnrestrictedCharacterString
+        | ASN1Type::Any(_)
+        | ASN1Type::ChoiceSelectionType(_) => return,
+        ASN1Type::ElsewhereDeclaredType(e) => return visitor.visit_declaration_elsewhere(e),
+        ASN1Type::Boolean(b) => &b.constraints,
+        ASN1Type::Integer(i) => {
+            for value in i.distinguished_values.iter().flatten() {
+                visitor.visit_distinguished_value(value);
+            }
+            &i.constraints
+        }
+        ASN1Type::Real(r) => &r.constraints,
+        ASN1Type::BitString(b) => {
+            for value in b.distinguished_values.iter().flatten() {
+                visitor.visit_distinguished_value(value);
+            }
+            &b.constraints
+        }
+        ASN1Type::OctetString(o) => &o.constraints,
+        ASN1Type::CharacterString(c) => &c.constraints,
+        ASN1Type::Enumerated(e) => {
+            for member in &e.members {
+                visitor.visit_enumeral(member);
+            }
+            &e.constraints
+        }
+        ASN1Type::Choice(c) => {
+            for option in &c.options {
+                visitor.visit_choice_option(option);
+            }
+            &c.constraints
+        }
+        ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+            for member in &s.members {
+                visitor.visit_sequence_or_set_member(member);
+            }
+            &s.constraints
+        }
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+            visitor.visit_type(&s.element_type);
+            &s.constraints
+        }
+        ASN1Type::Time(t) => &t.constraints,
+        ASN1Type::GeneralizedTime(t) => &t.constraints,
+        ASN1Type::UTCTime(t) => &t.constraints,
+        ASN1Type::ObjectIdentifier(o)
+        | ASN1Type::RelativeOid(o)
+        | ASN1Type::OidIri(o)
+        | ASN1Type::RelativeOidIri(o) => &o.constraints,
+        ASN1Type::ObjectDescriptor(o) => &o.constraints,
+        ASN1Type::ObjectClassField(f) => &f.constraints,
+    };
+    for constraint in constraints {
+        visitor.visit_constraint(constraint);
+    }
+}
+
+pub fn walk_declaration_elsewhere<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &DeclarationElsewhere,
+) {
+    for constraint in &node.constraints {
+        visitor.visit_constraint(constraint);
+    }
+}
+
+pub fn walk_sequence_or_set_member<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &SequenceOrSetMember,
+) {
+    visitor.visit_type(&node.ty);
+    if let Optionality::Default(value) = &node.optionality {
+        visitor.visit_value(value);
+    }
+    for constraint in &node.constraints {
+        visitor.visit_constraint(constraint);
+    }
+}
+
+pub fn walk_choice_option<V: Visitor + ?Sized>(visitor: &mut V, node: &ChoiceOption) {
+    visitor.visit_type(&node.ty);
+    for constraint in &node.constraints {
+        visitor.visit_constraint(constraint);
+    }
+}
+
+pub fn walk_value<V: Visitor + ?Sized>(visitor: &mut V, node: &ASN1Value) {
+    match node {
+        ASN1Value::Choice { inner_value, .. } => visitor.visit_value(inner_value),
+        ASN1Value::SequenceOrSet(fields) => {
+            for (_, value) in fields {
+                visitor.visit_value(value);
+            }
+        }
+        ASN1Value::LinkedNestedValue { value, .. } => visitor.visit_value(value),
+        ASN1Value::ParameterizedValue {
+            actual_parameters, ..
+        } => {
+            for parameter in actual_parameters {
+                visitor.visit_parameter(parameter);
+            }
+        }
+        ASN1Value::LinkedStructLikeValue(fields) => {
+            for (_, ty, value) in fields {
+                visitor.visit_type(ty);
+                match value {
+                    StructLikeFieldValue::Explicit(v) | StructLikeFieldValue::Implicit(v) => {
+                        visitor.visit_value(v)
+                    }
+                }
+            }
+        }
+        ASN1Value::LinkedArrayLikeValue(values) => {
+            for value in values {
+                visitor.visit_value(value);
+            }
+        }
+        _ => (),
+    }
+}
+
+pub fn walk_constraint<V: Visitor + ?Sized>(visitor: &mut V, node: &Constraint) {
+    match node {
+        Constraint::Subtype(specs) => {
+            visitor.visit_element_or_set_operation(&specs.set);
+            if let Some(additions) = &specs.extension_additions {
+                visitor.visit_element_or_set_operation(additions);
+            }
+        }
+        Constraint::Table(table) => visitor.visit_table_constraint(table),
+        Constraint::Parameter(parameters) => {
+            for parameter in parameters {
+                visitor.visit_parameter(parameter);
+            }
+        }
+        Constraint::Content(content) => visitor.visit_content_constraint(content),
+    }
+}
+
+pub fn walk_element_or_set_operation<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ElementOrSetOperation,
+) {
+    match node {
+        ElementOrSetOperation::Element(element) => visitor.visit_subtype_elements(element),
+        ElementOrSetOperation::SetOperation(operation) => {
+            visitor.visit_subtype_elements(&operation.base);
+            visitor.visit_element_or_set_operation(&operation.operant);
+        }
+    }
+}
+
+pub fn walk_subtype_elements<V: Visitor + ?Sized>(visitor: &mut V, node: &SubtypeElements) {
+    match node {
+        SubtypeElements::SingleValue { value, .. } => visitor.visit_value(value),
+        SubtypeElements::ContainedSubtype { subtype, .. } => visitor.visit_type(subtype),
+        SubtypeElements::ValueRange { min, max, .. } => {
+            for value in [min, max].into_iter().flatten() {
+                visitor.visit_value(value);
+            }
+        }
+        SubtypeElements::PermittedAlphabet(set) | SubtypeElements::SizeConstraint(set) => {
+            visitor.visit_element_or_set_operation(set)
+        }
+        SubtypeElements::TypeConstraint(ty) => visitor.visit_type(ty),
+        SubtypeElements::SingleTypeConstraint(constraints) => {
+            for constraint in constraints {
+                visitor.visit_constraint(constraint);
+            }
+        }
+        SubtypeElements::MultipleTypeConstraints(inner) => {
+            for named in &inner.constraints {
+                visitor.visit_named_constraint(named);
+            }
+        }
+        SubtypeElements::PatternConstraint(_)
+        | SubtypeElements::UserDefinedConstraint(_)
+        | SubtypeElements::PropertySettings(_) => (),
+    }
+}
+
+pub fn walk_named_constraint<V: Visitor + ?Sized>(visitor: &mut V, node: &NamedConstraint) {
+    for constraint in &node.constraints {
+        visitor.visit_constraint(constraint);
+    }
+}
+
+pub fn walk_table_constraint<V: Visitor + ?Sized>(visitor: &mut V, node: &TableConstraint) {
+    visitor.visit_object_set(&node.object_set);
+}
+
+pub fn walk_content_constraint<V: Visitor + ?Sized>(visitor: &mut V, node: &ContentConstraint) {
+    match node {
+        ContentConstraint::Containing(ty) => visitor.visit_type(ty),
+        ContentConstraint::EncodedBy(value) => visitor.visit_value(value),
+        ContentConstraint::ContainingEncodedBy {
+            containing,
+            encoded_by,
+        } => {
+            visitor.visit_type(containing);
+            visitor.visit_value(encoded_by);
+        }
+    }
+}
+
+pub fn walk_parameter<V: Visitor + ?Sized>(visitor: &mut V, node: &Parameter) {
+    match node {
+        Parameter::ValueParameter(value) => visitor.visit_value(value),
+        Parameter::TypeParameter(ty) => visitor.visit_type(ty),
+        Parameter::InformationObjectParameter(fields) => {
+            visitor.visit_information_object_fields(fields)
+        }
+        Parameter::ObjectSetParameter(set) => visitor.visit_object_set(set),
+    }
+}
+
+pub fn walk_object_class_defn<V: Visitor + ?Sized>(visitor: &mut V, node: &ObjectClassDefn) {
+    for field in &node.fields {
+        visitor.visit_information_object_class_field(field);
+    }
+}
+
+pub fn walk_information_object_class_field<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &InformationObjectClassField,
+) {
+    if let Some(ty) = &node.ty {
+        visitor.visit_type(ty);
+    }
+    if let Optionality::Default(value) = &node.optionality {
+        visitor.visit_value(value);
+    }
+}
+
+pub fn walk_information<V: Visitor + ?Sized>(visitor: &mut V, node: &ASN1Information) {
+    match node {
+        ASN1Information::ObjectSet(set) => visitor.visit_object_set(set),
+        ASN1Information::Object(object) => visitor.visit_information_object_fields(&object.fields),
+    }
+}
+
+pub fn walk_information_object_fields<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &InformationObjectFields,
+) {
+    match node {
+        InformationObjectFields::DefaultSyntax(fields) => {
+            for field in fields {
+                visitor.visit_information_object_field(field);
+            }
+        }
+        InformationObjectFields::CustomSyntax(applications) => {
+            for application in applications {
+                visitor.visit_syntax_application(application);
+            }
+        }
+    }
+}
+
+pub fn walk_information_object_field<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &InformationObjectField,
+) {
+    match node {
+        InformationObjectField::TypeField(field) => visitor.visit_type(&field.ty),
+        InformationObjectField::FixedValueField(field) => visitor.visit_value(&field.value),
+        InformationObjectField::ObjectSetField(field) => visitor.visit_object_set(&field.value),
+    }
+}
+
+pub fn walk_syntax_application<V: Visitor + ?Sized>(visitor: &mut V, node: &SyntaxApplication) {
+    match node {
+        SyntaxApplication::ObjectSetDeclaration(set) => visitor.visit_object_set(set),
+        SyntaxApplication::ValueReference(value) => visitor.visit_value(value),
+        SyntaxApplication::TypeReference(ty) => visitor.visit_type(ty),
+        SyntaxApplication::LiteralOrTypeReference(reference) => {
+            visitor.visit_declaration_elsewhere(reference)
+        }
+        SyntaxApplication::Comma | SyntaxApplication::Literal(_) => (),
+    }
+}
+
+pub fn walk_object_set<V: Visitor + ?Sized>(visitor: &mut V, node: &ObjectSet) {
+    for value in &node.values {
+        match value {
+            ObjectSetValue::Inline(fields) => visitor.visit_information_object_fields(fields),
+            ObjectSetValue::Instantiation(_, parameters) => {
+                for parameter in parameters {
+                    visitor.visit_parameter(parameter);
+                }
+            }
+            ObjectSetValue::Reference(_) => (),
+        }
+    }
+}
+
+/// Mutable traversal of the intermediate representation.
+///
+/// Mirrors [`Visitor`], but hands out mutable references so that implementors
+/// can rewrite the nodes they visit. The default implementations call the
+/// matching `walk_*_mut` function.
+pub trait VisitorMut {
+    fn visit_toplevel_definition_mut(&mut self, node: &mut ToplevelDefinition) {
+        walk_toplevel_definition_mut(self, node)
+    }
+
+    fn visit_toplevel_type_definition_mut(&mut self, node: &mut ToplevelTypeDefinition) {
+        walk_toplevel_type_definition_mut(self, node)
+    }
+
+    fn visit_toplevel_value_definition_mut(&mut self, node: &mut ToplevelValueDefinition) {
+        walk_toplevel_value_definition_mut(self, node)
+    }
+
+    fn visit_object_class_assignment_mut(&mut self, node: &mut ObjectClassAssignment) {
+        walk_object_class_assignment_mut(self, node)
+    }
+
+    fn visit_toplevel_information_definition_mut(
+        &mut self,
+        node: &mut ToplevelInformationDefinition,
+    ) {
+        walk_toplevel_information_definition_mut(self, node)
+    }
+
+    fn visit_toplevel_macro_definition_mut(&mut self, _node: &mut ToplevelMacroDefinition) {}
+
+    fn visit_parameterization_mut(&mut self, node: &mut Parameterization) {
+        walk_parameterization_mut(self, node)
+    }
+
+    fn visit_type_mut(&mut self, node: &mut ASN1Type) {
+        walk_type_mut(self, node)
+    }
+
+    fn visit_declaration_elsewhere_mut(&mut self, node: &mut DeclarationElsewhere) {
+        walk_declaration_elsewhere_mut(self, node)
+    }
+
+    fn visit_sequence_or_set_member_mut(&mut self, node: &mut SequenceOrSetMember) {
+        walk_sequence_or_set_member_mut(self, node)
+    }
+
+    fn visit_choice_option_mut(&mut self, node: &mut ChoiceOption) {
+        walk_choice_option_mut(self, node)
+    }
+
+    fn visit_enumeral_mut(&mut self, _node: &mut Enumeral) {}
+
+    fn visit_distinguished_value_mut(&mut self, _node: &mut DistinguishedValue) {}
+
+    fn visit_value_mut(&mut self, node: &mut ASN1Value) {
+        walk_value_mut(self, node)
+    }
+
+    fn visit_constraint_mut(&mut self, node: &mut Constraint) {
+        walk_constraint_mut(self, node)
+    }
+
+    fn visit_element_or_set_operation_mut(&mut self, node: &mut ElementOrSetOperation) {
+        walk_element_or_set_operation_mut(self, node)
+    }
+
+    fn visit_subtype_elements_mut(&mut self, node: &mut SubtypeElements) {
+        walk_subtype_elements_mut(self, node)
+    }
+
+    fn visit_named_constraint_mut(&mut self, node: &mut NamedConstraint) {
+        walk_named_constraint_mut(self, node)
+    }
+
+    fn visit_table_constraint_mut(&mut self, node: &mut TableConstraint) {
+        walk_table_constraint_mut(self, node)
+    }
+
+    fn visit_content_constraint_mut(&mut self, node: &mut ContentConstraint) {
+        walk_content_constraint_mut(self, node)
+    }
+
+    fn visit_parameter_mut(&mut self, node: &mut Parameter) {
+        walk_parameter_mut(self, node)
+    }
+
+    fn visit_object_class_defn_mut(&mut self, node: &mut ObjectClassDefn) {
+        walk_object_class_defn_mut(self, node)
+    }
+
+    fn visit_information_object_class_field_mut(&mut self, node: &mut InformationObjectClassField) {
+        walk_information_object_class_field_mut(self, node)
+    }
+
+    fn visit_information_mut(&mut self, node: &mut ASN1Information) {
+        walk_information_mut(self, node)
+    }
+
+    fn visit_information_object_fields_mut(&mut self, node: &mut InformationObjectFields) {
+        walk_information_object_fields_mut(self, node)
+    }
+
+    fn visit_information_object_field_mut(&mut self, node: &mut InformationObjectField) {
+        walk_information_object_field_mut(self, node)
+    }
+
+    fn visit_syntax_application_mut(&mut self, node: &mut SyntaxApplication) {
+        walk_syntax_application_mut(self, node)
+    }
+
+    fn visit_object_set_mut(&mut self, node: &mut ObjectSet) {
+        walk_object_set_mut(self, node)
+    }
+}
+
+pub fn walk_toplevel_definition_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ToplevelDefinition,
+) {
+    match node {
+        ToplevelDefinition::Type(t) => visitor.visit_toplevel_type_definition_mut(t),
+        ToplevelDefinition::Value(v) => visitor.visit_toplevel_value_definition_mut(v),
+        ToplevelDefinition::Class(c) => visitor.visit_object_class_assignment_mut(c),
+        ToplevelDefinition::Object(o) => visitor.visit_toplevel_information_definition_mut(o),
+        ToplevelDefinition::Macro(m) => visitor.visit_toplevel_macro_definition_mut(m),
+    }
+}
+
+pub fn walk_toplevel_type_definition_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ToplevelTypeDefinition,
+) {
+    if let Some(parameterization) = &mut node.parameterization {
+        visitor.visit_parameterization_mut(parameterization);
+    }
+    visitor.visit_type_mut(&mut node.ty);
+}
+
+pub fn walk_toplevel_value_definition_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ToplevelValueDefinition,
+) {
+    if let Some(parameterization) = &mut node.parameterization {
+        visitor.visit_parameterization_mut(parameterization);
+    }
+    visitor.visit_type_mut(&mut node.associated_type);
+    visitor.visit_value_mut(&mut node.value);
+}
+
+pub fn walk_object_class_assignment_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ObjectClassAssignment,
+) {
+    visitor.visit_parameterization_mut(&mut node.parameterization);
+    visitor.visit_object_class_defn_mut(&mut node.definition);
+}
+
+pub fn walk_toplevel_information_definition_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ToplevelInformationDefinition,
+) {
+    if let Some(parameterization) = &mut node.parameterization {
+        visitor.visit_parameterization_mut(parameterization);
+    }
+    match &mut node.class {
+        ClassLink::ByReference(class) => visitor.visit_object_class_defn_mut(class),
+        ClassLink::ByInstantiation(_, parameters) => {
+            for parameter in parameters {
+                visitor.visit_parameter_mut(parameter);
+            }
+        }
+        ClassLink::ByName(_) => (),
+    }
+    visitor.visit_information_mut(&mut node.value);
+}
+
+pub fn walk_parameterization_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Parameterization,
+) {
+    for argument in &mut node.parameters {
+        if let ParameterGovernor::TypeOrClass(ty) = &mut argument.param_governor {
+            visitor.visit_type_mut(ty);
+        }
+    }
+}
+
+pub fn walk_type_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut ASN1Type) {
+    let constraints = match node {
+        ASN1Type::Null
+        | ASN1Type::EmbeddedPdv
+        | ASN1Type::External
+        | ASN1Type::UnrestrictedCharacterString
+        | ASN1Type::Any(_)
+        | ASN1Type::ChoiceSelectionType(_) => return,
+        ASN1Type::ElsewhereDeclaredType(e) => return visitor.visit_declaration_elsewhere_mut(e),
+        ASN1Type::Boolean(b) => &mut b.constraints,
+        ASN1Type::Integer(i) => {
+            for value in i.distinguished_values.iter_mut().flatten() {
+                visitor.visit_distinguished_value_mut(value);
+            }
+            &mut i.constraints
+        }
+        ASN1Type::Real(r) => &mut r.constraints,
+        ASN1Type::BitString(b) => {
+            for value in b.distinguished_values.iter_mut().flatten() {
+                visitor.visit_distinguished_value_mut(value);
+            }
+            &mut b.constraints
+        }
+        ASN1Type::OctetString(o) => &mut o.constraints,
+        ASN1Type::CharacterString(c) => &mut c.constraints,
+        ASN1Type::Enumerated(e) => {
+            for member in &mut e.members {
+                visitor.visit_enumeral_mut(member);
+            }
+            &mut e.constraints
+        }
+        ASN1Type::Choice(c) => {
+            for option in &mut c.options {
+                visitor.visit_choice_option_mut(option);
+            }
+            &mut c.constraints
+        }
+        ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+            for member in &mut s.members {
+                visitor.visit_sequence_or_set_member_mut(member);
+            }
+            &mut s.constraints
+        }
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+            visitor.visit_type_mut(&mut s.element_type);
+            &mut s.constraints
+        }
+        ASN1Type::Time(t) => &mut t.constraints,
+        ASN1Type::GeneralizedTime(t) => &mut t.constraints,
+        ASN1Type::UTCTime(t) => &mut t.constraints,
+        ASN1Type::ObjectIdentifier(o)
+        | ASN1Type::RelativeOid(o)
+        | ASN1Type::OidIri(o)
+        | ASN1Type::RelativeOidIri(o) => &mut o.constraints,
+        ASN1Type::ObjectDescriptor(o) => &mut o.constraints,
+        ASN1Type::ObjectClassField(f) => &mut f.constraints,
+    };
+    for constraint in constraints {
+        visitor.visit_constraint_mut(constraint);
+    }
+}
+
+pub fn walk_declaration_elsewhere_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DeclarationElsewhere,
+) {
+    for constraint in &mut node.constraints {
+        visitor.visit_constraint_mut(constraint);
+    }
+}
+
+pub fn walk_sequence_or_set_member_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut SequenceOrSetMember,
+) {
+    visitor.visit_type_mut(&mut node.ty);
+    if let Optionality::Default(value) = &mut node.optionality {
+        visitor.visit_value_mut(value);
+    }
+    for constraint in &mut node.constraints {
+        visitor.visit_constraint_mut(constraint);
+    }
+}
+
+pub fn walk_choice_option_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut ChoiceOption) {
+    visitor.visit_type_mut(&mut node.ty);
+    for constraint in &mut node.constraints {
+        visitor.visit_constraint_mut(constraint);
+    }
+}
+
+pub fn walk_value_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut ASN1Value) {
+    match node {
+        ASN1Value::Choice { inner_value, .. } => visitor.visit_value_mut(inner_value),
+        ASN1Value::SequenceOrSet(fields) => {
+            for (_, value) in fields {
+                visitor.visit_value_mut(value);
+            }
+        }
+        ASN1Value::LinkedNestedValue { value, .. } => visitor.visit_value_mut(value),
+        ASN1Value::ParameterizedValue {
+            actual_parameters, ..
+        } => {
+            for parameter in actual_parameters {
+                visitor.visit_parameter_mut(parameter);
+            }
+        }
+        ASN1Value::LinkedStructLikeValue(fields) => {
+            for (_, ty, value) in fields {
+                visitor.visit_type_mut(ty);
+                match value {
+                    StructLikeFieldValue::Explicit(v) | StructLikeFieldValue::Implicit(v) => {
+                        visitor.visit_value_mut(v)
+                    }
+                }
+            }
+        }
+        ASN1Value::LinkedArrayLikeValue(values) => {
+            for value in values {
+                visitor.visit_value_mut(value);
+            }
+        }
+        _ => (),
+    }
+}
+
+pub fn walk_constraint_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Constraint) {
+    match node {
+        Constraint::Subtype(specs) => {
+            visitor.visit_element_or_set_operation_mut(&mut specs.set);
+            if let Some(additions) = &mut specs.extension_additions {
+                visitor.visit_element_or_set_operation_mut(additions);
+            }
+        }
+        Constraint::Table(table) => visitor.visit_table_constraint_mut(table),
+        Constraint::Parameter(parameters) => {
+            for parameter in parameters {
+                visitor.visit_parameter_mut(parameter);
+            }
+        }
+        Constraint::Content(content) => visitor.visit_content_constraint_mut(content),
+    }
+}
+
+pub fn walk_element_or_set_operation_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ElementOrSetOperation,
+) {
+    match node {
+        ElementOrSetOperation::Element(element) => visitor.visit_subtype_elements_mut(element),
+        ElementOrSetOperation::SetOperation(operation) => {
+            visitor.visit_subtype_elements_mut(&mut operation.base);
+            visitor.visit_element_or_set_operation_mut(&mut operation.operant);
+        }
+    }
+}
+
+pub fn walk_subtype_elements_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut SubtypeElements,
+) {
+    match node {
+        SubtypeElements::SingleValue { value, .. } => visitor.visit_value_mut(value),
+        SubtypeElements::ContainedSubtype { subtype, .. } => visitor.visit_type_mut(subtype),
+        SubtypeElements::ValueRange { min, max, .. } => {
+            for value in [min, max].into_iter().flatten() {
+                visitor.visit_value_mut(value);
+            }
+        }
+        SubtypeElements::PermittedAlphabet(set) | SubtypeElements::SizeConstraint(set) => {
+            visitor.visit_element_or_set_operation_mut(set)
+        }
+        SubtypeElements::TypeConstraint(ty) => visitor.visit_type_mut(ty),
+        SubtypeElements::SingleTypeConstraint(constraints) => {
+            for constraint in constraints {
+                visitor.visit_constraint_mut(constraint);
+            }
+        }
+        SubtypeElements::MultipleTypeConstraints(inner) => {
+            for named in &mut inner.constraints {
+                visitor.visit_named_constraint_mut(named);
+            }
+        }
+        SubtypeElements::PatternConstraint(_)
+        | SubtypeElements::UserDefinedConstraint(_)
+        | SubtypeElements::PropertySettings(_) => (),
+    }
+}
+
+pub fn walk_named_constraint_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut NamedConstraint,
+) {
+    for constraint in &mut node.constraints {
+        visitor.visit_constraint_mut(constraint);
+    }
+}
+
+pub fn walk_table_constraint_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TableConstraint,
+) {
+    visitor.visit_object_set_mut(&mut node.object_set);
+}
+
+pub fn walk_content_constraint_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ContentConstraint,
+) {
+    match node {
+        ContentConstraint::Containing(ty) => visitor.visit_type_mut(ty),
+        ContentConstraint::EncodedBy(value) => visitor.visit_value_mut(value),
+        ContentConstraint::ContainingEncodedBy {
+            containing,
+            encoded_by,
+        } => {
+            visitor.visit_type_mut(containing);
+            visitor.visit_value_mut(encoded_by);
+        }
+    }
+}
+
+pub fn walk_parameter_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Parameter) {
+    match node {
+        Parameter::ValueParameter(value) => visitor.visit_value_mut(value),
+        Parameter::TypeParameter(ty) => visitor.visit_type_mut(ty),
+        Parameter::InformationObjectParameter(fields) => {
+            visitor.visit_information_object_fields_mut(fields)
+        }
+        Parameter::ObjectSetParameter(set) => visitor.visit_object_set_mut(set),
+    }
+}
+
+pub fn walk_object_class_defn_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ObjectClassDefn,
+) {
+    for field in &mut node.fields {
+        visitor.visit_information_object_class_field_mut(field);
+    }
+}
+
+pub fn walk_information_object_class_field_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut InformationObjectClassField,
+) {
+    if let Some(ty) = &mut node.ty {
+        visitor.visit_type_mut(ty);
+    }
+    if let Optionality::Default(value) = &mut node.optionality {
+        visitor.visit_value_mut(value);
+    }
+}
+
+pub fn walk_information_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut ASN1Information) {
+    match node {
+        ASN1Information::ObjectSet(set) => visitor.visit_object_set_mut(set),
+        ASN1Information::Object(object) => {
+            visitor.visit_information_object_fields_mut(&mut object.fields)
+        }
+    }
+}
+
+pub fn walk_information_object_fields_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut InformationObjectFields,
+) {
+    match node {
+        InformationObjectFields::DefaultSyntax(fields) => {
+            for field in fields {
+                visitor.visit_information_object_field_mut(field);
+            }
+        }
+        InformationObjectFields::CustomSyntax(applications) => {
+            for application in applications {
+                visitor.visit_syntax_application_mut(application);
+            }
+        }
+    }
+}
+
+pub fn walk_information_object_field_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut InformationObjectField,
+) {
+    match node {
+        InformationObjectField::TypeField(field) => visitor.visit_type_mut(&mut field.ty),
+        InformationObjectField::FixedValueField(field) => visitor.visit_value_mut(&mut field.value),
+        InformationObjectField::ObjectSetField(field) => {
+            visitor.visit_object_set_mut(&mut field.value)
+        }
+    }
+}
+
+pub fn walk_syntax_application_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut SyntaxApplication,
+) {
+    match node {
+        SyntaxApplication::ObjectSetDeclaration(set) => visitor.visit_object_set_mut(set),
+        SyntaxApplication::ValueReference(value) => visitor.visit_value_mut(value),
+        SyntaxApplication::TypeReference(ty) => visitor.visit_type_mut(ty),
+        SyntaxApplication::LiteralOrTypeReference(reference) => {
+            visitor.visit_declaration_elsewhere_mut(reference)
+        }
+        SyntaxApplication::Comma | SyntaxApplication::Literal(_) => (),
+    }
+}
+
+pub fn walk_object_set_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut ObjectSet) {
+    for value in &mut node.values {
+        match value {
+            ObjectSetValue::Inline(fields) => visitor.visit_information_object_fields_mut(fields),
+            ObjectSetValue::Instantiation(_, parameters) => {
+                for parameter in parameters {
+                    visitor.visit_parameter_mut(parameter);
+                }
+            }
+            ObjectSetValue::Reference(_) => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::asn_spec;
+
+    fn definitions(asn: &str) -> Vec<ToplevelDefinition> {
+        asn_spec(asn.into())
+            .unwrap()
+            .into_iter()
+            .flat_map(|(_, definitions)| definitions)
+            .collect()
+    }
+
+    const SPEC: &str = r#"
+    Test DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        Inner ::= INTEGER (0..7)
+        Outer ::= SEQUENCE {
+            wrapped OCTET STRING (CONTAINING Inner),
+            choice CHOICE {
+                bits BIT STRING (CONTAINING Inner ENCODED BY { 2 1 2 1 }),
+                plain BOOLEAN
+            },
+            list SEQUENCE OF Inner
+        }
+        outer-value Outer ::= { wrapped '00'H, list { 1, 2 } }
+    END
+    "#;
+
+    #[derive(Default)]
+    struct ContainingFinder {
+        current_field: Option<String>,
+        fields: Vec<String>,
+    }
+
+    impl Visitor for ContainingFinder {
+        fn visit_sequence_or_set_member(&mut self, node: &SequenceOrSetMember) {
+            self.current_field = Some(node.name.clone());
+            walk_sequence_or_set_member(self, node);
+        }
+
+        fn visit_choice_option(&mut self, node: &ChoiceOption) {
+            self.current_field = Some(node.name.clone());
+            walk_choice_option(self, node);
+        }
+
+        fn visit_content_constraint(&mut self, node: &ContentConstraint) {
+            self.fields.extend(self.current_field.clone());
+            walk_content_constraint(self, node);
+        }
+    }
+
+    #[test]
+    fn finds_containing_constraints() {
+        let mut finder = ContainingFinder::default();
+        for definition in definitions(SPEC) {
+            finder.visit_toplevel_definition(&definition);
+        }
+        assert_eq!(finder.fields, vec!["wrapped", "bits"]);
+    }
+
+    struct RenameReferences;
+
+    impl VisitorMut for RenameReferences {
+        fn visit_declaration_elsewhere_mut(&mut self, node: &mut DeclarationElsewhere) {
+            if node.identifier == "Inner" {
+                node.identifier = "Renamed".into();
+            }
+            walk_declaration_elsewhere_mut(self, node);
+        }
+    }
+
+    #[test]
+    fn rewrites_nested_references() {
+        let mut definitions = definitions(SPEC);
+        for definition in &mut definitions {
+            RenameReferences.visit_toplevel_definition_mut(definition);
+        }
+
+        #[derive(Default)]
+        struct References(Vec<String>);
+        impl Visitor for References {
+            fn visit_declaration_elsewhere(&mut self, node: &DeclarationElsewhere) {
+                self.0.push(node.identifier.clone());
+            }
+        }
+        let mut references = References::default();
+        for definition in &definitions {
+            references.visit_toplevel_definition(definition);
+        }
+        assert_eq!(references.0, vec!["Renamed", "Renamed", "Renamed", "Outer"]);
+    }
+}
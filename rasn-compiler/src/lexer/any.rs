@@ -0,0 +1,73 @@
+//! The `any` module contains lexers for the ASN.1:1990 `ANY` type,
+//! which is still used by many older specifications.
+use nom::{
+    bytes::complete::tag,
+    character::complete::satisfy,
+    combinator::{map, not, opt},
+    sequence::{preceded, terminated},
+    Parser,
+};
+
+use crate::{
+    input::Input,
+    intermediate::{types::Any, *},
+};
+
+use super::{
+    common::{identifier, skip_ws_and_comments},
+    error::ParserResult,
+};
+
+/// Tries to parse an ASN1 ANY
+///
+/// *`input` - [Input]-wrapped string slice to be matched against
+///
+/// `any` will try to match an `ANY` or an `ANY DEFINED BY component` declaration in the `input` string.
+/// If the match succeeds, the lexer will consume the match and return the remaining string
+/// and an `ASN1Type::Any` value representing the ASN1 declaration.
+/// If the match fails, the lexer will not consume the input and will return an error.
+pub fn any(input: Input<'_>) -> ParserResult<'_, ASN1Type> {
+    map(
+        preceded(
+            skip_ws_and_comments(terminated(
+                tag(ANY),
+                not(satisfy(|c| c.is_ascii_alphanumeric() || c == '-')),
+            )),
+            opt(preceded(
+                skip_ws_and_comments(tag(DEFINED_BY)),
+                skip_ws_and_comments(identifier),
+            )),
+        ),
+        |defined_by| {
+            ASN1Type::Any(Any {
+                defined_by: defined_by.map(String::from),
+            })
+        },
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::intermediate::{types::Any, ASN1Type};
+
+    use super::any;
+
+    #[test]
+    fn parses_any() {
+        assert_eq!(
+            any("ANY".into()).unwrap().1,
+            ASN1Type::Any(Any { defined_by: None })
+        );
+        assert_eq!(
+            any(" ANY DEFINED BY -- the algorithm\n algorithm".into())
+                .unwrap()
+                .1,
+            ASN1Type::Any(Any {
+                defined_by: Some("algorithm".into())
+            })
+        );
+        assert!(any("ANY-ELEMENT".into()).is_err());
+        assert!(any("AnyType".into()).is_err());
+    }
+}
@@ -98,11 +98,12 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::SingleValue {
-                            value: ASN1Value::Integer(8),
+                            value: ASN1Value::Integer(8.into()),
                             extensible: false
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })]
             })
         )
@@ -118,12 +119,13 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(8)),
-                            max: Some(ASN1Value::Integer(18)),
+                            min: Some(ASN1Value::Integer(8.into())),
+                            max: Some(ASN1Value::Integer(18.into())),
                             extensible: false
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })]
             })
         )
@@ -139,11 +141,12 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::SingleValue {
-                            value: ASN1Value::Integer(2),
+                            value: ASN1Value::Integer(2.into()),
                             extensible: true
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })]
             })
         )
@@ -159,12 +162,13 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(8)),
-                            max: Some(ASN1Value::Integer(18)),
+                            min: Some(ASN1Value::Integer(8.into())),
+                            max: Some(ASN1Value::Integer(18.into())),
                             extensible: true
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })]
             })
         )
@@ -185,29 +189,30 @@ mod tests {
                 distinguished_values: Some(vec![
                     DistinguishedValue {
                         name: "heavyLoad".into(),
-                        value: 0
+                        value: 0.into()
                     },
                     DistinguishedValue {
                         name: "excessWidth".into(),
-                        value: 1
+                        value: 1.into()
                     },
                     DistinguishedValue {
                         name: "excessLength".into(),
-                        value: 2
+                        value: 2.into()
                     },
                     DistinguishedValue {
                         name: "excessHeight".into(),
-                        value: 3
+                        value: 3.into()
                     },
                 ]),
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::SingleValue {
-                            value: ASN1Value::Integer(4),
+                            value: ASN1Value::Integer(4.into()),
                             extensible: false
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })]
             })
         )
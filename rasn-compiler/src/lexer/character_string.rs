@@ -1,9 +1,9 @@
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{char, u8},
-    combinator::{map, map_res, opt},
-    sequence::{delimited, pair, terminated},
+    character::complete::{char, satisfy, u8},
+    combinator::{map, map_res, not, opt, value},
+    sequence::{delimited, pair, preceded, terminated},
     Parser,
 };
 
@@ -133,6 +133,45 @@ pub fn character_string(input: Input<'_>) -> ParserResult<'_, ASN1Type> {
     .parse(input)
 }
 
+/// Tries to parse an ASN1 ObjectDescriptor
+///
+/// *`input` - [Input]-wrapped string slice to be matched against
+///
+/// `object_descriptor` will try to match an ObjectDescriptor declaration in the `input` string.
+/// An ObjectDescriptor is a GraphicString with its own universal tag 7 (see ITU-T X.680 §48).
+/// If the match succeeds, the lexer will consume the match and return the remaining string
+/// and an `ASN1Type::ObjectDescriptor` value representing the ASN1 declaration.
+/// If the match fails, the lexer will not consume the input and will return an error.
+pub fn object_descriptor(input: Input<'_>) -> ParserResult<'_, ASN1Type> {
+    map(
+        preceded(
+            skip_ws_and_comments(terminated(
+                tag(OBJECT_DESCRIPTOR),
+                not(satisfy(|c| c.is_ascii_alphanumeric() || c == '-')),
+            )),
+            opt(constraints),
+        ),
+        |m| ASN1Type::ObjectDescriptor(m.into()),
+    )
+    .parse(input)
+}
+
+/// Tries to parse an unrestricted ASN1 CHARACTER STRING
+///
+/// *`input` - [Input]-wrapped string slice to be matched against
+///
+/// `unrestricted_character_string` will try to match a CHARACTER STRING declaration in the `input` string.
+/// If the match succeeds, the lexer will consume the match and return the remaining string
+/// and an `ASN1Type::UnrestrictedCharacterString` value representing the ASN1 declaration.
+/// If the match fails, the lexer will not consume the input and will return an error.
+pub fn unrestricted_character_string(input: Input<'_>) -> ParserResult<'_, ASN1Type> {
+    value(
+        ASN1Type::UnrestrictedCharacterString,
+        skip_ws_and_comments(tag(CHARACTER_STRING)),
+    )
+    .parse(input)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::input::Input;
@@ -140,7 +179,10 @@ mod tests {
 
     use crate::lexer::{
         asn1_value,
-        character_string::{character_string_value, quadruple, raw_string_literal},
+        character_string::{
+            character_string_value, object_descriptor, quadruple, raw_string_literal,
+            unrestricted_character_string,
+        },
     };
 
     use super::character_string;
@@ -166,11 +208,12 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::SingleValue {
-                            value: ASN1Value::Integer(8),
+                            value: ASN1Value::Integer(8.into()),
                             extensible: false
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })],
                 ty: CharacterStringType::IA5String
             })
@@ -186,12 +229,13 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(8)),
-                            max: Some(ASN1Value::Integer(18)),
+                            min: Some(ASN1Value::Integer(8.into())),
+                            max: Some(ASN1Value::Integer(18.into())),
                             extensible: false
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })],
                 ty: CharacterStringType::IA5String
             })
@@ -209,11 +253,12 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::SingleValue {
-                            value: ASN1Value::Integer(2),
+                            value: ASN1Value::Integer(2.into()),
                             extensible: true
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })],
                 ty: CharacterStringType::IA5String
             })
@@ -229,12 +274,13 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(8)),
-                            max: Some(ASN1Value::Integer(18)),
+                            min: Some(ASN1Value::Integer(8.into())),
+                            max: Some(ASN1Value::Integer(18.into())),
                             extensible: true
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })],
                 ty: CharacterStringType::IA5String
             })
@@ -282,4 +328,37 @@ mod tests {
                 - Randall Munroe"
         );
     }
+
+    #[test]
+    fn parses_object_descriptor() {
+        assert_eq!(
+            object_descriptor(r#"ObjectDescriptor (SIZE(1..8))"#.into())
+                .unwrap()
+                .1,
+            ASN1Type::ObjectDescriptor(ObjectDescriptor {
+                constraints: vec![Constraint::Subtype(ElementSetSpecs {
+                    set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
+                        ElementOrSetOperation::Element(SubtypeElements::ValueRange {
+                            min: Some(ASN1Value::Integer(1.into())),
+                            max: Some(ASN1Value::Integer(8.into())),
+                            extensible: false
+                        })
+                    ))),
+                    extensible: false,
+                    extension_additions: None
+                })]
+            })
+        );
+        assert!(object_descriptor("ObjectDescriptorType".into()).is_err());
+    }
+
+    #[test]
+    fn parses_unrestricted_character_string() {
+        assert_eq!(
+            unrestricted_character_string("CHARACTER STRING".into())
+                .unwrap()
+                .1,
+            ASN1Type::UnrestrictedCharacterString
+        );
+    }
 }
@@ -16,7 +16,11 @@ use super::{constraint::constraints, error::ParserResult, *};
 
 pub fn choice_value(input: Input<'_>) -> ParserResult<'_, ASN1Value> {
     map(
-        skip_ws_and_comments(separated_pair(identifier, char(':'), asn1_value)),
+        skip_ws_and_comments(separated_pair(
+            identifier,
+            char(':'),
+            skip_ws_and_comments(asn1_value),
+        )),
         |(id, val)| ASN1Value::Choice {
             type_name: None,
             variant_name: id.to_owned(),
@@ -72,28 +76,16 @@ pub fn choice(input: Input<'_>) -> ParserResult<'_, ASN1Type> {
     map(
         preceded(
             skip_ws_and_comments(tag(CHOICE)),
-            in_braces((
-                many0(terminated(
-                    skip_ws_and_comments(choice_option),
-                    optional_comma,
-                )),
+            in_braces_keeping_comments((
+                many0(separated_option),
                 opt(terminated(
                     extension_marker,
                     opt(skip_ws_and_comments(char(COMMA))),
                 )),
                 opt(map(
                     many0(alt((
-                        map(
-                            terminated(skip_ws_and_comments(choice_option), optional_comma),
-                            |extension| vec![extension],
-                        ),
-                        terminated(
-                            in_brackets(in_brackets(many1(terminated(
-                                skip_ws_and_comments(choice_option),
-                                optional_comma,
-                            )))),
-                            optional_comma,
-                        ),
+                        map(separated_option, |extension| vec![extension]),
+                        extension_group,
                     ))),
                     |extensions| extensions.into_iter().flatten().collect(),
                 )),
@@ -104,13 +96,77 @@ pub fn choice(input: Input<'_>) -> ParserResult<'_, ASN1Type> {
     .parse(input)
 }
 
+/// Parses an extension addition group along with its separating comma.
+/// The comments preceding the group are attached to its first option
+/// and the comment on the same line after the group to its last option.
+fn extension_group(input: Input<'_>) -> ParserResult<'_, Vec<ChoiceOption>> {
+    map(
+        (
+            skip_ws(many0(comment)),
+            delimited(
+                pair(
+                    skip_ws_and_comments(char(LEFT_BRACKET)),
+                    skip_ws_and_comments(char(LEFT_BRACKET)),
+                ),
+                many1(separated_option),
+                pair(
+                    skip_ws_and_comments(char(RIGHT_BRACKET)),
+                    skip_ws_and_comments(char(RIGHT_BRACKET)),
+                ),
+            ),
+            optional_comma,
+            trailing_comment,
+        ),
+        |(comments, mut options, _, trailing)| {
+            if let Some(first) = options.first_mut() {
+                let mut leading = comments.join("\n");
+                if !first.comments.is_empty() {
+                    append_comment(&mut leading, &first.comments);
+                }
+                first.comments = leading;
+            }
+            if let (Some(last), Some(comment)) = (options.last_mut(), trailing) {
+                match &mut last.trailing_comment {
+                    Some(trailing_comment) => append_comment(trailing_comment, comment),
+                    None => last.trailing_comment = Some(comment.into()),
+                }
+            }
+            options
+        },
+    )
+    .parse(input)
+}
+
+/// Parses an option of a CHOICE along with its separating comma.
+/// A comment on the same line after the option is attached to it.
+fn separated_option(input: Input<'_>) -> ParserResult<'_, ChoiceOption> {
+    map(
+        (choice_option, optional_comma, trailing_comment),
+        |(mut option, _, comment)| {
+            option.trailing_comment = comment.map(String::from);
+            option
+        },
+    )
+    .parse(input)
+}
+
+/// Parses an option of a CHOICE along with the comments preceding it.
 fn choice_option(input: Input<'_>) -> ParserResult<'_, ChoiceOption> {
-    into((
-        skip_ws_and_comments(identifier),
-        opt(asn_tag),
-        skip_ws_and_comments(asn1_type),
-        opt(skip_ws_and_comments(constraints)),
-    ))
+    map(
+        pair(
+            skip_ws(many0(comment)),
+            into((
+                skip_ws_and_comments(identifier),
+                opt(asn_tag),
+                skip_ws_and_comments(asn1_type),
+                opt(skip_ws_and_comments(constraints)),
+            )),
+        ),
+        |(comments, option): (_, ChoiceOption)| ChoiceOption {
+            comments: comments.join("\n"),
+            ..option
+        },
+    )
     .parse(input)
 }
 
@@ -147,21 +203,27 @@ mod tests {
                         name: "normal".into(),
                         tag: None,
                         ty: ASN1Type::Null,
-                        constraints: vec![]
+                        constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     },
                     ChoiceOption {
                         is_recursive: false,
                         name: "high".into(),
                         tag: None,
                         ty: ASN1Type::Null,
-                        constraints: vec![]
+                        constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     },
                     ChoiceOption {
                         is_recursive: false,
                         name: "medium".into(),
                         tag: None,
                         ty: ASN1Type::Null,
-                        constraints: vec![]
+                        constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     }
                 ],
                 constraints: vec![]
@@ -199,6 +261,8 @@ mod tests {
                             constraints: vec![],
                         },),
                         constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     },
                     ChoiceOption {
                         is_recursive: false,
@@ -211,6 +275,8 @@ mod tests {
                             constraints: vec![],
                         },),
                         constraints: vec![],
+                        comments: String::from(" original extension indicator of V1"),
+                        trailing_comment: None,
                     },
                     ChoiceOption {
                         is_recursive: false,
@@ -223,6 +289,8 @@ mod tests {
                             constraints: vec![],
                         },),
                         constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: Some(" Extension in V2".into()),
                     },
                     ChoiceOption {
                         is_recursive: false,
@@ -235,6 +303,8 @@ mod tests {
                             constraints: vec![],
                         },),
                         constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: Some(" Extension in V3.1".into()),
                     },
                 ],
                 constraints: vec![],
@@ -2,7 +2,7 @@ use nom::{
     branch::alt,
     bytes::complete::{is_not, tag},
     character::complete::{
-        alpha1, alphanumeric1, char, i128, multispace0, multispace1, one_of, u64,
+        alpha1, alphanumeric1, char, digit1, multispace0, multispace1, one_of, space0, u64,
     },
     combinator::{cut, into, map, map_res, opt, peek, recognize, rest, success, value},
     multi::{many0, many1},
@@ -36,6 +36,19 @@ pub fn comment(input: Input<'_>) -> ParserResult<'_, &str> {
     skip_ws(alt((block_comment, line_comment))).parse(input)
 }
 
+/// Parses a comment that follows on the same line, e.g. after a component and its separating comma.
+pub fn trailing_comment(input: Input<'_>) -> ParserResult<'_, Option<&str>> {
+    opt(preceded(space0, alt((block_comment, line_comment)))).parse(input)
+}
+
+/// Appends a comment line to the comments of an IR element.
+pub fn append_comment(comments: &mut String, comment: &str) {
+    if !comments.is_empty() {
+        comments.push('\n');
+    }
+    comments.push_str(comment);
+}
+
 pub fn line_comment(input: Input<'_>) -> ParserResult<'_, &str> {
     delimited(
         tag(LINE_COMMENT),
@@ -181,6 +194,8 @@ where
     )
 }
 
+/// Parses the version brackets `[[` and `]]` of an extension addition group around `inner`.
+/// The comments at the start of the brackets are left to `inner`.
 pub fn in_version_brackets<'a, F>(
     inner: F,
 ) -> impl Parser<Input<'a>, Output = F::Output, Error = F::Error>
@@ -189,7 +204,7 @@ where
 {
     delimited(
         skip_ws_and_comments(tag("[[")),
-        skip_ws_and_comments(inner),
+        inner,
         skip_ws_and_comments(tag("]]")),
     )
 }
@@ -218,6 +233,21 @@ where
     )
 }
 
+/// Like [in_braces], but leaves the comments at the start of the braces to `inner`,
+/// e.g. to attach them to the first component of a constructed type.
+pub fn in_braces_keeping_comments<'a, F>(
+    inner: F,
+) -> impl Parser<Input<'a>, Output = F::Output, Error = F::Error>
+where
+    F: Parser<Input<'a>, Error = ErrorTree<'a>>,
+{
+    delimited(
+        skip_ws_and_comments(char(LEFT_BRACE)),
+        inner,
+        skip_ws_and_comments(char(RIGHT_BRACE)),
+    )
+}
+
 pub fn all_value(input: Input<'_>) -> ParserResult<'_, ASN1Value> {
     value(ASN1Value::All, skip_ws_and_comments(tag(ALL))).parse(input)
 }
@@ -269,11 +299,32 @@ pub fn distinguished_values(input: Input<'_>) -> ParserResult<'_, Vec<Distinguis
 }
 
 pub fn distinguished_val(input: Input<'_>) -> ParserResult<'_, DistinguishedValue> {
-    map_into(pair(skip_ws_and_comments(identifier), in_parentheses(i128))).parse(input)
+    map_into(pair(
+        skip_ws_and_comments(identifier),
+        in_parentheses(skip_ws_and_comments(big_integer)),
+    ))
+    .parse(input)
+}
+
+/// Parses a signed decimal number of arbitrary size.
+///
+/// ASN.1 does not limit the size of INTEGER values, and specifications
+/// such as cryptographic test vectors contain numbers far beyond 128 bits.
+pub fn big_integer(input: Input<'_>) -> ParserResult<'_, num::BigInt> {
+    map_res(
+        recognize(pair(opt(one_of("+-")), digit1)),
+        |number: Input<'_>| {
+            number
+                .inner()
+                .parse::<num::BigInt>()
+                .map_err(|_| MiscError("Failed to parse integer."))
+        },
+    )
+    .parse(input)
 }
 
 pub fn optional_comma(input: Input<'_>) -> ParserResult<'_, Option<char>> {
-    skip_ws_and_comments(opt(char(COMMA))).parse(input)
+    opt(skip_ws_and_comments(char(COMMA))).parse(input)
 }
 
 pub fn uppercase_identifier(input: Input<'_>) -> ParserResult<'_, &str> {
@@ -309,9 +360,9 @@ where
     F::Output: Clone,
 {
     alt((
-        value(Optionality::Optional, tag(OPTIONAL)),
+        value(Optionality::Optional, skip_ws_and_comments(tag(OPTIONAL))),
         preceded(
-            tag(DEFAULT),
+            skip_ws_and_comments(tag(DEFAULT)),
             cut(skip_ws_and_comments(f.map(Optionality::Default))),
         ),
         success(Optionality::Required),
@@ -509,11 +560,11 @@ and one */"#
             [
                 DistinguishedValue {
                     name: "positiveOutOfRange".into(),
-                    value: 160,
+                    value: 160.into(),
                 },
                 DistinguishedValue {
                     name: "unavailable".into(),
-                    value: 161,
+                    value: 161.into(),
                 },
             ]
         )
@@ -532,15 +583,15 @@ and one */"#
             [
                 DistinguishedValue {
                     name: "negativeOutOfRange".into(),
-                    value: 159,
+                    value: 159.into(),
                 },
                 DistinguishedValue {
                     name: "positiveOutOfRange".into(),
-                    value: 160,
+                    value: 160.into(),
                 },
                 DistinguishedValue {
                     name: "unavailable".into(),
-                    value: 161,
+                    value: 161.into(),
                 },
             ]
         )
@@ -561,7 +612,7 @@ and one */"#
                 .parse("DEFAULT\t-1".into())
                 .unwrap()
                 .1,
-            Optionality::Default(ASN1Value::Integer(-1))
+            Optionality::Default(ASN1Value::Integer((-1).into()))
         );
     }
 
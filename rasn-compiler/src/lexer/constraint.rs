@@ -7,7 +7,7 @@ use nom::{
     bytes::complete::tag,
     character::complete::char,
     combinator::{into, map, map_res, opt, value},
-    multi::{many0_count, many1, separated_list0, separated_list1},
+    multi::{many0_count, many1, separated_list1},
     sequence::{delimited, pair, preceded, separated_pair, terminated},
     Parser,
 };
@@ -34,6 +34,7 @@ pub fn constraints(input: Input<'_>) -> ParserResult<'_, Vec<Constraint>> {
             Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(c),
                 extensible: false,
+                extension_additions: None,
             })
         }),
         map(parameters, Constraint::Parameter),
@@ -64,6 +65,18 @@ pub fn constraint(input: Input<'_>) -> ParserResult<'_, Constraint> {
     .parse(input)
 }
 
+/// Parses the value set of a value set assignment as a subtype constraint.
+///
+/// # Syntax
+///
+/// ```text
+/// ValueSet ::=
+///     "{" ElementSetSpecs "}"
+/// ```
+pub fn value_set(input: Input<'_>) -> ParserResult<'_, Constraint> {
+    skip_ws_and_comments(in_braces(map(element_set_specs, Constraint::Subtype))).parse(input)
+}
+
 /// Parses a GeneralConstraint.
 ///
 /// # Syntax
@@ -137,7 +150,7 @@ pub fn set_operator(input: Input<'_>) -> ParserResult<'_, SetOperator> {
 ///     EXCEPT Elements
 /// ```
 fn element_set_specs(input: Input<'_>) -> ParserResult<'_, ElementSetSpecs> {
-    into(pair(
+    let (input, (set, marker)) = pair(
         alt((
             map(set_operation, ElementOrSetOperation::SetOperation),
             map(subtype_elements, ElementOrSetOperation::Element),
@@ -146,8 +159,34 @@ fn element_set_specs(input: Input<'_>) -> ParserResult<'_, ElementSetSpecs> {
             char(COMMA),
             extension_marker,
         ))),
+    )
+    .parse(input)?;
+    // The extension marker is consumed by the last root element if it is a value or a range
+    let (input, extension_additions) = if marker.is_some() || ends_with_extension_marker(&set) {
+        opt(extension_additions).parse(input)?
+    } else {
+        (input, None)
+    };
+    Ok((
+        input,
+        ElementSetSpecs {
+            set,
+            extensible: marker.is_some(),
+            extension_additions,
+        },
     ))
-    .parse(input)
+}
+
+fn ends_with_extension_marker(set: &ElementOrSetOperation) -> bool {
+    match set {
+        ElementOrSetOperation::Element(
+            SubtypeElements::SingleValue { extensible, .. }
+            | SubtypeElements::ContainedSubtype { extensible, .. }
+            | SubtypeElements::ValueRange { extensible, .. },
+        ) => *extensible,
+        ElementOrSetOperation::Element(_) => false,
+        ElementOrSetOperation::SetOperation(op) => ends_with_extension_marker(&op.operant),
+    }
 }
 
 fn set_operation(input: Input<'_>) -> ParserResult<'_, SetOperation> {
@@ -227,37 +266,28 @@ fn subtype_elements(input: Input<'_>) -> ParserResult<'_, SubtypeElements> {
     .parse(input)
 }
 
-fn extension_additions(input: Input<'_>) -> ParserResult<'_, ()> {
-    value(
-        (),
-        opt(pair(
-            skip_ws_and_comments(char(COMMA)),
-            skip_ws_and_comments(separated_list0(
-                skip_ws_and_comments(char(COMMA)),
-                skip_ws_and_comments(alt((
-                    value(
-                        0,
-                        pair(
-                            terminated(
-                                alt((value(None, tag(MIN)), map(asn1_value, Some))),
-                                skip_ws_and_comments(opt(char(GREATER_THAN))),
-                            ),
-                            preceded(
-                                range_seperator,
-                                preceded(
-                                    opt(char(LESS_THAN)),
-                                    skip_ws_and_comments(alt((
-                                        value(None, tag(MAX)),
-                                        map(asn1_value, Some),
-                                    ))),
-                                ),
-                            ),
-                        ),
-                    ),
-                    value(0, asn1_value),
-                ))),
-            )),
-        )),
+/// Parses the AdditionalElementSetSpec that follows the extension marker.
+/// Comma-separated values and ranges are accepted as well and read as their union.
+fn extension_additions(input: Input<'_>) -> ParserResult<'_, ElementOrSetOperation> {
+    preceded(
+        skip_ws_and_comments(char(COMMA)),
+        skip_ws_and_comments(alt((
+            map(set_operation, ElementOrSetOperation::SetOperation),
+            map(
+                separated_list1(skip_ws_and_comments(char(COMMA)), subtype_elements),
+                |elements| {
+                    let mut elements = elements.into_iter().rev();
+                    let last = ElementOrSetOperation::Element(elements.next().unwrap());
+                    elements.fold(last, |operant, base| {
+                        ElementOrSetOperation::SetOperation(SetOperation {
+                            base,
+                            operator: SetOperator::Union,
+                            operant: Box::new(operant),
+                        })
+                    })
+                },
+            ),
+        ))),
     )
     .parse(input)
 }
@@ -267,10 +297,9 @@ fn single_value(input: Input<'_>) -> ParserResult<'_, SubtypeElements> {
         skip_ws_and_comments(char(LEFT_PARENTHESIS)),
         skip_ws_and_comments(into(pair(
             asn1_value,
-            opt(skip_ws_and_comments(delimited(
+            opt(skip_ws_and_comments(preceded(
                 char(COMMA),
                 extension_marker,
-                extension_additions,
             ))),
         ))),
         skip_ws_and_comments(char(RIGHT_PARENTHESIS)),
@@ -315,10 +344,9 @@ fn value_range(input: Input<'_>) -> ParserResult<'_, SubtypeElements> {
                         skip_ws_and_comments(alt((value(None, tag(MAX)), map(asn1_value, Some)))),
                     ),
                 ),
-                opt(skip_ws_and_comments(delimited(
+                opt(skip_ws_and_comments(preceded(
                     char(COMMA),
                     extension_marker,
-                    extension_additions,
                 ))),
             ),
             |(min, max, ext)| SubtypeElements::ValueRange {
@@ -409,14 +437,8 @@ fn permitted_alphabet_constraint(input: Input<'_>) -> ParserResult<'_, SubtypeEl
     opt_delimited(
         skip_ws_and_comments(char(LEFT_PARENTHESIS)),
         skip_ws_and_comments(map(
-            preceded(
-                tag(FROM),
-                in_parentheses(alt((
-                    map(set_operation, ElementOrSetOperation::SetOperation),
-                    map(subtype_elements, ElementOrSetOperation::Element),
-                ))),
-            ),
-            |i| SubtypeElements::PermittedAlphabet(Box::new(i)),
+            preceded(tag(FROM), in_parentheses(element_set_specs)),
+            |specs| SubtypeElements::PermittedAlphabet(Box::new(specs.set)),
         )),
         skip_ws_and_comments(char(RIGHT_PARENTHESIS)),
     )
@@ -624,43 +646,47 @@ mod tests {
             constraints("(5)".into()).unwrap().1,
             vec![Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::SingleValue {
-                    value: ASN1Value::Integer(5),
+                    value: ASN1Value::Integer(5.into()),
                     extensible: false
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         );
         assert_eq!(
             constraints("(5..9)".into()).unwrap().1,
             vec![Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                    min: Some(ASN1Value::Integer(5)),
-                    max: Some(ASN1Value::Integer(9)),
+                    min: Some(ASN1Value::Integer(5.into())),
+                    max: Some(ASN1Value::Integer(9.into())),
                     extensible: false
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         );
         assert_eq!(
             constraints("(-5..9)".into()).unwrap().1,
             vec![Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                    min: Some(ASN1Value::Integer(-5)),
-                    max: Some(ASN1Value::Integer(9)),
+                    min: Some(ASN1Value::Integer((-5).into())),
+                    max: Some(ASN1Value::Integer(9.into())),
                     extensible: false
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         );
         assert_eq!(
             constraints("(-9..-4,...)".into()).unwrap().1,
             vec![Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                    min: Some(ASN1Value::Integer(-9)),
-                    max: Some(ASN1Value::Integer(-4)),
+                    min: Some(ASN1Value::Integer((-9).into())),
+                    max: Some(ASN1Value::Integer((-4).into())),
                     extensible: true
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         );
     }
@@ -671,11 +697,17 @@ mod tests {
             constraints("(1..32767,..., 8388607)".into()).unwrap().1,
             vec![Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                    min: Some(ASN1Value::Integer(1)),
-                    max: Some(ASN1Value::Integer(32767)),
+                    min: Some(ASN1Value::Integer(1.into())),
+                    max: Some(ASN1Value::Integer(32767.into())),
                     extensible: true
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: Some(ElementOrSetOperation::Element(
+                    SubtypeElements::SingleValue {
+                        value: ASN1Value::Integer(8388607.into()),
+                        extensible: false
+                    }
+                ))
             })]
         )
     }
@@ -686,11 +718,12 @@ mod tests {
             constraints("((5..9))".into()).unwrap().1,
             vec![Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                    min: Some(ASN1Value::Integer(5)),
-                    max: Some(ASN1Value::Integer(9)),
+                    min: Some(ASN1Value::Integer(5.into())),
+                    max: Some(ASN1Value::Integer(9.into())),
                     extensible: false
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         );
     }
@@ -703,11 +736,12 @@ mod tests {
                 .1,
             vec![Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                    min: Some(ASN1Value::Integer(-9)),
-                    max: Some(ASN1Value::Integer(-4)),
+                    min: Some(ASN1Value::Integer((-9).into())),
+                    max: Some(ASN1Value::Integer((-4).into())),
                     extensible: true
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         );
         assert_eq!(
@@ -716,11 +750,12 @@ mod tests {
                 .1,
             vec![Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                    min: Some(ASN1Value::Integer(-9)),
-                    max: Some(ASN1Value::Integer(-4)),
+                    min: Some(ASN1Value::Integer((-9).into())),
+                    max: Some(ASN1Value::Integer((-4).into())),
                     extensible: true
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         );
     }
@@ -732,12 +767,13 @@ mod tests {
             vec![Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                     ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                        min: Some(ASN1Value::Integer(3)),
-                        max: Some(ASN1Value::Integer(16)),
+                        min: Some(ASN1Value::Integer(3.into())),
+                        max: Some(ASN1Value::Integer(16.into())),
                         extensible: true
                     })
                 ))),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         )
     }
@@ -755,12 +791,13 @@ mod tests {
                     operator: SetOperator::Except,
                     operant: Box::new(ElementOrSetOperation::Element(
                         SubtypeElements::SingleValue {
-                            value: ASN1Value::Integer(1),
+                            value: ASN1Value::Integer(1.into()),
                             extensible: false
                         }
                     ))
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         )
     }
@@ -780,6 +817,7 @@ mod tests {
                     base: SubtypeElements::SingleTypeConstraint(vec![Constraint::Subtype(
                         ElementSetSpecs {
                             extensible: false,
+                            extension_additions: None,
                             set: ElementOrSetOperation::Element(
                                 SubtypeElements::MultipleTypeConstraints(InnerTypeConstraint {
                                     is_partial: true,
@@ -796,14 +834,15 @@ mod tests {
                                                     operant: Box::new(
                                                         ElementOrSetOperation::Element(
                                                             SubtypeElements::SingleValue {
-                                                                value: ASN1Value::Integer(1),
+                                                                value: ASN1Value::Integer(1.into()),
                                                                 extensible: false
                                                             }
                                                         )
                                                     )
                                                 }
                                             ),
-                                            extensible: false
+                                            extensible: false,
+                                            extension_additions: None
                                         })],
                                         presence: ComponentPresence::Unspecified
                                     }]
@@ -816,6 +855,7 @@ mod tests {
                         SubtypeElements::SingleTypeConstraint(vec![Constraint::Subtype(
                             ElementSetSpecs {
                                 extensible: false,
+                                extension_additions: None,
                                 set: ElementOrSetOperation::Element(
                                     SubtypeElements::MultipleTypeConstraints(InnerTypeConstraint {
                                         is_partial: true,
@@ -834,7 +874,7 @@ mod tests {
                                                                 ElementOrSetOperation::Element(
                                                                     SubtypeElements::SingleValue {
                                                                         value: ASN1Value::Integer(
-                                                                            2
+                                                                            2.into()
                                                                         ),
                                                                         extensible: false
                                                                     }
@@ -842,7 +882,8 @@ mod tests {
                                                             )
                                                         }
                                                     ),
-                                                    extensible: false
+                                                    extensible: false,
+                                                    extension_additions: None
                                                 }
                                             )],
                                             presence: ComponentPresence::Unspecified
@@ -853,7 +894,8 @@ mod tests {
                         )])
                     ))
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         )
     }
@@ -885,12 +927,13 @@ mod tests {
                                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                                     set: ElementOrSetOperation::Element(
                                         SubtypeElements::ValueRange {
-                                            min: Some(ASN1Value::Integer(0)),
-                                            max: Some(ASN1Value::Integer(5)),
+                                            min: Some(ASN1Value::Integer(0.into())),
+                                            max: Some(ASN1Value::Integer(5.into())),
                                             extensible: false
                                         }
                                     ),
-                                    extensible: false
+                                    extensible: false,
+                                    extension_additions: None
                                 })],
                                 presence: ComponentPresence::Present
                             },
@@ -902,7 +945,8 @@ mod tests {
                         ]
                     }
                 )),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         );
     }
@@ -934,19 +978,21 @@ mod tests {
                                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                                     set: ElementOrSetOperation::Element(
                                         SubtypeElements::ValueRange {
-                                            min: Some(ASN1Value::Integer(0)),
-                                            max: Some(ASN1Value::Integer(5)),
+                                            min: Some(ASN1Value::Integer(0.into())),
+                                            max: Some(ASN1Value::Integer(5.into())),
                                             extensible: false
                                         }
                                     ),
-                                    extensible: false
+                                    extensible: false,
+                                    extension_additions: None
                                 })],
                                 presence: ComponentPresence::Unspecified
                             }
                         ]
                     }
                 )),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         );
     }
@@ -976,7 +1022,8 @@ mod tests {
                                     }]
                                 }),
                             ),
-                            extensible: false
+                            extensible: false,
+                            extension_additions: None
                         }
                     )]),
                     operator: SetOperator::Union,
@@ -993,12 +1040,14 @@ mod tests {
                                         }]
                                     })
                                 ),
-                                extensible: false
+                                extensible: false,
+                                extension_additions: None
                             }
                         )])
                     ))
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         );
     }
@@ -1050,7 +1099,8 @@ mod tests {
                         })
                     ))
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         );
     }
@@ -1068,27 +1118,28 @@ mod tests {
             vec![Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::SetOperation(SetOperation {
                     base: SubtypeElements::ValueRange {
-                        min: Some(ASN1Value::Integer(0)),
-                        max: Some(ASN1Value::Integer(3)),
+                        min: Some(ASN1Value::Integer(0.into())),
+                        max: Some(ASN1Value::Integer(3.into())),
                         extensible: false
                     },
                     operator: SetOperator::Union,
                     operant: Box::new(ElementOrSetOperation::SetOperation(SetOperation {
                         base: SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(5)),
-                            max: Some(ASN1Value::Integer(8)),
+                            min: Some(ASN1Value::Integer(5.into())),
+                            max: Some(ASN1Value::Integer(8.into())),
                             extensible: false
                         },
                         operator: SetOperator::Union,
                         operant: Box::new(ElementOrSetOperation::Element(
                             SubtypeElements::SingleValue {
-                                value: ASN1Value::Integer(10),
+                                value: ASN1Value::Integer(10.into()),
                                 extensible: false
                             }
                         ))
                     }))
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         );
     }
@@ -1137,7 +1188,8 @@ mod tests {
                         ))
                     }))
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         );
     }
@@ -1166,7 +1218,7 @@ mod tests {
                         ObjectSetValue::Inline(InformationObjectFields::DefaultSyntax(vec![
                             InformationObjectField::FixedValueField(FixedValueField {
                                 identifier: "&id".into(),
-                                value: ASN1Value::Integer(5)
+                                value: ASN1Value::Integer(5.into())
                             }),
                             InformationObjectField::TypeField(TypeField {
                                 identifier: "&Type".into(),
@@ -1174,12 +1226,13 @@ mod tests {
                                     constraints: vec![Constraint::Subtype(ElementSetSpecs {
                                         set: ElementOrSetOperation::Element(
                                             SubtypeElements::ValueRange {
-                                                min: Some(ASN1Value::Integer(1)),
-                                                max: Some(ASN1Value::Integer(6)),
+                                                min: Some(ASN1Value::Integer(1.into())),
+                                                max: Some(ASN1Value::Integer(6.into())),
                                                 extensible: false
                                             }
                                         ),
-                                        extensible: false
+                                        extensible: false,
+                                        extension_additions: None
                                     })],
                                     distinguished_values: None,
                                 })
@@ -1314,17 +1367,19 @@ mod tests {
                             }))
                         }))
                     )),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 }),
                 Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(1)),
-                            max: Some(ASN1Value::Integer(255)),
+                            min: Some(ASN1Value::Integer(1.into())),
+                            max: Some(ASN1Value::Integer(255.into())),
                             extensible: false
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })
             ]
         )
@@ -1352,12 +1407,13 @@ mod tests {
                                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                                     set: ElementOrSetOperation::Element(
                                         SubtypeElements::ValueRange {
-                                            min: Some(ASN1Value::Integer(-16777215)),
-                                            max: Some(ASN1Value::Integer(16777215)),
+                                            min: Some(ASN1Value::Integer((-16777215).into())),
+                                            max: Some(ASN1Value::Integer(16777215.into())),
                                             extensible: false
                                         }
                                     ),
-                                    extensible: false
+                                    extensible: false,
+                                    extension_additions: None
                                 })],
                                 presence: ComponentPresence::Unspecified
                             },
@@ -1366,11 +1422,12 @@ mod tests {
                                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                                     set: ElementOrSetOperation::Element(
                                         SubtypeElements::SingleValue {
-                                            value: ASN1Value::Integer(2),
+                                            value: ASN1Value::Integer(2.into()),
                                             extensible: false
                                         }
                                     ),
-                                    extensible: false
+                                    extensible: false,
+                                    extension_additions: None
                                 })],
                                 presence: ComponentPresence::Unspecified
                             },
@@ -1379,19 +1436,21 @@ mod tests {
                                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                                     set: ElementOrSetOperation::Element(
                                         SubtypeElements::ValueRange {
-                                            min: Some(ASN1Value::Integer(-125)),
-                                            max: Some(ASN1Value::Integer(128)),
+                                            min: Some(ASN1Value::Integer((-125).into())),
+                                            max: Some(ASN1Value::Integer(128.into())),
                                             extensible: false
                                         }
                                     ),
-                                    extensible: false
+                                    extensible: false,
+                                    extension_additions: None
                                 })],
                                 presence: ComponentPresence::Unspecified
                             }
                         ]
                     }
                 )),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         )
     }
@@ -1408,7 +1467,8 @@ mod tests {
                         pattern: "[a-zA-Z]#(1,8)(-[a-zA-Z0-9]#(1,8))*".into()
                     }
                 )),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         )
     }
@@ -1429,8 +1489,7 @@ mod tests {
                                 }
                                 )
                             ),
-                            extensible: false
-                        }
+                            extensible: false, extension_additions: None }
                     )
                 ]
             )
@@ -1443,12 +1502,13 @@ mod tests {
             vec![Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                     ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                        min: Some(ASN1Value::Integer(1)),
-                        max: Some(ASN1Value::Integer(4)),
+                        min: Some(ASN1Value::Integer(1.into())),
+                        max: Some(ASN1Value::Integer(4.into())),
                         extensible: false
                     })
                 ))),
-                extensible: true
+                extensible: true,
+                extension_additions: None
             })]
         );
         assert_eq!(
@@ -1456,12 +1516,13 @@ mod tests {
             vec![Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                     ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                        min: Some(ASN1Value::Integer(1)),
-                        max: Some(ASN1Value::Integer(4)),
+                        min: Some(ASN1Value::Integer(1.into())),
+                        max: Some(ASN1Value::Integer(4.into())),
                         extensible: true
                     })
                 ))),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         )
     }
@@ -1480,7 +1541,8 @@ mod tests {
                         )]
                     }
                 )),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })]
         );
     }
@@ -1493,11 +1555,18 @@ mod tests {
                 .1,
             vec![Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                    min: Some(ASN1Value::Integer(1)),
-                    max: Some(ASN1Value::Integer(65535)),
+                    min: Some(ASN1Value::Integer(1.into())),
+                    max: Some(ASN1Value::Integer(65535.into())),
                     extensible: true
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: Some(ElementOrSetOperation::Element(
+                    SubtypeElements::ValueRange {
+                        min: Some(ASN1Value::Integer(65536.into())),
+                        max: Some(ASN1Value::Integer(109999.into())),
+                        extensible: false
+                    }
+                ))
             })]
         )
     }
@@ -1523,19 +1592,21 @@ mod tests {
                                 }
                             ),
                             extensible: false,
+                            extension_additions: None,
                         }
                     ),]),
                     operator: SetOperator::Intersection,
                     operant: Box::new(ElementOrSetOperation::Element(
                         SubtypeElements::SizeConstraint(Box::new(ElementOrSetOperation::Element(
                             SubtypeElements::SingleValue {
-                                value: ASN1Value::Integer(1,),
+                                value: ASN1Value::Integer(1.into()),
                                 extensible: false,
                             },
                         ))),
                     ))
                 }),
                 extensible: false,
+                extension_additions: None,
             })],
             constraints(r#"((WITH COMPONENT (EtsiTs103097Certificate))^(SIZE(1)))"#.into())
                 .unwrap()
@@ -1,8 +1,10 @@
+use std::collections::BTreeSet;
+
 use nom::{
     bytes::complete::tag,
     character::complete::{char, i128},
     combinator::{map, opt},
-    multi::{fold_many0, many0},
+    multi::many0,
     sequence::{preceded, terminated},
     Parser,
 };
@@ -32,6 +34,7 @@ pub fn enumerated_value(input: Input<'_>) -> ParserResult<'_, ToplevelValueDefin
             preceded(assignment, skip_ws_and_comments(value_reference)),
         ),
         |(c, n, params, p, e)| ToplevelValueDefinition {
+            trailing_comment: None,
             comments: c.into_iter().fold(String::new(), |mut acc, s| {
                 acc = acc + "\n" + s;
                 acc
@@ -75,34 +78,73 @@ fn enumeral(input: Input<'_>) -> ParserResult<'_, EnumeralInput<'_>> {
     .parse(input)
 }
 
-fn enumerals<'a>(
-    start_index: usize,
-) -> impl Parser<Input<'a>, Output = Vec<Enumeral>, Error = ErrorTree<'a>> {
-    fold_many0(
-        enumeral,
-        Vec::<Enumeral>::new,
-        move |mut acc, (name, index, _, comments)| {
-            acc.push(Enumeral {
-                name: name.into(),
-                description: comments.map(|c| c.into()),
-                index: index.unwrap_or((acc.len() + start_index) as i128),
-            });
-            acc
-        },
-    )
+fn enumerals<'a>() -> impl Parser<Input<'a>, Output = Vec<EnumeralInput<'a>>, Error = ErrorTree<'a>>
+{
+    many0(enumeral)
 }
 
 fn enumerated_body(input: Input<'_>) -> ParserResult<'_, EnumeralBody> {
-    in_braces(|input| {
-        let (input, root_enumerals) = enumerals(0).parse(input)?;
-        let (input, ext_marker) =
-            opt(terminated(extension_marker, opt(char(COMMA)))).parse(input)?;
-        let (input, ext_enumerals) = opt(enumerals(root_enumerals.len())).parse(input)?;
-        Ok((input, (root_enumerals, ext_marker, ext_enumerals)))
-    })
+    map(
+        in_braces((
+            enumerals(),
+            opt(terminated(extension_marker, opt(char(COMMA)))),
+            opt(enumerals()),
+        )),
+        |(root, ext_marker, additions)| {
+            let (root, additions) = number_enumerals(root, additions.unwrap_or_default());
+            (
+                root,
+                ext_marker,
+                (!additions.is_empty()).then_some(additions),
+            )
+        },
+    )
     .parse(input)
 }
 
+/// Assigns their numbers to the enumerals without an explicit one.
+/// *As defined in Rec. ITU-T X.680 (02/2021) §20*
+/// Root enumerals receive the smallest non-negative integers that are not yet in use,
+/// while additional enumerals receive the smallest integer that is greater than
+/// the numbers of the preceding additions and not used by the root enumerals.
+fn number_enumerals(
+    root: Vec<EnumeralInput<'_>>,
+    additions: Vec<EnumeralInput<'_>>,
+) -> (Vec<Enumeral>, Vec<Enumeral>) {
+    let into_enumeral = |(name, _, _, comment): EnumeralInput<'_>, index| Enumeral {
+        name: name.into(),
+        description: comment.map(|c| c.into()),
+        index,
+    };
+    let mut used = root
+        .iter()
+        .filter_map(|(_, index, _, _)| *index)
+        .collect::<BTreeSet<_>>();
+    let next_unused =
+        |used: &BTreeSet<i128>, from: i128| (from..).find(|i| !used.contains(i)).unwrap_or(from);
+    let root = root
+        .into_iter()
+        .map(|enumeral| {
+            let index = enumeral.1.unwrap_or_else(|| next_unused(&used, 0));
+            used.insert(index);
+            into_enumeral(enumeral, index)
+        })
+        .collect();
+    let mut previous = None;
+    let additions = additions
+        .into_iter()
+        .map(|enumeral| {
+            let index = enumeral
+                .1
+                .unwrap_or_else(|| next_unused(&used, previous.map_or(0, |p: i128| p + 1)));
+            used.insert(index);
+            previous = Some(index);
+            into_enumeral(enumeral, index)
+        })
+        .collect();
+    (root, additions)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -111,16 +153,16 @@ mod tests {
     #[test]
     fn parses_enumerals_with_line_comments() {
         assert_eq!(
-            enumerals(0)
-                .parse(
-                    r#"forward     (1), -- This means forward
+            enumerated_body(
+                r#"{ forward     (1), -- This means forward
       backward    (2), -- This means backward
       unavailable (3)  -- This means nothing
-      "#
-                    .into()
-                )
-                .unwrap()
-                .1,
+      }"#
+                .into()
+            )
+            .unwrap()
+            .1
+             .0,
             [
                 Enumeral {
                     name: "forward".into(),
@@ -235,6 +277,35 @@ mod tests {
         )
     }
 
+    #[test]
+    fn numbers_root_enumerals_around_explicit_numbers() {
+        let ASN1Type::Enumerated(e) = enumerated("ENUMERATED { a, b(0), c, d(2), e }".into())
+            .unwrap()
+            .1
+        else {
+            unreachable!()
+        };
+        assert_eq!(
+            e.members.iter().map(|m| m.index).collect::<Vec<_>>(),
+            [1, 0, 3, 2, 4]
+        );
+    }
+
+    #[test]
+    fn numbers_additional_enumerals_after_preceding_additions() {
+        let ASN1Type::Enumerated(e) =
+            enumerated("ENUMERATED { a(1), b(2), ..., c, d(7), e, f }".into())
+                .unwrap()
+                .1
+        else {
+            unreachable!()
+        };
+        assert_eq!(
+            e.members.iter().map(|m| m.index).collect::<Vec<_>>(),
+            [1, 2, 0, 7, 8, 9]
+        );
+    }
+
     #[test]
     fn parses_enumerated_with_ellipsis() {
         assert_eq!(
@@ -343,6 +414,7 @@ mod tests {
             .1,
             ToplevelValueDefinition {
                 comments: String::from("\n Alias of another enumeral"),
+                trailing_comment: None,
                 name: String::from("enumeral-alias"),
                 associated_type: ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
                     parent: None,
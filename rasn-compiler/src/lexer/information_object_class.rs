@@ -18,7 +18,7 @@ use crate::{
     },
     lexer::{
         common::{assignment, comment, optionality, skip_ws},
-        parameterization::parameterization,
+        parameterization::{parameterization, parameters},
     },
 };
 
@@ -53,6 +53,7 @@ pub fn object_class_assignement(input: Input<'_>) -> ParserResult<'_, ObjectClas
         ),
         |v| ObjectClassAssignment {
             comments: v.0.join("\n"),
+            trailing_comment: None,
             name: v.1.into(),
             parameterization: v.2.unwrap_or_default(),
             definition: v.3,
@@ -169,6 +170,7 @@ pub fn object_class_defn(input: Input<'_>) -> ParserResult<'_, ObjectClassDefn>
 pub fn object_class_field_type(input: Input<'_>) -> ParserResult<'_, ObjectClassFieldType> {
     into((
         skip_ws_and_comments(uppercase_identifier),
+        opt(parameters),
         many1(skip_ws_and_comments(preceded(
             char(DOT),
             skip_ws_and_comments(object_field_identifier),
@@ -192,6 +194,12 @@ pub fn object_set(input: Input<'_>) -> ParserResult<'_, ObjectSet> {
             skip_ws_and_comments(union_mark),
             skip_ws_and_comments(alt((
                 into(information_object),
+                map(
+                    pair(skip_ws_and_comments(identifier), parameters),
+                    |(identifier, parameters)| {
+                        ObjectSetValue::Instantiation(identifier.into(), parameters)
+                    },
+                ),
                 into(skip_ws_and_comments(identifier)),
             ))),
         ),
@@ -258,7 +266,7 @@ fn information_object_field(input: Input<'_>) -> ParserResult<'_, InformationObj
         skip_ws_and_comments(object_field_identifier),
         opt(skip_ws_and_comments(asn1_type)),
         opt(into_inner(skip_ws_and_comments(tag(UNIQUE)))),
-        skip_ws_and_comments(optionality(asn1_value)),
+        optionality(asn1_value),
     ))
     .parse(input)
 }
@@ -365,7 +373,9 @@ mod tests {
                                         constraints: vec![],
                                         distinguished_values: None,
                                     }),
-                                    constraints: vec![]
+                                    constraints: vec![],
+                                    comments: String::new(),
+                                    trailing_comment: None,
                                 },
                                 ChoiceOption {
                                     is_recursive: false,
@@ -374,7 +384,9 @@ mod tests {
                                     ty: ASN1Type::OctetString(OctetString {
                                         constraints: vec![],
                                     }),
-                                    constraints: vec![]
+                                    constraints: vec![],
+                                    comments: String::new(),
+                                    trailing_comment: None,
                                 }
                             ],
                             constraints: vec![]
@@ -553,6 +565,7 @@ mod tests {
                 .1,
             ToplevelTypeDefinition {
                 comments: "".into(),
+                trailing_comment: None,
                 tag: None,
                 name: "AttributeValue".into(),
                 ty: ASN1Type::ObjectClassField(ObjectClassFieldType {
@@ -608,7 +621,7 @@ mod tests {
         let (rest, res) = optionality(asn1_value).parse(input).unwrap();
 
         assert_eq!(rest.inner(), ",");
-        assert_eq!(res, Optionality::Default(ASN1Value::Integer(123)));
+        assert_eq!(res, Optionality::Default(ASN1Value::Integer(123.into())));
     }
 
     #[test]
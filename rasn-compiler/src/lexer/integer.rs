@@ -1,7 +1,6 @@
 use error::ParserResult;
 use nom::{
     bytes::complete::tag,
-    character::complete::i128,
     combinator::{map, opt},
     Parser,
 };
@@ -11,7 +10,7 @@ use crate::intermediate::{ASN1Type, ASN1Value, INTEGER};
 use super::{constraint::*, *};
 
 pub fn integer_value(input: Input<'_>) -> ParserResult<'_, ASN1Value> {
-    map(skip_ws_and_comments(i128), ASN1Value::Integer).parse(input)
+    map(skip_ws_and_comments(big_integer), ASN1Value::Integer).parse(input)
 }
 
 /// Tries to parse an ASN1 INTEGER
@@ -52,11 +51,12 @@ mod tests {
             ASN1Type::Integer(Integer {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                        min: Some(ASN1Value::Integer(-9)),
-                        max: Some(ASN1Value::Integer(-4)),
+                        min: Some(ASN1Value::Integer((-9).into())),
+                        max: Some(ASN1Value::Integer((-4).into())),
                         extensible: true
                     }),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })],
                 distinguished_values: None,
             })
@@ -66,14 +66,29 @@ mod tests {
             ASN1Type::Integer(Integer {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                        min: Some(ASN1Value::Integer(-9)),
-                        max: Some(ASN1Value::Integer(-4)),
+                        min: Some(ASN1Value::Integer((-9).into())),
+                        max: Some(ASN1Value::Integer((-4).into())),
                         extensible: false
                     }),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })],
                 distinguished_values: None,
             })
         );
     }
+
+    #[test]
+    fn parses_integer_values_beyond_128_bits() {
+        assert_eq!(
+            integer_value("-340282366920938463463374607431768211457".into())
+                .unwrap()
+                .1,
+            ASN1Value::Integer(
+                "-340282366920938463463374607431768211457"
+                    .parse::<num::BigInt>()
+                    .unwrap()
+            )
+        );
+    }
 }
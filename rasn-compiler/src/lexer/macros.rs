@@ -1,7 +1,7 @@
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::char;
-use nom::combinator::{cut, map, map_res, not, opt, peek, value};
+use nom::combinator::{consumed, cut, map, map_res, not, opt, peek, value};
 use nom::multi::{many0, many1, separated_list1};
 use nom::sequence::{delimited, pair, preceded, separated_pair, terminated};
 use nom::Parser;
@@ -24,6 +24,8 @@ use super::character_string::raw_string_literal;
 pub struct MacroDefinition<'i> {
     pub name: &'i str,
     pub substance: MacroSubstance<'i>,
+    /// Source text of the macro substance, starting with `BEGIN` or the macro reference
+    pub substance_source: &'i str,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -65,11 +67,12 @@ pub fn macro_definition(input: Input<'_>) -> ParserResult<'_, MacroDefinition<'_
                 skip_ws_and_comments(tag(MACRO)),
                 skip_ws_and_comments(tag(ASSIGN)),
             ),
-            context_boundary(cut(macro_substance)),
+            context_boundary(cut(consumed(macro_substance))),
         ),
-        |v| MacroDefinition {
-            name: v.0,
-            substance: v.1,
+        |(name, (source, substance))| MacroDefinition {
+            name,
+            substance,
+            substance_source: source.into_inner().trim(),
         },
     )
     .parse(input)
@@ -585,8 +588,7 @@ mod tests {
 
     #[test]
     fn parses_snmp_trap_type() {
-        let input = Input::from(
-            r#"
+        let source = r#"
             TRAP-TYPE MACRO ::=
             BEGIN
                 TYPE NOTATION ::= "ENTERPRISE" value
@@ -609,8 +611,8 @@ mod tests {
                         "REFERENCE" value (reference DisplayString)
                         | empty
             END
-            "#,
-        );
+            "#;
+        let input = Input::from(source);
 
         let (_, result) = macro_definition(input).unwrap();
 
@@ -734,7 +736,9 @@ mod tests {
                             ]
                         }
                     ]
-                })
+                }),
+                substance_source: &source
+                    [source.find("BEGIN").unwrap()..source.rfind("END").unwrap() + 3]
             }
         );
     }
@@ -13,7 +13,7 @@ use nom::{
     branch::alt,
     bytes::complete::{tag, take_until},
     character::complete::{char, multispace1},
-    combinator::{into, map, opt, recognize},
+    combinator::{into, map, map_opt, opt, peek, recognize, verify},
     multi::{many0, many1},
     sequence::{delimited, pair, preceded, terminated},
     Parser,
@@ -27,12 +27,15 @@ use crate::{
 use crate::{intermediate::macros::ToplevelMacroDefinition, AsnSourceUnit};
 
 use self::{
-    bit_string::*, boolean::*, character_string::*, choice::*, common::*, constraint::*,
+    any::*, bit_string::*, boolean::*, character_string::*, choice::*, common::*, constraint::*,
     embedded_pdv::*, enumerated::*, error::LexerError, external::*, information_object_class::*,
     integer::*, null::*, object_identifier::*, octet_string::*, parameterization::*, real::*,
     sequence::*, sequence_of::*, set::*, set_of::*, time::*,
 };
 
+pub(crate) use self::information_object_class::type_identifier;
+
+mod any;
 mod bit_string;
 mod boolean;
 mod character_string;
@@ -86,30 +89,109 @@ pub fn asn_spec(
     }
 }
 
+/// Parses the headers of the modules in an ASN.1 source without lexing their bodies.
+/// A body is skipped up to the next `END` keyword that is followed by the header
+/// of another module or by the end of the input.
+pub(crate) fn module_headers(input: AsnSourceUnit) -> Result<Vec<ModuleHeader>, LexerError> {
+    let mut headers = Vec::new();
+    let mut remaining_input = Input::from(&input);
+    'modules: loop {
+        let (body, header) = module_header::module_header(remaining_input)?;
+        headers.push(header);
+        remaining_input = body;
+        while let Some(position) = find_keyword(remaining_input.inner(), END) {
+            let Ok((next, _)) = end(nom::Input::take_from(&remaining_input, position)) else {
+                remaining_input = nom::Input::take_from(&remaining_input, position + END.len());
+                continue;
+            };
+            if next.is_empty() {
+                return Ok(headers);
+            }
+            if module_header::module_header(next.clone()).is_ok() {
+                remaining_input = next;
+                continue 'modules;
+            }
+            remaining_input = nom::Input::take_from(&remaining_input, position + END.len());
+        }
+        return Ok(headers);
+    }
+}
+
+/// Returns the position of the first occurrence of `keyword` that is not part of a longer word.
+fn find_keyword(input: &str, keyword: &str) -> Option<usize> {
+    let is_word_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+    input.match_indices(keyword).map(|(i, _)| i).find(|&i| {
+        !input[..i].ends_with(is_word_char) && !input[i + keyword.len()..].starts_with(is_word_char)
+    })
+}
+
 pub(crate) fn asn_module(
     input: Input<'_>,
 ) -> ParserResult<'_, (ModuleHeader, Vec<ToplevelDefinition>)> {
-    pair(
-        module_header::module_header,
-        terminated(
-            many0(skip_ws(alt((
-                map(object_class_assignement, ToplevelDefinition::Class),
-                map(
-                    top_level_information_declaration,
-                    ToplevelDefinition::Object,
-                ),
-                map(top_level_type_declaration, ToplevelDefinition::Type),
-                map(top_level_value_declaration, ToplevelDefinition::Value),
-                map(macro_definition, |m| {
-                    ToplevelDefinition::Macro(ToplevelMacroDefinition::from(m))
-                }),
-            )))),
-            context_boundary(skip_ws_and_comments(alt((end, encoding_control)))),
+    let source_file = input.src_file();
+    map(
+        pair(
+            module_header::module_header,
+            terminated(
+                many0(map(
+                    pair(
+                        pair(
+                            peek(skip_ws_and_comments(location)),
+                            skip_ws(alt((
+                                map(object_class_assignement, ToplevelDefinition::Class),
+                                map(
+                                    top_level_information_declaration,
+                                    ToplevelDefinition::Object,
+                                ),
+                                map(top_level_type_declaration, ToplevelDefinition::Type),
+                                map(top_level_value_set_declaration, ToplevelDefinition::Type),
+                                map(top_level_value_declaration, ToplevelDefinition::Value),
+                                map(macro_definition, |m| {
+                                    ToplevelDefinition::Macro(ToplevelMacroDefinition::from(m))
+                                }),
+                            ))),
+                        ),
+                        trailing_comment,
+                    ),
+                    |((location, mut tld), comment)| {
+                        // A comment on the same line after the assignment belongs to the definition
+                        let trailing_comment = match &mut tld {
+                            ToplevelDefinition::Type(t) => Some(&mut t.trailing_comment),
+                            ToplevelDefinition::Value(v) => Some(&mut v.trailing_comment),
+                            ToplevelDefinition::Class(c) => Some(&mut c.trailing_comment),
+                            ToplevelDefinition::Object(o) => Some(&mut o.trailing_comment),
+                            ToplevelDefinition::Macro(_) => None,
+                        };
+                        if let Some(trailing_comment) = trailing_comment {
+                            *trailing_comment = comment.map(String::from);
+                        }
+                        (location, tld)
+                    },
+                )),
+                context_boundary(skip_ws_and_comments(alt((end, encoding_control)))),
+            ),
         ),
+        move |(mut header, definitions)| {
+            header.source_file = source_file.clone();
+            let definitions = definitions
+                .into_iter()
+                .map(|(location, tld)| {
+                    header.locations.insert(tld.name().clone(), location);
+                    tld
+                })
+                .collect();
+            (header, definitions)
+        },
     )
     .parse(input)
 }
 
+/// Line and column of the input, without consuming it
+fn location(input: Input<'_>) -> ParserResult<'_, (usize, usize)> {
+    let location = (input.line(), input.column());
+    Ok((input, location))
+}
+
 fn encoding_control(input: Input<'_>) -> ParserResult<'_, &str> {
     into_inner(delimited(
         skip_ws_and_comments(tag(ENCODING_CONTROL)),
@@ -137,6 +219,30 @@ pub fn top_level_type_declaration(input: Input<'_>) -> ParserResult<'_, Toplevel
     .parse(input)
 }
 
+/// Parses a value set assignment, such as `Small INTEGER ::= { 1 | 2 }`,
+/// as a type that is constrained to the values of the set.
+fn top_level_value_set_declaration(input: Input<'_>) -> ParserResult<'_, ToplevelTypeDefinition> {
+    map_opt(
+        (
+            skip_ws(many0(comment)),
+            skip_ws(type_reference),
+            opt(parameterization),
+            skip_ws_and_comments(asn1_type),
+            preceded(assignment, value_set),
+        ),
+        |(comments, name, parameterization, mut ty, constraint)| {
+            ty.constraints_mut()?.push(constraint);
+            Some(ToplevelTypeDefinition::from((
+                comments,
+                name,
+                parameterization,
+                (None, ty),
+            )))
+        },
+    )
+    .parse(input)
+}
+
 pub fn top_level_information_declaration(
     input: Input<'_>,
 ) -> ParserResult<'_, ToplevelInformationDefinition> {
@@ -160,6 +266,9 @@ pub fn asn1_type(input: Input<'_>) -> ParserResult<'_, ASN1Type> {
             utc_time,
             external,
             embedded_pdv,
+            any,
+            object_descriptor,
+            unrestricted_character_string,
             instance_of,
             generalized_time,
             real,
@@ -199,19 +308,28 @@ pub fn asn1_value(input: Input<'_>) -> ParserResult<'_, ASN1Value> {
 }
 
 pub fn elsewhere_declared_value(input: Input<'_>) -> ParserResult<'_, ASN1Value> {
-    map(
-        pair(
-            opt(skip_ws_and_comments(recognize(many1(pair(
-                identifier,
-                tag(".&"),
-            ))))),
-            value_reference,
+    alt((
+        map(
+            pair(value_reference, parameters),
+            |(id, actual_parameters)| ASN1Value::ParameterizedValue {
+                identifier: id.into(),
+                actual_parameters,
+            },
         ),
-        |(p, id)| ASN1Value::ElsewhereDeclaredValue {
-            parent: p.map(|par| par.inner().to_string()),
-            identifier: id.into(),
-        },
-    )
+        map(
+            pair(
+                opt(skip_ws_and_comments(recognize(many1(pair(
+                    identifier,
+                    tag(".&"),
+                ))))),
+                value_reference,
+            ),
+            |(p, id)| ASN1Value::ElsewhereDeclaredValue {
+                parent: p.map(|par| par.inner().to_string()),
+                identifier: id.into(),
+            },
+        ),
+    ))
     .parse(input)
 }
 
@@ -261,21 +379,48 @@ fn top_level_information_object_declaration(
     into((
         skip_ws(many0(comment)),
         skip_ws(context_boundary(identifier)),
-        skip_ws(opt(parameterization)),
-        skip_ws(uppercase_identifier),
+        skip_ws_and_comments(opt(parameterization)),
+        class_link,
         preceded(assignment, information_object),
     ))
     .parse(input)
 }
 
+/// Parses the class reference of an information object or object set declaration.
+/// Apart from the useful classes TYPE-IDENTIFIER and ABSTRACT-SYNTAX, class references
+/// must not be ASN.1 keywords, so that value declarations of types such as
+/// `RELATIVE-OID` are not mistaken for information objects.
+fn object_class_reference(input: Input<'_>) -> ParserResult<'_, &str> {
+    verify(uppercase_identifier, |id: &str| {
+        !ASN1_KEYWORDS.contains(&id) || id == TYPE_IDENTIFIER || id == ABSTRACT_SYNTAX
+    })
+    .parse(input)
+}
+
+/// Parses the class reference of an information object or object set declaration,
+/// along with the actual parameters of a parameterized class.
+fn class_link(input: Input<'_>) -> ParserResult<'_, ClassLink> {
+    map(
+        pair(
+            skip_ws_and_comments(object_class_reference),
+            opt(parameters),
+        ),
+        |(class, actual_parameters)| match actual_parameters {
+            Some(actual_parameters) => ClassLink::ByInstantiation(class.into(), actual_parameters),
+            None => ClassLink::ByName(class.into()),
+        },
+    )
+    .parse(input)
+}
+
 fn top_level_object_set_declaration(
     input: Input<'_>,
 ) -> ParserResult<'_, ToplevelInformationDefinition> {
     into((
         skip_ws(many0(comment)),
         skip_ws(context_boundary(identifier)),
-        skip_ws(opt(parameterization)),
-        skip_ws(uppercase_identifier),
+        skip_ws_and_comments(opt(parameterization)),
+        class_link,
         preceded(assignment, object_set),
     ))
     .parse(input)
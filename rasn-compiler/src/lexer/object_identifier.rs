@@ -4,7 +4,10 @@
 //! identify a so-called _information object_.
 use crate::{
     input::Input,
-    intermediate::{ASN1Type, ObjectIdentifierArc, ObjectIdentifierValue, OBJECT_IDENTIFIER},
+    intermediate::{
+        types::ObjectIdentifier, ASN1Type, ObjectIdentifierArc, ObjectIdentifierValue,
+        OBJECT_IDENTIFIER, OID_IRI, RELATIVE_OID_IRI,
+    },
 };
 
 use nom::{
@@ -20,7 +23,7 @@ use nom::{
 use super::{
     common::{in_braces, in_parentheses, skip_ws, skip_ws_and_comments, value_reference},
     constraint::constraints,
-    error::ParserResult,
+    error::{ErrorTree, ParserResult},
     RELATIVE_OID,
 };
 
@@ -44,17 +47,38 @@ pub fn object_identifier_value(input: Input<'_>) -> ParserResult<'_, ObjectIdent
     .parse(input)
 }
 
+/// Tries to parse an OBJECT IDENTIFIER, RELATIVE-OID, OID-IRI, or RELATIVE-OID-IRI type.
+/// The latter three are distinct types (see ITU-T X.680 §33 - §35) that share the
+/// representation of an OBJECT IDENTIFIER.
 pub fn object_identifier(input: Input<'_>) -> ParserResult<'_, ASN1Type> {
-    map(
-        into(preceded(
-            skip_ws_and_comments(alt((tag(OBJECT_IDENTIFIER), tag(RELATIVE_OID)))),
-            opt(skip_ws_and_comments(constraints)),
-        )),
-        ASN1Type::ObjectIdentifier,
-    )
+    alt((
+        map(
+            oid_type_with_constraints(OBJECT_IDENTIFIER),
+            ASN1Type::ObjectIdentifier,
+        ),
+        // RELATIVE-OID-IRI needs to be matched before its prefix RELATIVE-OID
+        map(
+            oid_type_with_constraints(RELATIVE_OID_IRI),
+            ASN1Type::RelativeOidIri,
+        ),
+        map(
+            oid_type_with_constraints(RELATIVE_OID),
+            ASN1Type::RelativeOid,
+        ),
+        map(oid_type_with_constraints(OID_IRI), ASN1Type::OidIri),
+    ))
     .parse(input)
 }
 
+fn oid_type_with_constraints<'a>(
+    keyword: &'static str,
+) -> impl Parser<Input<'a>, Output = ObjectIdentifier, Error = ErrorTree<'a>> {
+    into(preceded(
+        skip_ws_and_comments(tag(keyword)),
+        opt(skip_ws_and_comments(constraints)),
+    ))
+}
+
 fn object_identifier_arc(input: Input<'_>) -> ParserResult<'_, ObjectIdentifierArc> {
     skip_ws(alt((
         numeric_id,
@@ -106,4 +130,26 @@ mod tests {
             ])
         )
     }
+
+    #[test]
+    fn parses_relative_oid_and_iri_types() {
+        assert_eq!(
+            object_identifier("RELATIVE-OID".into()).unwrap().1,
+            ASN1Type::RelativeOid(ObjectIdentifier {
+                constraints: vec![]
+            })
+        );
+        assert_eq!(
+            object_identifier("RELATIVE-OID-IRI".into()).unwrap().1,
+            ASN1Type::RelativeOidIri(ObjectIdentifier {
+                constraints: vec![]
+            })
+        );
+        assert_eq!(
+            object_identifier("OID-IRI".into()).unwrap().1,
+            ASN1Type::OidIri(ObjectIdentifier {
+                constraints: vec![]
+            })
+        );
+    }
 }
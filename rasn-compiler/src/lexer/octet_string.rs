@@ -51,11 +51,12 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::SingleValue {
-                            value: ASN1Value::Integer(8),
+                            value: ASN1Value::Integer(8.into()),
                             extensible: false
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })]
             })
         )
@@ -70,12 +71,13 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(8)),
-                            max: Some(ASN1Value::Integer(18)),
+                            min: Some(ASN1Value::Integer(8.into())),
+                            max: Some(ASN1Value::Integer(18.into())),
                             extensible: false
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })]
             })
         )
@@ -90,11 +92,12 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::SingleValue {
-                            value: ASN1Value::Integer(2),
+                            value: ASN1Value::Integer(2.into()),
                             extensible: true
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })]
             })
         )
@@ -109,12 +112,13 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(8)),
-                            max: Some(ASN1Value::Integer(18)),
+                            min: Some(ASN1Value::Integer(8.into())),
+                            max: Some(ASN1Value::Integer(18.into())),
                             extensible: true
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })]
             })
         )
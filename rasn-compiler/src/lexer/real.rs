@@ -136,12 +136,13 @@ mod tests {
                                     constraints: vec![Constraint::Subtype(ElementSetSpecs {
                                         set: ElementOrSetOperation::Element(
                                             SubtypeElements::ValueRange {
-                                                min: Some(ASN1Value::Integer(-16777215)),
-                                                max: Some(ASN1Value::Integer(16777215)),
+                                                min: Some(ASN1Value::Integer((-16777215).into())),
+                                                max: Some(ASN1Value::Integer(16777215.into())),
                                                 extensible: false
                                             }
                                         ),
-                                        extensible: false
+                                        extensible: false,
+                                        extension_additions: None
                                     })],
                                     presence: ComponentPresence::Unspecified
                                 },
@@ -150,11 +151,12 @@ mod tests {
                                     constraints: vec![Constraint::Subtype(ElementSetSpecs {
                                         set: ElementOrSetOperation::Element(
                                             SubtypeElements::SingleValue {
-                                                value: ASN1Value::Integer(2),
+                                                value: ASN1Value::Integer(2.into()),
                                                 extensible: false
                                             }
                                         ),
-                                        extensible: false
+                                        extensible: false,
+                                        extension_additions: None
                                     })],
                                     presence: ComponentPresence::Unspecified
                                 },
@@ -163,19 +165,21 @@ mod tests {
                                     constraints: vec![Constraint::Subtype(ElementSetSpecs {
                                         set: ElementOrSetOperation::Element(
                                             SubtypeElements::ValueRange {
-                                                min: Some(ASN1Value::Integer(-125)),
-                                                max: Some(ASN1Value::Integer(128)),
+                                                min: Some(ASN1Value::Integer((-125).into())),
+                                                max: Some(ASN1Value::Integer(128.into())),
                                                 extensible: false
                                             }
                                         ),
-                                        extensible: false
+                                        extensible: false,
+                                        extension_additions: None
                                     })],
                                     presence: ComponentPresence::Unspecified
                                 }
                             ]
                         }
                     )),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })]
             })
         )
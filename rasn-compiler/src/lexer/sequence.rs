@@ -49,16 +49,10 @@ pub fn sequence(input: Input<'_>) -> ParserResult<'_, ASN1Type> {
         preceded(
             skip_ws_and_comments(tag(SEQUENCE)),
             pair(
-                in_braces((
-                    many0(terminated(
-                        skip_ws_and_comments(sequence_component),
-                        optional_comma,
-                    )),
+                in_braces_keeping_comments((
+                    many0(separated_component),
                     opt(terminated(extension_marker, opt(char(COMMA)))),
-                    opt(many0(terminated(
-                        skip_ws_and_comments(alt((extension_group, sequence_component))),
-                        optional_comma,
-                    ))),
+                    opt(many0(alt((extension_group, separated_component)))),
                 )),
                 opt(constraints),
             ),
@@ -68,19 +62,24 @@ pub fn sequence(input: Input<'_>) -> ParserResult<'_, ASN1Type> {
     .parse(input)
 }
 
+/// Parses an extension addition group along with its separating comma.
+/// The comments preceding the group and the comment on the same line after it
+/// are attached to the group.
 fn extension_group(input: Input<'_>) -> ParserResult<'_, SequenceComponent> {
     map(
-        in_version_brackets(preceded(
-            opt(pair(
-                skip_ws_and_comments(i128),
-                skip_ws_and_comments(char(':')),
+        (
+            skip_ws(many0(comment)),
+            in_version_brackets(preceded(
+                opt(pair(
+                    skip_ws_and_comments(i128),
+                    skip_ws_and_comments(char(':')),
+                )),
+                many1(separated_component),
             )),
-            skip_ws_and_comments(many1(terminated(
-                skip_ws_and_comments(sequence_component),
-                optional_comma,
-            ))),
-        )),
-        |ext_group| {
+            optional_comma,
+            trailing_comment,
+        ),
+        |(comments, ext_group, _, trailing)| {
             let mut components_of = vec![];
             let mut members = vec![];
             for comp in ext_group {
@@ -102,6 +101,8 @@ fn extension_group(input: Input<'_>) -> ParserResult<'_, SequenceComponent> {
                 }),
                 optionality: Optionality::Required,
                 constraints: vec![],
+                comments: comments.join("\n"),
+                trailing_comment: trailing.map(String::from),
             })
         },
     )
@@ -109,10 +110,10 @@ fn extension_group(input: Input<'_>) -> ParserResult<'_, SequenceComponent> {
 }
 
 pub fn sequence_component(input: Input<'_>) -> ParserResult<'_, SequenceComponent> {
-    skip_ws_and_comments(alt((
+    alt((
         map(
             preceded(
-                tag(COMPONENTS_OF),
+                skip_ws_and_comments(tag(COMPONENTS_OF)),
                 skip_ws_and_comments(alt((
                     into_inner(recognize(separated_list1(tag(".&"), identifier))),
                     type_reference,
@@ -121,18 +122,43 @@ pub fn sequence_component(input: Input<'_>) -> ParserResult<'_, SequenceComponen
             |id| SequenceComponent::ComponentsOf(id.into()),
         ),
         map(sequence_or_set_member, SequenceComponent::Member),
-    )))
+    ))
     .parse(input)
 }
 
+/// Parses a component of a SEQUENCE or SET along with its separating comma.
+/// A comment on the same line after the component is attached to it.
+pub fn separated_component(input: Input<'_>) -> ParserResult<'_, SequenceComponent> {
+    map(
+        (sequence_component, optional_comma, trailing_comment),
+        |(mut component, _, comment)| {
+            if let SequenceComponent::Member(member) = &mut component {
+                member.trailing_comment = comment.map(String::from);
+            }
+            component
+        },
+    )
+    .parse(input)
+}
+
+/// Parses a member of a SEQUENCE or SET along with the comments preceding it.
 pub fn sequence_or_set_member(input: Input<'_>) -> ParserResult<'_, SequenceOrSetMember> {
-    into((
-        skip_ws_and_comments(identifier),
-        opt(asn_tag),
-        skip_ws_and_comments(asn1_type),
-        opt(constraints),
-        skip_ws_and_comments(optionality(asn1_value)),
-    ))
+    map(
+        pair(
+            skip_ws(many0(comment)),
+            into((
+                skip_ws_and_comments(identifier),
+                opt(asn_tag),
+                skip_ws_and_comments(asn1_type),
+                opt(constraints),
+                optionality(asn1_value),
+            )),
+        ),
+        |(comments, member): (_, SequenceOrSetMember)| SequenceOrSetMember {
+            comments: comments.join("\n"),
+            ..member
+        },
+    )
     .parse(input)
 }
 
@@ -167,10 +193,12 @@ is_recursive: false,
                         parent: None,
                         module: None,
                         identifier: "Shape".into(),
-                        constraints: vec![Constraint::Subtype(ElementSetSpecs { set: ElementOrSetOperation::Element(SubtypeElements::MultipleTypeConstraints(InnerTypeConstraint { is_partial: true, constraints: vec![NamedConstraint { identifier: "elliptical".into(), constraints: vec![], presence: ComponentPresence::Absent },NamedConstraint { identifier: "radial".into(), constraints: vec![], presence: ComponentPresence::Absent },NamedConstraint { identifier: "radialShapes".into(), constraints: vec![], presence: ComponentPresence::Absent }] })), extensible: false })
+                        constraints: vec![Constraint::Subtype(ElementSetSpecs { set: ElementOrSetOperation::Element(SubtypeElements::MultipleTypeConstraints(InnerTypeConstraint { is_partial: true, constraints: vec![NamedConstraint { identifier: "elliptical".into(), constraints: vec![], presence: ComponentPresence::Absent },NamedConstraint { identifier: "radial".into(), constraints: vec![], presence: ComponentPresence::Absent },NamedConstraint { identifier: "radialShapes".into(), constraints: vec![], presence: ComponentPresence::Absent }] })), extensible: false, extension_additions: None })
                      ]}),
                     optionality: Optionality::Optional,
                     constraints: vec![],
+comments: String::new(),
+trailing_comment: None,
                 }
             ]
         })
@@ -205,7 +233,9 @@ is_recursive: false,
                             constraints: vec![]
                         }),
                         optionality: Optionality::Required,
-                        constraints: vec![]
+                        constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     },
                     SequenceOrSetMember {
                         is_recursive: false,
@@ -220,6 +250,8 @@ is_recursive: false,
                         }),
                         optionality: Optionality::Required,
                         constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     }
                 ]
             })
@@ -258,6 +290,8 @@ is_recursive: false,
                         }),
                         optionality: Optionality::Required,
                         constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     },
                     SequenceOrSetMember {
                         is_recursive: false,
@@ -271,6 +305,8 @@ is_recursive: false,
                         }),
                         optionality: Optionality::Required,
                         constraints: vec![],
+                        comments: String::from("x"),
+                        trailing_comment: Some(" y ".into()),
                     },
                     SequenceOrSetMember {
                         is_recursive: false,
@@ -284,6 +320,8 @@ is_recursive: false,
                         }),
                         optionality: Optionality::Optional,
                         constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: Some(" this is optional".into()),
                     }
                 ]
             })
@@ -322,6 +360,8 @@ is_recursive: false,
                         }),
                         optionality: Optionality::Optional,
                         constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     },
                     SequenceOrSetMember {
                         is_recursive: false,
@@ -338,6 +378,8 @@ is_recursive: false,
                             parent: None
                         }),
                         constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     },
                     SequenceOrSetMember {
                         is_recursive: false,
@@ -354,6 +396,8 @@ is_recursive: false,
                             parent: None
                         }),
                         constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     }
                 ]
             })
@@ -386,16 +430,19 @@ is_recursive: false,
                         ty: ASN1Type::Integer(Integer {
                             constraints: vec![Constraint::Subtype(ElementSetSpecs {
                                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                                    min: Some(ASN1Value::Integer(0)),
-                                    max: Some(ASN1Value::Integer(9999)),
+                                    min: Some(ASN1Value::Integer(0.into())),
+                                    max: Some(ASN1Value::Integer(9999.into())),
                                     extensible: false
                                 }),
-                                extensible: false
+                                extensible: false,
+                                extension_additions: None
                             })],
                             distinguished_values: None,
                         }),
                         optionality: Optionality::Required,
                         constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     },
                     SequenceOrSetMember {
                         is_recursive: false,
@@ -406,6 +453,8 @@ is_recursive: false,
                         }),
                         optionality: Optionality::Default(ASN1Value::Boolean(false)),
                         constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     },
                     SequenceOrSetMember {
                         is_recursive: false,
@@ -417,18 +466,21 @@ is_recursive: false,
                                     SubtypeElements::SizeConstraint(Box::new(
                                         ElementOrSetOperation::Element(
                                             SubtypeElements::ValueRange {
-                                                min: Some(ASN1Value::Integer(1)),
-                                                max: Some(ASN1Value::Integer(24)),
+                                                min: Some(ASN1Value::Integer(1.into())),
+                                                max: Some(ASN1Value::Integer(24.into())),
                                                 extensible: false
                                             }
                                         )
                                     ))
                                 ),
-                                extensible: false
+                                extensible: false,
+                                extension_additions: None
                             })],
                         }),
                         optionality: Optionality::Optional,
                         constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     }
                 ]
             })
@@ -483,6 +535,8 @@ is_recursive: false,
                                 }),
                                 optionality: Optionality::Required,
                                 constraints: vec![],
+                                comments: String::new(),
+                                trailing_comment: Some(" WOW!".into()),
                             },
                             SequenceOrSetMember {
                                 is_recursive: false,
@@ -494,6 +548,8 @@ is_recursive: false,
                                 }),
                                 optionality: Optionality::Default(ASN1Value::Boolean(true)),
                                 constraints: vec![],
+                                comments: String::new(),
+                                trailing_comment: None,
                             },
                             SequenceOrSetMember {
                                 is_recursive: false,
@@ -516,13 +572,16 @@ is_recursive: false,
                                                         SubtypeElements::SizeConstraint(Box::new(
                                                             ElementOrSetOperation::Element(
                                                                 SubtypeElements::SingleValue {
-                                                                    value: ASN1Value::Integer(1),
+                                                                    value: ASN1Value::Integer(
+                                                                        1.into()
+                                                                    ),
                                                                     extensible: true
                                                                 }
                                                             )
                                                         ))
                                                     ),
-                                                    extensible: false
+                                                    extensible: false,
+                                                    extension_additions: None
                                                 }
                                             )],
                                             distinguished_values: None
@@ -531,15 +590,21 @@ is_recursive: false,
                                             vec![false]
                                         )),
                                         constraints: vec![],
+                                        comments: String::new(),
+                                        trailing_comment: None,
                                     }]
                                 }),
                                 optionality: Optionality::Optional,
                                 constraints: vec![],
+                                comments: String::new(),
+                                trailing_comment: None,
                             }
                         ]
                     }),
                     optionality: Optionality::Required,
                     constraints: vec![],
+                    comments: String::new(),
+                    trailing_comment: None,
                 }]
             })
         )
@@ -557,7 +622,7 @@ is_recursive: false,
                     Box::new(ASN1Value::Choice {
                         type_name: None,
                         variant_name: "content".into(),
-                        inner_value: Box::new(ASN1Value::Integer(0))
+                        inner_value: Box::new(ASN1Value::Integer(0.into()))
                     })
                 ),
                 (
@@ -596,16 +661,19 @@ is_recursive: false,
                         ty: ASN1Type::Integer(Integer {
                             constraints: vec![Constraint::Subtype(ElementSetSpecs {
                                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                                    min: Some(ASN1Value::Integer(0)),
-                                    max: Some(ASN1Value::Integer(254)),
+                                    min: Some(ASN1Value::Integer(0.into())),
+                                    max: Some(ASN1Value::Integer(254.into())),
                                     extensible: false
                                 }),
-                                extensible: false
+                                extensible: false,
+                                extension_additions: None
                             })],
                             distinguished_values: None,
                         }),
                         optionality: Optionality::Required,
-                        constraints: vec![]
+                        constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     },
                     SequenceOrSetMember {
                         is_recursive: false,
@@ -624,17 +692,20 @@ is_recursive: false,
                                         constraints: vec![Constraint::Subtype(ElementSetSpecs {
                                             set: ElementOrSetOperation::Element(
                                                 SubtypeElements::ValueRange {
-                                                    min: Some(ASN1Value::Integer(0)),
-                                                    max: Some(ASN1Value::Integer(254)),
+                                                    min: Some(ASN1Value::Integer(0.into())),
+                                                    max: Some(ASN1Value::Integer(254.into())),
                                                     extensible: false
                                                 }
                                             ),
-                                            extensible: false
+                                            extensible: false,
+                                            extension_additions: None
                                         })],
                                         distinguished_values: None,
                                     }),
                                     optionality: Optionality::Required,
-                                    constraints: vec![]
+                                    constraints: vec![],
+                                    comments: String::new(),
+                                    trailing_comment: None,
                                 },
                                 SequenceOrSetMember {
                                     is_recursive: false,
@@ -644,12 +715,16 @@ is_recursive: false,
                                         constraints: vec![]
                                     }),
                                     optionality: Optionality::Default(ASN1Value::Boolean(true)),
-                                    constraints: vec![]
+                                    constraints: vec![],
+                                    comments: String::new(),
+                                    trailing_comment: None,
                                 }
                             ]
                         }),
                         optionality: Optionality::Required,
-                        constraints: vec![]
+                        constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     }
                 ]
             })
@@ -683,7 +758,9 @@ is_recursive: false,
                         constraints: vec![]
                     }),
                     optionality: Optionality::Required,
-                    constraints: vec![]
+                    constraints: vec![],
+                    comments: String::new(),
+                    trailing_comment: None,
                 }]
             })
         )
@@ -788,11 +865,12 @@ integrityCheckValue     ICV OPTIONAL
                         element_type: Box::new(ASN1Type::Integer(Integer {
                             constraints: vec![Constraint::Subtype(ElementSetSpecs {
                                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                                    min: Some(ASN1Value::Integer(1,),),
-                                    max: Some(ASN1Value::Integer(5,),),
+                                    min: Some(ASN1Value::Integer(1.into()),),
+                                    max: Some(ASN1Value::Integer(5.into(),),),
                                     extensible: false,
                                 },),
                                 extensible: false,
+                                extension_additions: None,
                             },),],
                             distinguished_values: None,
                         },)),
@@ -801,6 +879,8 @@ integrityCheckValue     ICV OPTIONAL
                     optionality: Optionality::Required,
                     is_recursive: false,
                     constraints: vec![],
+                    comments: String::new(),
+                    trailing_comment: None,
                 }],
             },)
         )
@@ -843,6 +923,8 @@ integrityCheckValue     ICV OPTIONAL
                     optionality: Optionality::Required,
                     is_recursive: false,
                     constraints: vec![],
+                    comments: String::new(),
+                    trailing_comment: None,
                 },],
             },)
         )
@@ -873,6 +955,8 @@ integrityCheckValue     ICV OPTIONAL
                     optionality: Optionality::Required,
                     is_recursive: false,
                     constraints: vec![],
+                    comments: String::new(),
+                    trailing_comment: None,
                 }],
             })
         )
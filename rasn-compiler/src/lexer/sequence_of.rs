@@ -94,12 +94,13 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(1)),
-                            max: Some(ASN1Value::Integer(13)),
+                            min: Some(ASN1Value::Integer(1.into())),
+                            max: Some(ASN1Value::Integer(13.into())),
                             extensible: true
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })],
                 element_type: Box::new(ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
                     parent: None,
@@ -123,12 +124,13 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(1)),
-                            max: Some(ASN1Value::Integer(13)),
+                            min: Some(ASN1Value::Integer(1.into())),
+                            max: Some(ASN1Value::Integer(13.into())),
                             extensible: true
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })],
                 element_type: Box::new(ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
                     parent: None,
@@ -157,25 +159,27 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(1)),
-                            max: Some(ASN1Value::Integer(13)),
+                            min: Some(ASN1Value::Integer(1.into())),
+                            max: Some(ASN1Value::Integer(13.into())),
                             extensible: true
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })],
                 element_type: Box::new(ASN1Type::Integer(Integer {
                     constraints: vec![Constraint::Subtype(ElementSetSpecs {
                         set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(1)),
-                            max: Some(ASN1Value::Integer(13)),
+                            min: Some(ASN1Value::Integer(1.into())),
+                            max: Some(ASN1Value::Integer(13.into())),
                             extensible: true
                         }),
-                        extensible: false
+                        extensible: false,
+                        extension_additions: None
                     })],
                     distinguished_values: Some(vec![DistinguishedValue {
                         name: "one-distinguished-value".into(),
-                        value: 12
+                        value: 12.into()
                     }])
                 }))
             })
@@ -198,12 +202,13 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(1)),
-                            max: Some(ASN1Value::Integer(4)),
+                            min: Some(ASN1Value::Integer(1.into())),
+                            max: Some(ASN1Value::Integer(4.into())),
                             extensible: false
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })],
                 element_type: Box::new(ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
                     parent: None,
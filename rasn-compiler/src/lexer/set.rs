@@ -5,7 +5,7 @@ use nom::{
 
 use crate::intermediate::*;
 
-use super::{common::optional_comma, constraint::constraints, sequence::sequence_component, *};
+use super::{constraint::constraints, sequence::separated_component, *};
 
 /// Tries to parse an ASN1 SET
 ///
@@ -22,16 +22,10 @@ pub fn set(input: Input<'_>) -> ParserResult<'_, ASN1Type> {
         preceded(
             skip_ws_and_comments(tag(SET)),
             pair(
-                in_braces((
-                    many0(terminated(
-                        skip_ws_and_comments(sequence_component),
-                        optional_comma,
-                    )),
+                in_braces_keeping_comments((
+                    many0(separated_component),
                     opt(terminated(extension_marker, opt(char(COMMA)))),
-                    opt(many0(terminated(
-                        skip_ws_and_comments(sequence_component),
-                        optional_comma,
-                    ))),
+                    opt(many0(separated_component)),
                 )),
                 opt(constraints),
             ),
@@ -74,6 +68,8 @@ mod tests {
                         }),
                         optionality: Optionality::Required,
                         constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     },
                     SequenceOrSetMember {
                         is_recursive: false,
@@ -89,7 +85,9 @@ mod tests {
                             }))
                         }),
                         optionality: Optionality::Default(ASN1Value::SequenceOrSet(vec![])),
-                        constraints: vec![]
+                        constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     }
                 ]
             })
@@ -93,12 +93,13 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(1)),
-                            max: Some(ASN1Value::Integer(13)),
+                            min: Some(ASN1Value::Integer(1.into())),
+                            max: Some(ASN1Value::Integer(13.into())),
                             extensible: true
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })],
                 element_type: Box::new(ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
                     parent: None,
@@ -122,12 +123,13 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(1)),
-                            max: Some(ASN1Value::Integer(13)),
+                            min: Some(ASN1Value::Integer(1.into())),
+                            max: Some(ASN1Value::Integer(13.into())),
                             extensible: true
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })],
                 element_type: Box::new(ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
                     parent: None,
@@ -156,25 +158,27 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(1)),
-                            max: Some(ASN1Value::Integer(13)),
+                            min: Some(ASN1Value::Integer(1.into())),
+                            max: Some(ASN1Value::Integer(13.into())),
                             extensible: true
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })],
                 element_type: Box::new(ASN1Type::Integer(Integer {
                     constraints: vec![Constraint::Subtype(ElementSetSpecs {
                         set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(1)),
-                            max: Some(ASN1Value::Integer(13)),
+                            min: Some(ASN1Value::Integer(1.into())),
+                            max: Some(ASN1Value::Integer(13.into())),
                             extensible: true
                         }),
-                        extensible: false
+                        extensible: false,
+                        extension_additions: None
                     })],
                     distinguished_values: Some(vec![DistinguishedValue {
                         name: "one-distinguished-value".into(),
-                        value: 12
+                        value: 12.into()
                     }]),
                 }))
             })
@@ -197,12 +201,13 @@ mod tests {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(1)),
-                            max: Some(ASN1Value::Integer(4)),
+                            min: Some(ASN1Value::Integer(1.into())),
+                            max: Some(ASN1Value::Integer(4.into())),
                             extensible: false
                         })
                     ))),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })],
                 element_type: Box::new(ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
                     parent: None,
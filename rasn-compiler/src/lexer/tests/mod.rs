@@ -34,11 +34,12 @@ fn parses_toplevel_simple_integer_declaration() {
             *int.constraints.first().unwrap(),
             Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                    min: Some(ASN1Value::Integer(1)),
-                    max: Some(ASN1Value::Integer(8)),
+                    min: Some(ASN1Value::Integer(1.into())),
+                    max: Some(ASN1Value::Integer(8.into())),
                     extensible: false
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })
         );
     } else {
@@ -72,11 +73,12 @@ fn parses_toplevel_macro_integer_declaration() {
             *int.constraints.first().unwrap(),
             Constraint::Subtype(ElementSetSpecs {
                 set: ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                    min: Some(ASN1Value::Integer(0)),
-                    max: Some(ASN1Value::Integer(161)),
+                    min: Some(ASN1Value::Integer(0.into())),
+                    max: Some(ASN1Value::Integer(161.into())),
                     extensible: true
                 }),
-                extensible: false
+                extensible: false,
+                extension_additions: None
             })
         );
 
@@ -85,7 +87,7 @@ fn parses_toplevel_macro_integer_declaration() {
             int.distinguished_values.as_ref().unwrap()[0],
             DistinguishedValue {
                 name: String::from("positiveOutOfRange"),
-                value: 160
+                value: 160.into()
             }
         );
     } else {
@@ -169,6 +171,7 @@ fn parses_toplevel_crossrefering_declaration() {
         ToplevelTypeDefinition {
             parameterization: None,
             comments: " Comments go here".into(),
+            trailing_comment: None,
             name: "EventZone".into(),
             ty: ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
                 parent: None,
@@ -179,6 +182,7 @@ fn parses_toplevel_crossrefering_declaration() {
                         base: SubtypeElements::SingleTypeConstraint(vec![Constraint::Subtype(
                             ElementSetSpecs {
                                 extensible: false,
+                                extension_additions: None,
                                 set: ElementOrSetOperation::Element(
                                     SubtypeElements::MultipleTypeConstraints(InnerTypeConstraint {
                                         is_partial: true,
@@ -196,6 +200,7 @@ fn parses_toplevel_crossrefering_declaration() {
                             SubtypeElements::SingleTypeConstraint(vec![Constraint::Subtype(
                                 ElementSetSpecs {
                                     extensible: false,
+                                    extension_additions: None,
                                     set: ElementOrSetOperation::Element(
                                         SubtypeElements::MultipleTypeConstraints(
                                             InnerTypeConstraint {
@@ -212,7 +217,8 @@ fn parses_toplevel_crossrefering_declaration() {
                             )])
                         ))
                     }),
-                    extensible: false
+                    extensible: false,
+                    extension_additions: None
                 })]
             }),
             tag: None,
@@ -235,6 +241,7 @@ fn parses_anonymous_sequence_of_declaration() {
         ToplevelTypeDefinition {
             parameterization: None,
             comments: "Comments".into(),
+            trailing_comment: None,
             name: "InterferenceManagementZones".into(),
             ty: ASN1Type::SequenceOf(SequenceOrSetOf {
                 element_tag: None,
@@ -242,12 +249,13 @@ fn parses_anonymous_sequence_of_declaration() {
                 constraints: vec![Constraint::Subtype(ElementSetSpecs {
                     set: ElementOrSetOperation::Element(SubtypeElements::SizeConstraint(Box::new(
                         ElementOrSetOperation::Element(SubtypeElements::ValueRange {
-                            min: Some(ASN1Value::Integer(1)),
-                            max: Some(ASN1Value::Integer(16)),
+                            min: Some(ASN1Value::Integer(1.into())),
+                            max: Some(ASN1Value::Integer(16.into())),
                             extensible: false
                         })
                     ))),
-                    extensible: true
+                    extensible: true,
+                    extension_additions: None
                 })],
                 element_type: Box::new(ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
                     parent: None,
@@ -278,6 +286,7 @@ fn parses_object_set_value() {
         .1,
         ToplevelInformationDefinition {
             comments: "comments".into(),
+            trailing_comment: None,
             name: "CpmContainers".into(),
             module_header: None,
             parameterization: None,
@@ -349,6 +358,7 @@ fn parses_empty_extensible_object_set() {
         .1,
         ToplevelInformationDefinition {
             comments: "".into(),
+            trailing_comment: None,
             module_header: None,
             parameterization: None,
             name: "Reg-AdvisorySpeed".into(),
@@ -375,6 +385,7 @@ fn parses_class_declaration() {
         .1,
         ObjectClassAssignment {
             comments: "".into(),
+            trailing_comment: None,
             name: "REG-EXT-ID-AND-TYPE".into(),
             module_header: None,
             parameterization: Parameterization::default(),
@@ -429,6 +440,7 @@ fn parses_parameterized_declaration() {
         .1,
         ToplevelTypeDefinition {
             comments: "".into(),
+            trailing_comment: None,
             module_header: None,
             name: "RegionalExtension".into(),
             ty: ASN1Type::Sequence(SequenceOrSet {
@@ -452,7 +464,9 @@ fn parses_parameterized_declaration() {
                             })]
                         }),
                         optionality: Optionality::Required,
-                        constraints: vec![]
+                        constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     },
                     SequenceOrSetMember {
                         is_recursive: false,
@@ -473,7 +487,9 @@ fn parses_parameterized_declaration() {
                             })]
                         }),
                         optionality: Optionality::Required,
-                        constraints: vec![]
+                        constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     }
                 ]
             }),
@@ -503,6 +519,7 @@ fn parses_choice() {
         .1,
         ToplevelTypeDefinition {
             comments: "".into(),
+            trailing_comment: None,
             module_header: None,
             name: "Choice-example".into(),
             ty: ASN1Type::Choice(Choice {
@@ -513,21 +530,27 @@ fn parses_choice() {
                         name: "normal".into(),
                         tag: None,
                         ty: ASN1Type::Null,
-                        constraints: vec![]
+                        constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     },
                     ChoiceOption {
                         is_recursive: false,
                         name: "high".into(),
                         tag: None,
                         ty: ASN1Type::Null,
-                        constraints: vec![]
+                        constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     },
                     ChoiceOption {
                         is_recursive: false,
                         name: "medium".into(),
                         tag: None,
                         ty: ASN1Type::Null,
-                        constraints: vec![]
+                        constraints: vec![],
+                        comments: String::new(),
+                        trailing_comment: None,
                     }
                 ],
                 constraints: vec![]
@@ -543,6 +566,7 @@ fn parses_sequence_of_value() {
     assert_eq!(
         ToplevelValueDefinition {
             comments: "".into(),
+            trailing_comment: None,
             name: "test-Sequence".into(),
             associated_type: ASN1Type::SequenceOf(SequenceOrSetOf {
                 constraints: vec![],
@@ -555,9 +579,9 @@ fn parses_sequence_of_value() {
             }),
             parameterization: None,
             value: ASN1Value::SequenceOrSet(vec![
-                (None, Box::new(ASN1Value::Integer(1))),
-                (None, Box::new(ASN1Value::Integer(2))),
-                (None, Box::new(ASN1Value::Integer(3)))
+                (None, Box::new(ASN1Value::Integer(1.into()))),
+                (None, Box::new(ASN1Value::Integer(2.into()))),
+                (None, Box::new(ASN1Value::Integer(3.into())))
             ]),
             module_header: None
         },
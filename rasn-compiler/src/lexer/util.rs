@@ -287,7 +287,7 @@ mod tests {
             )))
             .parse("((5))".into())
             .map(|(i, o)| (i.into_inner(), o)),
-            Ok(("", vec![ASN1Value::Integer(5)]))
+            Ok(("", vec![ASN1Value::Integer(5.into())]))
         );
     }
 
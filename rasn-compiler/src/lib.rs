@@ -1,51 +1,100 @@
 #[doc = include_str!("../README.md")]
+#[cfg(feature = "serde")]
+mod cache;
 pub(crate) mod common;
+mod diagnostic;
+mod diff;
 mod error;
+mod explain;
+mod extraction;
 mod generator;
 mod input;
 pub mod intermediate;
 mod lexer;
+mod lint;
+mod listing;
+mod parallel;
+mod pass;
+mod search_path;
 #[cfg(test)]
 mod tests;
 mod validator;
 
 use std::{
     borrow::Cow,
-    cell::RefCell,
-    collections::BTreeMap,
+    collections::BTreeSet,
     fs::{self, read_to_string},
     io::Write,
     path::{Path, PathBuf},
-    rc::Rc,
     vec,
 };
 
+#[cfg(feature = "serde")]
+use cache::{Cache, MemoryCache};
+use diagnostic::Severity;
 use error::CompilerError;
-use generator::Backend;
-use intermediate::ToplevelDefinition;
-use lexer::{asn_spec, error::LexerError};
+use extraction::extract_marked_asn1;
+use generator::{Backend, GeneratedModule};
+use intermediate::{ModuleHeader, SharedModuleHeader, ToplevelDefinition};
+use lexer::{
+    asn_spec,
+    error::{LexerError, LexerErrorType},
+};
+use pass::IrPass;
 use prelude::{GeneratorError, GeneratorErrorType};
 use validator::Validator;
 
 pub type RasnCompiler<S> = Compiler<generator::rasn::Rasn, S>;
 pub type TsCompiler<S> = Compiler<generator::typescript::Typescript, S>;
+pub type PyCompiler<S> = Compiler<generator::python::Python, S>;
+pub type HtmlCompiler<S> =
+    Compiler<generator::documentation::Documentation<generator::documentation::Html>, S>;
+pub type MarkdownCompiler<S> =
+    Compiler<generator::documentation::Documentation<generator::documentation::Markdown>, S>;
+pub type Asn1Compiler<S> = Compiler<generator::asn1::Asn1, S>;
+pub type DotCompiler<S> = Compiler<generator::graph::Graph<generator::graph::Dot>, S>;
+pub type JsonGraphCompiler<S> = Compiler<generator::graph::Graph<generator::graph::Json>, S>;
 
 pub mod prelude {
     //! Convenience module that collects all necessary imports for
     //! using and customizing the compiler.
+    #[cfg(feature = "serde")]
+    pub use super::{cache::MemoryCache, diagnostic::sarif_log};
     pub use super::{
-        error::CompilerError, CompileResult, Compiler, CompilerMissingParams, CompilerOutputSet,
-        CompilerReady, CompilerSourcesSet,
+        diagnostic::{Diagnostic, DiagnosticCode, Severity, Span},
+        diff::{Change, Compatibility, SpecDiff},
+        error::CompilerError,
+        explain::{PerLayout, PerWidth, TypeExplanation, TypeNode},
+        extraction::extract_marked_asn1,
+        lint::{Lint, LintConfig, LintLevel, LintReport, LintRule},
+        listing::{DefinitionListing, ListedDefinition, ListedModule},
+        pass::{FlattenAliases, IrPass, PruneTypes, RenameIdentifiers},
+        CompileResult, Compiler, CompilerMissingParams, CompilerOutputSet, CompilerParsed,
+        CompilerReady, CompilerSourcesSet, CompilerValidated,
     };
     pub use crate::generator::{
+        asn1::{format_asn1, Asn1 as Asn1Backend, Config as Asn1Config},
+        documentation::{
+            Config as DocumentationConfig, Documentation as DocumentationBackend,
+            Format as DocumentationFormat, Html, Markdown,
+        },
         error::*,
-        rasn::{Config as RasnConfig, Rasn as RasnBackend},
+        graph::{
+            Config as GraphConfig, DefinitionId, DefinitionKind, DefinitionNode, DependencyEdge,
+            DependencyGraph, Dot, Format as GraphFormat, Graph as GraphBackend, ImportEdge, Json,
+            ModuleNode,
+        },
+        python::{Config as PythonConfig, Python as PythonBackend},
+        rasn::{Config as RasnConfig, DefinedByType, Rasn as RasnBackend},
         typescript::{Config as TsConfig, Typescript as TypescriptBackend},
         Backend, GeneratedModule,
     };
 
+    #[cfg(feature = "serde")]
+    pub use crate::intermediate::json::{to_ir_json, IR_JSON_VERSION};
     pub use crate::intermediate::{
-        ExtensibilityEnvironment, TaggingEnvironment, ToplevelDefinition,
+        ExtensibilityEnvironment, ModuleHeader, SharedModuleHeader, TaggingEnvironment,
+        ToplevelDefinition,
     };
 
     pub use crate::lexer::error::{LexerError, LexerErrorType, ReportData};
@@ -54,11 +103,13 @@ pub mod prelude {
     pub mod ir {
         pub use crate::intermediate::{
             constraints::*,
+            effective_constraints::*,
             encoding_rules::{per_visible::*, *},
             error::*,
             information_object::*,
             parameterization::*,
             types::*,
+            visit::*,
             *,
         };
     }
@@ -117,10 +168,41 @@ pub fn compile_to_rust(
         .map_err(|e| JsValue::from(e.to_string()))
 }
 
+/// The validated intermediate representation of ASN.1 modules as JSON
+#[cfg(all(target_family = "wasm", feature = "serde"))]
+#[wasm_bindgen(inspectable, getter_with_clone)]
+pub struct GeneratedIrJson {
+    pub json: String,
+    pub warnings: String,
+}
+
+#[cfg(all(target_family = "wasm", feature = "serde"))]
+#[wasm_bindgen]
+pub fn compile_to_ir_json(asn1: &str) -> Result<GeneratedIrJson, JsValue> {
+    Compiler::<crate::prelude::RasnBackend, _>::new()
+        .add_asn_literal(asn1)
+        .validate()
+        .map(|validated| GeneratedIrJson {
+            json: validated.to_ir_json(),
+            warnings: validated
+                .warnings()
+                .iter()
+                .fold(String::new(), |mut acc, w| {
+                    acc += &w.to_string();
+                    acc += "\n";
+                    acc
+                }),
+        })
+        .map_err(|e| JsValue::from(e.to_string()))
+}
+
 /// The rasn compiler
 pub struct Compiler<B: Backend, S: CompilerState> {
     state: S,
     backend: B,
+    options: ValidationOptions,
+    /// Transformations of the validated definitions, in the order they were added
+    passes: Vec<Box<dyn IrPass>>,
 }
 
 /// Typestate representing compiler with missing parameters
@@ -148,12 +230,29 @@ pub struct CompilerSourcesSet {
     sources: Vec<AsnSource>,
 }
 
+/// Typestate representing compiler that has parsed its ASN1 sources, but not yet validated them
+pub struct CompilerParsed {
+    modules: Vec<(ModuleHeader, Vec<ToplevelDefinition>)>,
+    /// Warnings raised for unreadable files in the search paths
+    warnings: Vec<CompilerError>,
+    output_mode: OutputMode,
+}
+
+/// Typestate representing compiler that has validated and linked its ASN1 sources
+pub struct CompilerValidated {
+    definitions: Vec<ToplevelDefinition>,
+    warnings: Vec<CompilerError>,
+    output_mode: OutputMode,
+}
+
 /// State of the rasn compiler
 pub trait CompilerState {}
 impl CompilerState for CompilerReady {}
 impl CompilerState for CompilerOutputSet {}
 impl CompilerState for CompilerSourcesSet {}
 impl CompilerState for CompilerMissingParams {}
+impl CompilerState for CompilerParsed {}
+impl CompilerState for CompilerValidated {}
 
 #[derive(Debug)]
 pub struct CompileResult {
@@ -168,9 +267,19 @@ impl CompileResult {
     }
 }
 
+/// Unformatted output of a compilation
+struct GeneratedBindings {
+    index: Option<String>,
+    /// Names of the ASN.1 modules with their generated bindings
+    modules: Vec<(String, String)>,
+    warnings: Vec<CompilerError>,
+}
+
 #[derive(Debug, PartialEq)]
 enum AsnSource {
     Path(PathBuf),
+    /// Specification text with ASN.1 between `-- ASN1START` and `-- ASN1STOP` markers
+    MarkedPath(PathBuf),
     Literal(String),
 }
 
@@ -185,8 +294,86 @@ impl<B: Backend, S: CompilerState> Compiler<B, S> {
         Compiler {
             state: self.state,
             backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
+
+    /// Add a directory to search for imported ASN1 modules.
+    /// Modules that the sources import, but do not define, are loaded from the `.asn`
+    /// and `.asn1` files in the search directories and their subdirectories.
+    /// The object identifier and the `WITH SUCCESSORS` or `WITH DESCENDANTS` selector
+    /// of an import choose between several revisions of a module.
+    /// * `search_path` - directory to search for imported modules
+    pub fn add_search_path(mut self, search_path: impl Into<PathBuf>) -> Self {
+        self.options.search_paths.push(search_path.into());
+        self
+    }
+
+    /// Add several directories to search for imported ASN1 modules.
+    /// * `search_paths` - iterator of directories to search for imported modules
+    pub fn add_search_paths(
+        mut self,
+        search_paths: impl Iterator<Item = impl Into<PathBuf>>,
+    ) -> Self {
+        self.options
+            .search_paths
+            .extend(search_paths.map(Into::into));
+        self
+    }
+
+    /// Set whether importing a symbol that the imported module does not export fails the
+    /// compilation, which is the default, or is reported as a warning.
+    /// * `severity` - severity of imports of symbols that are not exported
+    pub fn set_unexported_import_severity(mut self, severity: Severity) -> Self {
+        self.options.unexported_imports = severity;
+        self
+    }
+
+    /// Cache the results of the compilation in a directory, so that later compilations
+    /// only lex the sources that changed, and only validate and generate the bindings of the
+    /// ASN.1 modules that changed, along with the modules that depend on them.
+    /// The generated bindings are identical to those of a compilation without a cache.
+    /// After a successful compilation, the entries that it did not use are removed, so a
+    /// cache directory should not be shared by compilations of different specifications.
+    /// * `dir` - directory of the cache, which is created if it does not exist
+    #[cfg(feature = "serde")]
+    pub fn set_cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.options.cache = Some(Cache::new(dir));
+        self
+    }
+
+    /// Cache the results of the compilation in memory, like [Compiler::set_cache_dir] does
+    /// in a directory. Compilers that are given clones of the same cache share its entries.
+    /// * `cache` - cache to read and store the results of the compilation
+    #[cfg(feature = "serde")]
+    pub fn set_memory_cache(mut self, cache: MemoryCache) -> Self {
+        self.options.cache = Some(Cache::in_memory(cache));
+        self
+    }
+
+    /// Add a transformation of the validated definitions, which runs before the bindings are
+    /// generated. Passes run at the end of the validation in the order they were added,
+    /// so the definitions of a validated compiler already reflect them.
+    /// * `pass` - transformation of the validated definitions
+    /// ```rust
+    /// # use rasn_compiler::prelude::*;
+    /// Compiler::<RasnBackend, _>::new()
+    ///     .add_pass(RenameIdentifiers::new().rename("Integer", "MyInteger"))
+    ///     .add_pass(|definitions: &mut Vec<ToplevelDefinition>| {
+    ///         definitions.retain(|d| !d.name().starts_with("Deprecated"));
+    ///         Ok(())
+    ///     })
+    ///     .add_asn_literal(format!(
+    ///         "TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN {} END",
+    ///         "Integer ::= INTEGER (1..128) Deprecated-integer ::= INTEGER"
+    ///     ))
+    ///     .compile_to_string();
+    /// ```
+    pub fn add_pass(mut self, pass: impl IrPass + 'static) -> Self {
+        self.passes.push(Box::new(pass));
+        self
+    }
 }
 
 impl<B: Backend> Compiler<B, CompilerMissingParams> {
@@ -195,6 +382,8 @@ impl<B: Backend> Compiler<B, CompilerMissingParams> {
         Compiler {
             state: CompilerMissingParams,
             backend: B::default(),
+            options: ValidationOptions::default(),
+            passes: vec![],
         }
     }
 
@@ -203,6 +392,8 @@ impl<B: Backend> Compiler<B, CompilerMissingParams> {
         Compiler {
             state: CompilerMissingParams,
             backend: B::from_config(config),
+            options: ValidationOptions::default(),
+            passes: vec![],
         }
     }
 }
@@ -219,6 +410,8 @@ impl<B: Backend> Compiler<B, CompilerMissingParams> {
                 sources: vec![AsnSource::Path(path_to_source.into())],
             },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 
@@ -235,6 +428,45 @@ impl<B: Backend> Compiler<B, CompilerMissingParams> {
                     .collect(),
             },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
+        }
+    }
+
+    /// Add the ASN1 embedded in a specification text file to the compile command by path.
+    /// Only the ASN1 between `-- ASN1START` and `-- ASN1STOP` marker lines is compiled,
+    /// and diagnostics refer to the lines of the specification text.
+    /// * `path_to_source` - path to the specification text file to include
+    pub fn add_marked_asn_by_path(
+        self,
+        path_to_source: impl Into<PathBuf>,
+    ) -> Compiler<B, CompilerSourcesSet> {
+        Compiler {
+            state: CompilerSourcesSet {
+                sources: vec![AsnSource::MarkedPath(path_to_source.into())],
+            },
+            backend: self.backend,
+            options: self.options,
+            passes: self.passes,
+        }
+    }
+
+    /// Add the ASN1 embedded in several specification text files to the compile command by path.
+    /// See [Self::add_marked_asn_by_path].
+    /// * `paths_to_sources` - iterator of paths to the specification text files to be included
+    pub fn add_marked_asn_sources_by_path(
+        self,
+        paths_to_sources: impl Iterator<Item = impl Into<PathBuf>>,
+    ) -> Compiler<B, CompilerSourcesSet> {
+        Compiler {
+            state: CompilerSourcesSet {
+                sources: paths_to_sources
+                    .map(|p| AsnSource::MarkedPath(p.into()))
+                    .collect(),
+            },
+            backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 
@@ -253,6 +485,8 @@ impl<B: Backend> Compiler<B, CompilerMissingParams> {
                 sources: vec![AsnSource::Literal(literal.into())],
             },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 
@@ -269,6 +503,8 @@ impl<B: Backend> Compiler<B, CompilerMissingParams> {
                 output_mode: OutputMode::SingleFile(output_path.into()),
             },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 
@@ -277,6 +513,8 @@ impl<B: Backend> Compiler<B, CompilerMissingParams> {
         Compiler {
             state: CompilerOutputSet { output_mode },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 }
@@ -291,6 +529,8 @@ impl<B: Backend> Compiler<B, CompilerOutputSet> {
                 output_mode: self.state.output_mode,
             },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 
@@ -308,6 +548,47 @@ impl<B: Backend> Compiler<B, CompilerOutputSet> {
                 output_mode: self.state.output_mode,
             },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
+        }
+    }
+
+    /// Add the ASN1 embedded in a specification text file to the compile command by path.
+    /// Only the ASN1 between `-- ASN1START` and `-- ASN1STOP` marker lines is compiled,
+    /// and diagnostics refer to the lines of the specification text.
+    /// * `path_to_source` - path to the specification text file to include
+    pub fn add_marked_asn_by_path(
+        self,
+        path_to_source: impl Into<PathBuf>,
+    ) -> Compiler<B, CompilerReady> {
+        Compiler {
+            state: CompilerReady {
+                sources: vec![AsnSource::MarkedPath(path_to_source.into())],
+                output_mode: self.state.output_mode,
+            },
+            backend: self.backend,
+            options: self.options,
+            passes: self.passes,
+        }
+    }
+
+    /// Add the ASN1 embedded in several specification text files to the compile command by path.
+    /// See [Self::add_marked_asn_by_path].
+    /// * `paths_to_sources` - iterator of paths to the specification text files to be included
+    pub fn add_marked_asn_sources_by_path(
+        self,
+        paths_to_sources: impl Iterator<Item = impl Into<PathBuf>>,
+    ) -> Compiler<B, CompilerReady> {
+        Compiler {
+            state: CompilerReady {
+                sources: paths_to_sources
+                    .map(|p| AsnSource::MarkedPath(p.into()))
+                    .collect(),
+                output_mode: self.state.output_mode,
+            },
+            backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 
@@ -327,6 +608,8 @@ impl<B: Backend> Compiler<B, CompilerOutputSet> {
                 output_mode: self.state.output_mode,
             },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 }
@@ -343,6 +626,8 @@ impl<B: Backend> Compiler<B, CompilerSourcesSet> {
         Compiler {
             state: CompilerSourcesSet { sources },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 
@@ -357,6 +642,43 @@ impl<B: Backend> Compiler<B, CompilerSourcesSet> {
         Compiler {
             state: CompilerSourcesSet { sources },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
+        }
+    }
+
+    /// Add the ASN1 embedded in a specification text file to the compile command by path.
+    /// Only the ASN1 between `-- ASN1START` and `-- ASN1STOP` marker lines is compiled,
+    /// and diagnostics refer to the lines of the specification text.
+    /// * `path_to_source` - path to the specification text file to include
+    pub fn add_marked_asn_by_path(
+        self,
+        path_to_source: impl Into<PathBuf>,
+    ) -> Compiler<B, CompilerSourcesSet> {
+        let mut sources: Vec<AsnSource> = self.state.sources;
+        sources.push(AsnSource::MarkedPath(path_to_source.into()));
+        Compiler {
+            state: CompilerSourcesSet { sources },
+            backend: self.backend,
+            options: self.options,
+            passes: self.passes,
+        }
+    }
+
+    /// Add the ASN1 embedded in several specification text files to the compile command by path.
+    /// See [Self::add_marked_asn_by_path].
+    /// * `paths_to_sources` - iterator of paths to the specification text files to be included
+    pub fn add_marked_asn_sources_by_path(
+        self,
+        paths_to_sources: impl Iterator<Item = impl Into<PathBuf>>,
+    ) -> Compiler<B, CompilerSourcesSet> {
+        let mut sources: Vec<AsnSource> = self.state.sources;
+        sources.extend(paths_to_sources.map(|p| AsnSource::MarkedPath(p.into())));
+        Compiler {
+            state: CompilerSourcesSet { sources },
+            backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 
@@ -375,6 +697,8 @@ impl<B: Backend> Compiler<B, CompilerSourcesSet> {
         Compiler {
             state: CompilerSourcesSet { sources },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 
@@ -389,6 +713,8 @@ impl<B: Backend> Compiler<B, CompilerSourcesSet> {
                 output_mode: OutputMode::SingleFile(output_path.into()),
             },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 
@@ -400,6 +726,8 @@ impl<B: Backend> Compiler<B, CompilerSourcesSet> {
                 output_mode,
             },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 
@@ -407,10 +735,30 @@ impl<B: Backend> Compiler<B, CompilerSourcesSet> {
     /// Returns a Result wrapping a compilation result:
     /// * _Ok_  - tuple containing the stringified bindings for the ASN1 spec as well as a vector of warnings raised during the compilation
     /// * _Err_ - Unrecoverable error, no bindings were generated
-    pub fn compile_to_string(self) -> Result<CompileResult, CompilerError> {
+    pub fn compile_to_string(self) -> Result<CompileResult, CompilerError>
+    where
+        B: Clone + Send,
+    {
         self.set_output_mode(OutputMode::NoOutput)
             .compile_to_string()
     }
+
+    /// Parses the ASN1 sources, along with the modules they import from the search paths,
+    /// without validating them.
+    /// Returns a Result wrapping the compiler in its parsed state:
+    /// * _Ok_  - compiler holding the parsed modules
+    /// * _Err_ - Unrecoverable error, the sources could not be parsed
+    pub fn parse(self) -> Result<Compiler<B, CompilerParsed>, CompilerError> {
+        self.set_output_mode(OutputMode::NoOutput).parse()
+    }
+
+    /// Parses, validates and links the ASN1 sources.
+    /// Returns a Result wrapping the compiler in its validated state:
+    /// * _Ok_  - compiler holding the validated definitions
+    /// * _Err_ - Unrecoverable error, the sources could not be parsed or validated
+    pub fn validate(self) -> Result<Compiler<B, CompilerValidated>, CompilerError> {
+        self.parse()?.validate()
+    }
 }
 
 impl<B: Backend> Compiler<B, CompilerReady> {
@@ -425,6 +773,8 @@ impl<B: Backend> Compiler<B, CompilerReady> {
                 sources,
             },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 
@@ -442,6 +792,49 @@ impl<B: Backend> Compiler<B, CompilerReady> {
                 output_mode: self.state.output_mode,
             },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
+        }
+    }
+
+    /// Add the ASN1 embedded in a specification text file to the compile command by path.
+    /// Only the ASN1 between `-- ASN1START` and `-- ASN1STOP` marker lines is compiled,
+    /// and diagnostics refer to the lines of the specification text.
+    /// * `path_to_source` - path to the specification text file to include
+    pub fn add_marked_asn_by_path(
+        self,
+        path_to_source: impl Into<PathBuf>,
+    ) -> Compiler<B, CompilerReady> {
+        let mut sources: Vec<AsnSource> = self.state.sources;
+        sources.push(AsnSource::MarkedPath(path_to_source.into()));
+        Compiler {
+            state: CompilerReady {
+                sources,
+                output_mode: self.state.output_mode,
+            },
+            backend: self.backend,
+            options: self.options,
+            passes: self.passes,
+        }
+    }
+
+    /// Add the ASN1 embedded in several specification text files to the compile command by path.
+    /// See [Self::add_marked_asn_by_path].
+    /// * `paths_to_sources` - iterator of paths to the specification text files to be included
+    pub fn add_marked_asn_sources_by_path(
+        self,
+        paths_to_sources: impl Iterator<Item = impl Into<PathBuf>>,
+    ) -> Compiler<B, CompilerReady> {
+        let mut sources: Vec<AsnSource> = self.state.sources;
+        sources.extend(paths_to_sources.map(|p| AsnSource::MarkedPath(p.into())));
+        Compiler {
+            state: CompilerReady {
+                sources,
+                output_mode: self.state.output_mode,
+            },
+            backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 
@@ -463,22 +856,214 @@ impl<B: Backend> Compiler<B, CompilerReady> {
                 sources,
             },
             backend: self.backend,
+            options: self.options,
+            passes: self.passes,
         }
     }
 
+    /// Parses the ASN1 sources, along with the modules they import from the search paths,
+    /// without validating them.
+    /// Returns a Result wrapping the compiler in its parsed state, which gives access to the
+    /// top-level definitions of every parsed module:
+    /// * _Ok_  - compiler holding the parsed modules
+    /// * _Err_ - Unrecoverable error, the sources could not be parsed
+    pub fn parse(self) -> Result<Compiler<B, CompilerParsed>, CompilerError> {
+        let (modules, warnings) = parse_sources(&self.state.sources, &self.options)?;
+        Ok(Compiler {
+            state: CompilerParsed {
+                modules,
+                warnings,
+                output_mode: self.state.output_mode,
+            },
+            backend: self.backend,
+            options: self.options,
+            passes: self.passes,
+        })
+    }
+
+    /// Parses, validates and links the ASN1 sources.
+    /// Returns a Result wrapping the compiler in its validated state, which gives access to the
+    /// valid top-level definitions and the warnings raised for the invalid ones:
+    /// * _Ok_  - compiler holding the validated definitions
+    /// * _Err_ - Unrecoverable error, the sources could not be parsed or validated
+    pub fn validate(self) -> Result<Compiler<B, CompilerValidated>, CompilerError> {
+        self.parse()?.validate()
+    }
+
     /// Runs the rasn compiler command and returns stringified Rust.
     /// Returns a Result wrapping a compilation result:
     /// * _Ok_  - tuple containing the stringified bindings for the ASN1 spec as well as a vector of warnings raised during the compilation
     /// * _Err_ - Unrecoverable error, no bindings were generated
-    pub fn compile_to_string(mut self) -> Result<CompileResult, CompilerError> {
+    pub fn compile_to_string(self) -> Result<CompileResult, CompilerError>
+    where
+        B: Clone + Send,
+    {
+        self.validate()?.compile_to_string()
+    }
+
+    /// Runs the rasn compiler command.
+    /// Returns a Result wrapping a compilation result:
+    /// * _Ok_  - Vector of warnings raised during the compilation
+    /// * _Err_ - Unrecoverable error, no bindings were generated
+    pub fn compile(self) -> Result<Vec<CompilerError>, CompilerError>
+    where
+        B: Clone + Send,
+    {
+        self.validate()?.compile()
+    }
+}
+
+impl<B: Backend> Compiler<B, CompilerParsed> {
+    /// Returns the parsed ASN1 modules along with their top-level definitions.
+    pub fn modules(&self) -> &[(ModuleHeader, Vec<ToplevelDefinition>)] {
+        &self.state.modules
+    }
+
+    /// Returns the parsed ASN1 modules for modification before validation.
+    pub fn modules_mut(&mut self) -> &mut Vec<(ModuleHeader, Vec<ToplevelDefinition>)> {
+        &mut self.state.modules
+    }
+
+    /// Consumes the compiler and returns the parsed ASN1 modules.
+    pub fn into_modules(self) -> Vec<(ModuleHeader, Vec<ToplevelDefinition>)> {
+        self.state.modules
+    }
+
+    /// Set the output destination for the generated bindings.
+    pub fn set_output_mode(mut self, output_mode: OutputMode) -> Self {
+        self.state.output_mode = output_mode;
+        self
+    }
+
+    /// Validates and links the parsed ASN1 modules, then runs the added passes over them.
+    /// Returns a Result wrapping the compiler in its validated state:
+    /// * _Ok_  - compiler holding the validated definitions
+    /// * _Err_ - Unrecoverable error, the modules could not be validated
+    pub fn validate(mut self) -> Result<Compiler<B, CompilerValidated>, CompilerError> {
+        let (mut definitions, validation_warnings) =
+            validate_modules(self.state.modules, &self.options)?;
+        let mut warnings = self.state.warnings;
+        warnings.extend(validation_warnings);
+        for pass in &mut self.passes {
+            pass.run(&mut definitions)?;
+        }
+        Ok(Compiler {
+            state: CompilerValidated {
+                definitions,
+                warnings,
+                output_mode: self.state.output_mode,
+            },
+            backend: self.backend,
+            options: self.options,
+            passes: self.passes,
+        })
+    }
+}
+
+impl<B: Backend> Compiler<B, CompilerValidated> {
+    /// Returns the validated and linked top-level definitions of all modules.
+    pub fn definitions(&self) -> &[ToplevelDefinition] {
+        &self.state.definitions
+    }
+
+    /// Returns the validated top-level definitions for modification before generation.
+    pub fn definitions_mut(&mut self) -> &mut Vec<ToplevelDefinition> {
+        &mut self.state.definitions
+    }
+
+    /// Returns the warnings raised during the validation,
+    /// including the errors of definitions that were dropped as invalid.
+    pub fn warnings(&self) -> &[CompilerError] {
+        &self.state.warnings
+    }
+
+    /// Consumes the compiler and returns the validated top-level definitions
+    /// along with the warnings raised during the validation.
+    pub fn into_parts(self) -> (Vec<ToplevelDefinition>, Vec<CompilerError>) {
+        (self.state.definitions, self.state.warnings)
+    }
+
+    /// Set the output destination for the generated bindings.
+    pub fn set_output_mode(mut self, output_mode: OutputMode) -> Self {
+        self.state.output_mode = output_mode;
+        self
+    }
+
+    /// Runs the rasn compiler command and returns stringified Rust.
+    /// Returns a Result wrapping a compilation result:
+    /// * _Ok_  - tuple containing the stringified bindings for the ASN1 spec as well as a vector of warnings raised during the compilation
+    /// * _Err_ - Unrecoverable error, no bindings were generated
+    pub fn compile_to_string(mut self) -> Result<CompileResult, CompilerError>
+    where
+        B: Clone + Send,
+    {
         self.internal_compile().map(CompileResult::fmt::<B>)
     }
 
+    /// Exports the validated top-level definitions as JSON.
+    /// See [`to_ir_json`](crate::prelude::to_ir_json) for the schema.
+    #[cfg(feature = "serde")]
+    pub fn to_ir_json(&self) -> String {
+        intermediate::json::to_ir_json(&self.state.definitions)
+    }
+
+    /// Writes the JSON export of the validated top-level definitions to the output
+    /// instead of generating bindings. Uses the file name `ir.json` if the output is a directory.
+    /// Returns a Result wrapping the warnings raised during the validation.
+    #[cfg(feature = "serde")]
+    pub fn emit_ir_json(self) -> Result<Vec<CompilerError>, CompilerError> {
+        let json = self.to_ir_json();
+        match &self.state.output_mode {
+            OutputMode::SingleFile(path) if !path.is_dir() => Self::write_generated(path, &json)?,
+            OutputMode::SingleFile(path) | OutputMode::Directory(path) => {
+                Self::write_generated(&path.join("ir.json"), &json)?
+            }
+            OutputMode::Stdout => std::io::stdout()
+                .write_all(json.as_bytes())
+                .map_err(|err| {
+                    GeneratorError::new(
+                        None,
+                        &format!("Failed to write IR JSON to stdout: {err}"),
+                        GeneratorErrorType::IO,
+                    )
+                })?,
+            OutputMode::NoOutput => (),
+        }
+        Ok(self.state.warnings)
+    }
+
     /// Runs the rasn compiler command.
     /// Returns a Result wrapping a compilation result:
     /// * _Ok_  - Vector of warnings raised during the compilation
     /// * _Err_ - Unrecoverable error, no bindings were generated
-    pub fn compile(mut self) -> Result<Vec<CompilerError>, CompilerError> {
+    pub fn compile(mut self) -> Result<Vec<CompilerError>, CompilerError>
+    where
+        B: Clone + Send,
+    {
+        if let OutputMode::Directory(path) = &self.state.output_mode {
+            let path = path.clone();
+            let bindings = self.internal_compile_modules()?;
+            fs::create_dir_all(&path).map_err(|e| {
+                GeneratorError::new(
+                    None,
+                    &format!("Failed to create output directory {}: {e}", path.display()),
+                    GeneratorErrorType::IO,
+                )
+            })?;
+            let index = bindings
+                .index
+                .map(|index| (format!("index{}", B::FILE_EXTENSION), index));
+            let modules = bindings
+                .modules
+                .into_iter()
+                .map(|(module_name, generated)| (B::module_file_name(&module_name), generated));
+            for (file_name, generated) in index.into_iter().chain(modules) {
+                let generated = B::format_bindings(&generated).unwrap_or(generated);
+                Self::write_generated(&path.join(file_name), &generated)?;
+            }
+            return Ok(bindings.warnings);
+        }
+
         let result = self.internal_compile()?.fmt::<B>();
 
         self.output_generated(&result.generated)?;
@@ -486,59 +1071,137 @@ impl<B: Backend> Compiler<B, CompilerReady> {
         Ok(result.warnings)
     }
 
-    fn internal_compile(&mut self) -> Result<CompileResult, CompilerError> {
+    fn internal_compile(&mut self) -> Result<CompileResult, CompilerError>
+    where
+        B: Clone + Send,
+    {
+        let bindings = self.internal_compile_modules()?;
+        Ok(CompileResult {
+            generated: bindings
+                .index
+                .into_iter()
+                .chain(bindings.modules.into_iter().map(|(_, generated)| generated))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            warnings: bindings.warnings,
+        })
+    }
+
+    /// Compiles the sources and returns the generated bindings per ASN.1 module
+    /// along with all warnings raised during the compilation.
+    fn internal_compile_modules(&mut self) -> Result<GeneratedBindings, CompilerError>
+    where
+        B: Clone + Send,
+    {
         let mut generated_modules = vec![];
         let mut warnings = Vec::<CompilerError>::new();
-        let mut modules: Vec<ToplevelDefinition> = vec![];
-        for src in &self.state.sources {
-            let src_unit = src.try_into()?;
-            modules.append(
-                &mut asn_spec(src_unit)?
-                    .into_iter()
-                    .flat_map(|(header, tlds)| {
-                        let header_ref = Rc::new(RefCell::new(header));
-                        tlds.into_iter().map(move |mut tld| {
-                            tld.apply_tagging_environment(&header_ref.borrow().tagging_environment);
-                            tld.set_module_header(header_ref.clone());
-                            tld
-                        })
-                    })
-                    .collect(),
-            );
+        let mut valid_items = std::mem::take(&mut self.state.definitions);
+        if !B::SOURCE_ORDER {
+            valid_items.sort_by(|a, b| a.name().cmp(b.name()));
         }
-        let (valid_items, mut validator_errors) = Validator::new(modules).validate()?;
-        let modules = valid_items.into_iter().fold(
-            BTreeMap::<String, Vec<ToplevelDefinition>>::new(),
+        let mut validator_errors = std::mem::take(&mut self.state.warnings);
+        self.backend.prepare(&valid_items);
+        let mut modules = valid_items.into_iter().fold(
+            Vec::<(String, Vec<ToplevelDefinition>)>::new(),
             |mut modules, tld| {
                 let key = tld
                     .get_module_header()
-                    .map_or(<_>::default(), |module| module.borrow().name.clone());
-                match modules.entry(key) {
-                    std::collections::btree_map::Entry::Vacant(v) => {
-                        v.insert(vec![tld]);
-                    }
-                    std::collections::btree_map::Entry::Occupied(ref mut e) => {
-                        e.get_mut().push(tld)
-                    }
+                    .map_or(<_>::default(), |module| module.read().name.clone());
+                match modules.iter_mut().find(|(name, _)| *name == key) {
+                    Some((_, tlds)) => tlds.push(tld),
+                    None => modules.push((key, vec![tld])),
                 }
                 modules
             },
         );
-        for (_, module) in modules {
-            let mut generated_module = self.backend.generate_module(module)?;
+        if !B::SOURCE_ORDER {
+            modules.sort_by(|(a, _), (b, _)| a.cmp(b));
+        }
+        let (module_names, modules): (Vec<_>, Vec<_>) = modules.into_iter().unzip();
+        let generated = self.generate_modules(&module_names, modules);
+        for (module_name, generated_module) in module_names.into_iter().zip(generated) {
+            let mut generated_module = generated_module?;
             if let Some(m) = generated_module.generated {
-                generated_modules.push(m);
+                generated_modules.push((module_name, m));
             }
             warnings.append(&mut generated_module.warnings);
         }
         warnings.append(&mut validator_errors);
+        // Entries that this compilation did not use belong to earlier versions of the sources
+        #[cfg(feature = "serde")]
+        if let Some(cache) = &self.options.cache {
+            cache.prune();
+        }
 
-        Ok(CompileResult {
-            generated: generated_modules.join("\n"),
+        Ok(GeneratedBindings {
+            index: self.backend.generate_index(),
+            modules: generated_modules,
             warnings,
         })
     }
 
+    /// Generates the bindings of the given modules, reusing the cached bindings of
+    /// the modules that did not change since they were generated.
+    #[cfg(feature = "serde")]
+    fn generate_modules(
+        &self,
+        names: &[String],
+        modules: Vec<Vec<ToplevelDefinition>>,
+    ) -> Vec<Result<GeneratedModule, GeneratorError>>
+    where
+        B: Clone + Send,
+    {
+        let Some(cache) = &self.options.cache else {
+            return parallel::map_with(
+                parallel::available_threads(),
+                &self.backend,
+                modules,
+                B::generate_module,
+            );
+        };
+        let keys = cache::module_keys(&self.backend, names, &modules);
+        parallel::map_with(
+            parallel::available_threads(),
+            &self.backend,
+            modules.into_iter().zip(keys).collect(),
+            |backend, (module, key)| cache.generate(backend, key, module),
+        )
+    }
+
+    /// Generates the bindings of the given modules.
+    #[cfg(not(feature = "serde"))]
+    fn generate_modules(
+        &self,
+        _names: &[String],
+        modules: Vec<Vec<ToplevelDefinition>>,
+    ) -> Vec<Result<GeneratedModule, GeneratorError>>
+    where
+        B: Clone + Send,
+    {
+        parallel::map_with(
+            parallel::available_threads(),
+            &self.backend,
+            modules,
+            B::generate_module,
+        )
+    }
+
+    fn write_generated(path: &Path, generated: &str) -> Result<(), GeneratorError> {
+        if fs::read(path).is_ok_and(|existing| existing == generated.as_bytes()) {
+            return Ok(());
+        }
+        fs::write(path, generated).map_err(|e| {
+            GeneratorError::new(
+                None,
+                &format!(
+                    "Failed to write generated bindings to {}: {e}",
+                    path.display()
+                ),
+                GeneratorErrorType::IO,
+            )
+        })
+    }
+
     fn output_generated(&self, generated: &str) -> Result<(), GeneratorError> {
         match &self.state.output_mode {
             OutputMode::SingleFile(path) => {
@@ -547,16 +1210,7 @@ impl<B: Backend> Compiler<B, CompilerReady> {
                 } else {
                     path
                 };
-                fs::write(path, generated).map_err(|e| {
-                    GeneratorError::new(
-                        None,
-                        &format!(
-                            "Failed to write generated bindings to {}: {e}",
-                            path.display()
-                        ),
-                        GeneratorErrorType::IO,
-                    )
-                })
+                Self::write_generated(path, generated)
             }
             OutputMode::Stdout => {
                 std::io::stdout()
@@ -569,17 +1223,139 @@ impl<B: Backend> Compiler<B, CompilerReady> {
                         )
                     })
             }
-            OutputMode::NoOutput => Ok(()),
+            OutputMode::Directory(_) | OutputMode::NoOutput => Ok(()),
         }
     }
 }
 
-/// Where the [Compiler] output should go.
+/// Options of the parsing and validation of ASN.1 sources
 #[derive(Debug)]
+struct ValidationOptions {
+    /// Directories to load imported modules from
+    search_paths: Vec<PathBuf>,
+    /// Severity of imports of symbols that the imported module does not export
+    unexported_imports: Severity,
+    /// On-disk cache of parsed sources, validated and generated modules
+    #[cfg(feature = "serde")]
+    cache: Option<Cache>,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            search_paths: vec![],
+            unexported_imports: Severity::Error,
+            #[cfg(feature = "serde")]
+            cache: None,
+        }
+    }
+}
+
+/// Parses and validates ASN.1 sources, along with the modules they import from the search paths.
+/// Returns the valid top-level definitions along with the errors raised for the invalid ones.
+fn validate_sources(
+    sources: &[AsnSource],
+    options: &ValidationOptions,
+) -> Result<(Vec<ToplevelDefinition>, Vec<CompilerError>), CompilerError> {
+    let (modules, mut warnings) = parse_sources(sources, options)?;
+    let (definitions, validation_warnings) = validate_modules(modules, options)?;
+    warnings.extend(validation_warnings);
+    Ok((definitions, warnings))
+}
+
+/// Parses ASN.1 sources, along with the modules they import from the search paths.
+/// Returns the parsed modules along with the warnings raised for unreadable files in the search paths.
+#[allow(clippy::type_complexity)]
+fn parse_sources(
+    sources: &[AsnSource],
+    options: &ValidationOptions,
+) -> Result<
+    (
+        Vec<(ModuleHeader, Vec<ToplevelDefinition>)>,
+        Vec<CompilerError>,
+    ),
+    CompilerError,
+> {
+    let lexed = parallel::map(
+        parallel::available_threads(),
+        sources.iter().collect(),
+        |src| -> Result<_, CompilerError> {
+            let unit = AsnSourceUnit::try_from(src)?;
+            #[cfg(feature = "serde")]
+            let cached = options
+                .cache
+                .as_ref()
+                .map(|cache| (cache, Cache::source_key(&unit.source)));
+            #[cfg(feature = "serde")]
+            if let Some(parsed) = cached.and_then(|(cache, key)| cache.parsed(key)) {
+                return Ok(parsed);
+            }
+            let parsed = asn_spec(unit)?;
+            #[cfg(feature = "serde")]
+            if let Some((cache, key)) = cached {
+                cache.store_parsed(key, &parsed);
+            }
+            Ok(parsed)
+        },
+    );
+    let mut modules = vec![];
+    for lexed in lexed {
+        modules.extend(lexed?);
+    }
+    let warnings = search_path::load_imports(&mut modules, &options.search_paths);
+    Ok((modules, warnings))
+}
+
+/// Validates and links parsed ASN.1 modules.
+/// Returns the valid top-level definitions along with the errors raised for the invalid ones.
+fn validate_modules(
+    modules: Vec<(ModuleHeader, Vec<ToplevelDefinition>)>,
+    options: &ValidationOptions,
+) -> Result<(Vec<ToplevelDefinition>, Vec<CompilerError>), CompilerError> {
+    #[cfg(feature = "serde")]
+    if let Some(cache) = &options.cache {
+        return cache.validate(modules, |modules, scope| {
+            validate_in_scope(modules, options, Some(scope))
+        });
+    }
+    validate_in_scope(modules, options, None)
+}
+
+/// Validates and links parsed ASN.1 modules, only returning the definitions
+/// of the modules in the scope, see [Validator::with_scope].
+fn validate_in_scope(
+    modules: Vec<(ModuleHeader, Vec<ToplevelDefinition>)>,
+    options: &ValidationOptions,
+    scope: Option<BTreeSet<String>>,
+) -> Result<(Vec<ToplevelDefinition>, Vec<CompilerError>), CompilerError> {
+    let tlds = modules
+        .into_iter()
+        .flat_map(|(header, tlds)| {
+            let header_ref = SharedModuleHeader::new(header);
+            tlds.into_iter().map(move |mut tld| {
+                tld.apply_tagging_environment(&header_ref.read().tagging_environment);
+                tld.set_module_header(header_ref.clone());
+                tld
+            })
+        })
+        .collect();
+    Validator::new(tlds)
+        .with_unexported_imports(options.unexported_imports)
+        .with_scope(scope)
+        .validate()
+}
+
+/// Where the [Compiler] output should go.
+/// Files whose content would not change are not written again, so that their
+/// modification time only changes when the generated bindings do.
+#[derive(Debug, Clone)]
 pub enum OutputMode {
     /// Write all compiled modules to a single file. Uses a default filename if path is a
     /// directory.
     SingleFile(PathBuf),
+    /// Write each compiled module to its own file in the given directory.
+    /// File names are derived from the module names by the backend.
+    Directory(PathBuf),
     /// Write all compiled modules to stdout.
     Stdout,
     /// Do not write anything, only check.
@@ -609,6 +1385,18 @@ impl<'a> TryFrom<&'a AsnSource> for AsnSourceUnit<'a> {
                 path: Some(path),
                 source: Cow::Owned(read_to_string(path).map_err(LexerError::from)?),
             }),
+            AsnSource::MarkedPath(path) => {
+                let text = read_to_string(path).map_err(LexerError::from)?;
+                Ok(AsnSourceUnit {
+                    path: Some(path),
+                    source: Cow::Owned(extract_marked_asn1(&text).ok_or_else(|| LexerError {
+                        kind: LexerErrorType::IO(format!(
+                            "Found no ASN1 between `-- ASN1START` and `-- ASN1STOP` markers in {}",
+                            path.display()
+                        )),
+                    })?),
+                })
+            }
             AsnSource::Literal(literal) => Ok(AsnSourceUnit {
                 path: None,
                 source: Cow::Borrowed(literal),
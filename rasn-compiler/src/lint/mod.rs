@@ -0,0 +1,218 @@
+//! Style checks on the validated definitions of an ASN.1 specification.
+//! Every [LintRule] can be allowed, reported as a warning or denied individually.
+
+use std::{collections::BTreeMap, fmt::Display, path::PathBuf, str::FromStr};
+
+use crate::{
+    error::CompilerError, intermediate::ToplevelDefinition, validate_sources, AsnSource,
+    ValidationOptions,
+};
+
+mod rules;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintRule {
+    /// Type references are UpperCamelCase, optionally with hyphens, e.g. `ProtocolIE-Container`
+    TypeReferenceNaming,
+    /// Value references are lowerCamelCase, e.g. `maxNrOfItems`
+    ValueReferenceNaming,
+    /// Top-level `SEQUENCE`, `SET`, `CHOICE` and `ENUMERATED` types have an extension marker,
+    /// unless their module implies extensibility
+    MissingExtensionMarker,
+    /// `INTEGER` types have a finite value range and `OCTET STRING` types an upper size bound,
+    /// so that PER can encode them efficiently
+    UnconstrainedPerType,
+    /// Imported symbols are referenced by the importing module.
+    /// Validation replaces value references with their values,
+    /// so imported value references are not checked
+    UnusedImport,
+    /// Types that are not exported are referenced in the specification
+    UnusedType,
+    /// Definitions do not shadow symbols imported into their module
+    ShadowedName,
+}
+
+impl LintRule {
+    pub const ALL: &'static [LintRule] = &[
+        LintRule::TypeReferenceNaming,
+        LintRule::ValueReferenceNaming,
+        LintRule::MissingExtensionMarker,
+        LintRule::UnconstrainedPerType,
+        LintRule::UnusedImport,
+        LintRule::UnusedType,
+        LintRule::ShadowedName,
+    ];
+
+    /// Name of the rule, e.g. `unused-import`
+    pub fn name(&self) -> &'static str {
+        match self {
+            LintRule::TypeReferenceNaming => "type-reference-naming",
+            LintRule::ValueReferenceNaming => "value-reference-naming",
+            LintRule::MissingExtensionMarker => "missing-extension-marker",
+            LintRule::UnconstrainedPerType => "unconstrained-per-type",
+            LintRule::UnusedImport => "unused-import",
+            LintRule::UnusedType => "unused-type",
+            LintRule::ShadowedName => "shadowed-name",
+        }
+    }
+
+    /// Level of the rule if it is not configured.
+    /// Only specifications that are encoded with PER need to constrain their
+    /// integers and octet strings, so `unconstrained-per-type` is allowed by default.
+    pub fn default_level(&self) -> LintLevel {
+        match self {
+            LintRule::UnconstrainedPerType => LintLevel::Allow,
+            _ => LintLevel::Warn,
+        }
+    }
+}
+
+impl Display for LintRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for LintRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|rule| rule.name() == s)
+            .copied()
+            .ok_or_else(|| format!("Unknown lint rule {s}"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintLevel {
+    /// The rule is not checked
+    Allow,
+    /// Violations of the rule are reported
+    Warn,
+    /// Violations of the rule are reported and fail the lint run
+    Deny,
+}
+
+impl Display for LintLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LintLevel::Allow => write!(f, "allow"),
+            LintLevel::Warn => write!(f, "warning"),
+            LintLevel::Deny => write!(f, "error"),
+        }
+    }
+}
+
+/// Levels of the lint rules. Rules that are not configured use their
+/// [LintRule::default_level].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LintConfig {
+    levels: BTreeMap<LintRule, LintLevel>,
+}
+
+impl LintConfig {
+    pub fn set_level(mut self, rule: LintRule, level: LintLevel) -> Self {
+        self.levels.insert(rule, level);
+        self
+    }
+
+    pub fn level(&self, rule: LintRule) -> LintLevel {
+        self.levels
+            .get(&rule)
+            .copied()
+            .unwrap_or(rule.default_level())
+    }
+}
+
+/// A violation of a lint rule.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lint {
+    pub rule: LintRule,
+    pub level: LintLevel,
+    /// Module of the violating definition or import
+    pub module: String,
+    pub message: String,
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}[{}]: {}: {}",
+            self.level, self.rule, self.module, self.message
+        )
+    }
+}
+
+/// The lints of an ASN.1 specification.
+///
+/// ### Example
+/// ```
+/// # use rasn_compiler::prelude::{LintConfig, LintLevel, LintReport, LintRule};
+/// let config = LintConfig::default().set_level(LintRule::MissingExtensionMarker, LintLevel::Deny);
+/// let report = LintReport::from_literal(
+///     "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+///         Message ::= SEQUENCE { id INTEGER (0..255) }
+///     END",
+///     &config,
+/// ).unwrap();
+/// assert_eq!(
+///     report.lints[0].to_string(),
+///     "error[missing-extension-marker]: Example: `Message` is not extensible"
+/// );
+/// assert!(report.is_denied());
+/// ```
+#[derive(Debug, Default)]
+pub struct LintReport {
+    pub lints: Vec<Lint>,
+    /// Errors raised while validating the specification.
+    /// Definitions that fail to validate are not linted.
+    pub warnings: Vec<CompilerError>,
+}
+
+impl LintReport {
+    /// Lints validated top-level definitions.
+    pub fn new(tlds: &[ToplevelDefinition], config: &LintConfig) -> Self {
+        Self {
+            lints: rules::lint(tlds, config),
+            warnings: vec![],
+        }
+    }
+
+    /// Parses, validates and lints a specification given by the paths of its ASN.1 modules.
+    pub fn from_paths(
+        paths: impl IntoIterator<Item = impl Into<PathBuf>>,
+        config: &LintConfig,
+    ) -> Result<Self, CompilerError> {
+        Self::from_sources(
+            &paths
+                .into_iter()
+                .map(|p| AsnSource::Path(p.into()))
+                .collect::<Vec<_>>(),
+            config,
+        )
+    }
+
+    /// Parses, validates and lints a specification given as ASN.1 source text.
+    pub fn from_literal(
+        literal: impl Into<String>,
+        config: &LintConfig,
+    ) -> Result<Self, CompilerError> {
+        Self::from_sources(&[AsnSource::Literal(literal.into())], config)
+    }
+
+    fn from_sources(sources: &[AsnSource], config: &LintConfig) -> Result<Self, CompilerError> {
+        let (tlds, warnings) = validate_sources(sources, &ValidationOptions::default())?;
+        Ok(Self {
+            lints: rules::lint(&tlds, config),
+            warnings,
+        })
+    }
+
+    /// Returns `true` if any lint violates a denied rule.
+    pub fn is_denied(&self) -> bool {
+        self.lints.iter().any(|lint| lint.level == LintLevel::Deny)
+    }
+}
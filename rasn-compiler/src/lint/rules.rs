@@ -0,0 +1,366 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    generator::graph::{DefinitionKind, DependencyGraph},
+    intermediate::{constraints::Constraint, effective_constraints::EffectiveConstraint, *},
+};
+
+use super::{Lint, LintConfig, LintLevel, LintRule};
+
+struct Linter<'a> {
+    config: &'a LintConfig,
+    lints: Vec<Lint>,
+}
+
+impl Linter<'_> {
+    fn report(&mut self, rule: LintRule, module: &str, message: String) {
+        let level = self.config.level(rule);
+        if level != LintLevel::Allow {
+            self.lints.push(Lint {
+                rule,
+                level,
+                module: module.into(),
+                message,
+            });
+        }
+    }
+}
+
+pub(super) fn lint(tlds: &[ToplevelDefinition], config: &LintConfig) -> Vec<Lint> {
+    let mut linter = Linter {
+        config,
+        lints: vec![],
+    };
+    let types = tlds
+        .iter()
+        .filter_map(|tld| match tld {
+            ToplevelDefinition::Type(t) if t.parameterization.is_none() => {
+                Some((t.name.as_str(), &t.ty))
+            }
+            _ => None,
+        })
+        .collect::<BTreeMap<_, _>>();
+    let resolve = |name: &str| types.get(name).copied();
+
+    for tld in tlds {
+        let Some(header) = tld.get_module_header() else {
+            continue;
+        };
+        let header = header.read();
+        let module = &header.name;
+        let name = tld.name();
+        match tld {
+            ToplevelDefinition::Type(t) => {
+                check_type_reference(&mut linter, module, name);
+                let extensible = match &t.ty {
+                    ASN1Type::Sequence(s) | ASN1Type::Set(s) => Some(s.extensible.is_some()),
+                    ASN1Type::Choice(c) => Some(c.extensible.is_some()),
+                    ASN1Type::Enumerated(e) => Some(e.extensible.is_some()),
+                    _ => None,
+                };
+                if extensible == Some(false)
+                    && header.extensibility_environment == ExtensibilityEnvironment::Explicit
+                {
+                    linter.report(
+                        LintRule::MissingExtensionMarker,
+                        module,
+                        format!("`{name}` is not extensible"),
+                    );
+                }
+                if t.parameterization.is_none() {
+                    check_per_constraints(&mut linter, module, &t.ty, &[], name, &resolve);
+                }
+            }
+            ToplevelDefinition::Value(_) => check_value_reference(&mut linter, module, name),
+            ToplevelDefinition::Object(_) if name.starts_with(char::is_lowercase) => {
+                check_value_reference(&mut linter, module, name)
+            }
+            ToplevelDefinition::Object(_) => check_type_reference(&mut linter, module, name),
+            ToplevelDefinition::Class(_) | ToplevelDefinition::Macro(_) => (),
+        }
+        if let Some(import) = header
+            .imports
+            .iter()
+            .find(|import| import.types.contains(name))
+        {
+            linter.report(
+                LintRule::ShadowedName,
+                module,
+                format!(
+                    "`{name}` shadows the symbol imported from `{}`",
+                    import.global_module_reference.module_reference
+                ),
+            );
+        }
+    }
+
+    check_usage(&mut linter, tlds);
+    linter.lints
+}
+
+fn check_type_reference(linter: &mut Linter, module: &str, name: &str) {
+    // Every hyphen-separated part of the name starts a new word
+    let upper_camel = name
+        .split('-')
+        .all(|part| part.starts_with(|c: char| c.is_ascii_uppercase() || c.is_ascii_digit()));
+    if !upper_camel {
+        linter.report(
+            LintRule::TypeReferenceNaming,
+            module,
+            format!("`{name}` is not an UpperCamelCase type reference"),
+        );
+    }
+}
+
+fn check_value_reference(linter: &mut Linter, module: &str, name: &str) {
+    if !name.starts_with(|c: char| c.is_ascii_lowercase()) || name.contains('-') {
+        linter.report(
+            LintRule::ValueReferenceNaming,
+            module,
+            format!("`{name}` is not a lowerCamelCase value reference"),
+        );
+    }
+}
+
+/// Reports `INTEGER` and `OCTET STRING` types, nested ones included,
+/// whose PER encoding is not bounded by their effective constraint.
+fn check_per_constraints<'a>(
+    linter: &mut Linter,
+    module: &str,
+    ty: &'a ASN1Type,
+    constraints: &'a [Constraint],
+    path: &str,
+    resolve: &dyn Fn(&str) -> Option<&'a ASN1Type>,
+) {
+    let effective = || EffectiveConstraint::new(ty, resolve).constrain(constraints, resolve);
+    match ty {
+        ASN1Type::Integer(_)
+            if effective()
+                .values
+                .is_none_or(|v| v.min().is_none() || v.max().is_none()) =>
+        {
+            linter.report(
+                LintRule::UnconstrainedPerType,
+                module,
+                format!("`{path}` is an INTEGER without a finite value range"),
+            )
+        }
+        ASN1Type::OctetString(_) if effective().size.is_none_or(|s| s.max().is_none()) => linter
+            .report(
+                LintRule::UnconstrainedPerType,
+                module,
+                format!("`{path}` is an OCTET STRING without an upper size bound"),
+            ),
+        ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+            for member in &s.members {
+                let path = format!("{path}.{}", member.name);
+                check_per_constraints(
+                    linter,
+                    module,
+                    &member.ty,
+                    &member.constraints,
+                    &path,
+                    resolve,
+                );
+            }
+        }
+        ASN1Type::Choice(c) => {
+            for option in &c.options {
+                let path = format!("{path}.{}", option.name);
+                check_per_constraints(
+                    linter,
+                    module,
+                    &option.ty,
+                    &option.constraints,
+                    &path,
+                    resolve,
+                );
+            }
+        }
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => check_per_constraints(
+            linter,
+            module,
+            &s.element_type,
+            &[],
+            &format!("{path}[]"),
+            resolve,
+        ),
+        _ => (),
+    }
+}
+
+/// Reports imported symbols that their module never references, and types
+/// that are neither exported by their module nor referenced anywhere.
+/// Exported imports count as used, since the module re-exports them.
+fn check_usage(linter: &mut Linter, tlds: &[ToplevelDefinition]) {
+    let graph = DependencyGraph::new(tlds);
+    let exports = tlds
+        .iter()
+        .filter_map(|tld| tld.get_module_header())
+        .map(|header| {
+            let header = header.read();
+            let exports = match &header.exports {
+                Some(Exports::Identifier(symbols)) => Some(symbols.clone()),
+                _ => None,
+            };
+            (header.name.clone(), exports)
+        })
+        .collect::<BTreeMap<_, _>>();
+    let exported = |module: &str, symbol: &String| {
+        exports
+            .get(module)
+            .is_none_or(|exports| exports.as_ref().is_none_or(|e| e.contains(symbol)))
+    };
+
+    let mut used = BTreeSet::new();
+    for dependency in &graph.dependencies {
+        if dependency.user != dependency.dependency {
+            used.insert((&dependency.user.module, &dependency.dependency.name));
+        }
+    }
+    for definition in &graph.definitions {
+        for reference in &definition.unresolved {
+            used.insert((&definition.id.module, reference));
+        }
+    }
+
+    for import in &graph.imports {
+        for symbol in &import.symbols {
+            // The linker replaces references to values with the values themselves,
+            // so imported value references cannot be told apart from unused ones.
+            if symbol.starts_with(char::is_lowercase)
+                || exports
+                    .get(&import.importing)
+                    .is_some_and(|e| e.as_ref().is_some_and(|e| e.contains(symbol)))
+                || used.contains(&(&import.importing, symbol))
+            {
+                continue;
+            }
+            linter.report(
+                LintRule::UnusedImport,
+                &import.importing,
+                format!(
+                    "`{symbol}` is imported from `{}`, but never used",
+                    import.imported
+                ),
+            );
+        }
+    }
+
+    for definition in &graph.definitions {
+        let id = &definition.id;
+        if definition.kind == DefinitionKind::Type
+            && !exported(&id.module, &id.name)
+            && !graph
+                .dependencies
+                .iter()
+                .any(|d| &d.dependency == id && d.user != *id)
+        {
+            linter.report(
+                LintRule::UnusedType,
+                &id.module,
+                format!("`{}` is neither exported nor used", id.name),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{LintConfig, LintLevel, LintReport, LintRule};
+
+    fn lints(modules: &str, config: &LintConfig) -> Vec<String> {
+        LintReport::from_literal(modules, config)
+            .unwrap()
+            .lints
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn lints_naming_and_extensibility() {
+        assert_eq!(
+            lints(
+                "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Message-header ::= SEQUENCE { id INTEGER (0..255), ... }
+                Kind ::= ENUMERATED { a, b }
+                ProtocolIE-Container ::= CHOICE { a BOOLEAN, ... }
+                max-items INTEGER ::= 4
+                END",
+                &LintConfig::default()
+            ),
+            vec![
+                "warning[type-reference-naming]: Example: `Message-header` is not an UpperCamelCase type reference",
+                "warning[missing-extension-marker]: Example: `Kind` is not extensible",
+                "warning[value-reference-naming]: Example: `max-items` is not a lowerCamelCase value reference",
+            ]
+        );
+        assert!(lints(
+            "Example DEFINITIONS AUTOMATIC TAGS EXTENSIBILITY IMPLIED ::= BEGIN
+            Kind ::= ENUMERATED { a, b }
+            END",
+            &LintConfig::default()
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn lints_unconstrained_per_types() {
+        let module = "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Small ::= INTEGER (0..255)
+            Message ::= SEQUENCE {
+                id Small,
+                count INTEGER (0..MAX),
+                payload OCTET STRING,
+                digest OCTET STRING (SIZE (32)),
+                ...
+            }
+            END";
+        assert!(lints(module, &LintConfig::default()).is_empty());
+        assert_eq!(
+            lints(
+                module,
+                &LintConfig::default().set_level(LintRule::UnconstrainedPerType, LintLevel::Deny)
+            ),
+            vec![
+                "error[unconstrained-per-type]: Example: `Message.count` is an INTEGER without a finite value range",
+                "error[unconstrained-per-type]: Example: `Message.payload` is an OCTET STRING without an upper size bound",
+            ]
+        );
+    }
+
+    #[test]
+    fn lints_unused_and_shadowed_names() {
+        let modules = "Base DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Id ::= INTEGER (0..255)
+            Flag ::= BOOLEAN
+            Name ::= IA5String
+            END
+            Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            EXPORTS Message;
+            IMPORTS Id, Flag, Name FROM Base;
+            Message ::= SEQUENCE { id Id, name Helper, ... }
+            Helper ::= Name
+            Orphan ::= BOOLEAN
+            Flag ::= INTEGER (0..1)
+            END";
+        assert_eq!(
+            lints(modules, &LintConfig::default()),
+            vec![
+                "warning[shadowed-name]: Example: `Flag` shadows the symbol imported from `Base`",
+                "warning[unused-import]: Example: `Flag` is imported from `Base`, but never used",
+                "warning[unused-type]: Example: `Flag` is neither exported nor used",
+                "warning[unused-type]: Example: `Orphan` is neither exported nor used",
+            ]
+        );
+        assert!(lints(
+            modules,
+            &LintConfig::default()
+                .set_level(LintRule::ShadowedName, LintLevel::Allow)
+                .set_level(LintRule::UnusedImport, LintLevel::Allow)
+                .set_level(LintRule::UnusedType, LintLevel::Allow)
+        )
+        .is_empty());
+    }
+}
@@ -0,0 +1,293 @@
+//! Listing of the modules of an ASN.1 specification with their top-level definitions.
+//! Definitions are listed in the order of the sources, with their kinds and the
+//! locations of their assignments. The sources are lexed, but not validated,
+//! so that specifications with unresolved references can be listed as well.
+
+use std::{fmt::Display, path::PathBuf};
+
+use crate::{
+    diagnostic::Span, error::CompilerError, generator::documentation::catalog::DefinitionKind,
+    lexer::asn_spec, AsnSource, AsnSourceUnit,
+};
+
+/// A top-level definition of a listed module.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListedDefinition {
+    pub name: String,
+    pub kind: DefinitionKind,
+    /// Location of the assignment, or `None` if it was not found in the source
+    pub location: Option<Span>,
+}
+
+/// A module of a listed specification.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListedModule {
+    pub name: String,
+    /// Location of the module header, or `None` if it was not found in the source
+    pub location: Option<Span>,
+    pub definitions: Vec<ListedDefinition>,
+}
+
+/// The modules of an ASN.1 specification with their top-level definitions.
+///
+/// ### Example
+/// ```
+/// # use rasn_compiler::prelude::{DefinitionKind, DefinitionListing};
+/// let listing = DefinitionListing::from_literal(
+///     "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+///         Message ::= SEQUENCE { id INTEGER (0..255) }
+///         max-id INTEGER ::= 255
+///     END",
+/// ).unwrap();
+/// let definition = &listing.modules[0].definitions[1];
+/// assert_eq!(definition.name, "max-id");
+/// assert_eq!(definition.kind, DefinitionKind::Value);
+/// assert_eq!(definition.location.as_ref().unwrap().to_string(), "<literal>:3:9");
+/// ```
+#[derive(Debug, Default)]
+pub struct DefinitionListing {
+    pub modules: Vec<ListedModule>,
+}
+
+impl DefinitionListing {
+    /// Lists the modules of a specification given by the paths of its ASN.1 modules.
+    pub fn from_paths(
+        paths: impl IntoIterator<Item = impl Into<PathBuf>>,
+    ) -> Result<Self, CompilerError> {
+        Self::from_sources(
+            &paths
+                .into_iter()
+                .map(|p| AsnSource::Path(p.into()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Lists the modules of a specification given by the paths of text files
+    /// with ASN.1 between `-- ASN1START` and `-- ASN1STOP` marker lines.
+    pub fn from_marked_paths(
+        paths: impl IntoIterator<Item = impl Into<PathBuf>>,
+    ) -> Result<Self, CompilerError> {
+        Self::from_sources(
+            &paths
+                .into_iter()
+                .map(|p| AsnSource::MarkedPath(p.into()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Lists the modules of a specification given as ASN.1 source text.
+    pub fn from_literal(literal: impl Into<String>) -> Result<Self, CompilerError> {
+        Self::from_sources(&[AsnSource::Literal(literal.into())])
+    }
+
+    fn from_sources(sources: &[AsnSource]) -> Result<Self, CompilerError> {
+        let mut modules = vec![];
+        for source in sources {
+            let unit = AsnSourceUnit::try_from(source)?;
+            let file = unit.path.map(|p| p.display().to_string());
+            let text = unit.source.to_string();
+            let lexed = asn_spec(unit)?;
+            let locator = Locator::new(&text);
+            let span = |(line, column)| Span {
+                file: file.clone(),
+                line,
+                column,
+            };
+            // Definitions are searched between the header of their module and the next one
+            let mut start = 0;
+            let headers = lexed
+                .iter()
+                .map(|(header, _)| {
+                    let location = locator.module(&header.name, start);
+                    if let Some((line, _)) = location {
+                        start = line;
+                    }
+                    location
+                })
+                .collect::<Vec<_>>();
+            for (i, (header, definitions)) in lexed.iter().enumerate() {
+                let start = headers[i].map_or(0, |(line, _)| line);
+                let end = headers[i + 1..]
+                    .iter()
+                    .find_map(|h| h.map(|(line, _)| line - 1))
+                    .unwrap_or(locator.lines.len());
+                modules.push(ListedModule {
+                    name: header.name.clone(),
+                    location: headers[i].map(span),
+                    definitions: definitions
+                        .iter()
+                        .map(|definition| ListedDefinition {
+                            name: definition.name().clone(),
+                            kind: DefinitionKind::of(definition),
+                            location: locator.definition(definition.name(), start, end).map(span),
+                        })
+                        .collect(),
+                });
+            }
+        }
+        Ok(Self { modules })
+    }
+}
+
+impl Display for DefinitionListing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let location =
+            |location: &Option<Span>| location.as_ref().map_or(String::new(), Span::to_string);
+        for module in &self.modules {
+            writeln!(f, "{}  {}", module.name, location(&module.location))?;
+            let kind_width = module
+                .definitions
+                .iter()
+                .map(|d| d.kind.label().len())
+                .max()
+                .unwrap_or(0);
+            let name_width = module
+                .definitions
+                .iter()
+                .map(|d| d.name.len())
+                .max()
+                .unwrap_or(0);
+            for definition in &module.definitions {
+                writeln!(
+                    f,
+                    "  {:kind_width$}  {:name_width$}  {}",
+                    definition.kind.label(),
+                    definition.name,
+                    location(&definition.location)
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Finds the assignments of modules and definitions in an ASN.1 source by the names they assign,
+/// since the lexer does not record the locations of the definitions.
+struct Locator<'a> {
+    /// Lines of the source without comments
+    lines: Vec<&'a str>,
+}
+
+impl<'a> Locator<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            lines: source
+                .lines()
+                .map(|line| line.find("--").map_or(line, |comment| &line[..comment]))
+                .collect(),
+        }
+    }
+
+    /// Line and column of the header of module `name`, searching from line `start` on
+    fn module(&self, name: &str, start: usize) -> Option<(usize, usize)> {
+        (start..self.lines.len()).find_map(|i| {
+            assigned_name(self.lines[i], name)?;
+            // `DEFINITIONS` may follow the module identifier on one of the next lines
+            let header = self.lines[i..self.lines.len().min(i + 4)].join(" ");
+            assigned_name(&header, name)?
+                .contains("DEFINITIONS")
+                .then(|| position(self.lines[i], i))
+        })
+    }
+
+    /// Line and column of the assignment of `name` between the lines `start` and `end`
+    fn definition(&self, name: &str, start: usize, end: usize) -> Option<(usize, usize)> {
+        let end = end.min(self.lines.len());
+        (start..end).find_map(|i| {
+            assigned_name(self.lines[i], name)?;
+            // The assignment may continue on one of the next lines
+            let assignment = self.lines[i..end.min(i + 4)].join(" ");
+            let (left, _) = assigned_name(&assignment, name)?.split_once("::=")?;
+            // Only parameter lists and the type of a value may precede the assignment,
+            // unlike in lists of imported or exported symbols
+            let mut depth = 0;
+            let separated = left.chars().any(|c| {
+                match c {
+                    '{' => depth += 1,
+                    '}' => depth -= 1,
+                    _ => (),
+                }
+                depth == 0 && (c == ',' || c == ';')
+            });
+            (!separated && !left.split_whitespace().any(|word| word == "FROM"))
+                .then(|| position(self.lines[i], i))
+        })
+    }
+}
+
+/// The remainder of `line` if it starts with the reference `name`
+fn assigned_name<'l>(line: &'l str, name: &str) -> Option<&'l str> {
+    let rest = line.trim_start().strip_prefix(name)?;
+    (!rest.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '-')).then_some(rest)
+}
+
+/// One-based line and column of the first character of the line with index `index`
+fn position(line: &str, index: usize) -> (usize, usize) {
+    let indentation = line.len() - line.trim_start().len();
+    (index + 1, line[..indentation].chars().count() + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_definitions_with_locations() {
+        let listing = DefinitionListing::from_literal(
+            r#"Module-A { iso(1) 0 } DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+    IMPORTS Flag FROM Module-B;
+    -- Small ::= comments are no assignments
+    Small ::= INTEGER (0..7)
+    TEST-CLASS ::= CLASS { &id INTEGER UNIQUE } WITH SYNTAX { ID &id }
+    object TEST-CLASS ::= { ID 1 }
+    Objects TEST-CLASS ::= { { ID 1 } | { ID 2 } }
+END
+
+Module-B
+DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+  Flag
+    ::= BOOLEAN
+  Small ::= INTEGER (0..15)
+END"#,
+        )
+        .unwrap();
+        let listed = listing
+            .modules
+            .iter()
+            .map(|m| {
+                let location = |l: &Option<Span>| l.as_ref().map(|s| (s.line, s.column));
+                (
+                    m.name.as_str(),
+                    location(&m.location),
+                    m.definitions
+                        .iter()
+                        .map(|d| (d.name.as_str(), d.kind, location(&d.location)))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            listed,
+            vec![
+                (
+                    "Module-A",
+                    Some((1, 1)),
+                    vec![
+                        ("Small", DefinitionKind::Type, Some((4, 5))),
+                        ("TEST-CLASS", DefinitionKind::Class, Some((5, 5))),
+                        ("object", DefinitionKind::Object, Some((6, 5))),
+                        ("Objects", DefinitionKind::ObjectSet, Some((7, 5))),
+                    ]
+                ),
+                (
+                    "Module-B",
+                    Some((10, 1)),
+                    vec![
+                        ("Flag", DefinitionKind::Type, Some((12, 3))),
+                        ("Small", DefinitionKind::Type, Some((14, 3))),
+                    ]
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,101 @@
+//! Runs independent units of work, such as lexing the ASN.1 sources or generating
+//! the bindings of the ASN.1 modules, on multiple threads.
+//! Results are returned in the order of the inputs, so that the output of the
+//! compiler does not depend on the scheduling of the threads.
+
+use std::{num::NonZeroUsize, thread};
+
+/// Number of threads that work is distributed over. Targets without threads use one.
+pub(crate) fn available_threads() -> usize {
+    if cfg!(target_family = "wasm") {
+        1
+    } else {
+        thread::available_parallelism().map_or(1, NonZeroUsize::get)
+    }
+}
+
+/// Applies `f` to every item on up to `threads` threads and returns the results in the order of the items.
+pub(crate) fn map<T, R, F>(threads: usize, items: Vec<T>, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    map_with(threads, &(), items, |_, item| f(item))
+}
+
+/// Applies `f` to every item on up to `threads` threads and returns the results in the order of the items.
+/// Every thread works on a contiguous chunk of the items and on its own clone of `state`.
+pub(crate) fn map_with<S, T, R, F>(threads: usize, state: &S, items: Vec<T>, f: F) -> Vec<R>
+where
+    S: Clone + Send,
+    T: Send,
+    R: Send,
+    F: Fn(&mut S, T) -> R + Sync,
+{
+    let threads = threads.min(items.len());
+    if threads <= 1 {
+        let mut state = state.clone();
+        return items.into_iter().map(|item| f(&mut state, item)).collect();
+    }
+    let chunk_size = items.len().div_ceil(threads);
+    let mut items = items.into_iter();
+    let chunks = (0..threads)
+        .map(|_| items.by_ref().take(chunk_size).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    thread::scope(|scope| {
+        let workers = chunks
+            .into_iter()
+            .map(|chunk| {
+                let mut state = state.clone();
+                let f = &f;
+                scope.spawn(move || {
+                    chunk
+                        .into_iter()
+                        .map(|item| f(&mut state, item))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .flat_map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::CompilerError, intermediate::ToplevelDefinition};
+
+    #[test]
+    fn ir_is_thread_safe() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<ToplevelDefinition>();
+        assert_send_sync::<CompilerError>();
+    }
+
+    #[test]
+    fn keeps_order_of_items() {
+        for threads in [1, 3, 4, 16] {
+            assert_eq!(
+                map(threads, (0..10).collect(), |i| i * 2),
+                (0..10).map(|i| i * 2).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn clones_state_per_thread() {
+        let counts = map_with(2, &0, vec![(); 4], |count, _| {
+            *count += 1;
+            *count
+        });
+        assert_eq!(counts, vec![1, 2, 1, 2]);
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    error::CompilerError,
+    intermediate::{
+        visit::*, ASN1Type, ASN1Value, DeclarationElsewhere, ModuleHeader, ToplevelDefinition,
+        ToplevelTypeDefinition, ToplevelValueDefinition,
+    },
+};
+
+use super::{defining_module, module_headers, IrPass};
+
+/// Maximum number of aliases that are followed to find the aliased type
+const MAX_ALIAS_DEPTH: usize = 32;
+
+/// Collapses type aliases, i.e. type definitions such as `Alias ::= Target` that reference
+/// another type without adding constraints, tags or parameters.
+/// References to an alias in the alias's module are replaced with references to the aliased
+/// type, and the alias is removed unless another module imports it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FlattenAliases;
+
+impl IrPass for FlattenAliases {
+    fn run(&mut self, definitions: &mut Vec<ToplevelDefinition>) -> Result<(), CompilerError> {
+        let aliases = aliases(definitions);
+        if aliases.is_empty() {
+            return Ok(());
+        }
+        for definition in definitions.iter_mut() {
+            let Some(header) = definition.get_module_header() else {
+                continue;
+            };
+            let header = header.read();
+            Flattener {
+                aliases: &aliases,
+                header: &header,
+            }
+            .visit_toplevel_definition_mut(definition);
+        }
+
+        let imported = module_headers(definitions)
+            .iter()
+            .flat_map(|header| {
+                header
+                    .read()
+                    .imports
+                    .iter()
+                    .flat_map(|i| {
+                        i.types.iter().map(|t| {
+                            (
+                                i.global_module_reference.module_reference.clone(),
+                                t.clone(),
+                            )
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<BTreeSet<_>>();
+        definitions.retain(|definition| {
+            let Some(header) = definition.get_module_header() else {
+                return true;
+            };
+            let key = (header.read().name.clone(), definition.name().clone());
+            !aliases.contains_key(&key) || imported.contains(&key)
+        });
+        Ok(())
+    }
+}
+
+/// Collects the aliases of all modules by module and name, mapped to the reference
+/// to the type that they alias. Chains of aliases within a module are followed to
+/// the first type that is not an alias of the same module.
+fn aliases(definitions: &[ToplevelDefinition]) -> BTreeMap<(String, String), DeclarationElsewhere> {
+    let mut aliases = BTreeMap::new();
+    for definition in definitions {
+        let ToplevelDefinition::Type(ToplevelTypeDefinition {
+            name,
+            tag: None,
+            parameterization: None,
+            ty: ASN1Type::ElsewhereDeclaredType(target),
+            module_header: Some(header),
+            ..
+        }) = definition
+        else {
+            continue;
+        };
+        if target.parent.is_some() || !target.constraints.is_empty() {
+            continue;
+        }
+        let module = header.read().name.clone();
+        aliases.insert((module, name.clone()), (target.clone(), header.clone()));
+    }
+
+    let mut resolved = BTreeMap::new();
+    for ((module, name), (target, header)) in &aliases {
+        let mut target = target;
+        for _ in 0..MAX_ALIAS_DEPTH {
+            let target_module =
+                defining_module(&header.read(), target.module.as_deref(), &target.identifier);
+            match aliases.get(&(target_module.clone(), target.identifier.clone())) {
+                Some((next, _)) if &target_module == module && next.identifier != *name => {
+                    target = next
+                }
+                _ => break,
+            }
+        }
+        resolved.insert((module.clone(), name.clone()), target.clone());
+    }
+    resolved
+}
+
+/// Replaces the references to aliases in the definitions of a single module
+struct Flattener<'a> {
+    aliases: &'a BTreeMap<(String, String), DeclarationElsewhere>,
+    header: &'a ModuleHeader,
+}
+
+impl Flattener<'_> {
+    /// The aliased type of a reference to an alias of the module
+    fn alias(
+        &self,
+        explicit_module: Option<&str>,
+        reference: &str,
+    ) -> Option<&DeclarationElsewhere> {
+        let module = defining_module(self.header, explicit_module, reference);
+        if module != self.header.name {
+            return None;
+        }
+        self.aliases.get(&(module, reference.to_string()))
+    }
+
+    /// The supertypes of the nested value of a top-level value definition list the types
+    /// below the type of the value. If the value's type is an alias, the aliased type becomes
+    /// the type of the value, so it is removed from the supertypes along with the aliases.
+    fn flatten_typed_value(&self, ty: &ASN1Type, value: &mut ASN1Value) {
+        if let (
+            ASN1Type::ElsewhereDeclaredType(reference),
+            ASN1Value::LinkedNestedValue { supertypes, .. },
+        ) = (ty, value)
+        {
+            if reference.parent.is_none()
+                && self
+                    .alias(reference.module.as_deref(), &reference.identifier)
+                    .is_some()
+            {
+                supertypes.retain(|supertype| self.alias(None, supertype).is_none());
+                if !supertypes.is_empty() {
+                    supertypes.remove(0);
+                }
+            }
+        }
+    }
+}
+
+impl VisitorMut for Flattener<'_> {
+    fn visit_declaration_elsewhere_mut(&mut self, node: &mut DeclarationElsewhere) {
+        if node.parent.is_none() {
+            if let Some(target) = self.alias(node.module.as_deref(), &node.identifier) {
+                node.identifier = target.identifier.clone();
+                node.module = target.module.clone();
+            }
+        }
+        walk_declaration_elsewhere_mut(self, node);
+    }
+
+    fn visit_toplevel_value_definition_mut(&mut self, node: &mut ToplevelValueDefinition) {
+        self.flatten_typed_value(&node.associated_type, &mut node.value);
+        walk_toplevel_value_definition_mut(self, node);
+    }
+
+    fn visit_value_mut(&mut self, node: &mut ASN1Value) {
+        if let ASN1Value::LinkedNestedValue { supertypes, .. } = node {
+            supertypes.retain(|supertype| self.alias(None, supertype).is_none());
+        }
+        walk_value_mut(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pass::tests::definitions;
+
+    #[test]
+    fn flattens_alias_chains() {
+        let mut definitions = definitions(
+            r#"Module-A DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Base ::= INTEGER (0..7)
+                Alias ::= Base
+                Alias-Of-Alias ::= Alias
+                Constrained ::= Alias (1..2)
+                Wrapper ::= SEQUENCE { value Alias-Of-Alias }
+                Imported-Alias ::= Base
+                alias-value Alias-Of-Alias ::= 3
+            END
+
+            Module-B DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                IMPORTS Imported-Alias FROM Module-A;
+                Other ::= SEQUENCE { value Imported-Alias }
+            END"#,
+        );
+        FlattenAliases.run(&mut definitions).unwrap();
+        let names = definitions
+            .iter()
+            .map(|d| d.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                "Base",
+                "Constrained",
+                "Wrapper",
+                "Imported-Alias",
+                "alias-value",
+                "Other"
+            ]
+        );
+        let references = |name: &str| {
+            crate::intermediate::references::definition_references(
+                definitions.iter().find(|d| d.name() == name).unwrap(),
+            )
+        };
+        assert_eq!(references("Constrained"), vec!["Base"]);
+        assert_eq!(references("Wrapper"), vec!["Base"]);
+        assert_eq!(references("Other"), vec!["Imported-Alias"]);
+        assert!(matches!(
+            definitions.iter().find(|d| d.name() == "alias-value"),
+            Some(ToplevelDefinition::Value(ToplevelValueDefinition {
+                associated_type: ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere { identifier, .. }),
+                value: ASN1Value::LinkedNestedValue { supertypes, .. },
+                ..
+            })) if identifier == "Base" && supertypes.is_empty()
+        ));
+    }
+}
@@ -0,0 +1,95 @@
+//! Transformations of the validated definitions of an ASN.1 specification,
+//! which run between the validation and the generation of bindings.
+//! Besides custom [IrPass]es, the module provides built-in passes for renaming definitions
+//! ([RenameIdentifiers]), collapsing type aliases ([FlattenAliases]) and removing
+//! definitions that are not needed ([PruneTypes]).
+
+use crate::{
+    error::CompilerError,
+    intermediate::{ModuleHeader, SharedModuleHeader, ToplevelDefinition},
+};
+
+mod flatten;
+mod prune;
+mod rename;
+
+pub use flatten::FlattenAliases;
+pub use prune::PruneTypes;
+pub use rename::RenameIdentifiers;
+
+/// A transformation of the validated top-level definitions of all modules.
+/// Closures that take the definitions by mutable reference are passes as well.
+pub trait IrPass {
+    fn run(&mut self, definitions: &mut Vec<ToplevelDefinition>) -> Result<(), CompilerError>;
+}
+
+impl<F> IrPass for F
+where
+    F: FnMut(&mut Vec<ToplevelDefinition>) -> Result<(), CompilerError>,
+{
+    fn run(&mut self, definitions: &mut Vec<ToplevelDefinition>) -> Result<(), CompilerError> {
+        self(definitions)
+    }
+}
+
+/// Name of the module that defines the symbol a reference in the given module points to.
+/// The module is either given explicitly, the module that the symbol is imported from,
+/// or the referencing module itself.
+fn defining_module(header: &ModuleHeader, explicit: Option<&str>, symbol: &str) -> String {
+    explicit
+        .map(ToString::to_string)
+        .or_else(|| {
+            header
+                .imports
+                .iter()
+                .find(|i| i.types.iter().any(|t| t.trim_end_matches("{}") == symbol))
+                .map(|i| i.global_module_reference.module_reference.clone())
+        })
+        .unwrap_or_else(|| header.name.clone())
+}
+
+/// Module headers of the given definitions, without duplicates
+fn module_headers(definitions: &[ToplevelDefinition]) -> Vec<SharedModuleHeader> {
+    let mut headers: Vec<SharedModuleHeader> = vec![];
+    for header in definitions
+        .iter()
+        .filter_map(ToplevelDefinition::get_module_header)
+    {
+        if !headers.iter().any(|h| h.ptr_eq(&header)) {
+            headers.push(header);
+        }
+    }
+    headers
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, validate_sources, AsnSource, ValidationOptions};
+
+    /// Validates the given modules and returns their definitions
+    pub(super) fn definitions(asn: &str) -> Vec<ToplevelDefinition> {
+        validate_sources(
+            &[AsnSource::Literal(asn.into())],
+            &ValidationOptions::default(),
+        )
+        .unwrap()
+        .0
+    }
+
+    #[test]
+    fn runs_closures_as_passes() {
+        let mut definitions = definitions(
+            r#"Test DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Kept ::= BOOLEAN
+                Removed ::= INTEGER
+            END"#,
+        );
+        let mut pass = |definitions: &mut Vec<ToplevelDefinition>| {
+            definitions.retain(|d| d.name() != "Removed");
+            Ok(())
+        };
+        pass.run(&mut definitions).unwrap();
+        assert_eq!(definitions.len(), 1);
+        assert_eq!(definitions[0].name(), "Kept");
+    }
+}
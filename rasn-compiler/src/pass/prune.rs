@@ -0,0 +1,124 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    error::CompilerError,
+    generator::graph::{DefinitionId, DependencyGraph},
+    intermediate::ToplevelDefinition,
+};
+
+use super::{module_headers, IrPass};
+
+/// Removes all definitions that the root definitions do not depend on,
+/// directly or through other definitions, along with their imports.
+/// ```rust
+/// # use rasn_compiler::prelude::*;
+/// // keep only the PDUs `Request` and `Response` and the definitions they depend on
+/// let prune = PruneTypes::new().keep("Request").keep("Response");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PruneTypes {
+    /// Names of the root definitions
+    roots: BTreeSet<String>,
+}
+
+impl PruneTypes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep the definitions called `name`, in any module, and their dependencies.
+    pub fn keep(mut self, name: impl Into<String>) -> Self {
+        self.roots.insert(name.into());
+        self
+    }
+}
+
+impl IrPass for PruneTypes {
+    fn run(&mut self, definitions: &mut Vec<ToplevelDefinition>) -> Result<(), CompilerError> {
+        let graph = DependencyGraph::new(definitions);
+        let mut dependencies = BTreeMap::<&DefinitionId, Vec<&DefinitionId>>::new();
+        for edge in &graph.dependencies {
+            dependencies
+                .entry(&edge.user)
+                .or_default()
+                .push(&edge.dependency);
+        }
+        let mut pending = graph
+            .definitions
+            .iter()
+            .map(|d| &d.id)
+            .filter(|id| self.roots.contains(&id.name))
+            .collect::<Vec<_>>();
+        let mut kept = BTreeSet::new();
+        while let Some(id) = pending.pop() {
+            if kept.insert(id.clone()) {
+                pending.extend(dependencies.get(id).into_iter().flatten());
+            }
+        }
+        let defined = graph
+            .definitions
+            .iter()
+            .map(|d| d.id.clone())
+            .collect::<BTreeSet<_>>();
+
+        definitions.retain(|definition| match definition.get_module_header() {
+            Some(header) => kept.contains(&DefinitionId {
+                module: header.read().name.clone(),
+                name: definition.name().clone(),
+            }),
+            None => self.roots.contains(definition.name()),
+        });
+
+        // Imports of definitions that were removed would not resolve in the generated bindings
+        for header in module_headers(definitions) {
+            let mut header = header.write();
+            for import in &mut header.imports {
+                let module = &import.global_module_reference.module_reference;
+                import.types.retain(|symbol| {
+                    let id = DefinitionId {
+                        module: module.clone(),
+                        name: symbol.trim_end_matches("{}").to_string(),
+                    };
+                    !defined.contains(&id) || kept.contains(&id)
+                });
+            }
+            header.imports.retain(|import| !import.types.is_empty());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pass::tests::definitions;
+
+    #[test]
+    fn keeps_dependencies_of_roots() {
+        let mut definitions = definitions(
+            r#"Module-A DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Used ::= INTEGER (0..7)
+                Unused ::= BOOLEAN
+            END
+
+            Module-B DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                IMPORTS Used, Unused FROM Module-A;
+                Root ::= SEQUENCE { used Used, nested Nested }
+                Nested ::= CHOICE { a BOOLEAN, b Used }
+                Other ::= SEQUENCE { unused Unused }
+            END"#,
+        );
+        PruneTypes::new()
+            .keep("Root")
+            .run(&mut definitions)
+            .unwrap();
+        let names = definitions
+            .iter()
+            .map(|d| d.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["Used", "Root", "Nested"]);
+        let header = definitions[1].get_module_header().unwrap();
+        assert_eq!(header.read().name, "Module-B");
+        assert_eq!(header.read().imports[0].types, vec!["Used"]);
+    }
+}
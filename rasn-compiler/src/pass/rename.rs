@@ -0,0 +1,285 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    error::CompilerError,
+    intermediate::{
+        information_object::{
+            ASN1Information, ClassLink, ObjectSet, ObjectSetValue, ToplevelInformationDefinition,
+        },
+        parameterization::{ParameterGovernor, Parameterization},
+        visit::*,
+        ASN1Type, ASN1Value, DeclarationElsewhere, Exports, ModuleHeader, ToplevelDefinition,
+    },
+};
+
+use super::{defining_module, module_headers, IrPass};
+
+/// Renames top-level definitions along with all references to them,
+/// including the imports and exports of the module headers.
+/// ```rust
+/// # use rasn_compiler::prelude::*;
+/// let rename = RenameIdentifiers::new()
+///     // rename `Message` in all modules
+///     .rename("Message", "LegacyMessage")
+///     // rename `Header` only in module `Protocol-V2`
+///     .rename_in_module("Protocol-V2", "Header", "HeaderV2");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RenameIdentifiers {
+    /// New names by module, if the rename is restricted to a module, and old name
+    renames: BTreeMap<(Option<String>, String), String>,
+}
+
+impl RenameIdentifiers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rename the definitions called `from` in all modules to `to`.
+    pub fn rename(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.renames.insert((None, from.into()), to.into());
+        self
+    }
+
+    /// Rename the definition called `from` in the module called `module` to `to`.
+    /// A rename that is restricted to a module takes precedence over one for all modules.
+    pub fn rename_in_module(
+        mut self,
+        module: impl Into<String>,
+        from: impl Into<String>,
+        to: impl Into<String>,
+    ) -> Self {
+        self.renames
+            .insert((Some(module.into()), from.into()), to.into());
+        self
+    }
+
+    fn renamed(&self, module: &str, symbol: &str) -> Option<&String> {
+        self.renames
+            .get(&(Some(module.to_string()), symbol.to_string()))
+            .or_else(|| self.renames.get(&(None, symbol.to_string())))
+    }
+
+    /// Renames a symbol of an import or export list, which may carry a `{}` suffix
+    /// if it refers to a parameterized definition.
+    fn rename_symbol(&self, module: &str, symbol: &mut String) {
+        let suffix = if symbol.ends_with("{}") { "{}" } else { "" };
+        if let Some(renamed) = self.renamed(module, symbol.trim_end_matches("{}")) {
+            *symbol = format!("{renamed}{suffix}");
+        }
+    }
+}
+
+impl IrPass for RenameIdentifiers {
+    fn run(&mut self, definitions: &mut Vec<ToplevelDefinition>) -> Result<(), CompilerError> {
+        for definition in definitions.iter_mut() {
+            let Some(header) = definition.get_module_header() else {
+                continue;
+            };
+            let header = header.read();
+            References {
+                renames: self,
+                header: &header,
+            }
+            .visit_toplevel_definition_mut(definition);
+            if let Some(renamed) = self.renamed(&header.name, definition.name()) {
+                *definition.name_mut() = renamed.clone();
+            }
+        }
+        for header in module_headers(definitions) {
+            let mut header = header.write();
+            let ModuleHeader {
+                name,
+                imports,
+                exports,
+                locations,
+                ..
+            } = &mut *header;
+            *locations = std::mem::take(locations)
+                .into_iter()
+                .map(
+                    |(definition, location)| match self.renamed(name, &definition) {
+                        Some(renamed) => (renamed.clone(), location),
+                        None => (definition, location),
+                    },
+                )
+                .collect();
+            for import in imports {
+                let module = &import.global_module_reference.module_reference;
+                for symbol in &mut import.types {
+                    self.rename_symbol(module, symbol);
+                }
+            }
+            if let Some(Exports::Identifier(symbols)) = exports {
+                for symbol in symbols {
+                    self.rename_symbol(name, symbol);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Renames the references of the definitions of a single module
+struct References<'a> {
+    renames: &'a RenameIdentifiers,
+    header: &'a ModuleHeader,
+}
+
+impl References<'_> {
+    fn rename(&self, explicit_module: Option<&str>, reference: &mut String) {
+        let module = defining_module(self.header, explicit_module, reference);
+        if let Some(renamed) = self.renames.renamed(&module, reference) {
+            *reference = renamed.clone();
+        }
+    }
+
+    /// References with a parent point to a field of the parent,
+    /// so only the parent refers to a top-level definition.
+    fn rename_with_parent(
+        &self,
+        explicit_module: Option<&str>,
+        parent: &mut Option<String>,
+        identifier: &mut String,
+    ) {
+        match parent {
+            Some(parent) => self.rename(explicit_module, parent),
+            None => self.rename(explicit_module, identifier),
+        }
+    }
+}
+
+impl VisitorMut for References<'_> {
+    fn visit_declaration_elsewhere_mut(&mut self, node: &mut DeclarationElsewhere) {
+        self.rename_with_parent(
+            node.module.as_deref(),
+            &mut node.parent,
+            &mut node.identifier,
+        );
+        walk_declaration_elsewhere_mut(self, node);
+    }
+
+    fn visit_type_mut(&mut self, node: &mut ASN1Type) {
+        match node {
+            ASN1Type::ObjectClassField(field) => self.rename(None, &mut field.class),
+            ASN1Type::ChoiceSelectionType(selection) => {
+                self.rename(None, &mut selection.choice_name)
+            }
+            ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+                for components_of in &mut s.components_of {
+                    self.rename(None, components_of);
+                }
+            }
+            _ => (),
+        }
+        walk_type_mut(self, node);
+    }
+
+    fn visit_value_mut(&mut self, node: &mut ASN1Value) {
+        match node {
+            ASN1Value::ElsewhereDeclaredValue { parent, identifier }
+            | ASN1Value::LinkedElsewhereDefinedValue {
+                parent, identifier, ..
+            } => self.rename_with_parent(None, parent, identifier),
+            ASN1Value::LinkedNestedValue { supertypes, .. } => {
+                for supertype in supertypes {
+                    self.rename(None, supertype);
+                }
+            }
+            ASN1Value::Choice {
+                type_name: Some(type_name),
+                ..
+            } => self.rename(None, type_name),
+            ASN1Value::EnumeratedValue { enumerated, .. } => self.rename(None, enumerated),
+            _ => (),
+        }
+        walk_value_mut(self, node);
+    }
+
+    fn visit_parameterization_mut(&mut self, node: &mut Parameterization) {
+        for argument in &mut node.parameters {
+            if let ParameterGovernor::Class(class) = &mut argument.param_governor {
+                self.rename(None, class);
+            }
+        }
+        walk_parameterization_mut(self, node);
+    }
+
+    fn visit_toplevel_information_definition_mut(
+        &mut self,
+        node: &mut ToplevelInformationDefinition,
+    ) {
+        if let ClassLink::ByName(class) = &mut node.class {
+            self.rename(None, class);
+        }
+        walk_toplevel_information_definition_mut(self, node);
+    }
+
+    fn visit_information_mut(&mut self, node: &mut ASN1Information) {
+        if let ASN1Information::Object(object) = node {
+            self.rename(None, &mut object.class_name);
+        }
+        walk_information_mut(self, node);
+    }
+
+    fn visit_object_set_mut(&mut self, node: &mut ObjectSet) {
+        for value in &mut node.values {
+            if let ObjectSetValue::Reference(reference) = value {
+                self.rename(None, reference);
+            }
+        }
+        walk_object_set_mut(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pass::tests::definitions;
+
+    #[test]
+    fn renames_definitions_and_references() {
+        let mut definitions = definitions(
+            r#"Module-A DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                EXPORTS Item;
+                Item ::= INTEGER (0..7)
+                List ::= SEQUENCE OF Item
+            END
+
+            Module-B DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                IMPORTS Item FROM Module-A;
+                Item-B ::= SEQUENCE { item Item }
+            END"#,
+        );
+        RenameIdentifiers::new()
+            .rename_in_module("Module-A", "Item", "Renamed")
+            .run(&mut definitions)
+            .unwrap();
+        let names = definitions
+            .iter()
+            .map(|d| d.name().as_str())
+            .collect::<Vec<_>>();
+        assert!(names.contains(&"Renamed") && !names.contains(&"Item"));
+
+        let references = definitions
+            .iter()
+            .flat_map(crate::intermediate::references::definition_references)
+            .collect::<Vec<_>>();
+        assert!(!references.contains(&"Item".to_string()));
+        assert_eq!(references.iter().filter(|r| *r == "Renamed").count(), 2);
+
+        let header = |name: &str| {
+            definitions
+                .iter()
+                .find(|d| d.name() == name)
+                .and_then(ToplevelDefinition::get_module_header)
+                .unwrap()
+        };
+        assert_eq!(
+            header("Renamed").read().exports,
+            Some(Exports::Identifier(vec!["Renamed".into()]))
+        );
+        let header = header("Item-B");
+        assert_eq!(header.read().imports[0].types, vec!["Renamed"]);
+    }
+}
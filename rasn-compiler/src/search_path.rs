@@ -0,0 +1,422 @@
+//! Resolution of imported modules that were not added to the compiler explicitly.
+//! The ASN.1 files in the search directories are indexed by the names and
+//! definitive object identifiers of the modules they contain. A module named in an
+//! `IMPORTS ... FROM` clause is loaded from the index, along with the modules it imports,
+//! if none of the compiled sources defines it. Only the module headers are parsed
+//! when the index is built; a file is lexed once one of its modules is imported.
+//! Files that cannot be read or parsed are reported as warnings.
+//!
+//! If the import names the module's object identifier, only a module with that
+//! identifier is loaded. `WITH SUCCESSORS` also accepts later revisions, i.e. identifiers
+//! that differ only by a greater last arc, and `WITH DESCENDANTS` accepts any identifier
+//! below the given one. Of several matching revisions, the latest is loaded.
+
+use std::{
+    cmp::Ordering,
+    collections::BTreeSet,
+    fs::{read_dir, read_to_string},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    diagnostic::Severity,
+    error::CompilerError,
+    intermediate::{
+        AssignedIdentifier, DefinitiveIdentifier, Import, ModuleHeader, ObjectIdentifierArc,
+        ToplevelDefinition, With,
+    },
+    lexer::{asn_spec, module_headers},
+    validator::error::{LinkerError, LinkerErrorType},
+    AsnSourceUnit,
+};
+
+type Module = (ModuleHeader, Vec<ToplevelDefinition>);
+
+/// Modules found in the search directories, indexed by their headers.
+/// A file is only lexed once one of its modules is imported.
+struct ModuleIndex {
+    files: Vec<SearchFile>,
+    /// Headers of the modules that have not been loaded, along with the index of their file
+    headers: Vec<(ModuleHeader, usize)>,
+    /// Files that could not be read or parsed
+    warnings: Vec<CompilerError>,
+}
+
+struct SearchFile {
+    path: PathBuf,
+    /// The lexed modules of the file that have not been loaded yet
+    modules: Option<Vec<Module>>,
+}
+
+/// Adds the modules that `modules` import, directly or indirectly, from the search directories.
+/// Returns warnings for the files in the search directories that cannot be read or parsed.
+pub(crate) fn load_imports(
+    modules: &mut Vec<Module>,
+    search_paths: &[PathBuf],
+) -> Vec<CompilerError> {
+    let mut loaded = modules
+        .iter()
+        .map(|(header, _)| header.name.clone())
+        .collect::<BTreeSet<_>>();
+    let mut imports = modules
+        .iter()
+        .flat_map(|(header, _)| header.imports.iter().cloned())
+        .filter(|import| !loaded.contains(&import.global_module_reference.module_reference))
+        .collect::<Vec<_>>();
+    if imports.is_empty() || search_paths.is_empty() {
+        return vec![];
+    }
+
+    let mut index = ModuleIndex::new(search_paths);
+    while let Some(import) = imports.pop() {
+        if loaded.contains(&import.global_module_reference.module_reference) {
+            continue;
+        }
+        if let Some(module) = index.take(&import) {
+            loaded.insert(module.0.name.clone());
+            imports.extend(module.0.imports.iter().cloned());
+            modules.push(module);
+        }
+    }
+    index.warnings
+}
+
+impl ModuleIndex {
+    fn new(search_paths: &[PathBuf]) -> Self {
+        let mut paths = vec![];
+        for dir in search_paths {
+            find_files(dir, &mut paths);
+        }
+        // Sorted, so that the first of several equal revisions is chosen deterministically
+        paths.sort();
+        let mut index = Self {
+            files: vec![],
+            headers: vec![],
+            warnings: vec![],
+        };
+        for path in paths {
+            let headers = read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|source| {
+                    module_headers(AsnSourceUnit {
+                        path: Some(&path),
+                        source: source.as_str().into(),
+                    })
+                    .map_err(|e| e.to_string())
+                });
+            match headers {
+                Ok(headers) => {
+                    index.headers.extend(
+                        headers
+                            .into_iter()
+                            .map(|header| (header, index.files.len())),
+                    );
+                    index.files.push(SearchFile {
+                        path,
+                        modules: None,
+                    });
+                }
+                Err(reason) => index.warn(&path, &reason),
+            }
+        }
+        index
+    }
+
+    /// Removes and returns the latest revision of the module that satisfies `import`.
+    fn take(&mut self, import: &Import) -> Option<Module> {
+        let reference = &import.global_module_reference;
+        let requested = match &reference.assigned_identifier {
+            AssignedIdentifier::ObjectIdentifierValue(oid) => Some(&oid.0),
+            _ => None,
+        };
+        let position = self
+            .headers
+            .iter()
+            .enumerate()
+            .filter(|(_, (header, _))| header.name == reference.module_reference)
+            .map(|(position, (header, _))| (position, definitive_oid(header)))
+            .filter(|(_, oid)| match (requested, oid) {
+                (None, _) => true,
+                (Some(requested), Some(oid)) => satisfies(oid, requested, import.with.as_ref()),
+                (Some(_), None) => false,
+            })
+            // `max_by` returns the last of equal elements, so the order is reversed
+            .rev()
+            .max_by(|(_, a), (_, b)| compare_oids(*a, *b))
+            .map(|(position, _)| position)?;
+        let (header, file) = self.headers.remove(position);
+        match self.lex(file) {
+            Some(modules) => modules
+                .iter()
+                .position(|(lexed, _)| {
+                    lexed.name == header.name
+                        && compare_oids(definitive_oid(lexed), definitive_oid(&header))
+                            == Ordering::Equal
+                })
+                .map(|position| modules.remove(position)),
+            // Another revision may still satisfy the import
+            None => self.take(import),
+        }
+    }
+
+    /// Returns the modules of a file, lexing it on first access.
+    /// Returns `None` if the file cannot be lexed, and drops the headers of its modules.
+    fn lex(&mut self, file: usize) -> Option<&mut Vec<Module>> {
+        if self.files[file].modules.is_none() {
+            let path = self.files[file].path.clone();
+            let lexed = read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|source| {
+                    asn_spec(AsnSourceUnit {
+                        path: Some(&path),
+                        source: source.as_str().into(),
+                    })
+                    .map_err(|e| e.to_string())
+                });
+            match lexed {
+                Ok(modules) => self.files[file].modules = Some(modules),
+                Err(reason) => {
+                    self.headers.retain(|(_, f)| *f != file);
+                    self.warn(&path, &reason);
+                    return None;
+                }
+            }
+        }
+        self.files[file].modules.as_mut()
+    }
+
+    fn warn(&mut self, path: &Path, reason: &str) {
+        self.warnings.push(
+            LinkerError::in_module(
+                &path.to_string_lossy(),
+                &format!("The file in the search paths cannot be read or parsed: {reason}"),
+                LinkerErrorType::UnreadableSearchPathFile,
+            )
+            .with_severity(Severity::Warning)
+            .into(),
+        );
+    }
+}
+
+/// Collects the `.asn` and `.asn1` files in `dir` and its subdirectories.
+fn find_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            find_files(&path, files);
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == "asn" || extension == "asn1")
+        {
+            files.push(path);
+        }
+    }
+}
+
+fn definitive_oid(header: &ModuleHeader) -> Option<&[ObjectIdentifierArc]> {
+    match header.module_identifier.as_ref()? {
+        DefinitiveIdentifier::DefinitiveOID(oid)
+        | DefinitiveIdentifier::DefinitiveOIDandIRI { oid, .. } => Some(&oid.0),
+    }
+}
+
+/// Returns `true` if a module identified by `oid` may be imported as `requested`.
+fn satisfies(
+    oid: &[ObjectIdentifierArc],
+    requested: &[ObjectIdentifierArc],
+    with: Option<&With>,
+) -> bool {
+    let arcs_match = |a: &[ObjectIdentifierArc], b: &[ObjectIdentifierArc]| {
+        a.len() == b.len() && a.iter().zip(b).all(|(a, b)| arc_eq(a, b))
+    };
+    match with {
+        None => arcs_match(oid, requested),
+        Some(With::Successors) => match (oid.split_last(), requested.split_last()) {
+            (Some((last, prefix)), Some((requested_last, requested_prefix))) => {
+                arcs_match(prefix, requested_prefix)
+                    && (arc_eq(last, requested_last) || last.number > requested_last.number)
+            }
+            _ => false,
+        },
+        Some(With::Descendants) => {
+            oid.len() >= requested.len() && arcs_match(&oid[..requested.len()], requested)
+        }
+    }
+}
+
+/// Arcs are compared by number, or by name if either of them has no number.
+fn arc_eq(a: &ObjectIdentifierArc, b: &ObjectIdentifierArc) -> bool {
+    match (a.number, b.number) {
+        (Some(a), Some(b)) => a == b,
+        _ => a.name.is_some() && a.name == b.name,
+    }
+}
+
+/// Orders revisions by their object identifiers. Modules without one come first.
+fn compare_oids(a: Option<&[ObjectIdentifierArc]>, b: Option<&[ObjectIdentifierArc]>) -> Ordering {
+    let numbers = |oid: Option<&[ObjectIdentifierArc]>| {
+        oid.map(|oid| oid.iter().map(|arc| arc.number).collect::<Vec<_>>())
+    };
+    numbers(a).cmp(&numbers(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use crate::{prelude::RasnBackend, Compiler};
+
+    /// Writes the given modules to a new directory below the system's temporary directory.
+    fn search_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "rasn-compiler-search-path-{name}-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        for (file, module) in files {
+            fs::write(dir.join(file), module).unwrap();
+        }
+        dir
+    }
+
+    fn compile(dir: &PathBuf, module: &str) -> String {
+        Compiler::<RasnBackend, _>::new()
+            .add_asn_literal(module)
+            .add_search_path(dir)
+            .compile_to_string()
+            .unwrap()
+            .generated
+    }
+
+    #[test]
+    fn loads_imported_modules() {
+        let dir = search_dir(
+            "transitive",
+            &[
+                (
+                    "common.asn",
+                    "Common DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    IMPORTS Id FROM Base;
+                    Header ::= SEQUENCE { id Id }
+                    END",
+                ),
+                (
+                    "nested/base.asn1",
+                    "Base DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Id ::= INTEGER (0..255)
+                    END",
+                ),
+                ("unrelated.asn", "Broken DEFINITIONS ::= BEGIN"),
+            ],
+        );
+        let generated = compile(
+            &dir,
+            "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            IMPORTS Header FROM Common;
+            Message ::= SEQUENCE { header Header }
+            END",
+        );
+        assert!(generated.contains("pub mod common"));
+        assert!(generated.contains("pub mod base"));
+        assert!(generated.contains("pub struct Id(pub u8)"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn selects_revisions_by_object_identifier() {
+        let revision = |version: u8, bound: u16| {
+            format!(
+                "Base {{ iso(1) standard(0) base(42) version({version}) }}
+                DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Id ::= INTEGER (0..{bound})
+                END"
+            )
+        };
+        let (v1, v2, v3) = (revision(1, 255), revision(2, 1023), revision(3, 4095));
+        let dir = search_dir(
+            "revisions",
+            &[
+                ("base-v1.asn", &v1),
+                ("base-v2.asn", &v2),
+                ("nested/base-v3.asn", &v3),
+            ],
+        );
+        let import = |clause: &str| {
+            compile(
+                &dir,
+                &format!(
+                    "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    IMPORTS Id FROM Base {clause};
+                    Message ::= SEQUENCE {{ id Id }}
+                    END"
+                ),
+            )
+        };
+        assert!(import("{ iso(1) standard(0) base(42) version(2) }").contains("0..=1023"));
+        assert!(
+            import("{ iso(1) standard(0) base(42) version(2) } WITH SUCCESSORS")
+                .contains("0..=4095")
+        );
+        assert!(import("{ iso(1) standard(0) base(42) } WITH DESCENDANTS").contains("0..=4095"));
+        assert!(import("").contains("0..=4095"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn reports_unparseable_files() {
+        let dir = search_dir(
+            "unparseable",
+            &[
+                (
+                    "modules.asn",
+                    "Base DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Id ::= INTEGER (0..255) -- Ids END at 255
+                    END
+                    -- Another module follows the END of Base
+                    Other DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Name ::= UTF8String
+                    END",
+                ),
+                (
+                    "nested/flags.asn",
+                    "Flags DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Flag ::= BOOLEAN (
+                    END",
+                ),
+                (
+                    "unrelated.asn",
+                    "Unrelated DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Broken ::= SEQUENCE {
+                    END",
+                ),
+                ("notes.asn", "This is not a module."),
+            ],
+        );
+        let result = Compiler::<RasnBackend, _>::new()
+            .add_asn_literal(
+                "Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                IMPORTS Id FROM Base Name FROM Other Flag FROM Flags;
+                Message ::= SEQUENCE { id Id, name Name }
+                END",
+            )
+            .add_search_path(&dir)
+            .compile_to_string()
+            .unwrap();
+        let unreadable = result
+            .warnings
+            .iter()
+            .map(ToString::to_string)
+            .filter(|w| w.starts_with("UnreadableSearchPathFile"))
+            .collect::<Vec<_>>();
+        assert_eq!(unreadable.len(), 2, "{unreadable:?}");
+        assert!(unreadable[0].contains("notes.asn"));
+        assert!(unreadable[1].contains("flags.asn"));
+        assert!(result.generated.contains("pub struct Id(pub u8)"));
+        assert!(result.generated.contains("pub struct Name(pub Utf8String)"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,8 +1,8 @@
-use std::{cell::RefCell, io::Write, rc::Rc};
+use std::io::Write;
 
 use nom::FindSubstring;
 
-use crate::validator::Validator;
+use crate::{intermediate::SharedModuleHeader, validator::Validator};
 
 /// This function generates a stepwise-end-to-end test for a given ASN.1 module,
 /// i.e. it generates test functions with corresponding inputs and expected outputs for
@@ -150,9 +150,9 @@ fn validator_io(literal: &str) -> (String, String) {
         .unwrap()
         .into_iter()
         .flat_map(|(header, tlds)| {
-            let header_ref = Rc::new(RefCell::new(header));
+            let header_ref = SharedModuleHeader::new(header);
             tlds.into_iter().map(move |mut tld| {
-                tld.apply_tagging_environment(&header_ref.borrow().tagging_environment);
+                tld.apply_tagging_environment(&header_ref.read().tagging_environment);
                 tld.set_module_header(header_ref.clone());
                 tld
             })
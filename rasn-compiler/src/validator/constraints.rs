@@ -0,0 +1,131 @@
+//! Checks that the effective constraints of types and components are satisfiable
+//! and that extension markers appear only at the top level of a constraint.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    generator::asn1::notation::element_set_specs_notation,
+    intermediate::{
+        constraints::*,
+        effective_constraints::{EffectiveConstraint, IntegerSet},
+        *,
+    },
+};
+
+use super::error::{LinkerError, LinkerErrorType};
+
+pub(super) struct ConstraintChecker<'a> {
+    tlds: &'a BTreeMap<String, ToplevelDefinition>,
+}
+
+impl<'a> ConstraintChecker<'a> {
+    pub fn new(tlds: &'a BTreeMap<String, ToplevelDefinition>) -> Self {
+        Self { tlds }
+    }
+
+    /// Checks the constraints of a top-level type definition and of the types nested in it.
+    pub fn check(&self, tld: &ToplevelDefinition) -> Result<(), LinkerError> {
+        match tld {
+            ToplevelDefinition::Type(t) if t.parameterization.is_none() => {
+                self.check_type(&t.ty, &[], &t.name).map_err(|mut e| {
+                    e.contextualize(&t.name);
+                    e
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks a type, constrained further by the constraints of the component
+    /// or alternative it belongs to.
+    fn check_type(
+        &self,
+        ty: &ASN1Type,
+        constraints: &[Constraint],
+        path: &str,
+    ) -> Result<(), LinkerError> {
+        for constraint in ty.constraints().into_iter().flatten().chain(constraints) {
+            check_extension_markers(constraint)?;
+        }
+        let resolve = |name: &str| match self.tlds.get(name) {
+            Some(ToplevelDefinition::Type(t)) if t.parameterization.is_none() => Some(&t.ty),
+            _ => None,
+        };
+        let effective = EffectiveConstraint::new(ty, &resolve).constrain(constraints, &resolve);
+        if !effective.extensible {
+            let empty = |set: &Option<_>| set.as_ref().is_some_and(|s: &IntegerSet| s.is_empty());
+            let admits_no = if empty(&effective.values) {
+                Some("values")
+            } else if effective
+                .size
+                .as_ref()
+                .is_some_and(|s| s.intersection(&IntegerSet::range(Some(0), None)).is_empty())
+            {
+                Some("sizes")
+            } else if empty(&effective.alphabet) {
+                Some("characters")
+            } else {
+                None
+            };
+            if let Some(admits_no) = admits_no {
+                return Err(LinkerError::new(
+                    None,
+                    &format!("The constraints of `{path}` admit no {admits_no}"),
+                    LinkerErrorType::InvalidConstraintsError,
+                ));
+            }
+        }
+        match ty {
+            ASN1Type::Sequence(s) | ASN1Type::Set(s) => s.members.iter().try_for_each(|m| {
+                self.check_type(&m.ty, &m.constraints, &format!("{path}.{}", m.name))
+            }),
+            ASN1Type::Choice(c) => c.options.iter().try_for_each(|o| {
+                self.check_type(&o.ty, &o.constraints, &format!("{path}.{}", o.name))
+            }),
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+                self.check_type(&s.element_type, &[], &format!("{path}[]"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// An element set may only be extensible as a whole. Set arithmetic
+/// operates on element sets without extension markers (X.680 §50.1).
+fn check_extension_markers(constraint: &Constraint) -> Result<(), LinkerError> {
+    let Constraint::Subtype(set) = constraint else {
+        return Ok(());
+    };
+    fn nested_marker(set: &ElementOrSetOperation, in_set_operation: bool) -> bool {
+        match set {
+            ElementOrSetOperation::Element(e) => element_marker(e, in_set_operation),
+            ElementOrSetOperation::SetOperation(SetOperation { base, operant, .. }) => {
+                element_marker(base, true) || nested_marker(operant, true)
+            }
+        }
+    }
+    fn element_marker(element: &SubtypeElements, in_set_operation: bool) -> bool {
+        match element {
+            SubtypeElements::SingleValue { extensible, .. }
+            | SubtypeElements::ValueRange { extensible, .. }
+            | SubtypeElements::ContainedSubtype { extensible, .. } => {
+                *extensible && in_set_operation
+            }
+            SubtypeElements::SizeConstraint(set) | SubtypeElements::PermittedAlphabet(set) => {
+                nested_marker(set, false)
+            }
+            _ => false,
+        }
+    }
+    if nested_marker(&set.set, false) {
+        return Err(LinkerError::new(
+            None,
+            &format!(
+                "The constraint `({})` contains an extension marker inside set arithmetic",
+                element_set_specs_notation(set, 0)
+            ),
+            LinkerErrorType::InvalidConstraintsError,
+        ));
+    }
+    Ok(())
+}
@@ -1,24 +1,56 @@
 use core::fmt::{Display, Formatter, Result};
 use std::error::Error;
 
-use crate::intermediate::error::GrammarError;
+use crate::{
+    diagnostic::{Severity, Span},
+    intermediate::error::GrammarError,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct LinkerError {
     pub pdu: Option<String>,
+    /// The module that an error concerns, if it does not concern a single definition,
+    /// such as an invalid import.
+    pub module: Option<String>,
     pub details: String,
     pub kind: LinkerErrorType,
+    /// Errors reject the definition or the module that they concern,
+    /// while warnings leave them in place.
+    pub severity: Severity,
+    /// Location of the definition that the error concerns
+    pub span: Option<Span>,
 }
 
 impl LinkerError {
     pub fn new(pdu: Option<String>, details: &str, kind: LinkerErrorType) -> Self {
         LinkerError {
             pdu,
+            module: None,
             details: details.into(),
             kind,
+            severity: Severity::Error,
+            span: None,
         }
     }
 
+    /// Creates an error that concerns a module as a whole rather than one of its definitions.
+    pub fn in_module(module: &str, details: &str, kind: LinkerErrorType) -> Self {
+        LinkerError {
+            pdu: None,
+            module: Some(module.into()),
+            details: details.into(),
+            kind,
+            severity: Severity::Error,
+            span: None,
+        }
+    }
+
+    /// Reports the error with the given severity.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
     pub fn contextualize(&mut self, pdu: &str) {
         self.pdu = Some(pdu.into())
     }
@@ -28,6 +60,11 @@ impl LinkerError {
 pub enum LinkerErrorType {
     MissingDependency,
     InvalidConstraintsError,
+    AmbiguousTagsError,
+    InvalidValueError,
+    InvalidParameterization,
+    UnexportedImport,
+    UnreadableSearchPathFile,
     Unknown,
 }
 
@@ -35,13 +72,20 @@ impl Error for LinkerError {}
 
 impl Display for LinkerError {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        write!(
-            f,
-            "{:?} validating PDU {}: {}",
-            self.kind,
-            self.pdu.as_ref().unwrap_or(&"".into()),
-            self.details
-        )
+        match (&self.pdu, &self.module) {
+            (None, Some(module)) => write!(
+                f,
+                "{:?} validating module {module}: {}",
+                self.kind, self.details
+            ),
+            (pdu, _) => write!(
+                f,
+                "{:?} validating PDU {}: {}",
+                self.kind,
+                pdu.as_ref().unwrap_or(&"".into()),
+                self.details
+            ),
+        }
     }
 }
 
@@ -49,8 +93,11 @@ impl From<GrammarError> for LinkerError {
     fn from(value: GrammarError) -> Self {
         Self {
             pdu: None,
+            module: None,
             details: value.details,
             kind: LinkerErrorType::Unknown,
+            severity: value.severity,
+            span: value.span,
         }
     }
 }
@@ -0,0 +1,93 @@
+//! Checks that modules only import the symbols that the imported modules export.
+//! Modules without an `EXPORTS` clause, or with `EXPORTS ALL`, export all their symbols.
+//! Imports from modules that are not part of the specification are not checked.
+
+use std::collections::BTreeMap;
+
+use crate::intermediate::ToplevelDefinition;
+
+use super::error::{LinkerError, LinkerErrorType};
+
+/// Returns an error for every symbol that a module imports, but the imported module does not export.
+pub(super) fn check_imports(tlds: &BTreeMap<String, ToplevelDefinition>) -> Vec<LinkerError> {
+    let headers = tlds
+        .values()
+        .filter_map(|tld| tld.get_module_header())
+        .map(|header| {
+            let name = header.read().name.clone();
+            (name, header)
+        })
+        .collect::<BTreeMap<_, _>>();
+    let mut errors = vec![];
+    for (module, header) in &headers {
+        for import in &header.read().imports {
+            let exporting = &import.global_module_reference.module_reference;
+            let Some(exporting_header) = headers.get(exporting) else {
+                continue;
+            };
+            for symbol in &import.types {
+                if !exporting_header.read().exports(symbol) {
+                    errors.push(LinkerError::in_module(
+                        module,
+                        &format!(
+                            "`{symbol}` is imported from `{exporting}`, which does not export it"
+                        ),
+                        LinkerErrorType::UnexportedImport,
+                    ));
+                }
+            }
+        }
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::{Diagnostic, RasnBackend, Severity},
+        Compiler,
+    };
+
+    const MODULES: &str = "Base DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        EXPORTS Id, Container{};
+        Id ::= INTEGER (0..255)
+        Flag ::= BOOLEAN
+        Container { Element } ::= SEQUENCE OF Element
+        END
+        Example DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        IMPORTS Id, Flag, Container{} FROM Base;
+        Message ::= SEQUENCE { id Id, flag Flag, ids Container { Id } }
+        END";
+
+    #[test]
+    fn rejects_unexported_imports() {
+        let error = Compiler::<RasnBackend, _>::new()
+            .add_asn_literal(MODULES)
+            .compile_to_string()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "UnexportedImport validating module Example: `Flag` is imported from `Base`, which does not export it"
+        );
+        assert_eq!(Diagnostic::error(&error).notes, vec!["in module `Example`"]);
+    }
+
+    #[test]
+    fn warns_about_unexported_imports() {
+        let result = Compiler::<RasnBackend, _>::new()
+            .add_asn_literal(MODULES)
+            .set_unexported_import_severity(Severity::Warning)
+            .compile_to_string()
+            .unwrap();
+        assert_eq!(
+            result
+                .warnings
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["UnexportedImport validating module Example: `Flag` is imported from `Base`, which does not export it"]
+        );
+        assert_eq!(result.warnings[0].severity(), Severity::Warning);
+        assert!(result.generated.contains("pub struct Message"));
+    }
+}
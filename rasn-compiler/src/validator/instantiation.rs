@@ -0,0 +1,625 @@
+//! Monomorphisation of parameterized definitions (X.683).
+//! Every distinct instantiation of a parameterized type, value set, value, class,
+//! information object or object set becomes a concrete top-level definition.
+//! An assignment that consists of a single instantiation, such as
+//! `ImplType ::= ParamType { 2, TRUE }` or `value INTEGER ::= genValue { 7 }`, is itself
+//! the instance. Other instantiations, such as the type of a component, are named after
+//! the parameterized definition and its actual parameters, e.g. `ProtocolIE-Container-E-RABSetupItemIEs`.
+//! The dummy references of the parameterized definition are substituted by the actual
+//! parameters in its body, in its constraints and in the actual parameters of
+//! nested instantiations, which are instantiated in turn.
+//! Value sets are lexed as constrained types, so they are instantiated like types.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    mem::discriminant,
+};
+
+use num::bigint::Sign;
+
+use crate::{
+    generator::asn1::notation::type_notation,
+    intermediate::{
+        constraints::*,
+        information_object::{
+            ASN1Information, ClassLink, InformationObject, InformationObjectFields, ObjectSet,
+            ObjectSetValue, SyntaxApplication, ToplevelInformationDefinition,
+        },
+        parameterization::{ParameterGovernor, Parameterization},
+        visit::{
+            walk_object_set_mut, walk_syntax_application_mut,
+            walk_toplevel_information_definition_mut, walk_type_mut, walk_value_mut, VisitorMut,
+        },
+        *,
+    },
+};
+
+use super::error::{LinkerError, LinkerErrorType};
+
+/// Upper bound of the number of instances, which stops the instantiation
+/// of parameterized definitions whose actual parameters grow with every nesting level
+const MAX_INSTANCES: usize = 4096;
+
+struct Instantiator {
+    /// Parameterized definitions by name
+    parameterized: BTreeMap<String, ToplevelDefinition>,
+    /// Names of the instances by parameterized definition and actual parameters
+    instances: BTreeMap<String, String>,
+    /// Names of all top-level definitions, including the instances
+    names: BTreeSet<String>,
+    /// Instances whose nested instantiations are yet to be resolved
+    pending: Vec<ToplevelDefinition>,
+    errors: Vec<LinkerError>,
+    /// Name of the definition whose nested instantiations are being resolved
+    user: String,
+    /// Module header of the definition whose nested instantiations are being resolved,
+    /// which the instances are added to
+    module_header: Option<SharedModuleHeader>,
+}
+
+/// Replaces the instantiations of parameterized definitions in all top-level definitions
+/// by references to concrete instances, which are added to `tlds`.
+/// Instantiations that fail are left unchanged and reported.
+pub(super) fn instantiate(tlds: &mut BTreeMap<String, ToplevelDefinition>) -> Vec<LinkerError> {
+    let parameterized = tlds
+        .iter()
+        .filter(|(_, tld)| parameterization(tld).is_some())
+        .map(|(name, tld)| (name.clone(), tld.clone()))
+        .collect::<BTreeMap<_, _>>();
+    if parameterized.is_empty() {
+        return vec![];
+    }
+    let mut instantiator = Instantiator {
+        parameterized,
+        instances: BTreeMap::new(),
+        names: tlds.keys().cloned().collect(),
+        pending: vec![],
+        errors: vec![],
+        user: String::new(),
+        module_header: None,
+    };
+    for tld in tlds.values() {
+        if let Some((identifier, args)) = instantiator.single_instantiation(tld) {
+            instantiator
+                .instances
+                .insert(instance_key(identifier, args), tld.name().clone());
+        }
+    }
+
+    for tld in tlds.values_mut() {
+        if parameterization(tld).is_some() {
+            continue;
+        }
+        if let Some((identifier, args)) = instantiator
+            .single_instantiation(tld)
+            .map(|(identifier, args)| (identifier.clone(), args.clone()))
+        {
+            match instantiator.substitute(&identifier, &args) {
+                Ok(instance) => take_over_body(tld, instance),
+                Err(mut e) => {
+                    e.contextualize(tld.name());
+                    instantiator.errors.push(e);
+                    continue;
+                }
+            }
+        }
+        instantiator.instantiate_nested(tld);
+    }
+    while let Some(mut instance) = instantiator.pending.pop() {
+        instantiator.instantiate_nested(&mut instance);
+        tlds.insert(instance.name().clone(), instance);
+    }
+    instantiator.errors
+}
+
+fn parameterization(tld: &ToplevelDefinition) -> Option<&Parameterization> {
+    match tld {
+        ToplevelDefinition::Type(t) => t.parameterization.as_ref(),
+        ToplevelDefinition::Value(v) => v.parameterization.as_ref(),
+        ToplevelDefinition::Object(o) => o.parameterization.as_ref(),
+        ToplevelDefinition::Class(c) => c.is_parameterized().then_some(&c.parameterization),
+        ToplevelDefinition::Macro(_) => None,
+    }
+}
+
+fn remove_parameterization(tld: &mut ToplevelDefinition) {
+    match tld {
+        ToplevelDefinition::Type(t) => t.parameterization = None,
+        ToplevelDefinition::Value(v) => v.parameterization = None,
+        ToplevelDefinition::Object(o) => o.parameterization = None,
+        ToplevelDefinition::Class(c) => c.parameterization = Parameterization::default(),
+        ToplevelDefinition::Macro(_) => (),
+    }
+}
+
+/// Replaces the body of a definition that consists of a single instantiation by the instance.
+fn take_over_body(tld: &mut ToplevelDefinition, instance: ToplevelDefinition) {
+    match (tld, instance) {
+        (ToplevelDefinition::Type(t), ToplevelDefinition::Type(instance)) => {
+            t.ty = instance.ty;
+            t.tag = t.tag.take().or(instance.tag);
+        }
+        (ToplevelDefinition::Value(v), ToplevelDefinition::Value(instance)) => {
+            v.value = instance.value
+        }
+        (ToplevelDefinition::Object(o), ToplevelDefinition::Object(instance)) => {
+            o.value = instance.value
+        }
+        _ => (),
+    }
+}
+
+impl Instantiator {
+    /// Returns the parameterized definition and the actual parameters of an assignment
+    /// that consists of a single instantiation.
+    fn single_instantiation<'t>(
+        &self,
+        tld: &'t ToplevelDefinition,
+    ) -> Option<(&'t String, &'t Vec<Parameter>)> {
+        let (identifier, args) = match tld {
+            ToplevelDefinition::Type(ToplevelTypeDefinition {
+                ty: ASN1Type::ElsewhereDeclaredType(e),
+                parameterization: None,
+                ..
+            }) => match e.constraints.as_slice() {
+                [Constraint::Parameter(args)] => (&e.identifier, args),
+                _ => return None,
+            },
+            ToplevelDefinition::Value(ToplevelValueDefinition {
+                value:
+                    ASN1Value::ParameterizedValue {
+                        identifier,
+                        actual_parameters,
+                    },
+                parameterization: None,
+                ..
+            }) => (identifier, actual_parameters),
+            ToplevelDefinition::Object(ToplevelInformationDefinition {
+                value:
+                    ASN1Information::ObjectSet(ObjectSet {
+                        values,
+                        extensible: None,
+                    }),
+                parameterization: None,
+                ..
+            }) => match values.as_slice() {
+                [ObjectSetValue::Instantiation(identifier, args)] => (identifier, args),
+                _ => return None,
+            },
+            // An object set such as `{ Set { object } }` is lexed as an object in custom syntax
+            ToplevelDefinition::Object(ToplevelInformationDefinition {
+                value:
+                    ASN1Information::Object(InformationObject {
+                        fields: InformationObjectFields::CustomSyntax(applications),
+                        ..
+                    }),
+                parameterization: None,
+                ..
+            }) => match applications.as_slice() {
+                [SyntaxApplication::LiteralOrTypeReference(e)] => match e.constraints.as_slice() {
+                    [Constraint::Parameter(args)] => (&e.identifier, args),
+                    _ => return None,
+                },
+                _ => return None,
+            },
+            _ => return None,
+        };
+        self.parameterized
+            .get(identifier)
+            .filter(|parameterized| discriminant(*parameterized) == discriminant(tld))
+            .map(|_| (identifier, args))
+    }
+
+    /// Replaces the instantiations nested in a definition by references to instances.
+    fn instantiate_nested(&mut self, tld: &mut ToplevelDefinition) {
+        self.user = tld.name().clone();
+        self.module_header = tld.get_module_header();
+        self.visit_toplevel_definition_mut(tld);
+    }
+
+    /// Returns the name of the instance of a parameterized definition of the given kind
+    /// for the given actual parameters, creating the instance if it does not exist yet.
+    /// Returns `None` if `identifier` does not refer to a parameterized definition of that kind.
+    fn instance_of(
+        &mut self,
+        identifier: &str,
+        args: &[Parameter],
+        is_kind: impl Fn(&ToplevelDefinition) -> bool,
+    ) -> Option<String> {
+        if !self.parameterized.get(identifier).is_some_and(is_kind) {
+            return None;
+        }
+        match self.instance(identifier, args) {
+            Ok(name) => Some(name),
+            Err(mut error) => {
+                error.contextualize(&self.user);
+                self.errors.push(error);
+                None
+            }
+        }
+    }
+
+    /// Returns the name of the instance of a parameterized definition for the given actual
+    /// parameters, creating the instance if it does not exist yet.
+    fn instance(&mut self, identifier: &str, args: &[Parameter]) -> Result<String, LinkerError> {
+        let key = instance_key(identifier, args);
+        if let Some(name) = self.instances.get(&key) {
+            return Ok(name.clone());
+        }
+        if self.instances.len() >= MAX_INSTANCES {
+            return Err(invalid(format!(
+                "Instantiating `{identifier}` exceeds the maximum of {MAX_INSTANCES} instances"
+            )));
+        }
+        let mut instance = self.substitute(identifier, args)?;
+        let base = instance_name(identifier, args);
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while self.names.contains(&name) {
+            name = format!("{base}-{suffix}");
+            suffix += 1;
+        }
+        *instance.name_mut() = name.clone();
+        remove_parameterization(&mut instance);
+        if let Some(module_header) = self.module_header.clone() {
+            instance.set_module_header(module_header);
+        }
+        self.pending.push(instance);
+        self.names.insert(name.clone());
+        self.instances.insert(key, name.clone());
+        Ok(name)
+    }
+
+    /// Returns a parameterized definition with its dummy references
+    /// substituted by the actual parameters.
+    fn substitute(
+        &self,
+        identifier: &str,
+        args: &[Parameter],
+    ) -> Result<ToplevelDefinition, LinkerError> {
+        let parameterized = &self.parameterized[identifier];
+        let parameters = parameterization(parameterized).map_or(&[][..], |p| &p.parameters);
+        if parameters.len() != args.len() {
+            return Err(invalid(format!(
+                "`{identifier}` expects {} actual parameters, but {} were given",
+                parameters.len(),
+                args.len()
+            )));
+        }
+        let mut substitution = Substitution::default();
+        for (parameter, arg) in parameters.iter().zip(args) {
+            let dummy = parameter.dummy_reference.clone();
+            match (arg, &parameter.param_governor) {
+                (Parameter::ValueParameter(value), ParameterGovernor::TypeOrClass(_)) => {
+                    // Information objects governed by a class are parsed as value references
+                    if let ASN1Value::ElsewhereDeclaredValue {
+                        parent: None,
+                        identifier,
+                    } = value
+                    {
+                        substitution
+                            .object_sets
+                            .insert(dummy.clone(), reference(identifier));
+                    }
+                    substitution.values.insert(dummy, value.clone());
+                }
+                // Type references may be parsed as value references
+                (
+                    Parameter::ValueParameter(ASN1Value::ElsewhereDeclaredValue {
+                        parent,
+                        identifier,
+                    }),
+                    ParameterGovernor::None,
+                ) => {
+                    substitution
+                        .classes
+                        .insert(dummy.clone(), identifier.clone());
+                    substitution.types.insert(
+                        dummy,
+                        ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+                            parent: parent.clone(),
+                            module: None,
+                            identifier: identifier.clone(),
+                            constraints: vec![],
+                        }),
+                    );
+                }
+                (Parameter::TypeParameter(ty), ParameterGovernor::None) => {
+                    if let ASN1Type::ElsewhereDeclaredType(e) = ty {
+                        substitution
+                            .classes
+                            .insert(dummy.clone(), e.identifier.clone());
+                    }
+                    substitution.types.insert(dummy, ty.clone());
+                }
+                // Object set references are parsed as type references
+                (
+                    Parameter::TypeParameter(ASN1Type::ElsewhereDeclaredType(e)),
+                    ParameterGovernor::Class(_),
+                ) => {
+                    substitution
+                        .object_sets
+                        .insert(dummy, reference(&e.identifier));
+                }
+                (Parameter::ObjectSetParameter(set), _) => {
+                    substitution.object_sets.insert(dummy, set.clone());
+                }
+                (Parameter::InformationObjectParameter(fields), _) => {
+                    substitution.object_sets.insert(
+                        dummy,
+                        ObjectSet {
+                            values: vec![ObjectSetValue::Inline(fields.clone())],
+                            extensible: None,
+                        },
+                    );
+                }
+                _ => {
+                    return Err(invalid(format!(
+                        "Mismatching actual parameter for parameter {dummy} of `{identifier}`"
+                    )))
+                }
+            }
+        }
+        let mut instance = parameterized.clone();
+        substitution.visit_toplevel_definition_mut(&mut instance);
+        Ok(instance)
+    }
+}
+
+impl VisitorMut for Instantiator {
+    fn visit_toplevel_information_definition_mut(
+        &mut self,
+        node: &mut ToplevelInformationDefinition,
+    ) {
+        if let ClassLink::ByInstantiation(identifier, args) = &node.class {
+            if let Some(name) = self.instance_of(identifier, &args.clone(), |p| {
+                matches!(p, ToplevelDefinition::Class(_))
+            }) {
+                node.class = ClassLink::ByName(name);
+            }
+        }
+        walk_toplevel_information_definition_mut(self, node);
+        link_class_name(node);
+    }
+
+    fn visit_type_mut(&mut self, node: &mut ASN1Type) {
+        match node {
+            ASN1Type::ElsewhereDeclaredType(e) => {
+                if let Some(Constraint::Parameter(args)) = e
+                    .constraints
+                    .iter()
+                    .find(|c| matches!(c, Constraint::Parameter(_)))
+                {
+                    let Some(name) = self.instance_of(&e.identifier, &args.clone(), |p| {
+                        matches!(p, ToplevelDefinition::Type(_))
+                    }) else {
+                        if self.parameterized.contains_key(&e.identifier) {
+                            return;
+                        }
+                        return walk_type_mut(self, node);
+                    };
+                    e.identifier = name;
+                    e.module = None;
+                    e.constraints
+                        .retain(|c| !matches!(c, Constraint::Parameter(_)));
+                }
+            }
+            // The actual parameters of a class precede the constraints of its field types
+            ASN1Type::ObjectClassField(f) => {
+                if let Some(Constraint::Parameter(args)) = f.constraints.first() {
+                    if let Some(name) = self.instance_of(&f.class, &args.clone(), |p| {
+                        matches!(p, ToplevelDefinition::Class(_))
+                    }) {
+                        f.class = name;
+                        f.constraints.remove(0);
+                    }
+                }
+            }
+            _ => (),
+        }
+        walk_type_mut(self, node);
+    }
+
+    fn visit_value_mut(&mut self, node: &mut ASN1Value) {
+        if let ASN1Value::ParameterizedValue {
+            identifier,
+            actual_parameters,
+        } = node
+        {
+            if let Some(name) = self.instance_of(identifier, &actual_parameters.clone(), |p| {
+                matches!(p, ToplevelDefinition::Value(_))
+            }) {
+                *node = ASN1Value::ElsewhereDeclaredValue {
+                    parent: None,
+                    identifier: name,
+                };
+                return;
+            }
+        }
+        walk_value_mut(self, node);
+    }
+
+    fn visit_object_set_mut(&mut self, node: &mut ObjectSet) {
+        for value in &mut node.values {
+            if let ObjectSetValue::Instantiation(identifier, args) = value {
+                if let Some(name) = self.instance_of(identifier, &args.clone(), |p| {
+                    matches!(p, ToplevelDefinition::Object(_))
+                }) {
+                    *value = ObjectSetValue::Reference(name);
+                }
+            }
+        }
+        walk_object_set_mut(self, node);
+    }
+}
+
+/// Actual parameters of an instantiation by dummy reference
+#[derive(Default)]
+struct Substitution {
+    types: BTreeMap<String, ASN1Type>,
+    /// Class references, for dummy references that govern object class field types
+    classes: BTreeMap<String, String>,
+    values: BTreeMap<String, ASN1Value>,
+    object_sets: BTreeMap<String, ObjectSet>,
+}
+
+impl VisitorMut for Substitution {
+    fn visit_toplevel_information_definition_mut(
+        &mut self,
+        node: &mut ToplevelInformationDefinition,
+    ) {
+        walk_toplevel_information_definition_mut(self, node);
+        link_class_name(node);
+    }
+
+    fn visit_type_mut(&mut self, node: &mut ASN1Type) {
+        match node {
+            ASN1Type::ElsewhereDeclaredType(e) => {
+                if let Some(actual) = self.types.get(&e.identifier) {
+                    let mut actual = actual.clone();
+                    let mut constraints = std::mem::take(&mut e.constraints);
+                    constraints
+                        .iter_mut()
+                        .for_each(|c| self.visit_constraint_mut(c));
+                    if let Some(actual_constraints) = actual.constraints_mut() {
+                        actual_constraints.append(&mut constraints);
+                    }
+                    *node = actual;
+                    return;
+                }
+            }
+            ASN1Type::ObjectClassField(f) => {
+                if let Some(class) = self.classes.get(&f.class) {
+                    f.class = class.clone();
+                }
+            }
+            _ => (),
+        }
+        walk_type_mut(self, node);
+    }
+
+    fn visit_value_mut(&mut self, node: &mut ASN1Value) {
+        if let ASN1Value::ElsewhereDeclaredValue {
+            parent: None,
+            identifier,
+        } = node
+        {
+            if let Some(actual) = self.values.get(identifier) {
+                *node = actual.clone();
+                return;
+            }
+        }
+        walk_value_mut(self, node);
+    }
+
+    fn visit_object_set_mut(&mut self, node: &mut ObjectSet) {
+        let values = std::mem::take(&mut node.values);
+        for mut value in values {
+            match &mut value {
+                ObjectSetValue::Reference(reference) => {
+                    if let Some(actual) = self.object_sets.get(reference) {
+                        node.values.extend(actual.values.iter().cloned());
+                        node.extensible = node.extensible.or(actual.extensible);
+                        continue;
+                    }
+                }
+                ObjectSetValue::Inline(fields) => self.visit_information_object_fields_mut(fields),
+                ObjectSetValue::Instantiation(_, args) => {
+                    args.iter_mut().for_each(|a| self.visit_parameter_mut(a))
+                }
+            }
+            node.values.push(value);
+        }
+    }
+
+    fn visit_syntax_application_mut(&mut self, node: &mut SyntaxApplication) {
+        if let SyntaxApplication::LiteralOrTypeReference(reference) = node {
+            if let Some(actual) = self.types.get(&reference.identifier) {
+                *node = SyntaxApplication::TypeReference(actual.clone());
+                return;
+            }
+        }
+        walk_syntax_application_mut(self, node);
+    }
+}
+
+/// Names the class of an information object after the class it is linked to by name,
+/// which is the instance of the class if the object instantiates a parameterized class.
+fn link_class_name(tld: &mut ToplevelInformationDefinition) {
+    if let (ClassLink::ByName(class), ASN1Information::Object(object)) =
+        (&tld.class, &mut tld.value)
+    {
+        object.class_name = class.clone();
+    }
+}
+
+/// Identifies an instantiation by its parameterized definition and its actual parameters.
+fn instance_key(identifier: &str, args: &[Parameter]) -> String {
+    format!("{identifier}{args:?}")
+}
+
+/// Names an instance after its parameterized definition and its actual parameters.
+/// Actual parameters that are not references, integers or booleans are named by position.
+fn instance_name(identifier: &str, args: &[Parameter]) -> String {
+    let labels = args
+        .iter()
+        .enumerate()
+        .map(|(index, arg)| {
+            let label = match arg {
+                Parameter::TypeParameter(ASN1Type::ElsewhereDeclaredType(e)) => {
+                    e.identifier.clone()
+                }
+                Parameter::TypeParameter(ty) => {
+                    // The keyword of a built-in type without its constraints, e.g. `OCTET STRING`
+                    let notation = type_notation(ty, 0);
+                    notation
+                        .split(|c: char| !c.is_ascii_alphanumeric() && c != ' ')
+                        .next()
+                        .unwrap_or_default()
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .join("-")
+                }
+                Parameter::ValueParameter(ASN1Value::Integer(i)) if i.sign() != Sign::Minus => {
+                    i.to_string()
+                }
+                Parameter::ValueParameter(ASN1Value::Boolean(b)) => {
+                    String::from(if *b { "TRUE" } else { "FALSE" })
+                }
+                Parameter::ValueParameter(ASN1Value::ElsewhereDeclaredValue {
+                    identifier, ..
+                }) => identifier.clone(),
+                Parameter::ObjectSetParameter(set) => set
+                    .values
+                    .iter()
+                    .map(|value| match value {
+                        ObjectSetValue::Reference(reference)
+                        | ObjectSetValue::Instantiation(reference, _) => Some(reference.as_str()),
+                        ObjectSetValue::Inline(_) => None,
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .map(|references| references.join("-"))
+                    .unwrap_or_default(),
+                _ => String::new(),
+            };
+            if label.is_empty() {
+                (index + 1).to_string()
+            } else {
+                label
+            }
+        })
+        .collect::<Vec<_>>();
+    format!("{identifier}-{}", labels.join("-"))
+}
+
+/// An object set that consists of a reference to an object or object set
+fn reference(identifier: &str) -> ObjectSet {
+    ObjectSet {
+        values: vec![ObjectSetValue::Reference(identifier.into())],
+        extensible: None,
+    }
+}
+
+fn invalid(details: String) -> LinkerError {
+    LinkerError::new(None, &details, LinkerErrorType::InvalidParameterization)
+}
@@ -9,14 +9,25 @@ impl Constraint {
         tlds: &BTreeMap<String, ToplevelDefinition>,
     ) -> Result<(), GrammarError> {
         match self {
-            Constraint::Subtype(t) => t.set.link_cross_reference(identifier, tlds),
+            Constraint::Subtype(t) => {
+                t.set.link_cross_reference(identifier, tlds)?;
+                match &mut t.extension_additions {
+                    Some(additions) => additions.link_cross_reference(identifier, tlds),
+                    None => Ok(()),
+                }
+            }
             _ => Ok(()),
         }
     }
 
     pub(super) fn has_cross_reference(&self) -> bool {
         match self {
-            Self::Subtype(c) => c.set.has_cross_reference(),
+            Self::Subtype(c) => {
+                c.set.has_cross_reference()
+                    || c.extension_additions
+                        .as_ref()
+                        .is_some_and(|a| a.has_cross_reference())
+            }
             Self::Parameter(_) => true,
             _ => false,
         }
@@ -48,7 +48,7 @@ impl ToplevelInformationDefinition {
             }
             (ASN1Information::ObjectSet(ref mut o), ClassLink::ByReference(class)) => {
                 o.values.iter_mut().try_for_each(|value| match value {
-                    ObjectSetValue::Reference(_) => Ok(()),
+                    ObjectSetValue::Reference(_) | ObjectSetValue::Instantiation(..) => Ok(()),
                     ObjectSetValue::Inline(ref mut fields) => {
                         resolve_custom_syntax(fields, class)?;
                         link_object_fields(fields, class, tlds)
@@ -227,6 +227,8 @@ impl ObjectSetValue {
                 })) => Some(obj.values.clone()),
                 _ => None,
             },
+            // Instantiations that are left at this point have failed and have been reported
+            ObjectSetValue::Instantiation(..) => None,
             ObjectSetValue::Inline(InformationObjectFields::CustomSyntax(c)) => {
                 c.iter_mut()
                     .any(|field| field.link_object_set_reference(tlds));
@@ -243,6 +245,7 @@ impl ObjectSetValue {
     pub fn references_object_set_by_name(&self) -> bool {
         match self {
             ObjectSetValue::Reference(_) => true,
+            ObjectSetValue::Instantiation(..) => false,
             ObjectSetValue::Inline(InformationObjectFields::CustomSyntax(c)) => {
                 c.iter().any(|field| field.references_object_set_by_name())
             }
@@ -89,13 +89,13 @@ impl ToplevelDefinition {
             match &t.ty {
                 ASN1Type::Enumerated(e) => {
                     return e.members.iter().find_map(|m| {
-                        (&m.name == identifier).then_some(ASN1Value::Integer(m.index))
+                        (&m.name == identifier).then_some(ASN1Value::Integer(m.index.into()))
                     })
                 }
                 ASN1Type::Integer(i) => {
                     return i.distinguished_values.as_ref().and_then(|dv| {
                         dv.iter().find_map(|d| {
-                            (&d.name == identifier).then_some(ASN1Value::Integer(d.value))
+                            (&d.name == identifier).then_some(ASN1Value::Integer(d.value.clone()))
                         })
                     })
                 }
@@ -425,7 +425,7 @@ impl ASN1Type {
                     b.link_cross_reference(name, tlds)?;
                 }
                 if let Some(replacement) = s.element_type.link_constraint_reference(name, tlds)? {
-                    s.element_type = Box::new(replacement);
+                    *s.element_type = replacement;
                 }
             }
             ASN1Type::ElsewhereDeclaredType(e) => {
@@ -756,7 +756,11 @@ impl ASN1Type {
         match self {
             ASN1Type::Null => false,
             ASN1Type::Boolean(b) => b.constraints.iter().any(|c| c.has_cross_reference()),
-            ASN1Type::ObjectIdentifier(o) => o.constraints.iter().any(|c| c.has_cross_reference()),
+            ASN1Type::ObjectIdentifier(o)
+            | ASN1Type::RelativeOid(o)
+            | ASN1Type::OidIri(o)
+            | ASN1Type::RelativeOidIri(o) => o.constraints.iter().any(|c| c.has_cross_reference()),
+            ASN1Type::ObjectDescriptor(o) => o.constraints.iter().any(|c| c.has_cross_reference()),
             ASN1Type::Integer(i) => i.constraints.iter().any(|c| c.has_cross_reference()),
             ASN1Type::BitString(b) => b.constraints.iter().any(|c| c.has_cross_reference()),
             ASN1Type::OctetString(o) => o.constraints.iter().any(|c| c.has_cross_reference()),
@@ -818,6 +822,8 @@ impl ASN1Type {
                         tag: option.tag,
                         ty: option.ty.resolve_class_reference(tlds),
                         constraints: vec![],
+                        comments: option.comments,
+                        trailing_comment: None,
                     })
                     .collect(),
                 constraints: c.constraints,
@@ -1020,20 +1026,16 @@ impl ASN1Value {
                 let struct_value = pseudo_arcs
                     .chunks_mut(2)
                     .map(|chunk| {
-                        let err = || GrammarError {
-                            pdu: None,
-                            details:
-                                "Failed to interpret object identifier value as sequence value!"
-                                    .into(),
-                            kind: GrammarErrorType::LinkerError,
+                        let err = || {
+                            GrammarError::new(
+                                "Failed to interpret object identifier value as sequence value!",
+                                GrammarErrorType::LinkerError,
+                            )
                         };
                         if let [id, val] = chunk {
-                            val.number
-                                .and_then(|n| <u128 as TryInto<i128>>::try_into(n).ok())
-                                .ok_or_else(err)
-                                .map(|number| {
-                                    (id.name.take(), Box::new(ASN1Value::Integer(number)))
-                                })
+                            val.number.ok_or_else(err).map(|number| {
+                                (id.name.take(), Box::new(ASN1Value::Integer(number.into())))
+                            })
                         } else {
                             Err(err())
                         }
@@ -1052,7 +1054,7 @@ impl ASN1Value {
                 if matches![**value, ASN1Value::SequenceOrSet(_)] =>
             {
                 if let ASN1Value::SequenceOrSet(val) = &mut **value {
-                    *value = Box::new(Self::link_struct_like(val, s, tlds, type_name)?);
+                    **value = Self::link_struct_like(val, s, tlds, type_name)?;
                 }
                 Ok(())
             }
@@ -1066,14 +1068,14 @@ impl ASN1Value {
                 if matches![**value, ASN1Value::SequenceOrSet(_)] =>
             {
                 if let ASN1Value::SequenceOrSet(val) = &mut **value {
-                    *value = Box::new(Self::link_array_like(val, s, tlds)?);
+                    **value = Self::link_array_like(val, s, tlds)?;
                 }
                 Ok(())
             }
             (ASN1Type::Integer(i), ASN1Value::Integer(val)) => {
                 *self = ASN1Value::LinkedIntValue {
                     integer_type: i.int_type(),
-                    value: *val,
+                    value: std::mem::take(val),
                 };
                 Ok(())
             }
@@ -1085,7 +1087,52 @@ impl ASN1Value {
                 if matches![**value, ASN1Value::String(_)] =>
             {
                 if let ASN1Value::String(s) = &**value {
-                    *value = Box::new(ASN1Value::LinkedCharStringValue(t.ty, s.clone()));
+                    **value = ASN1Value::LinkedCharStringValue(t.ty, s.clone());
+                }
+                Ok(())
+            }
+            (ASN1Type::RelativeOid(_), ASN1Value::ObjectIdentifier(oid)) => {
+                *self = ASN1Value::LinkedRelativeOidValue(oid.clone());
+                Ok(())
+            }
+            (ASN1Type::RelativeOid(_), ASN1Value::LinkedNestedValue { value, .. })
+                if matches![**value, ASN1Value::ObjectIdentifier(_)] =>
+            {
+                if let ASN1Value::ObjectIdentifier(oid) = &**value {
+                    **value = ASN1Value::LinkedRelativeOidValue(oid.clone());
+                }
+                Ok(())
+            }
+            (ASN1Type::ObjectDescriptor(_), ASN1Value::String(s)) => {
+                *self =
+                    ASN1Value::LinkedCharStringValue(CharacterStringType::GraphicString, s.clone());
+                Ok(())
+            }
+            (ASN1Type::ObjectDescriptor(_), ASN1Value::LinkedNestedValue { value, .. })
+                if matches![**value, ASN1Value::String(_)] =>
+            {
+                if let ASN1Value::String(s) = &**value {
+                    **value = ASN1Value::LinkedCharStringValue(
+                        CharacterStringType::GraphicString,
+                        s.clone(),
+                    );
+                }
+                Ok(())
+            }
+            (ASN1Type::OidIri(_) | ASN1Type::RelativeOidIri(_), ASN1Value::String(s)) => {
+                *self =
+                    ASN1Value::LinkedCharStringValue(CharacterStringType::UTF8String, s.clone());
+                Ok(())
+            }
+            (
+                ASN1Type::OidIri(_) | ASN1Type::RelativeOidIri(_),
+                ASN1Value::LinkedNestedValue { value, .. },
+            ) if matches![**value, ASN1Value::String(_)] => {
+                if let ASN1Value::String(s) = &**value {
+                    **value = ASN1Value::LinkedCharStringValue(
+                        CharacterStringType::UTF8String,
+                        s.clone(),
+                    );
                 }
                 Ok(())
             }
@@ -1123,7 +1170,7 @@ impl ASN1Value {
                 ASN1Value::LinkedNestedValue { value, .. },
             ) if matches![**value, ASN1Value::SequenceOrSet(_)] => {
                 if let ASN1Value::SequenceOrSet(o) = &**value {
-                    *value = Box::new(ASN1Value::BitStringNamedBits(
+                    **value = ASN1Value::BitStringNamedBits(
                         o.iter()
                             .filter_map(|(_, v)| match &**v {
                                 ASN1Value::ElsewhereDeclaredValue { identifier, .. } => {
@@ -1135,7 +1182,7 @@ impl ASN1Value {
                                 _ => None,
                             })
                             .collect(),
-                    ));
+                    );
                     self.link_with_type(tlds, ty, type_name)?;
                 }
                 Ok(())
@@ -1160,9 +1207,9 @@ impl ASN1Value {
                 ASN1Value::LinkedNestedValue { value, .. },
             ) if matches![**value, ASN1Value::ObjectIdentifier(_)] => {
                 if let ASN1Value::ObjectIdentifier(o) = &**value {
-                    *value = Box::new(ASN1Value::BitStringNamedBits(
+                    **value = ASN1Value::BitStringNamedBits(
                         o.0.iter().filter_map(|arc| arc.name.clone()).collect(),
-                    ));
+                    );
                     self.link_with_type(tlds, ty, type_name)?;
                 }
                 Ok(())
@@ -1174,7 +1221,10 @@ impl ASN1Value {
                 }),
                 ASN1Value::BitStringNamedBits(o),
             ) => {
-                if let Some(highest_distinguished_bit) = distinguished.iter().map(|d| d.value).max()
+                if let Some(highest_distinguished_bit) = distinguished
+                    .iter()
+                    .map(|d| saturating_i128(&d.value))
+                    .max()
                 {
                     *self = ASN1Value::BitString(bit_string_value_from_named_bits(
                         highest_distinguished_bit,
@@ -1183,11 +1233,10 @@ impl ASN1Value {
                     ));
                     Ok(())
                 } else {
-                    Err(GrammarError {
-                        details: format!("Failed to resolve BIT STRING value {o:?}"),
-                        kind: GrammarErrorType::LinkerError,
-                        pdu: None,
-                    })
+                    Err(GrammarError::new(
+                        &format!("Failed to resolve BIT STRING value {o:?}"),
+                        GrammarErrorType::LinkerError,
+                    ))
                 }
             }
             (
@@ -1197,28 +1246,31 @@ impl ASN1Value {
                 }),
                 ASN1Value::LinkedNestedValue { value, .. },
             ) if matches![**value, ASN1Value::BitStringNamedBits(_)] => {
-                if let (ASN1Value::BitStringNamedBits(o), Some(highest_distinguished_bit)) =
-                    (&**value, distinguished.iter().map(|d| d.value).max())
-                {
-                    *value = Box::new(ASN1Value::BitString(bit_string_value_from_named_bits(
+                if let (ASN1Value::BitStringNamedBits(o), Some(highest_distinguished_bit)) = (
+                    &**value,
+                    distinguished
+                        .iter()
+                        .map(|d| saturating_i128(&d.value))
+                        .max(),
+                ) {
+                    **value = ASN1Value::BitString(bit_string_value_from_named_bits(
                         highest_distinguished_bit,
                         o,
                         distinguished,
-                    )));
+                    ));
                     Ok(())
                 } else {
-                    Err(GrammarError {
-                        details: format!("Failed to resolve BIT STRING value {value:?}"),
-                        kind: GrammarErrorType::LinkerError,
-                        pdu: None,
-                    })
+                    Err(GrammarError::new(
+                        &format!("Failed to resolve BIT STRING value {value:?}"),
+                        GrammarErrorType::LinkerError,
+                    ))
                 }
             }
             (ASN1Type::BitString(_), ASN1Value::LinkedNestedValue { value, .. })
                 if matches![**value, ASN1Value::OctetString(_)] =>
             {
                 if let ASN1Value::OctetString(o) = &**value {
-                    *value = Box::new(ASN1Value::BitString(octet_string_to_bit_string(o)));
+                    **value = ASN1Value::BitString(octet_string_to_bit_string(o));
                 }
                 Ok(())
             }
@@ -1230,7 +1282,7 @@ impl ASN1Value {
                 if matches![**value, ASN1Value::BitString(_)] =>
             {
                 if let ASN1Value::BitString(b) = &**value {
-                    *value = Box::new(ASN1Value::OctetString(bit_string_to_octet_string(b)?));
+                    **value = ASN1Value::OctetString(bit_string_to_octet_string(b)?);
                 }
                 Ok(())
             }
@@ -1247,13 +1299,13 @@ impl ASN1Value {
                         i.distinguished_values.as_ref().and_then(|dist_vals| {
                             dist_vals
                                 .iter()
-                                .find_map(|d| (&d.name == identifier).then_some(d.value))
+                                .find_map(|d| (&d.name == identifier).then_some(d.value.clone()))
                         })
                     {
-                        *value = Box::new(ASN1Value::LinkedIntValue {
+                        **value = ASN1Value::LinkedIntValue {
                             integer_type: i.int_type(),
                             value: distinguished_value,
-                        });
+                        };
                     }
                 }
                 Ok(())
@@ -1265,10 +1317,10 @@ impl ASN1Value {
                     let int_type = i.constraints.iter().fold(IntegerType::Unbounded, |acc, c| {
                         c.integer_constraints().max_restrictive(acc)
                     });
-                    *value = Box::new(ASN1Value::LinkedIntValue {
+                    **value = ASN1Value::LinkedIntValue {
                         integer_type: int_type,
-                        value: *v,
-                    });
+                        value: v.clone(),
+                    };
                 }
                 Ok(())
             }
@@ -1276,7 +1328,7 @@ impl ASN1Value {
                 if let Some(value) = i.distinguished_values.as_ref().and_then(|dist_vals| {
                     dist_vals
                         .iter()
-                        .find_map(|d| (&d.name == identifier).then_some(d.value))
+                        .find_map(|d| (&d.name == identifier).then_some(d.value.clone()))
                 }) {
                     *self = ASN1Value::LinkedIntValue {
                         integer_type: i.int_type(),
@@ -1293,10 +1345,10 @@ impl ASN1Value {
                         .iter()
                         .find(|(_, tld)| tld.has_enum_value(None, identifier))
                     {
-                        *value = Box::new(ASN1Value::EnumeratedValue {
+                        **value = ASN1Value::EnumeratedValue {
                             enumerated: tld.name().clone(),
                             enumerable: identifier.clone(),
-                        });
+                        };
                     }
                 }
                 Ok(())
@@ -1374,7 +1426,7 @@ impl ASN1Value {
                                 .fold(IntegerType::Unbounded, |acc, c| {
                                     c.integer_constraints().max_restrictive(acc)
                                 }),
-                            value: distinguished_value.value,
+                            value: distinguished_value.value.clone(),
                         }),
                     }))
                 } else {
@@ -1440,6 +1492,16 @@ impl ASN1Value {
             }
         })?;
 
+        // Values that lack a mandatory component are reported by the value checker
+        if s.members.iter().any(|member| {
+            member.optionality == Optionality::Required
+                && !val
+                    .iter()
+                    .any(|(name, _)| name.as_ref() == Some(&member.name))
+        }) {
+            return Ok(ASN1Value::SequenceOrSet(val.to_vec()));
+        }
+
         s.members
             .iter()
             .map(|member| {
@@ -1617,7 +1679,7 @@ fn bit_string_value_from_named_bits(
                 Some(bit)
                     == distinguished
                         .iter()
-                        .find_map(|d| (d.value == i).then_some(&d.name))
+                        .find_map(|d| (d.value == i.into()).then_some(&d.name))
             })
         })
         .collect()
@@ -1633,6 +1695,7 @@ mod tests {
         ($name:literal, $ty:expr) => {
             ToplevelTypeDefinition {
                 comments: String::new(),
+                trailing_comment: None,
                 tag: None,
                 module_header: None,
                 name: $name.into(),
@@ -1684,7 +1747,9 @@ mod tests {
                                 module: None,
                                 identifier: String::from("IntermediateBool"),
                                 constraints: vec![]
-                            })
+                            }),
+                            comments: String::new(),
+                            trailing_comment: None,
                         }]
                     })
                 )),
@@ -1693,6 +1758,7 @@ mod tests {
         };
         let mut example_value = ToplevelValueDefinition {
             comments: String::new(),
+            trailing_comment: None,
             name: "exampleValue".into(),
             parameterization: None,
             associated_type: ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
@@ -1713,6 +1779,7 @@ mod tests {
             example_value,
             ToplevelValueDefinition {
                 comments: "".into(),
+                trailing_comment: None,
                 name: "exampleValue".into(),
                 associated_type: ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
                     parent: None,
@@ -229,7 +229,7 @@ pub fn resolve_custom_syntax(
                 continue 'syntax_matching;
             }
         }
-        unsorted_default_syntax.sort_by(|&(a, _), &(b, _)| a.cmp(&b));
+        unsorted_default_syntax.sort_by_key(|&(a, _)| a);
         *fields = InformationObjectFields::DefaultSyntax(
             unsorted_default_syntax
                 .into_iter()
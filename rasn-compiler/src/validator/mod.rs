@@ -5,19 +5,23 @@
 //! assures that all dependencies of the individual
 //! data elements resolve, and checks for conflicting
 //! constraints and value definitions.
+mod constraints;
 pub(crate) mod error;
+mod exports;
+mod instantiation;
 mod linking;
+pub(crate) mod tagging;
 #[cfg(test)]
 mod tests;
+mod values;
 
 use std::{
-    cell::RefCell,
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashSet},
     ops::Not,
-    rc::Rc,
 };
 
 use crate::{
+    diagnostic::Severity,
     error::CompilerError,
     intermediate::{
         constraints::*,
@@ -28,26 +32,81 @@ use crate::{
 };
 
 use self::{
+    constraints::ConstraintChecker,
     error::{LinkerError, LinkerErrorType},
     information_object::{ASN1Information, InformationObjectClassField},
+    tagging::TagChecker,
+    values::ValueChecker,
 };
 
 pub struct Validator {
     tlds: BTreeMap<String, ToplevelDefinition>,
+    /// Names of the definitions in the order in which they appear in the sources
+    source_order: Vec<String>,
+    /// Whether imports of symbols that the imported module does not export
+    /// fail the validation or are reported as warnings
+    unexported_imports: Severity,
+    /// Names of the modules whose definitions are validated, see [Validator::with_scope]
+    scope: Option<BTreeSet<String>>,
 }
 
 impl Validator {
     pub fn new(tlds: Vec<ToplevelDefinition>) -> Validator {
         Self {
+            source_order: tlds.iter().map(|tld| tld.name().to_owned()).collect(),
             tlds: tlds
                 .into_iter()
                 .map(|tld| (tld.name().to_owned(), tld))
                 .collect(),
+            unexported_imports: Severity::Error,
+            scope: None,
         }
     }
 
+    pub fn with_unexported_imports(mut self, severity: Severity) -> Validator {
+        self.unexported_imports = severity;
+        self
+    }
+
+    /// Only validate and return the definitions of the given modules, along with all instances
+    /// of parameterized definitions. The definitions of the other modules only serve to resolve
+    /// references, so the modules that the given modules import from must be part of the scope.
+    /// Without a scope, all definitions are validated.
+    pub fn with_scope(mut self, modules: Option<BTreeSet<String>>) -> Validator {
+        self.scope = modules;
+        self
+    }
+
+    /// Returns whether a definition is validated, given the names of the definitions of the
+    /// sources. Instances of parameterized definitions are always validated, since the module
+    /// that they are added to depends on all their users.
+    fn in_scope(&self, sources: &HashSet<&String>, tld: &ToplevelDefinition) -> bool {
+        let Some(scope) = &self.scope else {
+            return true;
+        };
+        !sources.contains(tld.name())
+            || tld
+                .get_module_header()
+                .is_some_and(|header| scope.contains(&header.read().name))
+    }
+
+    fn scoped_keys(&self) -> HashSet<String> {
+        let sources = self.source_order.iter().collect::<HashSet<_>>();
+        self.tlds
+            .iter()
+            .filter(|(_, tld)| self.in_scope(&sources, tld))
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    /// Links the definitions. Definitions that cannot be linked completely are kept, so the
+    /// errors of the linking are reported as warnings, unlike invalid instantiations.
     fn link(mut self) -> Result<(Self, Vec<CompilerError>), LinkerError> {
-        let mut warnings: Vec<CompilerError> = vec![];
+        let mut warnings: Vec<CompilerError> = instantiation::instantiate(&mut self.tlds)
+            .into_iter()
+            .map(CompilerError::from)
+            .collect();
+        let scoped = self.scoped_keys();
         // Linking of ASN1 values depends on linked ASN1 types, so we order the key collection accordingly (note that we pop keys)
         let mut keys = self
             .tlds
@@ -58,13 +117,16 @@ impl Validator {
                     .not()
                     .then_some(k.clone())
             }))
+            .filter(|k| scoped.contains(k))
             .collect::<Vec<String>>();
         let mut visited_headers = HashSet::<String>::new();
         while let Some(key) = keys.pop() {
+            // The dummy references of parameterized object sets are resolved in their instances
             if matches![
                 self.tlds.get(&key),
                 Some(ToplevelDefinition::Object(ToplevelInformationDefinition {
                     value: ASN1Information::ObjectSet(_),
+                    parameterization: None,
                     ..
                 }))
             ] {
@@ -79,7 +141,7 @@ impl Validator {
                 {
                     if let Err(mut e) = set.resolve_object_set_references(&self.tlds) {
                         e.contextualize(&key);
-                        warnings.push(e.into())
+                        warnings.push(e.with_severity(Severity::Warning).into())
                     }
                 }
                 if let Some((k, tld)) = item {
@@ -109,7 +171,7 @@ impl Validator {
                 if let Some((k, ToplevelDefinition::Type(mut tld))) = self.tlds.remove_entry(&key) {
                     if let Err(mut e) = tld.ty.link_choice_selection_type(&self.tlds) {
                         e.contextualize(&key);
-                        warnings.push(e.into());
+                        warnings.push(e.with_severity(Severity::Warning).into());
                     }
                     self.tlds.insert(k, ToplevelDefinition::Type(tld));
                 }
@@ -122,35 +184,37 @@ impl Validator {
                 }
             }
             if self.has_constraint_reference(&key) {
-                match self.tlds.remove(&key).ok_or_else(|| LinkerError {
-                    pdu: Some(key.clone()),
-                    details: "Could not find toplevel declaration to remove!".into(),
-                    kind: LinkerErrorType::MissingDependency,
+                match self.tlds.remove(&key).ok_or_else(|| {
+                    LinkerError::new(
+                        Some(key.clone()),
+                        "Could not find toplevel declaration to remove!",
+                        LinkerErrorType::MissingDependency,
+                    )
                 }) {
                     Ok(mut tld) => {
                         if let Err(mut e) = tld.link_constraint_reference(&self.tlds) {
                             e.contextualize(&key);
-                            warnings.push(e.into());
+                            warnings.push(e.with_severity(Severity::Warning).into());
                         }
                         self.tlds.insert(tld.name().clone(), tld);
                     }
                     Err(mut e) => {
                         e.contextualize(&key);
-                        warnings.push(e.into());
+                        warnings.push(e.with_severity(Severity::Warning).into());
                     }
                 };
             }
             if let Some((k, mut tld)) = self.tlds.remove_entry(&key) {
                 if let Err(mut e) = tld.collect_supertypes(&self.tlds) {
                     e.contextualize(&key);
-                    warnings.push(e.into());
+                    warnings.push(e.with_severity(Severity::Warning).into());
                 }
                 self.tlds.insert(k, tld);
             }
             if let Some((k, mut tld)) = self.tlds.remove_entry(&key) {
                 if let Err(mut e) = tld.mark_recursive(&self.tlds) {
                     e.contextualize(&key);
-                    warnings.push(e.into());
+                    warnings.push(e.with_severity(Severity::Warning).into());
                 }
                 self.tlds.insert(k, tld);
             }
@@ -158,7 +222,7 @@ impl Validator {
                 .tlds
                 .get(&key)
                 .and_then(ToplevelDefinition::get_module_header)
-                .is_some_and(|m| visited_headers.contains(&m.borrow().name).not())
+                .is_some_and(|m| visited_headers.contains(&m.read().name).not())
             {
                 self.fill_in_associated_type_imports(key, &mut visited_headers);
             }
@@ -190,7 +254,7 @@ impl Validator {
                     );
                 }
             }
-            for import_modules in &module_header.borrow().imports {
+            for import_modules in &module_header.read().imports {
                 for import in &import_modules.types {
                     if import.starts_with(|c: char| c.is_lowercase()) {
                         match self.tlds.get(import) {
@@ -222,7 +286,7 @@ impl Validator {
                 }
             }
             for mut import in associated_type_imports {
-                let mut mut_module_header = module_header.borrow_mut();
+                let mut mut_module_header = module_header.write();
                 if let Some(mod_imports) = mut_module_header
                     .imports
                     .iter_mut()
@@ -236,7 +300,7 @@ impl Validator {
                 }
             }
 
-            visited_headers.insert(module_header.borrow().name.clone());
+            visited_headers.insert(module_header.read().name.clone());
         }
         self.tlds.insert(key, tld);
     }
@@ -244,7 +308,7 @@ impl Validator {
     fn associated_import_type(
         &self,
         associated_type: &str,
-        module_header: Rc<RefCell<ModuleHeader>>,
+        module_header: SharedModuleHeader,
         associated_type_imports: &mut Vec<Import>,
     ) {
         if let Some(ToplevelDefinition::Type(ToplevelTypeDefinition {
@@ -255,15 +319,15 @@ impl Validator {
         })) = self.tlds.get(associated_type)
         {
             let v_type_name = format!("{}{}", name, parameterization.as_ref().map_or("", |_| "{}"));
-            let v_type_mod_name = &m_hdr.borrow().name;
-            if v_type_mod_name != &module_header.borrow().name
-                && module_header.borrow().find_import(&v_type_name).is_none()
+            let v_type_mod_name = &m_hdr.read().name;
+            if v_type_mod_name != &module_header.read().name
+                && module_header.read().find_import(&v_type_name).is_none()
             {
                 associated_type_imports.push(Import {
                     types: vec![v_type_name],
                     global_module_reference: GlobalModuleReference {
-                        module_reference: m_hdr.borrow().name.clone(),
-                        assigned_identifier: match &m_hdr.borrow().module_identifier {
+                        module_reference: m_hdr.read().name.clone(),
+                        assigned_identifier: match &m_hdr.read().module_identifier {
                             Some(DefinitiveIdentifier::DefinitiveOID(oid))
                             | Some(DefinitiveIdentifier::DefinitiveOIDandIRI { oid, .. }) => {
                                 AssignedIdentifier::ObjectIdentifierValue(oid.clone())
@@ -280,7 +344,7 @@ impl Validator {
     fn associated_import_type_class_field(
         &self,
         field: &InformationObjectClassField,
-        module_header: Rc<RefCell<ModuleHeader>>,
+        module_header: SharedModuleHeader,
         associated_type_imports: &mut Vec<Import>,
     ) {
         if let Some(ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
@@ -326,7 +390,7 @@ impl Validator {
             .map(|t| match t {
                 ToplevelDefinition::Type(t) => t.ty.references_class_by_name(),
                 ToplevelDefinition::Object(i) => match i.class {
-                    ClassLink::ByReference(_) => false,
+                    ClassLink::ByReference(_) | ClassLink::ByInstantiation(..) => false,
                     ClassLink::ByName(_) => true,
                 },
                 _ => false,
@@ -369,18 +433,85 @@ impl Validator {
     pub fn validate(
         mut self,
     ) -> Result<(Vec<ToplevelDefinition>, Vec<CompilerError>), CompilerError> {
-        let warnings: Vec<CompilerError>;
-        (self, warnings) = self.link()?;
-        Ok(self.tlds.into_iter().fold(
+        // Errors point to the definitions that they concern, which may be rejected
+        let spans = self
+            .tlds
+            .iter()
+            .filter_map(|(name, tld)| {
+                let span = tld.get_module_header()?.read().location(name)?;
+                Some((name.clone(), span))
+            })
+            .collect::<BTreeMap<_, _>>();
+        let mut unexported_imports =
+            exports::check_imports(&self.tlds)
+                .into_iter()
+                .filter(|error| match (&self.scope, &error.module) {
+                    (Some(scope), Some(module)) => scope.contains(module),
+                    _ => true,
+                });
+        let mut warnings: Vec<CompilerError> = match self.unexported_imports {
+            Severity::Error => match unexported_imports.next() {
+                Some(error) => return Err(error.into()),
+                None => vec![],
+            },
+            Severity::Warning => unexported_imports
+                .map(|error| error.with_severity(Severity::Warning).into())
+                .collect(),
+        };
+        let mut link_warnings;
+        (self, link_warnings) = self.link()?;
+        warnings.append(&mut link_warnings);
+        let scoped = self.scoped_keys();
+        let constraint_checker = ConstraintChecker::new(&self.tlds);
+        let tag_checker = TagChecker::new(&self.tlds);
+        let value_checker = ValueChecker::new(&self.tlds);
+        let invalid = self
+            .tlds
+            .iter()
+            .filter(|(key, _)| scoped.contains(*key))
+            .filter_map(|(key, tld)| {
+                constraint_checker
+                    .check(tld)
+                    .and_then(|_| tag_checker.check(tld))
+                    .and_then(|_| value_checker.check(tld))
+                    .err()
+                    .map(|e| (key.clone(), e))
+            })
+            .collect::<Vec<_>>();
+        for (key, e) in invalid {
+            self.tlds.remove(&key);
+            warnings.push(e.into());
+        }
+        // Definitions are returned in source order, followed by the instances of parameterized definitions
+        let mut ordered = Vec::with_capacity(self.tlds.len());
+        for name in &self.source_order {
+            if let Some(tld) = self.tlds.remove(name) {
+                ordered.push(tld);
+            }
+        }
+        ordered.extend(self.tlds.into_values());
+        ordered.retain(|tld| scoped.contains(tld.name()));
+        let (tlds, mut errors) = ordered.into_iter().fold(
             (Vec::<ToplevelDefinition>::new(), warnings),
-            |(mut tlds, mut errors), (_, tld)| {
+            |(mut tlds, mut errors), tld| {
                 match tld.validate() {
                     Ok(_) => tlds.push(tld),
                     Err(e) => errors.push(e.into()),
                 }
                 (tlds, errors)
             },
-        ))
+        );
+        for error in &mut errors {
+            let (pdu, span) = match error {
+                CompilerError::Linker(e) => (&e.pdu, &mut e.span),
+                CompilerError::Grammar(e) => (&e.pdu, &mut e.span),
+                _ => continue,
+            };
+            if span.is_none() {
+                *span = pdu.as_ref().and_then(|pdu| spans.get(pdu)).cloned();
+            }
+        }
+        Ok((tlds, errors))
     }
 }
 
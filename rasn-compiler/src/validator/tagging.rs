@@ -0,0 +1,334 @@
+//! Checks that the tags of the alternatives of a `CHOICE`, the components of a `SET`
+//! and the optional components of a `SEQUENCE` are distinct (X.680 §25.5, §25.6, §27.3, §29.2).
+//! Otherwise a decoder cannot tell which alternative or component it is decoding.
+
+use std::collections::BTreeMap;
+
+use crate::intermediate::{types::*, *};
+
+use super::error::{LinkerError, LinkerErrorType};
+
+/// Maximum number of type references that are followed to determine the tags of a type
+const MAX_REFERENCE_DEPTH: usize = 32;
+
+/// Class and number of a tag. The tagging mode does not affect the distinctness of tags.
+type Tag = (TagClass, u64);
+
+/// A component or alternative with the tags it may be encoded with.
+/// `None` if the tags cannot be determined, e.g. for open types.
+struct Tagged<'a> {
+    name: &'a str,
+    tags: Option<Vec<Tag>>,
+    optional: bool,
+}
+
+pub(super) struct TagChecker<'a> {
+    tlds: &'a BTreeMap<String, ToplevelDefinition>,
+}
+
+impl<'a> TagChecker<'a> {
+    pub fn new(tlds: &'a BTreeMap<String, ToplevelDefinition>) -> Self {
+        Self { tlds }
+    }
+
+    /// Checks the tags of a top-level type definition and of the types nested in it.
+    pub fn check(&self, tld: &ToplevelDefinition) -> Result<(), LinkerError> {
+        match tld {
+            ToplevelDefinition::Type(t) => {
+                self.check_type(&t.ty, environment(tld), "")
+                    .map_err(|mut e| {
+                        e.contextualize(&t.name);
+                        e
+                    })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn check_type(
+        &self,
+        ty: &ASN1Type,
+        environment: TaggingEnvironment,
+        path: &str,
+    ) -> Result<(), LinkerError> {
+        match ty {
+            ASN1Type::Choice(c) => {
+                let options = c
+                    .options
+                    .iter()
+                    .map(|o| (o.name.as_str(), o.tag.as_ref(), &o.ty))
+                    .collect::<Vec<_>>();
+                let root = c.extensible.unwrap_or(options.len());
+                if let Some(tagged) = self.tagged(&options, root, environment, 0) {
+                    all_distinct(&tagged, "Alternatives", CHOICE, path)?;
+                }
+                for (name, _, ty) in options {
+                    self.check_type(ty, environment, &member_path(path, name))?;
+                }
+                Ok(())
+            }
+            ASN1Type::Set(s) | ASN1Type::Sequence(s) => {
+                let members = s
+                    .members
+                    .iter()
+                    .map(|m| (m.name.as_str(), m.tag.as_ref(), &m.ty))
+                    .collect::<Vec<_>>();
+                let root = s.extensible.unwrap_or(members.len());
+                if let Some(mut tagged) = self.tagged(&members, root, environment, 0) {
+                    if matches!(ty, ASN1Type::Set(_)) {
+                        all_distinct(&tagged, "Components", SET, path)?;
+                    } else {
+                        for (member, tagged) in s.members.iter().zip(tagged.iter_mut()) {
+                            tagged.optional |= !matches!(member.optionality, Optionality::Required);
+                        }
+                        optional_runs_distinct(&tagged, path)?;
+                    }
+                }
+                for (name, _, ty) in members {
+                    self.check_type(ty, environment, &member_path(path, name))?;
+                }
+                Ok(())
+            }
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+                self.check_type(&s.element_type, environment, path)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Determines the tags of components or alternatives, of which the first `root` ones
+    /// form the extension root. Extension additions are marked as optional.
+    /// Returns `None` if the tags are assigned automatically and therefore distinct.
+    fn tagged<'m>(
+        &self,
+        members: &[(&'m str, Option<&AsnTag>, &ASN1Type)],
+        root: usize,
+        environment: TaggingEnvironment,
+        depth: usize,
+    ) -> Option<Vec<Tagged<'m>>> {
+        if is_automatic(members, root, environment) {
+            return None;
+        }
+        Some(
+            members
+                .iter()
+                .enumerate()
+                .map(|(i, (name, tag, ty))| Tagged {
+                    name,
+                    tags: match tag {
+                        Some(tag) => Some(vec![(tag.tag_class, tag.id)]),
+                        None => self.tags(ty, environment, depth),
+                    },
+                    optional: i >= root,
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the tags a value of an untagged type may be encoded with.
+    /// An untagged `CHOICE` has the tags of all its alternatives.
+    fn tags(
+        &self,
+        ty: &ASN1Type,
+        environment: TaggingEnvironment,
+        depth: usize,
+    ) -> Option<Vec<Tag>> {
+        if let Some(number) = universal_tag(ty) {
+            return Some(vec![(TagClass::Universal, number)]);
+        }
+        match ty {
+            ASN1Type::Choice(c) => {
+                let root = c.extensible.unwrap_or(c.options.len());
+                let options = c
+                    .options
+                    .iter()
+                    .map(|o| (o.name.as_str(), o.tag.as_ref(), &o.ty))
+                    .collect::<Vec<_>>();
+                if is_automatic(&options, root, environment) {
+                    return Some(
+                        (0..options.len() as u64)
+                            .map(|id| (TagClass::ContextSpecific, id))
+                            .collect(),
+                    );
+                }
+                options
+                    .iter()
+                    .map(|(_, tag, ty)| match tag {
+                        Some(tag) => Some(vec![(tag.tag_class, tag.id)]),
+                        None => self.tags(ty, environment, depth),
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .map(|tags| tags.concat())
+            }
+            ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+                parent: None,
+                identifier,
+                ..
+            }) if depth < MAX_REFERENCE_DEPTH => match self.tlds.get(identifier)? {
+                ToplevelDefinition::Type(t) if t.parameterization.is_none() => match &t.tag {
+                    Some(tag) => Some(vec![(tag.tag_class, tag.id)]),
+                    None => self.tags(&t.ty, environment_of(t.module_header.as_ref()), depth + 1),
+                },
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+fn environment(tld: &ToplevelDefinition) -> TaggingEnvironment {
+    environment_of(tld.get_module_header().as_ref())
+}
+
+fn environment_of(header: Option<&SharedModuleHeader>) -> TaggingEnvironment {
+    header.map_or(TaggingEnvironment::Explicit, |h| {
+        h.read().tagging_environment
+    })
+}
+
+/// Returns the effective tags of components or alternatives, of which the first `root` ones
+/// form the extension root: the tags assigned in textual order if automatic tagging applies,
+/// and the declared tags otherwise. `None` for untagged components.
+pub(crate) fn effective_tags(
+    members: &[(&str, Option<&AsnTag>, &ASN1Type)],
+    root: usize,
+    environment: TaggingEnvironment,
+) -> Vec<Option<AsnTag>> {
+    let automatic = is_automatic(members, root, environment);
+    members
+        .iter()
+        .enumerate()
+        .map(|(i, (_, tag, _))| {
+            if automatic {
+                Some(AsnTag {
+                    environment: TaggingEnvironment::Automatic,
+                    tag_class: TagClass::ContextSpecific,
+                    id: i as u64,
+                })
+            } else {
+                tag.cloned()
+            }
+        })
+        .collect()
+}
+
+/// Automatic tagging applies if the module has `AUTOMATIC TAGS`
+/// and none of the components of the extension root is tagged (X.680 §25.3).
+fn is_automatic(
+    members: &[(&str, Option<&AsnTag>, &ASN1Type)],
+    root: usize,
+    environment: TaggingEnvironment,
+) -> bool {
+    environment == TaggingEnvironment::Automatic
+        && members.iter().take(root).all(|(_, tag, _)| tag.is_none())
+}
+
+/// Number of the universal tag of a type, or `None` for types without a tag of their own
+fn universal_tag(ty: &ASN1Type) -> Option<u64> {
+    Some(match ty {
+        ASN1Type::Boolean(_) => 1,
+        ASN1Type::Integer(_) => 2,
+        ASN1Type::BitString(_) => 3,
+        ASN1Type::OctetString(_) => 4,
+        ASN1Type::Null => 5,
+        ASN1Type::ObjectIdentifier(_) => 6,
+        ASN1Type::ObjectDescriptor(_) => 7,
+        ASN1Type::External => 8,
+        ASN1Type::Real(_) => 9,
+        ASN1Type::Enumerated(_) => 10,
+        ASN1Type::EmbeddedPdv => 11,
+        ASN1Type::RelativeOid(_) => 13,
+        ASN1Type::Time(_) => 14,
+        ASN1Type::Sequence(_) | ASN1Type::SequenceOf(_) => 16,
+        ASN1Type::Set(_) | ASN1Type::SetOf(_) => 17,
+        ASN1Type::UTCTime(_) => 23,
+        ASN1Type::GeneralizedTime(_) => 24,
+        ASN1Type::UnrestrictedCharacterString => 29,
+        ASN1Type::OidIri(_) => 35,
+        ASN1Type::RelativeOidIri(_) => 36,
+        ASN1Type::CharacterString(c) => match c.ty {
+            CharacterStringType::UTF8String => 12,
+            CharacterStringType::NumericString => 18,
+            CharacterStringType::PrintableString => 19,
+            CharacterStringType::TeletexString => 20,
+            CharacterStringType::VideotexString => 21,
+            CharacterStringType::IA5String => 22,
+            CharacterStringType::GraphicString => 25,
+            CharacterStringType::VisibleString => 26,
+            CharacterStringType::GeneralString => 27,
+            CharacterStringType::UniversalString => 28,
+            CharacterStringType::BMPString => 30,
+        },
+        _ => return None,
+    })
+}
+
+/// Checks that all components of a `SET` or alternatives of a `CHOICE` have distinct tags.
+fn all_distinct(tagged: &[Tagged], noun: &str, ty: &str, path: &str) -> Result<(), LinkerError> {
+    for (i, first) in tagged.iter().enumerate() {
+        for second in &tagged[i + 1..] {
+            if let Some(tag) = shared_tag(first, second) {
+                return Err(ambiguous(format!(
+                    "{noun} `{}` and `{}` of {ty} share the tag {}",
+                    member_path(path, first.name),
+                    member_path(path, second.name),
+                    tag_notation(tag)
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the tags of every run of optional components of a `SEQUENCE`
+/// are distinct from each other and from the tags of the component following the run.
+fn optional_runs_distinct(tagged: &[Tagged], path: &str) -> Result<(), LinkerError> {
+    let mut run: Vec<&Tagged> = vec![];
+    for component in tagged {
+        if component.tags.is_some() {
+            if let Some((optional, tag)) = run
+                .iter()
+                .find_map(|o| shared_tag(o, component).map(|tag| (o, tag)))
+            {
+                return Err(ambiguous(format!(
+                    "Optional component `{}` and component `{}` of {SEQUENCE} share the tag {}",
+                    member_path(path, optional.name),
+                    member_path(path, component.name),
+                    tag_notation(tag)
+                )));
+            }
+        }
+        if component.optional {
+            run.push(component);
+        } else {
+            run.clear();
+        }
+    }
+    Ok(())
+}
+
+fn shared_tag(first: &Tagged, second: &Tagged) -> Option<Tag> {
+    let (first, second) = first.tags.as_ref().zip(second.tags.as_ref())?;
+    first.iter().find(|tag| second.contains(tag)).copied()
+}
+
+fn ambiguous(details: String) -> LinkerError {
+    LinkerError::new(None, &details, LinkerErrorType::AmbiguousTagsError)
+}
+
+fn member_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+fn tag_notation((class, id): Tag) -> String {
+    match class {
+        TagClass::Universal => format!("[UNIVERSAL {id}]"),
+        TagClass::Application => format!("[APPLICATION {id}]"),
+        TagClass::Private => format!("[PRIVATE {id}]"),
+        TagClass::ContextSpecific => format!("[{id}]"),
+    }
+}
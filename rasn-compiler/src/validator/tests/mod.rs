@@ -1,2 +1,361 @@
-#[allow(unused_imports)]
-use super::*;
+//! Tests of the validation of single modules, which share a module fixture.
+use crate::{
+    error::CompilerError, intermediate::ToplevelDefinition, validate_sources, AsnSource,
+    ValidationOptions,
+};
+
+/// Validates a module `Example` with the given tagging environment and assignments,
+/// returning the valid definitions and the errors and warnings of the validation.
+fn validate(tagging: &str, body: &str) -> (Vec<ToplevelDefinition>, Vec<CompilerError>) {
+    validate_sources(
+        &[AsnSource::Literal(format!(
+            "Example DEFINITIONS {tagging} TAGS ::= BEGIN\n{body}\nEND"
+        ))],
+        &ValidationOptions::default(),
+    )
+    .unwrap()
+}
+
+fn messages(errors: &[CompilerError]) -> Vec<String> {
+    errors.iter().map(ToString::to_string).collect()
+}
+
+mod constraints {
+    use super::*;
+
+    fn invalid_constraints(body: &str) -> Vec<String> {
+        messages(&validate("AUTOMATIC", body).1)
+    }
+
+    #[test]
+    fn rejects_unsatisfiable_constraints() {
+        assert_eq!(
+            invalid_constraints(
+                "Small ::= INTEGER (0..10)
+                Empty ::= Small (20..30)"
+            ),
+            vec!["InvalidConstraintsError validating PDU Empty: The constraints of `Empty` admit no values"]
+        );
+        assert_eq!(
+            invalid_constraints("Name ::= IA5String (SIZE (1..4) ^ SIZE (6..8))"),
+            vec!["InvalidConstraintsError validating PDU Name: The constraints of `Name` admit no sizes"]
+        );
+        assert_eq!(
+            invalid_constraints(
+                "Message ::= SEQUENCE { digits NumericString (FROM (\"a\"..\"z\")) }"
+            ),
+            vec!["InvalidConstraintsError validating PDU Message: The constraints of `Message.digits` admit no characters"]
+        );
+        assert!(invalid_constraints(
+            "Small ::= INTEGER (0..10)
+            Extended ::= Small (20..30, ...)
+            Union ::= INTEGER ((0..10) | (20..30))
+            Except ::= INTEGER (0..10 EXCEPT 5)"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn rejects_nested_extension_markers() {
+        assert_eq!(
+            invalid_constraints("Nested ::= INTEGER ((0..10, ...) | 20)"),
+            vec!["InvalidConstraintsError validating PDU Nested: The constraint `(0..10, ... | 20)` contains an extension marker inside set arithmetic"]
+        );
+    }
+}
+
+mod instantiation {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::{
+        generator::asn1::notation::{type_notation, value_notation},
+        intermediate::information_object::*,
+    };
+
+    fn definitions(body: &str) -> BTreeMap<String, ToplevelDefinition> {
+        let (tlds, warnings) = validate("AUTOMATIC", body);
+        assert!(warnings.is_empty(), "{warnings:?}");
+        tlds.into_iter()
+            .map(|tld| (tld.name().clone(), tld))
+            .collect()
+    }
+
+    fn value(tld: &ToplevelDefinition) -> String {
+        match tld {
+            ToplevelDefinition::Value(v) => value_notation(&v.value),
+            _ => panic!("{} is not a value", tld.name()),
+        }
+    }
+
+    fn ty(tld: &ToplevelDefinition) -> String {
+        match tld {
+            ToplevelDefinition::Type(t) => type_notation(&t.ty, 0),
+            _ => panic!("{} is not a type", tld.name()),
+        }
+    }
+
+    fn object_set_size(tld: &ToplevelDefinition) -> usize {
+        match tld {
+            ToplevelDefinition::Object(ToplevelInformationDefinition {
+                value: ASN1Information::ObjectSet(set),
+                ..
+            }) => set.values.len(),
+            _ => panic!("{} is not an object set", tld.name()),
+        }
+    }
+
+    fn invalid_parameterizations(body: &str) -> Vec<String> {
+        messages(&validate("AUTOMATIC", body).1)
+            .into_iter()
+            .filter(|w| w.starts_with("InvalidParameterization"))
+            .collect()
+    }
+
+    #[test]
+    fn checks_actual_parameters() {
+        assert_eq!(
+            invalid_parameterizations(
+                "Container { ElementType, INTEGER: maxSize } ::= SEQUENCE (SIZE (1..maxSize)) OF ElementType
+                Message ::= SEQUENCE { ids Container { INTEGER } }"
+            ),
+            vec!["InvalidParameterization validating PDU Message: `Container` expects 2 actual parameters, but 1 were given"]
+        );
+        assert!(invalid_parameterizations(
+            "Container { ElementType, INTEGER: maxSize } ::= SEQUENCE (SIZE (1..maxSize)) OF ElementType
+            Message ::= SEQUENCE { ids Container { INTEGER, 8 } }"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn instantiates_parameterized_values() {
+        let definitions = definitions(
+            "genValue { INTEGER: x } INTEGER ::= x
+            value INTEGER ::= genValue { 7 }
+            Message ::= SEQUENCE { a INTEGER DEFAULT genValue { 9 } }",
+        );
+        assert_eq!(value(&definitions["value"]), "7");
+        assert_eq!(value(&definitions["genValue-9"]), "9");
+        assert_eq!(
+            ty(&definitions["Message"]),
+            "SEQUENCE {\n    a INTEGER DEFAULT genValue-9\n}"
+        );
+    }
+
+    #[test]
+    fn instantiates_parameterized_value_sets() {
+        let definitions = definitions(
+            "Small { INTEGER: max } INTEGER ::= { 1 | max }
+            Three ::= Small { 3 }
+            Message ::= SEQUENCE { a INTEGER (Small { 4 }) }",
+        );
+        assert_eq!(ty(&definitions["Three"]), "INTEGER (1 | 3)");
+        assert_eq!(ty(&definitions["Small-4"]), "INTEGER (1 | 4)");
+    }
+
+    #[test]
+    fn instantiates_parameterized_object_sets() {
+        let definitions = definitions(
+            "EXTENSION ::= CLASS { &id INTEGER UNIQUE, &Type } WITH SYNTAX { ID &id TYPE &Type }
+            Extensions { EXTENSION: extension } EXTENSION ::= { extension | { ID 1 TYPE BOOLEAN } }
+            counter EXTENSION ::= { ID 2 TYPE INTEGER }
+            label EXTENSION ::= { ID 3 TYPE UTF8String }
+            MessageExtensions EXTENSION ::= { Extensions { counter } }
+            Message ::= SEQUENCE {
+                id EXTENSION.&id ({Extensions {label}}),
+                value EXTENSION.&Type ({Extensions {label}}{@id})
+            }",
+        );
+        assert_eq!(object_set_size(&definitions["MessageExtensions"]), 2);
+        assert_eq!(object_set_size(&definitions["Extensions-label"]), 2);
+    }
+
+    #[test]
+    fn instantiates_parameterized_classes() {
+        let definitions = definitions(
+            "SETTING { ValueType } ::= CLASS { &id INTEGER UNIQUE, &value ValueType }
+                WITH SYNTAX { ID &id VAL &value }
+            enabled SETTING { BOOLEAN } ::= { ID 1 VAL TRUE }
+            Message ::= SEQUENCE { value SETTING { BOOLEAN }.&value }",
+        );
+        assert!(matches!(
+            definitions["SETTING-BOOLEAN"],
+            ToplevelDefinition::Class(_)
+        ));
+        assert!(matches!(
+            &definitions["enabled"],
+            ToplevelDefinition::Object(ToplevelInformationDefinition {
+                value: ASN1Information::Object(InformationObject { class_name, .. }),
+                ..
+            }) if class_name == "SETTING-BOOLEAN"
+        ));
+        assert_eq!(
+            ty(&definitions["Message"]),
+            "SEQUENCE {\n    value BOOLEAN\n}"
+        );
+    }
+}
+
+mod tagging {
+    use super::*;
+    use crate::prelude::Severity;
+
+    fn ambiguities(tagging: &str, body: &str) -> Vec<String> {
+        messages(&validate(tagging, body).1)
+    }
+
+    #[test]
+    fn reports_ambiguous_tags_as_errors() {
+        let (definitions, errors) = validate(
+            "AUTOMATIC",
+            "S ::= SEQUENCE { a [0] INTEGER OPTIONAL, b [0] INTEGER }",
+        );
+        assert!(definitions.is_empty());
+        assert_eq!(errors[0].severity(), Severity::Error);
+    }
+
+    #[test]
+    fn rejects_ambiguous_choice_and_set_tags() {
+        assert_eq!(
+            ambiguities(
+                "IMPLICIT",
+                "Choice ::= CHOICE { a [0] NULL, b BOOLEAN, ..., c [0] INTEGER }"
+            ),
+            vec!["AmbiguousTagsError validating PDU Choice: Alternatives `a` and `c` of CHOICE share the tag [0]"]
+        );
+        assert_eq!(
+            ambiguities(
+                "EXPLICIT",
+                "Inner ::= CHOICE { flag BOOLEAN, id [APPLICATION 1] INTEGER }
+                Set ::= SET { inner Inner, id [APPLICATION 1] INTEGER }"
+            ),
+            vec!["AmbiguousTagsError validating PDU Set: Components `inner` and `id` of SET share the tag [APPLICATION 1]"]
+        );
+        assert!(ambiguities(
+            "IMPLICIT",
+            "Choice ::= CHOICE { a NULL, b BOOLEAN, c SEQUENCE OF NULL, d SET OF NULL }"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn rejects_ambiguous_optional_sequence_tags() {
+        assert_eq!(
+            ambiguities(
+                "IMPLICIT",
+                "Sequence ::= SEQUENCE { a INTEGER OPTIONAL, b BOOLEAN DEFAULT TRUE, c INTEGER }"
+            ),
+            vec!["AmbiguousTagsError validating PDU Sequence: Optional component `a` and component `c` of SEQUENCE share the tag [UNIVERSAL 2]"]
+        );
+        assert_eq!(
+            ambiguities(
+                "EXPLICIT",
+                "Sequence ::= SEQUENCE { outer SEQUENCE { a [1] INTEGER OPTIONAL, ..., b [1] INTEGER } }"
+            ),
+            vec!["AmbiguousTagsError validating PDU Sequence: Optional component `outer.a` and component `outer.b` of SEQUENCE share the tag [1]"]
+        );
+        assert!(ambiguities(
+            "IMPLICIT",
+            "Sequence ::= SEQUENCE { a INTEGER OPTIONAL, b BOOLEAN, c INTEGER OPTIONAL }"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn accepts_automatic_tags() {
+        assert!(ambiguities(
+            "AUTOMATIC",
+            "Choice ::= CHOICE { a INTEGER, b INTEGER, ..., c INTEGER }
+            Sequence ::= SEQUENCE { a INTEGER OPTIONAL, b INTEGER }"
+        )
+        .is_empty());
+        assert_eq!(
+            ambiguities(
+                "AUTOMATIC",
+                "Sequence ::= SEQUENCE { a [0] INTEGER OPTIONAL, b INTEGER OPTIONAL, c [UNIVERSAL 2] INTEGER }"
+            ),
+            vec!["AmbiguousTagsError validating PDU Sequence: Optional component `b` and component `c` of SEQUENCE share the tag [UNIVERSAL 2]"]
+        );
+    }
+}
+
+mod values {
+    use super::*;
+
+    fn invalid_values(body: &str) -> Vec<String> {
+        warnings(body)
+            .into_iter()
+            .filter(|w| w.starts_with("InvalidValueError"))
+            .collect()
+    }
+
+    fn warnings(body: &str) -> Vec<String> {
+        messages(&validate("AUTOMATIC", body).1)
+    }
+
+    #[test]
+    fn checks_default_values() {
+        assert_eq!(
+            invalid_values(
+                "Counter ::= INTEGER (0..255)
+                Message ::= SEQUENCE { count Counter DEFAULT 300, flag BOOLEAN DEFAULT 1 }"
+            ),
+            vec!["InvalidValueError validating PDU Message: Value `300` of `count` violates the constraint `(0..255)`"]
+        );
+        assert_eq!(
+            invalid_values("Message ::= SEQUENCE { flag BOOLEAN DEFAULT 1 }"),
+            vec!["InvalidValueError validating PDU Message: Value `1` of `flag` is a INTEGER value, but BOOLEAN was expected"]
+        );
+        assert!(invalid_values(
+            "Counter ::= INTEGER (0..255, ...)
+            Message ::= SEQUENCE { count Counter DEFAULT 300, name IA5String DEFAULT \"abc\" }"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn checks_value_assignments() {
+        assert_eq!(
+            invalid_values(
+                "Name ::= IA5String (SIZE (1..4))
+                name Name ::= \"abcde\""
+            ),
+            vec!["InvalidValueError validating PDU name: Value `\"abcde\"` violates the constraint `(SIZE (1..4))`"]
+        );
+        assert_eq!(
+            invalid_values(
+                "Digits ::= PrintableString (FROM (\"0\"..\"9\"))
+                digits Digits ::= \"12a\""
+            ),
+            vec!["InvalidValueError validating PDU digits: Value `\"12a\"` violates the constraint `(FROM (\"0\"..\"9\"))`"]
+        );
+        assert_eq!(
+            invalid_values(
+                "Choice ::= CHOICE { a INTEGER, b BOOLEAN }
+                choice Choice ::= c: 5"
+            ),
+            vec!["InvalidValueError validating PDU choice: Value `c: 5` selects the alternative `c`, which CHOICE does not define"]
+        );
+        assert_eq!(
+            invalid_values(
+                "Enm ::= ENUMERATED { a, b, ..., c }
+                e Enm ::= d
+                valid Enm ::= c"
+            ),
+            vec!["InvalidValueError validating PDU e: Value `d` is not an enumeral of ENUMERATED"]
+        );
+    }
+
+    #[test]
+    fn checks_sequence_completeness() {
+        assert_eq!(
+            warnings(
+                "Message ::= SEQUENCE { id INTEGER (0..7), note UTF8String OPTIONAL, ..., extra BOOLEAN }
+                incomplete Message ::= { note \"hi\" }
+                valid Message ::= { id 7 }"
+            ),
+            vec!["InvalidValueError validating PDU incomplete: Value `{ note \"hi\" }` lacks the mandatory component `id`"]
+        );
+    }
+}
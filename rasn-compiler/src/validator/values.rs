@@ -0,0 +1,464 @@
+//! Checks value assignments and `DEFAULT` values against their governing types.
+//! A value must be of the kind its type expects, satisfy the subtype constraints
+//! of the type and of the types it references, and name only existing
+//! components, alternatives and enumerals. `SEQUENCE` and `SET` values
+//! must contain all mandatory components of the extension root.
+//! Constraints and values that cannot be evaluated, e.g. because they
+//! reference parameters or extensible constraints, are accepted.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    generator::asn1::notation::{element_set_specs_notation, value_notation},
+    intermediate::{
+        constraints::*,
+        effective_constraints::{character_set, EffectiveConstraint},
+        types::*,
+        utils::saturating_i128,
+        *,
+    },
+};
+
+use super::error::{LinkerError, LinkerErrorType};
+
+/// Maximum number of type and value references that are followed while checking a value
+const MAX_REFERENCE_DEPTH: usize = 32;
+
+/// What a subtype element is applied to
+#[derive(Clone, Copy)]
+enum Subject<'v> {
+    Integer(i128),
+    String(&'v str),
+    /// A value of which only the size can be constrained
+    Sized(usize),
+}
+
+pub(super) struct ValueChecker<'a> {
+    tlds: &'a BTreeMap<String, ToplevelDefinition>,
+}
+
+impl<'a> ValueChecker<'a> {
+    pub fn new(tlds: &'a BTreeMap<String, ToplevelDefinition>) -> Self {
+        Self { tlds }
+    }
+
+    /// Checks a value assignment or the `DEFAULT` values of a type definition.
+    pub fn check(&self, tld: &ToplevelDefinition) -> Result<(), LinkerError> {
+        let result = match tld {
+            ToplevelDefinition::Value(v) if v.parameterization.is_none() => {
+                self.check_value(&v.value, &v.associated_type, "", vec![], 0)
+            }
+            ToplevelDefinition::Type(t) if t.parameterization.is_none() => {
+                self.check_defaults(&t.ty, "")
+            }
+            _ => Ok(()),
+        };
+        result.map_err(|mut e| {
+            e.contextualize(tld.name());
+            e
+        })
+    }
+
+    fn check_defaults(&self, ty: &ASN1Type, path: &str) -> Result<(), LinkerError> {
+        match ty {
+            ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+                for member in &s.members {
+                    let path = member_path(path, &member.name);
+                    if let Optionality::Default(value) = &member.optionality {
+                        let constraints = member.constraints.iter().collect();
+                        self.check_value(value, &member.ty, &path, constraints, 0)?;
+                    }
+                    self.check_defaults(&member.ty, &path)?;
+                }
+                Ok(())
+            }
+            ASN1Type::Choice(c) => c
+                .options
+                .iter()
+                .try_for_each(|o| self.check_defaults(&o.ty, &member_path(path, &o.name))),
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+                self.check_defaults(&s.element_type, path)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks a value against a type, collecting the constraints
+    /// of the type and of the types it references on the way.
+    fn check_value<'t>(
+        &self,
+        value: &ASN1Value,
+        ty: &'t ASN1Type,
+        path: &str,
+        mut constraints: Vec<&'t Constraint>,
+        depth: usize,
+    ) -> Result<(), LinkerError>
+    where
+        'a: 't,
+    {
+        if depth > MAX_REFERENCE_DEPTH {
+            return Ok(());
+        }
+        constraints.extend(ty.constraints().into_iter().flatten());
+        if let ASN1Type::ElsewhereDeclaredType(DeclarationElsewhere {
+            parent: None,
+            identifier,
+            ..
+        }) = ty
+        {
+            return match self.tlds.get(identifier) {
+                Some(ToplevelDefinition::Type(t)) if t.parameterization.is_none() => {
+                    self.check_value(value, &t.ty, path, constraints, depth + 1)
+                }
+                _ => Ok(()),
+            };
+        }
+        let value = match self.resolve(value, ty, depth) {
+            Some(value) => value,
+            None => return Ok(()),
+        };
+
+        match (ty, value) {
+            (ASN1Type::Integer(i), value) => {
+                let integer = match value {
+                    ASN1Value::Integer(v) | ASN1Value::LinkedIntValue { value: v, .. } => {
+                        Some(saturating_i128(v))
+                    }
+                    ASN1Value::ElsewhereDeclaredValue {
+                        parent: None,
+                        identifier,
+                    } => i
+                        .distinguished_values
+                        .iter()
+                        .flatten()
+                        .find_map(|d| (&d.name == identifier).then_some(saturating_i128(&d.value))),
+                    _ => return self.expect_kind(value, ty, path, &["INTEGER"]),
+                };
+                match integer {
+                    Some(integer) => {
+                        self.satisfies(value, Subject::Integer(integer), &constraints, path)
+                    }
+                    None => Ok(()),
+                }
+            }
+            (ASN1Type::CharacterString(c), value) => match value {
+                ASN1Value::String(s) | ASN1Value::LinkedCharStringValue(_, s) => {
+                    let character_set = character_set(c.ty);
+                    if let Some(character) = s.chars().find(|ch| {
+                        character_set
+                            .as_ref()
+                            .is_some_and(|set| !set.contains(*ch as i128))
+                    }) {
+                        return Err(invalid(format!(
+                            "{} contains the character {character:?}, which is not a {} character",
+                            subject(value, path),
+                            ty.as_str()
+                        )));
+                    }
+                    self.satisfies(value, Subject::String(s), &constraints, path)
+                }
+                _ => self.expect_kind(value, ty, path, &["character string"]),
+            },
+            (ASN1Type::OctetString(_), value) => match value {
+                ASN1Value::OctetString(o) => {
+                    self.satisfies(value, Subject::Sized(o.len()), &constraints, path)
+                }
+                _ => self.expect_kind(value, ty, path, &["OCTET STRING", "BIT STRING"]),
+            },
+            (ASN1Type::BitString(b), value) => match value {
+                ASN1Value::BitString(bits) if b.distinguished_values.is_none() => {
+                    self.satisfies(value, Subject::Sized(bits.len()), &constraints, path)
+                }
+                ASN1Value::BitStringNamedBits(names) => {
+                    let distinguished = b.distinguished_values.iter().flatten();
+                    match names
+                        .iter()
+                        .find(|n| !distinguished.clone().any(|d| &&d.name == n))
+                    {
+                        Some(name) => Err(invalid(format!(
+                            "{} names the bit `{name}`, which {} does not define",
+                            subject(value, path),
+                            ty.as_str()
+                        ))),
+                        None => Ok(()),
+                    }
+                }
+                _ => self.expect_kind(value, ty, path, &["BIT STRING", "OCTET STRING"]),
+            },
+            (ASN1Type::Enumerated(e), value) => match value {
+                ASN1Value::EnumeratedValue {
+                    enumerable: identifier,
+                    ..
+                }
+                | ASN1Value::ElsewhereDeclaredValue {
+                    parent: None,
+                    identifier,
+                }
+                | ASN1Value::LinkedElsewhereDefinedValue {
+                    parent: None,
+                    identifier,
+                    ..
+                } if !e.members.iter().any(|m| &m.name == identifier) => {
+                    Err(invalid(format!(
+                        "{} is not an enumeral of {}",
+                        subject(value, path),
+                        ty.as_str()
+                    )))
+                }
+                _ => self.expect_kind(value, ty, path, &["ENUMERATED"]),
+            },
+            (ASN1Type::Choice(c), value) => match value {
+                ASN1Value::Choice {
+                    variant_name,
+                    inner_value,
+                    ..
+                } => match c.options.iter().find(|o| &o.name == variant_name) {
+                    Some(option) => self.check_value(
+                        inner_value,
+                        &option.ty,
+                        &member_path(path, variant_name),
+                        vec![],
+                        depth + 1,
+                    ),
+                    None => Err(invalid(format!(
+                        "{} selects the alternative `{variant_name}`, which {CHOICE} does not define",
+                        subject(value, path)
+                    ))),
+                },
+                _ => self.expect_kind(value, ty, path, &["CHOICE"]),
+            },
+            (ASN1Type::Sequence(s) | ASN1Type::Set(s), value) => match value {
+                ASN1Value::SequenceOrSet(components) => {
+                    self.check_components(value, components, s, ty, path, depth)
+                }
+                ASN1Value::LinkedStructLikeValue(fields) => {
+                    for (name, _, field_value) in fields {
+                        if let (StructLikeFieldValue::Explicit(v), Some(member)) =
+                            (field_value, s.members.iter().find(|m| &m.name == name))
+                        {
+                            self.check_value(
+                                v,
+                                &member.ty,
+                                &member_path(path, name),
+                                vec![],
+                                depth + 1,
+                            )?;
+                        }
+                    }
+                    Ok(())
+                }
+                _ => self.expect_kind(value, ty, path, &[]),
+            },
+            (ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s), value) => {
+                let items = match value {
+                    ASN1Value::SequenceOrSet(items) => {
+                        items.iter().map(|(_, item)| &**item).collect::<Vec<_>>()
+                    }
+                    ASN1Value::LinkedArrayLikeValue(items) => items.iter().map(|i| &**i).collect(),
+                    _ => return self.expect_kind(value, ty, path, &[]),
+                };
+                self.satisfies(value, Subject::Sized(items.len()), &constraints, path)?;
+                items.into_iter().enumerate().try_for_each(|(i, item)| {
+                    self.check_value(
+                        item,
+                        &s.element_type,
+                        &format!("{path}[{i}]"),
+                        vec![],
+                        depth + 1,
+                    )
+                })
+            }
+            (ASN1Type::Null, value) => self.expect_kind(value, ty, path, &["NULL"]),
+            (ASN1Type::Boolean(_), value) => self.expect_kind(value, ty, path, &["BOOLEAN"]),
+            (ASN1Type::Real(_), value) => self.expect_kind(value, ty, path, &["REAL", "INTEGER"]),
+            (
+                ASN1Type::Time(_) | ASN1Type::GeneralizedTime(_) | ASN1Type::UTCTime(_),
+                value,
+            ) => self.expect_kind(value, ty, path, &["character string"]),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks a value against each of the subtype constraints of its type.
+    fn satisfies(
+        &self,
+        value: &ASN1Value,
+        subject_value: Subject,
+        constraints: &[&Constraint],
+        path: &str,
+    ) -> Result<(), LinkerError> {
+        let resolve = |name: &str| match self.tlds.get(name) {
+            Some(ToplevelDefinition::Type(t)) if t.parameterization.is_none() => Some(&t.ty),
+            _ => None,
+        };
+        for constraint in constraints {
+            let Constraint::Subtype(set) = constraint else {
+                continue;
+            };
+            // Extension additions are not part of the intermediate representation,
+            // so values outside an extensible root may still be permitted.
+            let effective =
+                EffectiveConstraint::from_constraints(std::slice::from_ref(*constraint), &resolve);
+            let permitted = effective.extensible
+                || match subject_value {
+                    Subject::Integer(i) => effective.permits_integer(i),
+                    Subject::String(s) => effective.permits_string(s),
+                    Subject::Sized(size) => effective.permits_size(size),
+                };
+            if !permitted {
+                return Err(invalid(format!(
+                    "{} violates the constraint `({})`",
+                    subject(value, path),
+                    element_set_specs_notation(set, 0)
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the components of a `SEQUENCE` or `SET` value.
+    fn check_components(
+        &self,
+        value: &ASN1Value,
+        components: &[(Option<String>, Box<ASN1Value>)],
+        s: &SequenceOrSet,
+        ty: &ASN1Type,
+        path: &str,
+        depth: usize,
+    ) -> Result<(), LinkerError> {
+        let mut present = BTreeSet::new();
+        for (name, component) in components {
+            let Some(name) = name else {
+                continue;
+            };
+            let Some(member) = s.members.iter().find(|m| &m.name == name) else {
+                return Err(invalid(format!(
+                    "{} contains the component `{name}`, which {} does not define",
+                    subject(value, path),
+                    ty.as_str()
+                )));
+            };
+            if !present.insert(name) {
+                return Err(invalid(format!(
+                    "{} contains the component `{name}` more than once",
+                    subject(value, path)
+                )));
+            }
+            self.check_value(
+                component,
+                &member.ty,
+                &member_path(path, name),
+                vec![],
+                depth + 1,
+            )?;
+        }
+        let root = s.extensible.unwrap_or(s.members.len());
+        if present.is_empty() && !components.is_empty() {
+            // Values of sequence-of types and object identifiers cannot be told apart
+            return Ok(());
+        }
+        match s
+            .members
+            .iter()
+            .take(root)
+            .find(|m| matches!(m.optionality, Optionality::Required) && !present.contains(&m.name))
+        {
+            Some(missing) => Err(invalid(format!(
+                "{} lacks the mandatory component `{}`",
+                subject(value, path),
+                missing.name
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Resolves references to other value assignments. Returns `None` if the value
+    /// cannot be resolved, in which case the linker has already reported it.
+    fn resolve<'v>(
+        &self,
+        value: &'v ASN1Value,
+        ty: &ASN1Type,
+        depth: usize,
+    ) -> Option<&'v ASN1Value>
+    where
+        'a: 'v,
+    {
+        match value {
+            ASN1Value::LinkedNestedValue { value, .. } => self.resolve(value, ty, depth),
+            ASN1Value::ElsewhereDeclaredValue {
+                parent: None,
+                identifier,
+            }
+            | ASN1Value::LinkedElsewhereDefinedValue {
+                parent: None,
+                identifier,
+                ..
+            } if depth < MAX_REFERENCE_DEPTH => match self.tlds.get(identifier) {
+                Some(ToplevelDefinition::Value(v)) => self.resolve(&v.value, ty, depth + 1),
+                // Named numbers and enumerals are checked against the type
+                _ => matches!(ty, ASN1Type::Integer(_) | ASN1Type::Enumerated(_)).then_some(value),
+            },
+            ASN1Value::ElsewhereDeclaredValue { .. }
+            | ASN1Value::LinkedElsewhereDefinedValue { .. }
+            | ASN1Value::All => None,
+            value => Some(value),
+        }
+    }
+
+    /// Rejects values of a different kind than the type expects.
+    /// Values that could be of several kinds, such as braced values, are accepted.
+    fn expect_kind(
+        &self,
+        value: &ASN1Value,
+        ty: &ASN1Type,
+        path: &str,
+        expected: &[&str],
+    ) -> Result<(), LinkerError> {
+        match value_kind(value) {
+            Some(kind) if !expected.contains(&kind) => Err(invalid(format!(
+                "{} is a {kind} value, but {} was expected",
+                subject(value, path),
+                ty.as_str()
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The kind of values that can be told apart without knowing their type
+fn value_kind(value: &ASN1Value) -> Option<&'static str> {
+    match value {
+        ASN1Value::Null => Some("NULL"),
+        ASN1Value::Boolean(_) => Some("BOOLEAN"),
+        ASN1Value::Integer(_) | ASN1Value::LinkedIntValue { .. } => Some("INTEGER"),
+        ASN1Value::Real(_) => Some("REAL"),
+        ASN1Value::String(_) | ASN1Value::LinkedCharStringValue(..) | ASN1Value::Time(_) => {
+            Some("character string")
+        }
+        ASN1Value::BitString(_) => Some("BIT STRING"),
+        ASN1Value::OctetString(_) => Some("OCTET STRING"),
+        ASN1Value::Choice { .. } => Some("CHOICE"),
+        ASN1Value::EnumeratedValue { .. } => Some("ENUMERATED"),
+        _ => None,
+    }
+}
+
+fn subject(value: &ASN1Value, path: &str) -> String {
+    if path.is_empty() {
+        format!("Value `{}`", value_notation(value))
+    } else {
+        format!("Value `{}` of `{path}`", value_notation(value))
+    }
+}
+
+fn member_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+fn invalid(details: String) -> LinkerError {
+    LinkerError::new(None, &details, LinkerErrorType::InvalidValueError)
+}